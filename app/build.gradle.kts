@@ -126,6 +126,7 @@ configurations.all {
         force("com.fasterxml.jackson.core:jackson-annotations:2.19.2")
         force("com.fasterxml.jackson.dataformat:jackson-dataformat-smile:2.19.2")
         force("com.fasterxml.jackson.dataformat:jackson-dataformat-yaml:2.19.2")
+        force("com.fasterxml.jackson.dataformat:jackson-dataformat-toml:2.19.2")
     }
 }
 
@@ -146,6 +147,7 @@ dependencies {
     implementation(libs.jackson.databind)
     implementation(libs.jackson.smile)
     implementation(libs.jackson.yaml)
+    implementation(libs.jackson.toml)
     implementation(libs.jackson.jq)
     implementation(libs.lz4)
     implementation(libs.jspecify)
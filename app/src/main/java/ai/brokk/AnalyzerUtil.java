@@ -292,12 +292,12 @@ public class AnalyzerUtil {
     }
 
     /**
-     * Get source code for a code unit by fully qualified name. Currently, only methods and classes are supported. For
-     * overloaded methods, will combine sources for these as far as their fqNames match.
+     * Get source code for a code unit by fully qualified name. Currently, only methods, classes and macros are
+     * supported. For overloaded methods, will combine sources for these as far as their fqNames match.
      */
     public static Optional<String> getSource(IAnalyzer analyzer, String fqName, boolean includeComments) {
         List<CodeWithSource> allParts = analyzer.getDefinitions(fqName).stream()
-                .filter(cu -> cu.isFunction() || cu.isClass())
+                .filter(cu -> cu.isFunction() || cu.isClass() || cu.isMacro())
                 .flatMap(
                         cu -> analyzer.getSources(cu, includeComments).stream().map(src -> new CodeWithSource(src, cu)))
                 .toList();
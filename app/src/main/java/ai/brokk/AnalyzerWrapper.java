@@ -224,8 +224,7 @@ public class AnalyzerWrapper implements AbstractWatchService.Listener, IAnalyzer
         // Only consider tracked files that match our analyzer's language extensions
         var relevantFiles = batch.getFiles().stream()
                 .filter(finalTrackedFiles::contains) // Must be tracked by git
-                .filter(pf -> projectLanguages.stream()
-                        .anyMatch(L -> L.getExtensions().contains(pf.extension())))
+                .filter(pf -> projectLanguages.stream().anyMatch(L -> L.affectsAnalysis(pf)))
                 .collect(Collectors.toSet());
 
         // 3) Update analyzer for relevant files
@@ -0,0 +1,334 @@
+package ai.brokk;
+
+import static java.util.Objects.requireNonNull;
+
+import ai.brokk.EditBlock.EditResult;
+import ai.brokk.EditBlock.SearchReplaceBlock;
+import ai.brokk.analyzer.LintResult;
+import ai.brokk.analyzer.LintResult.LintDiagnostic;
+import ai.brokk.analyzer.LintResult.Suggestion;
+import ai.brokk.analyzer.LintResult.Suggestion.Applicability;
+import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.context.Context;
+import ai.brokk.io.ProjectFiles;
+import java.io.IOException;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.HashMap;
+import java.util.HashSet;
+import java.util.IdentityHashMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.function.Function;
+import java.util.stream.Collectors;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.jetbrains.annotations.Blocking;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * Applies the machine-applicable replacements of lint diagnostics (rustc and clippy mark these
+ * {@code MachineApplicable}) as SEARCH/REPLACE blocks through {@link EditBlock}, so that trivial build errors such as
+ * a missing {@code &} or an unused {@code mut} are fixed without spending an LLM turn.
+ *
+ * <p>A fix is the group of suggestions one diagnostic proposes under the same help message; its spans are applied
+ * together or not at all. Each span is checked against the lines the linter saw, so a fix is reported as conflicting
+ * rather than applied when the file has changed there since the lint ran, or when it overlaps a fix taken earlier.
+ * Fixes on neighbouring lines share one block, and each block is widened with surrounding lines until its SEARCH text
+ * is unique in the file.
+ */
+public final class SuggestionEdits {
+    private static final Logger logger = LogManager.getLogger(SuggestionEdits.class);
+
+    /** Maximum number of context lines added on each side of a block to make its SEARCH text unique. */
+    private static final int MAX_CONTEXT_LINES = 5;
+
+    private SuggestionEdits() {}
+
+    /** The suggestions a diagnostic proposes under one help message, applied as a unit. */
+    public record Fix(LintDiagnostic diagnostic, List<Suggestion> suggestions) {
+        public String description() {
+            String message = suggestions.getFirst().message();
+            String code = diagnostic.code().isEmpty() ? "" : " [" + diagnostic.code() + "]";
+            return "%s:%d: %s%s".formatted(diagnostic.file(), diagnostic.line(), message, code);
+        }
+    }
+
+    /**
+     * Outcome of one fix.
+     *
+     * @param conflict why the fix was not applied, or null if it was
+     */
+    public record FixOutcome(Fix fix, @Nullable String conflict) {
+        public boolean applied() {
+            return conflict == null;
+        }
+    }
+
+    public record Report(List<FixOutcome> outcomes, EditResult editResult) {
+        public List<FixOutcome> applied() {
+            return outcomes.stream().filter(FixOutcome::applied).toList();
+        }
+
+        public List<FixOutcome> conflicted() {
+            return outcomes.stream().filter(o -> !o.applied()).toList();
+        }
+
+        /** Summarizes the outcomes for the agent and the user, one line per fix. */
+        public String format() {
+            var lines = outcomes.stream()
+                    .map(o -> o.applied()
+                            ? "  applied: " + o.fix().description()
+                            : "  conflicted: " + o.fix().description() + " (" + o.conflict() + ")")
+                    .collect(Collectors.joining("\n"));
+            return "Applied %d of %d machine-applicable fixes:\n%s"
+                    .formatted(applied().size(), outcomes.size(), lines);
+        }
+    }
+
+    /** A single span replacement, positioned by 1-based line and code point column. */
+    private record Edit(Fix fix, int lineStart, int columnStart, int lineEnd, int columnEnd, String replacement) {
+        boolean overlaps(Edit other) {
+            if (lineStart == other.lineStart && columnStart == other.columnStart) {
+                return true;
+            }
+            return compare(lineStart, columnStart, other.lineEnd, other.columnEnd) < 0
+                    && compare(other.lineStart, other.columnStart, lineEnd, columnEnd) < 0;
+        }
+
+        private static int compare(int line, int column, int otherLine, int otherColumn) {
+            return line != otherLine ? Integer.compare(line, otherLine) : Integer.compare(column, otherColumn);
+        }
+    }
+
+    record Plan(
+            List<SearchReplaceBlock> blocks,
+            Map<SearchReplaceBlock, List<Fix>> fixesByBlock,
+            List<FixOutcome> conflicts) {}
+
+    /**
+     * Groups the machine-applicable suggestions of each diagnostic into fixes, in diagnostic order. A fix identical to
+     * an earlier one (the same lint reported for several targets) is dropped.
+     */
+    public static List<Fix> machineApplicableFixes(LintResult lint) {
+        var fixes = new ArrayList<Fix>();
+        var seen = new HashSet<List<Suggestion>>();
+        for (var diagnostic : lint.diagnostics()) {
+            var byMessage = new LinkedHashMap<String, List<Suggestion>>();
+            for (var suggestion : diagnostic.suggestions()) {
+                if (suggestion.applicability() == Applicability.MACHINE_APPLICABLE) {
+                    byMessage
+                            .computeIfAbsent(suggestion.message(), k -> new ArrayList<>())
+                            .add(suggestion);
+                }
+            }
+            for (var suggestions : byMessage.values()) {
+                if (seen.add(suggestions)) {
+                    fixes.add(new Fix(diagnostic, List.copyOf(suggestions)));
+                }
+            }
+        }
+        return fixes;
+    }
+
+    /**
+     * Applies the machine-applicable fixes of {@code lint} to the project and reports which applied cleanly and which
+     * conflicted, either with edits made since the lint ran or with each other.
+     */
+    @Blocking
+    public static Report apply(Context ctx, IConsoleIO io, LintResult lint) throws IOException, InterruptedException {
+        Path root = ctx.getContextManager().getProject().getRoot();
+        var fixes = machineApplicableFixes(lint);
+        var plan = plan(fixes, file -> ProjectFiles.read(new ProjectFile(root, file)));
+        if (plan.blocks().isEmpty()) {
+            return new Report(plan.conflicts(), new EditResult(Map.of(), List.of()));
+        }
+
+        var editResult = EditBlock.apply(ctx, io, plan.blocks());
+        Map<Fix, FixOutcome> outcomes = new HashMap<>();
+        plan.conflicts().forEach(c -> outcomes.put(c.fix(), c));
+        for (var result : editResult.blockResults()) {
+            @Nullable String conflict = result.succeeded()
+                    ? null
+                    : Optional.ofNullable(result.commentary())
+                            .map(String::strip)
+                            .orElse(String.valueOf(result.reason()));
+            for (var fix : plan.fixesByBlock().getOrDefault(result.block(), List.of())) {
+                outcomes.put(fix, new FixOutcome(fix, conflict));
+            }
+        }
+        var ordered = fixes.stream().map(outcomes::get).filter(Objects::nonNull).toList();
+        logger.debug("Applied {} of {} machine-applicable fixes", editResult.successes().size(), ordered.size());
+        return new Report(ordered, editResult);
+    }
+
+    /**
+     * Converts fixes into SEARCH/REPLACE blocks against the current file contents. Fixes whose spans no longer match
+     * the file, or that overlap a fix accepted earlier, become conflicts instead.
+     */
+    static Plan plan(List<Fix> fixes, Function<String, Optional<String>> readFile) {
+        Map<String, Optional<List<String>>> linesByFile = new HashMap<>();
+        Map<String, List<Edit>> editsByFile = new LinkedHashMap<>();
+        var conflicts = new ArrayList<FixOutcome>();
+
+        for (var fix : fixes) {
+            var edits = new ArrayList<Edit>();
+            @Nullable String conflict = null;
+            for (var suggestion : fix.suggestions()) {
+                var lines = linesByFile.computeIfAbsent(
+                        suggestion.file(), f -> readFile.apply(f).map(c -> c.lines().toList()));
+                var edit = new Edit(
+                        fix,
+                        suggestion.lineStart(),
+                        suggestion.columnStart(),
+                        suggestion.lineEnd(),
+                        suggestion.columnEnd(),
+                        suggestion.replacement());
+                var accepted = editsByFile.getOrDefault(suggestion.file(), List.of());
+                if (lines.isEmpty()) {
+                    conflict = "file not found: " + suggestion.file();
+                } else if (!matchesSource(suggestion, lines.get())) {
+                    conflict = "source changed since the linter ran";
+                } else if (accepted.stream().anyMatch(edit::overlaps) || edits.stream().anyMatch(edit::overlaps)) {
+                    conflict = "overlaps an earlier fix";
+                }
+                if (conflict != null) {
+                    break;
+                }
+                edits.add(edit);
+            }
+            if (conflict != null) {
+                conflicts.add(new FixOutcome(fix, conflict));
+                continue;
+            }
+            for (int i = 0; i < edits.size(); i++) {
+                editsByFile
+                        .computeIfAbsent(fix.suggestions().get(i).file(), f -> new ArrayList<>())
+                        .add(edits.get(i));
+            }
+        }
+
+        var blocks = new ArrayList<SearchReplaceBlock>();
+        Map<SearchReplaceBlock, List<Fix>> fixesByBlock = new IdentityHashMap<>();
+        editsByFile.forEach((file, edits) -> {
+            var lines = requireNonNull(linesByFile.get(file)).orElseThrow();
+            var regions = regions(edits);
+            for (int i = 0; i < regions.size(); i++) {
+                // Context may reach into untouched lines only, never into lines another block rewrites.
+                int lowerBound = i == 0 ? 0 : lastLine(regions.get(i - 1), lines) + 1;
+                int upperBound = i == regions.size() - 1
+                        ? lines.size() - 1
+                        : regions.get(i + 1).getFirst().lineStart() - 2;
+                var block = toBlock(file, lines, regions.get(i), lowerBound, upperBound);
+                blocks.add(block);
+                fixesByBlock.put(
+                        block, regions.get(i).stream().map(Edit::fix).distinct().toList());
+            }
+        });
+        return new Plan(List.copyOf(blocks), fixesByBlock, List.copyOf(conflicts));
+    }
+
+    private static SearchReplaceBlock toBlock(
+            String file, List<String> lines, List<Edit> region, int lowerBound, int upperBound) {
+        int first = region.getFirst().lineStart() - 1;
+        int last = lastLine(region, lines);
+        for (int added = 0; added < MAX_CONTEXT_LINES && occurrences(lines, first, last) > 1; added++) {
+            boolean grew = false;
+            if (first > lowerBound) {
+                first--;
+                grew = true;
+            }
+            if (last < upperBound) {
+                last++;
+                grew = true;
+            }
+            if (!grew) {
+                break;
+            }
+        }
+
+        var window = lines.subList(first, last + 1);
+        String before = String.join("\n", window);
+        var lineOffsets = new int[window.size()];
+        for (int i = 1; i < window.size(); i++) {
+            lineOffsets[i] = lineOffsets[i - 1] + window.get(i - 1).length() + 1;
+        }
+        int windowStart = first;
+        var after = new StringBuilder(before);
+        var descending = region.stream()
+                .sorted(Comparator.comparingInt(Edit::lineStart)
+                        .thenComparingInt(Edit::columnStart)
+                        .reversed())
+                .toList();
+        for (var edit : descending) {
+            int start = offset(window, lineOffsets, edit.lineStart() - 1 - windowStart, edit.columnStart(), before);
+            int end = offset(window, lineOffsets, edit.lineEnd() - 1 - windowStart, edit.columnEnd(), before);
+            after.replace(start, Math.max(start, end), edit.replacement());
+        }
+        return new SearchReplaceBlock(file, before + "\n", after + "\n");
+    }
+
+    /** Offset in the joined window of a 1-based code point column on a window line; past the window is its end. */
+    private static int offset(List<String> window, int[] lineOffsets, int index, int column, String joined) {
+        if (index >= window.size()) {
+            return joined.length();
+        }
+        String line = window.get(index);
+        int codePoints = line.codePointCount(0, line.length());
+        return lineOffsets[index] + line.offsetByCodePoints(0, Math.min(Math.max(0, column - 1), codePoints));
+    }
+
+    private static int lastLine(List<Edit> region, List<String> lines) {
+        int lineEnd = region.stream().mapToInt(Edit::lineEnd).max().orElseThrow();
+        return Math.min(lineEnd, lines.size()) - 1;
+    }
+
+    private static int occurrences(List<String> lines, int first, int last) {
+        var window = lines.subList(first, last + 1);
+        int count = 0;
+        for (int i = 0; i + window.size() <= lines.size(); i++) {
+            if (lines.subList(i, i + window.size()).equals(window)) {
+                count++;
+            }
+        }
+        return count;
+    }
+
+    private static boolean matchesSource(Suggestion suggestion, List<String> lines) {
+        if (suggestion.lineStart() < 1 || suggestion.lineEnd() < suggestion.lineStart()) {
+            return false;
+        }
+        var expected = suggestion.sourceLines();
+        if (expected.isEmpty()) {
+            return false;
+        }
+        for (int i = 0; i < expected.size(); i++) {
+            int index = suggestion.lineStart() - 1 + i;
+            if (index >= lines.size() || !lines.get(index).equals(expected.get(i))) {
+                return false;
+            }
+        }
+        return suggestion.lineStart() <= lines.size();
+    }
+
+    /** Groups a file's edits into runs whose line ranges overlap or touch, in line order. */
+    private static List<List<Edit>> regions(List<Edit> edits) {
+        var sorted = edits.stream()
+                .sorted(Comparator.comparingInt(Edit::lineStart).thenComparingInt(Edit::columnStart))
+                .toList();
+        var regions = new ArrayList<List<Edit>>();
+        int regionEnd = Integer.MIN_VALUE;
+        for (var edit : sorted) {
+            if (regions.isEmpty() || edit.lineStart() > regionEnd + 1) {
+                regions.add(new ArrayList<>());
+            }
+            regions.getLast().add(edit);
+            regionEnd = Math.max(regionEnd, edit.lineEnd());
+        }
+        return regions;
+    }
+}
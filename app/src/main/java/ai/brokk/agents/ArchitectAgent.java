@@ -1062,6 +1062,11 @@ public class ArchitectAgent {
 
                 if (depTools.isPresent()) {
                     allowed.add("importDependency");
+                    if (DependencyTools.supportsCargoGraph(cm.getProject())) {
+                        allowed.add("explainCargoDependency");
+                        allowed.add("findCargoDependents");
+                        allowed.add("findDuplicateCargoCrates");
+                    }
                 }
 
                 if (!readOnly && this.offerUndoToolNext) {
@@ -482,6 +482,9 @@ public class BuildAgent {
                 - `{{#classes}}...{{/classes}}` — simple class names
                 - `{{#fqclasses}}...{{/fqclasses}}` — fully-qualified class names
                 - `{{#packages}}...{{/packages}}` — package paths, dotted modules, or directories
+                - `{{#cargotests}}...{{/cargotests}}` — Rust test paths within their crate (e.g. `shapes::tests::test_area`), for `--exact`
+                - `{{#cargopackages}}...{{/cargopackages}}` — Cargo packages owning those tests, for `-p`
+                - `{{#cargotargets}}...{{/cargotargets}}` — Cargo target selectors holding those tests (`--lib`, `--test name`, ...)
 
                 Inside a section, each item exposes:
                 - `{{value}}` or `{{.}}` — the string value
@@ -494,7 +497,8 @@ public class BuildAgent {
 
                 The lists are DecoratedCollection instances, so you get first/last/index/value fields.
 
-                For module- or package-oriented test runners like `go test` and `cargo test`, use the `{{#packages}}` section variable to target specific components.
+                For module- or package-oriented test runners like `go test`, use the `{{#packages}}` section variable to target specific components.
+                For `cargo test`, use the `cargotests`, `cargopackages` and `cargotargets` sections so that exactly the affected tests run.
 
                 Examples:
 
@@ -505,7 +509,7 @@ public class BuildAgent {
                 | **Gradle**        | `gradle --quiet test{{#classes}} --tests {{value}}{{/classes}}`
                 | **Go**            | `go test {{#packages}}{{value}} {{/packages}} -run '{{#classes}}{{value}}{{^last}}|{{/last}}{{/classes}}'`
                 | **.NET CLI**      | `dotnet test --verbosity quiet --filter "{{#classes}}FullyQualifiedName\\~{{value}}{{^last}}|{{/last}}{{/classes}}"`
-                | **Cargo**         | `cargo test -q {{#packages}}{{value}} {{/packages}}`
+                | **Cargo**         | `cargo test -q {{#cargopackages}}-p {{value}} {{/cargopackages}}{{#cargotargets}}{{value}} {{/cargotargets}}-- --exact {{#cargotests}}{{value}} {{/cargotests}}`
                 | **pytest**        | `uv sync && pytest -q {{#packages}}{{value}}{{^last}} {{/last}}{{/packages}}`
                 | **Poetry**        | `poetry install --no-interaction && poetry run pytest -q {{#packages}}{{value}}{{^last}} {{/last}}{{/packages}}`
                 | **Jest**          | `jest --silent {{#files}}{{value}}{{^last}} {{/last}}{{/files}}`
@@ -816,6 +820,9 @@ public class BuildAgent {
             if (template.contains("{{#packages}}")) {
                 interpolatedCmd = BuildTools.interpolateMustacheTemplate(interpolatedCmd, items, "packages");
             }
+            if (template.contains("{{#cargotests}}")) {
+                interpolatedCmd = BuildTools.interpolateMustacheTemplate(interpolatedCmd, classItems, "cargotests");
+            }
 
             if (!interpolatedCmd.equals(template)) {
                 var result = BuildVerifier.verify(project, interpolatedCmd, details.environmentVariables());
@@ -1057,7 +1064,7 @@ public class BuildAgent {
 
     // Allowed top-level Mustache keys (section variables)
     private static final Set<String> ALLOWED_TOP_LEVEL_KEYS =
-            Set.of("files", "classes", "fqclasses", "packages", "pyver");
+            Set.of("files", "classes", "fqclasses", "packages", "pyver", "cargotests", "cargopackages", "cargotargets");
 
     // Allowed per-item keys inside sections
     private static final Set<String> ALLOWED_ITEM_KEYS = Set.of(".", "value", "first", "last", "index");
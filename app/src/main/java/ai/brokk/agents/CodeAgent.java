@@ -10,6 +10,7 @@ import ai.brokk.Llm;
 import ai.brokk.Llm.StreamingResult;
 import ai.brokk.LlmOutputMeta;
 import ai.brokk.Service;
+import ai.brokk.SuggestionEdits;
 import ai.brokk.TaskResult;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.ProjectFile;
@@ -1039,9 +1040,17 @@ public class CodeAgent {
 
         String buildError;
         try {
-            context =
-                    contextManager.getProject().getBuildRunner().runVerification(context, null, es.testFilesOverride());
+            var buildRunner = contextManager.getProject().getBuildRunner();
+            context = buildRunner.runVerification(context, null, es.testFilesOverride());
             buildError = context.getBuildError();
+            if (!buildError.isEmpty()) {
+                var fixedEs = applyLintSuggestions(es);
+                if (fixedEs != null) {
+                    es = fixedEs;
+                    context = buildRunner.runVerification(context, null, es.testFilesOverride());
+                    buildError = context.getBuildError();
+                }
+            }
         } catch (InterruptedException e) {
             logger.debug("CodeAgent interrupted during build verification.");
             Thread.currentThread().interrupt();
@@ -1159,6 +1168,35 @@ public class CodeAgent {
         }
     }
 
+    /**
+     * Applies the machine-applicable fixes cargo suggested for a failed verification, so that trivial errors are
+     * fixed without an LLM turn. Returns the updated edit state if any fix applied and the build should be re-run,
+     * or null otherwise.
+     */
+    private @Nullable EditState applyLintSuggestions(EditState es) throws InterruptedException {
+        var lint = contextManager.getProject().getBuildRunner().lastCargoLint();
+        if (lint.isEmpty() || SuggestionEdits.machineApplicableFixes(lint.get()).isEmpty()) {
+            return null;
+        }
+        SuggestionEdits.Report fixReport;
+        try {
+            fixReport = SuggestionEdits.apply(context, io, lint.get());
+        } catch (IOException e) {
+            logger.warn("Unable to apply machine-applicable lint fixes", e);
+            return null;
+        }
+        report(fixReport.format());
+        int applied = fixReport.editResult().successes().size();
+        if (applied == 0) {
+            return null;
+        }
+        return es.afterApply(
+                es.consecutiveApplyFailures(),
+                es.blocksAppliedWithoutBuild() + applied,
+                0,
+                fixReport.editResult().originalContents());
+    }
+
     private static class JavaPreLintFalsePositiveException extends RuntimeException {
         public JavaPreLintFalsePositiveException(String message) {
             super(message);
@@ -761,6 +761,9 @@ public class LutzAgent {
         if (DependencyTools.isSupported(cm.getProject())) {
             names.add("importDependency");
         }
+        if (DependencyTools.supportsCargoGraph(cm.getProject())) {
+            names.addAll(List.of("explainCargoDependency", "findCargoDependents", "findDuplicateCargoCrates"));
+        }
 
         return names;
     }
@@ -72,7 +72,11 @@ public class SearchAgent {
             "explainCommit",
             "xmlSkim",
             "xmlSelect",
-            "jq");
+            "tomlSkim",
+            "tomlSelect",
+            "jq",
+            "getCallGraphTo",
+            "getCallGraphFrom");
 
     private final IAppContextManager cm;
     private final String goal;
@@ -492,6 +496,14 @@ public class SearchAgent {
         if (project.getAllFiles().stream().anyMatch(f -> f.extension().equals("json"))) {
             names.add("jq");
         }
+        if (project.getAllFiles().stream().anyMatch(f -> f.extension().equals("toml"))) {
+            names.add("tomlSkim");
+            names.add("tomlSelect");
+        }
+        if (SearchTools.supportsCallGraphs(project)) {
+            names.add("getCallGraphTo");
+            names.add("getCallGraphFrom");
+        }
 
         if (objective == Objective.ANSWER_ONLY) {
             names.add("answer");
@@ -1,6 +1,7 @@
 package ai.brokk.executor.agents;
 
 import ai.brokk.project.IProject;
+import ai.brokk.tools.SearchTools;
 import ai.brokk.tools.WorkspaceTools;
 import com.fasterxml.jackson.annotation.JsonIgnore;
 import com.fasterxml.jackson.annotation.JsonProperty;
@@ -40,6 +41,8 @@ public record AgentDefinition(
             "getClassSkeletons",
             "getClassSources",
             "getMethodSources",
+            "getCallGraphTo",
+            "getCallGraphFrom",
             "getFileContents",
             "listFiles",
             "searchGitCommitMessages",
@@ -47,6 +50,8 @@ public record AgentDefinition(
             "explainCommit",
             "xmlSkim",
             "xmlSelect",
+            "tomlSkim",
+            "tomlSelect",
             "jq",
             "computeCyclomaticComplexity",
             "computeCognitiveComplexity",
@@ -55,6 +60,7 @@ public record AgentDefinition(
             "reportCommentDensityForCodeUnit",
             "reportCommentDensityForFiles",
             "reportExceptionHandlingSmells",
+            "reportRustRiskSmells",
             "reportStructuralCloneSmells",
             "reportSecretLikeCode",
             "reportTestAssertionSmells",
@@ -78,6 +84,8 @@ public record AgentDefinition(
             "getClassSkeletons",
             "getClassSources",
             "getMethodSources",
+            "getCallGraphTo",
+            "getCallGraphFrom",
             "getFileContents",
             "listFiles",
             "searchGitCommitMessages",
@@ -85,6 +93,8 @@ public record AgentDefinition(
             "explainCommit",
             "xmlSkim",
             "xmlSelect",
+            "tomlSkim",
+            "tomlSelect",
             "jq",
             "computeCyclomaticComplexity",
             "computeCognitiveComplexity",
@@ -93,6 +103,7 @@ public record AgentDefinition(
             "reportCommentDensityForCodeUnit",
             "reportCommentDensityForFiles",
             "reportExceptionHandlingSmells",
+            "reportRustRiskSmells",
             "reportStructuralCloneSmells",
             "reportSecretLikeCode",
             "reportTestAssertionSmells",
@@ -117,6 +128,8 @@ public record AgentDefinition(
             "searchFileContents",
             "getClassSources",
             "getMethodSources",
+            "getCallGraphTo",
+            "getCallGraphFrom",
             "getFileContents",
             "listFiles",
             "searchGitCommitMessages",
@@ -124,6 +137,8 @@ public record AgentDefinition(
             "explainCommit",
             "xmlSkim",
             "xmlSelect",
+            "tomlSkim",
+            "tomlSelect",
             "jq",
             // WorkspaceTools
             "addFilesToWorkspace",
@@ -139,6 +154,9 @@ public record AgentDefinition(
             "runShellCommand",
             // DependencyTools
             "importDependency",
+            "explainCargoDependency",
+            "findCargoDependents",
+            "findDuplicateCargoCrates",
             // CodeQualityTools
             "computeCyclomaticComplexity",
             "computeCognitiveComplexity",
@@ -147,6 +165,7 @@ public record AgentDefinition(
             "reportCommentDensityForCodeUnit",
             "reportCommentDensityForFiles",
             "reportExceptionHandlingSmells",
+            "reportRustRiskSmells",
             "reportStructuralCloneSmells",
             "reportSecretLikeCode",
             "reportTestAssertionSmells",
@@ -215,6 +234,14 @@ public record AgentDefinition(
         if (project.getAllFiles().stream().anyMatch(f -> f.extension().equals("json"))) {
             names.add("jq");
         }
+        if (project.getAllFiles().stream().anyMatch(f -> f.extension().equals("toml"))) {
+            names.add("tomlSkim");
+            names.add("tomlSelect");
+        }
+        if (SearchTools.supportsCallGraphs(project)) {
+            names.add("getCallGraphTo");
+            names.add("getCallGraphFrom");
+        }
         return names;
     }
 
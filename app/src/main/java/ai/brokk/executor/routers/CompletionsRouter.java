@@ -134,6 +134,7 @@ public final class CompletionsRouter implements SimpleHttpServer.CheckedHttpHand
             case FUNCTION -> "function";
             case FIELD -> "field";
             case MODULE -> "module";
+            case MACRO -> "macro";
         };
     }
 }
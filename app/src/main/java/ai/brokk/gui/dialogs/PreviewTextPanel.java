@@ -562,6 +562,7 @@ public class PreviewTextPanel extends JPanel implements ThemeAware, EditorFontSi
                         case CLASS -> 0;
                         case FIELD -> 1;
                         case FUNCTION -> isConstructorLike(unit, enclosingUnit) ? 3 : 2;
+                        case MACRO -> 2;
                         case MODULE -> 4;
                     };
                 }
@@ -741,6 +741,9 @@ public class SettingsProjectBuildPanel extends JPanel {
                     } else if (testSomeTemplate.contains("{{#classes}}")) {
                         listKey = "classes";
                         items = List.of("Placeholder");
+                    } else if (testSomeTemplate.contains("{{#cargotests}}")) {
+                        listKey = "cargotests";
+                        items = List.of("tests::placeholder");
                     } else {
                         publish(
                                 "\nWARNING: 'Test Some' command does not contain {{#files}}, {{#classes}}, or {{#fqclasses}}.\n");
@@ -17,6 +17,7 @@ import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.analyzer.RelaxedSourceLookupResolver;
 import ai.brokk.analyzer.usages.FuzzyResult;
 import ai.brokk.analyzer.usages.UsageHit;
+import ai.brokk.analyzer.usages.UsageKind;
 import ai.brokk.cli.MemoryConsole;
 import ai.brokk.context.Context;
 import ai.brokk.context.ContextDelta;
@@ -640,6 +641,12 @@ public class BrokkExternalMcpServer {
         }
 
         boolean includeTests = args.get("includeTests") instanceof Boolean b && b;
+        Set<UsageKind> kinds = args.get("kinds") instanceof List<?> kindsList
+                ? UsageKind.parseAll(kindsList.stream()
+                        .filter(String.class::isInstance)
+                        .map(String.class::cast)
+                        .toList())
+                : Set.of();
 
         List<String> symbols = symbolsList.stream()
                 .filter(String.class::isInstance)
@@ -658,7 +665,7 @@ public class BrokkExternalMcpServer {
         for (String symbol : symbols) {
             final FuzzyResult usageResult;
             try {
-                usageResult = UsageFinder.create(cm, fileFilter).findUsages(symbol);
+                usageResult = UsageFinder.create(cm, fileFilter).findUsages(symbol, kinds);
             } catch (InterruptedException e) {
                 Thread.currentThread().interrupt();
                 return rawResultText;
@@ -737,6 +737,26 @@ public final class MainProject extends AbstractProject {
         return language.internalName() + "SourceRoots";
     }
 
+    @Override
+    public String getLanguageSettings(Language language) {
+        return projectProps.getProperty(getLanguageSettingsKey(language), "");
+    }
+
+    @Override
+    public void setLanguageSettings(Language language, String json) {
+        var key = getLanguageSettingsKey(language);
+        if (Objects.equals(projectProps.getProperty(key), json)) {
+            return;
+        }
+        projectProps.setProperty(key, json);
+        saveProjectProperties();
+        logger.debug("Saved {} settings to project properties using key: {}", language.name(), key);
+    }
+
+    private static String getLanguageSettingsKey(Language language) {
+        return language.internalName() + "Settings";
+    }
+
     @Nullable
     private volatile IssueProvider issuesProviderCache = null;
 
@@ -351,4 +351,14 @@ public final class WorktreeProject extends AbstractProject {
     public void setSourceRoots(Language language, List<String> roots) {
         parent.setSourceRoots(language, roots);
     }
+
+    @Override
+    public String getLanguageSettings(Language language) {
+        return parent.getLanguageSettings(language);
+    }
+
+    @Override
+    public void setLanguageSettings(Language language, String json) {
+        parent.setLanguageSettings(language, json);
+    }
 }
@@ -7,6 +7,7 @@ import ai.brokk.analyzer.CommentDensityStats;
 import ai.brokk.analyzer.IAnalyzer;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.RustRiskSmellProvider;
 import ai.brokk.analyzer.usages.CandidateFileProvider;
 import ai.brokk.analyzer.usages.FuzzyResult;
 import ai.brokk.analyzer.usages.UsageHit;
@@ -27,6 +28,7 @@ import java.util.LinkedHashMap;
 import java.util.LinkedHashSet;
 import java.util.List;
 import java.util.Locale;
+import java.util.Map;
 import java.util.Optional;
 import java.util.Set;
 import java.util.stream.Collectors;
@@ -108,7 +110,8 @@ public class CodeQualityTools {
             """
             Computes cognitive complexity for methods in the given files.
             Flags methods above the threshold (typical default 15) for maintainability-focused review or refactor.
-            Returns a markdown-friendly report of flagged methods.""")
+            Returns a markdown-friendly report of flagged methods.
+            Rust methods also list the constructs their score comes from.""")
     public String computeCognitiveComplexity(
             @P("File paths relative to the project root.") List<String> filePaths,
             @P("Complexity threshold; methods above this are flagged. Use 0 or negative for default (15).")
@@ -125,8 +128,10 @@ public class CodeQualityTools {
             if (!file.exists()) continue;
 
             var complexities = analyzer.computeCognitiveComplexities(file);
+            var breakdowns = analyzer.computeCognitiveComplexityBreakdowns(file);
             for (var entry : complexities.entrySet()) {
-                foundAny |= analyzeUnitCognitiveComplexity(entry.getKey(), entry.getValue(), limit, lines);
+                var breakdown = breakdowns.getOrDefault(entry.getKey(), Map.of());
+                foundAny |= analyzeUnitCognitiveComplexity(entry.getKey(), entry.getValue(), breakdown, limit, lines);
             }
         }
 
@@ -135,7 +140,8 @@ public class CodeQualityTools {
                 : "No methods exceeded the cognitive complexity threshold of " + limit + ".";
     }
 
-    private boolean analyzeUnitCognitiveComplexity(CodeUnit cu, int complexity, int threshold, List<String> lines) {
+    private boolean analyzeUnitCognitiveComplexity(
+            CodeUnit cu, int complexity, Map<String, Integer> breakdown, int threshold, List<String> lines) {
         if (cu.isSynthetic() || complexity <= threshold) {
             return false;
         }
@@ -143,10 +149,19 @@ public class CodeQualityTools {
         String finding = "%s High cognitive complexity: %s (CogC: %d) in %s"
                 .formatted(FINDING_PREFIX, cu.fqName(), complexity, cu.source());
         contextManager.getIo().showNotification(IConsoleIO.NotificationRole.INFO, finding);
-        lines.add("- " + cu.fqName() + ": " + complexity);
+        lines.add("- " + cu.fqName() + ": " + complexity + formatComplexityBreakdown(breakdown));
         return true;
     }
 
+    private static String formatComplexityBreakdown(Map<String, Integer> breakdown) {
+        if (breakdown.isEmpty()) {
+            return "";
+        }
+        return breakdown.entrySet().stream()
+                .map(entry -> entry.getKey() + " +" + entry.getValue())
+                .collect(Collectors.joining(", ", " (", ")"));
+    }
+
     @Tool(
             """
             Reports long methods/functions, god objects/modules, and helper sprawl in the given files.
@@ -827,6 +842,88 @@ public class CodeQualityTools {
         return formatSecretScanReport(report, findingsCap);
     }
 
+    @Tool(
+            """
+            Detects Rust-specific risk hot spots per function, skipping test code: unwrap()/expect() calls,
+            unsafe blocks without a // SAFETY: comment, panic!/todo!/unimplemented! in library code,
+            and clone() inside loop bodies. Each function is scored by its weighted site counts.""")
+    public String reportRustRiskSmells(
+            @P("File paths relative to the project root.") List<String> filePaths,
+            @P("Minimum score to include a finding; values <= 0 default to 4.") int minScore,
+            @P("Maximum findings to emit; values <= 0 default to 80.") int maxFindings,
+            @P("Weight per unwrap() call; values < 0 use default.") int unwrapWeight,
+            @P("Weight per expect() call; values < 0 use default.") int expectWeight,
+            @P("Weight per unsafe block without a SAFETY comment; values < 0 use default.")
+                    int undocumentedUnsafeWeight,
+            @P("Weight per panic! in library code; values < 0 use default.") int panicWeight,
+            @P("Weight per todo! or unimplemented! in library code; values < 0 use default.") int unfinishedWeight,
+            @P("Weight per clone() inside a loop body; values < 0 use default.") int cloneInLoopWeight) {
+
+        int threshold = minScore > 0 ? minScore : 4;
+        int findingsCap = maxFindings > 0 ? maxFindings : 80;
+        var defaults = RustRiskSmellProvider.RustRiskSmellWeights.defaults();
+        var weights = new RustRiskSmellProvider.RustRiskSmellWeights(
+                pickWeight(unwrapWeight, defaults.unwrapWeight()),
+                pickWeight(expectWeight, defaults.expectWeight()),
+                pickWeight(undocumentedUnsafeWeight, defaults.undocumentedUnsafeWeight()),
+                pickWeight(panicWeight, defaults.panicWeight()),
+                pickWeight(unfinishedWeight, defaults.unfinishedWeight()),
+                pickWeight(cloneInLoopWeight, defaults.cloneInLoopWeight()));
+
+        var provider = contextManager.getAnalyzerUninterrupted().as(RustRiskSmellProvider.class);
+        if (provider.isEmpty()) {
+            return "Rust risk smells are not supported for the languages in this project.";
+        }
+        var findings = new ArrayList<RustRiskSmellProvider.RustRiskSmell>();
+        for (String path : filePaths) {
+            ProjectFile file = contextManager.toFile(path);
+            if (!file.exists()) {
+                continue;
+            }
+            findings.addAll(provider.get().findRustRiskSmells(file, weights));
+        }
+
+        var filtered = findings.stream()
+                .filter(f -> f.score() >= threshold)
+                .sorted(Comparator.comparingInt(RustRiskSmellProvider.RustRiskSmell::score)
+                        .reversed()
+                        .thenComparing(f -> f.file().toString())
+                        .thenComparing(RustRiskSmellProvider.RustRiskSmell::enclosingFqName))
+                .toList();
+
+        if (filtered.isEmpty()) {
+            return "No Rust risk smells met minScore " + threshold + ".";
+        }
+        int shown = Math.min(findingsCap, filtered.size());
+        var lines = new ArrayList<String>();
+        lines.add("## Rust risk smells");
+        lines.add("");
+        lines.add("- Min score: %d".formatted(threshold));
+        lines.add("- Findings shown: %d of %d".formatted(shown, filtered.size()));
+        lines.add("- Weights: %s".formatted(formatWeights(weights)));
+        lines.add("");
+        lines.add("| Score | Sites | Symbol | File | Reasons | First Site |");
+        lines.add("|------:|------:|--------|------|---------|------------|");
+        for (RustRiskSmellProvider.RustRiskSmell finding : filtered.subList(0, shown)) {
+            String reasons = sanitizeTableCell(String.join(", ", finding.reasons()));
+            String symbol = sanitizeTableCell(finding.enclosingFqName());
+            String file = sanitizeTableCell(finding.file().toString());
+            lines.add("| %d | %d | `%s` | `%s` | %s | `%s` |"
+                    .formatted(
+                            finding.score(),
+                            finding.siteCount(),
+                            symbol,
+                            file,
+                            "`" + reasons + "`",
+                            sanitizeTableCell(finding.excerpt())));
+        }
+        if (filtered.size() > shown) {
+            lines.add("");
+            lines.add("- Note: output truncated; increase maxFindings to see more.");
+        }
+        return String.join("\n", lines);
+    }
+
     @Tool(
             """
             Detects low-value or brittle test assertion smells using language-aware weighted heuristics.
@@ -1046,6 +1143,17 @@ public class CodeQualityTools {
                                 w.smallBodyMaxStatements());
     }
 
+    private static String formatWeights(RustRiskSmellProvider.RustRiskSmellWeights w) {
+        return "unwrap=%d, expect=%d, undocumentedUnsafe=%d, panic=%d, todoOrUnimplemented=%d, cloneInLoop=%d"
+                .formatted(
+                        w.unwrapWeight(),
+                        w.expectWeight(),
+                        w.undocumentedUnsafeWeight(),
+                        w.panicWeight(),
+                        w.unfinishedWeight(),
+                        w.cloneInLoopWeight());
+    }
+
     private static String formatWeights(IAnalyzer.TestAssertionWeights w) {
         return "noAssertion=%d, tautological=%d, constantTruth=%d, constantEquality=%d, nullnessOnly=%d,"
                         .formatted(
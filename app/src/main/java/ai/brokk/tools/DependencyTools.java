@@ -8,6 +8,8 @@ import ai.brokk.analyzer.DependencyCopyUtil;
 import ai.brokk.analyzer.Language;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.NodeJsDependencyHelper;
+import ai.brokk.analyzer.RustLanguage;
+import ai.brokk.analyzer.rust.CargoDependencyGraph;
 import ai.brokk.project.AbstractProject;
 import ai.brokk.project.IProject;
 import ai.brokk.util.Decompiler;
@@ -42,6 +44,9 @@ import org.jetbrains.annotations.Nullable;
 public class DependencyTools {
     private static final Logger logger = LogManager.getLogger(DependencyTools.class);
 
+    private static final String NO_CARGO_GRAPH = "No Cargo dependency graph available. "
+            + "Ensure this is a Cargo project with a Cargo.lock, or run 'cargo generate-lockfile'.";
+
     private final IAppContextManager contextManager;
     private final @Nullable MavenArtifactFetcher fetcher;
 
@@ -467,6 +472,174 @@ public class DependencyTools {
         return null;
     }
 
+    // ========== Cargo Dependency Graph ==========
+
+    /**
+     * Returns true if the Cargo dependency graph tools apply to the given project.
+     */
+    public static boolean supportsCargoGraph(IProject project) {
+        return project.getAnalyzerLanguages().contains(Languages.RUST);
+    }
+
+    @Blocking
+    @Tool("Explain why a Rust crate is in the Cargo dependency tree, like 'cargo tree -i': for every workspace member "
+            + "that pulls the crate in, the shortest chain of dependencies leading to it, with dependency kinds, "
+            + "target platforms, optional and renamed dependencies, and enabled features.")
+    public String explainCargoDependency(
+            @P("Crate name, optionally followed by a version or version prefix, e.g. 'syn' or 'syn 1.0'")
+                    String crateSpec)
+            throws InterruptedException {
+        checkInterrupted();
+        return formatCargoWhy(cargoDependencyGraph(), crateSpec.trim());
+    }
+
+    @Blocking
+    @Tool("List the Cargo workspace members that depend on a Rust crate, directly or transitively, "
+            + "with the dependency chain through which each one reaches it.")
+    public String findCargoDependents(@P("Crate name, e.g. 'serde' or 'tokio-util'") String crateName)
+            throws InterruptedException {
+        checkInterrupted();
+        return formatCargoDependents(cargoDependencyGraph(), crateName.trim());
+    }
+
+    @Blocking
+    @Tool("List the Rust crates that are built at more than one version in the Cargo workspace, like 'cargo tree -d', "
+            + "with the packages that require each version.")
+    public String findDuplicateCargoCrates() throws InterruptedException {
+        checkInterrupted();
+        return formatCargoDuplicates(cargoDependencyGraph());
+    }
+
+    private CargoDependencyGraph cargoDependencyGraph() {
+        return ((RustLanguage) Languages.RUST).getDependencyGraph(contextManager.getProject());
+    }
+
+    static String formatCargoWhy(CargoDependencyGraph graph, String crateSpec) {
+        if (graph.crates().isEmpty()) {
+            return NO_CARGO_GRAPH;
+        }
+        String[] parts = crateSpec.split("\\s+", 2);
+        String requestedVersion = parts.length > 1 ? parts[1].trim() : "";
+        var candidates = graph.cratesNamed(parts[0]).stream()
+                .filter(crate -> crate.version().startsWith(requestedVersion))
+                .toList();
+        if (candidates.isEmpty()) {
+            return "Crate '%s' is not in the Cargo dependency graph.".formatted(crateSpec);
+        }
+
+        var sb = new StringBuilder();
+        for (var crate : candidates) {
+            if (!sb.isEmpty()) {
+                sb.append('\n');
+            }
+            sb.append("## ").append(crate.display());
+            if (crate.source() != null) {
+                sb.append(" (").append(crate.source()).append(')');
+            }
+            sb.append('\n');
+            if (crate.workspaceMember()) {
+                sb.append("Workspace member.\n");
+            }
+            var dependents = graph.why(crate);
+            if (dependents.isEmpty() && !crate.workspaceMember()) {
+                sb.append("Not reachable from any workspace member.\n");
+            }
+            dependents.forEach(dependent ->
+                    sb.append("- ").append(formatCargoPath(graph, dependent)).append('\n'));
+        }
+        return sb.toString().stripTrailing();
+    }
+
+    static String formatCargoDependents(CargoDependencyGraph graph, String crateName) {
+        if (graph.crates().isEmpty()) {
+            return NO_CARGO_GRAPH;
+        }
+        if (graph.cratesNamed(crateName).isEmpty()) {
+            return "Crate '%s' is not in the Cargo dependency graph.".formatted(crateName);
+        }
+        var dependents = graph.dependentMembers(crateName);
+        if (dependents.isEmpty()) {
+            return "No workspace member depends on '%s'.".formatted(crateName);
+        }
+        var sb = new StringBuilder("Workspace members depending on %s:\n".formatted(crateName));
+        for (var dependent : dependents) {
+            sb.append("- ")
+                    .append(dependent.member().name())
+                    .append(dependent.isDirect() ? " (direct): " : " (transitive): ")
+                    .append(formatCargoPath(graph, dependent))
+                    .append('\n');
+        }
+        return sb.toString().stripTrailing();
+    }
+
+    static String formatCargoDuplicates(CargoDependencyGraph graph) {
+        if (graph.crates().isEmpty()) {
+            return NO_CARGO_GRAPH;
+        }
+        var duplicates = graph.duplicates();
+        if (duplicates.isEmpty()) {
+            return "Every crate in the Cargo dependency graph is built at a single version.";
+        }
+        var sb = new StringBuilder("Crates built at more than one version:\n");
+        duplicates.forEach((name, versions) -> {
+            sb.append("- ").append(name).append('\n');
+            for (var crate : versions) {
+                var requiredBy = graph.dependentsOf(crate.id()).stream()
+                        .map(edge -> {
+                            var from = graph.crate(edge.from());
+                            String label = from == null ? edge.from() : from.display();
+                            String details = formatCargoEdgeDetails(edge);
+                            return details.isEmpty() ? label : label + " " + details;
+                        })
+                        .distinct()
+                        .toList();
+                sb.append("  - ").append(crate.version());
+                if (!requiredBy.isEmpty()) {
+                    sb.append(", required by ").append(String.join(", ", requiredBy));
+                }
+                sb.append('\n');
+            }
+        });
+        return sb.toString().stripTrailing();
+    }
+
+    /** Renders a dependent's chain as e.g. {@code app 0.1.0 -> serde_json 1.0.1 -> serde 1.0.200 [optional]}. */
+    private static String formatCargoPath(CargoDependencyGraph graph, CargoDependencyGraph.Dependent dependent) {
+        var sb = new StringBuilder(dependent.member().display());
+        for (var edge : dependent.path()) {
+            var to = graph.crate(edge.to());
+            sb.append(" -> ").append(to == null ? edge.to() : to.display());
+            String details = formatCargoEdgeDetails(edge);
+            if (!details.isEmpty()) {
+                sb.append(' ').append(details);
+            }
+        }
+        return sb.toString();
+    }
+
+    private static String formatCargoEdgeDetails(CargoDependencyGraph.Dependency edge) {
+        var details = new ArrayList<String>();
+        if (!"normal".equals(edge.kind())) {
+            details.add(edge.kind());
+        }
+        if (edge.target() != null) {
+            details.add("target " + edge.target());
+        }
+        if (edge.optional()) {
+            details.add("optional");
+        }
+        if (edge.rename() != null) {
+            details.add("as " + edge.rename());
+        }
+        if (!edge.defaultFeatures()) {
+            details.add("no default features");
+        }
+        if (!edge.features().isEmpty()) {
+            details.add("features " + String.join(", ", edge.features()));
+        }
+        return details.isEmpty() ? "" : "[" + String.join("; ", details) + "]";
+    }
+
     // ========== Node.js Import ==========
 
     private String importNpmPackage(String packageName) throws InterruptedException {
@@ -10,11 +10,16 @@ import ai.brokk.AnalyzerUtil;
 import ai.brokk.Completions;
 import ai.brokk.ContextManager;
 import ai.brokk.IAppContextManager;
+import ai.brokk.analyzer.CallGraphProvider;
 import ai.brokk.analyzer.CodeUnit;
+import ai.brokk.analyzer.ConditionalCompilationProvider;
 import ai.brokk.analyzer.IAnalyzer;
+import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.analyzer.RelaxedSourceLookupResolver;
 import ai.brokk.analyzer.RelaxedSourceLookupResolver.RelaxedSourceLookup;
+import ai.brokk.analyzer.usages.UsageKind;
+import ai.brokk.analyzer.usages.UsageRenderer;
 import ai.brokk.concurrent.LoggingFuture;
 import ai.brokk.context.ContextFragments;
 import ai.brokk.git.CommitInfo;
@@ -22,6 +27,8 @@ import ai.brokk.git.GitRepo;
 import ai.brokk.git.GitRepoFactory;
 import ai.brokk.git.IGitRepo;
 import ai.brokk.io.ProjectFiles;
+import ai.brokk.project.IProject;
+import ai.brokk.usages.UsageFinder;
 import ai.brokk.util.AlmostGrep;
 import ai.brokk.util.FileTargetHeuristic;
 import ai.brokk.util.FilenamePatternMatcher;
@@ -31,11 +38,13 @@ import ai.brokk.util.SearchToolSupport;
 import ai.brokk.util.TextMatcher;
 import com.fasterxml.jackson.databind.JsonNode;
 import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.dataformat.toml.TomlMapper;
 import com.github.benmanes.caffeine.cache.Cache;
 import com.github.benmanes.caffeine.cache.Caffeine;
 import dev.langchain4j.agent.tool.P;
 import dev.langchain4j.agent.tool.Tool;
 import java.io.ByteArrayInputStream;
+import java.io.IOException;
 import java.io.StringWriter;
 import java.nio.charset.StandardCharsets;
 import java.nio.file.Files;
@@ -60,6 +69,7 @@ import java.util.concurrent.ForkJoinWorkerThread;
 import java.util.concurrent.Future;
 import java.util.concurrent.atomic.AtomicLong;
 import java.util.function.BiFunction;
+import java.util.function.Predicate;
 import java.util.regex.Pattern;
 import java.util.stream.Collectors;
 import java.util.stream.IntStream;
@@ -105,6 +115,7 @@ public class SearchTools {
     private static final int FILE_SKIM_LIMIT = 20;
     private static final int CLASS_COUNT_LIMIT = 10;
     private static final int RELATED_CONTENT_LIMIT = 5;
+    private static final int DEFAULT_CALL_GRAPH_DEPTH = 3;
 
     private static final int FILE_CONTENTS_CONTEXT_LINES_LIMIT = 5;
     static final int FILE_CONTENTS_TOTAL_MATCH_LIMIT = 500;
@@ -162,6 +173,8 @@ public class SearchTools {
 
     private static final ThreadLocal<ObjectMapper> jqMappers = ThreadLocal.withInitial(ObjectMapper::new);
 
+    private static final ThreadLocal<TomlMapper> tomlMappers = ThreadLocal.withInitial(TomlMapper::new);
+
     private static final ThreadLocal<Scope> jqScopes = ThreadLocal.withInitial(() -> {
         Scope rootScope = Scope.newEmptyScope();
         BuiltinFunctionLoader.getInstance().loadFunctions(Versions.JQ_1_6, rootScope);
@@ -1037,15 +1050,22 @@ public class SearchTools {
                     .append("\">\n");
 
             // Emit kind sections in a stable order based on analyzer's CodeUnitType
-            var kindOrder = List.of("CLASS", "FUNCTION", "FIELD", "MODULE");
+            var kindOrder = List.of("CLASS", "FUNCTION", "MACRO", "FIELD", "MODULE");
             kindOrder.forEach(kind -> {
                 var symbols = kindGroups.get(kind);
                 if (symbols != null && !symbols.isEmpty()) {
                     result.append("[").append(kind).append("]\n");
                     symbols.stream()
-                            .flatMap(cu -> analyzer.getDisplaySignatures(cu).stream()
-                                    .map(signature -> new SymbolSearchHit(
-                                            signature, cu.fqName(), cu.signature(), displayLineNumber(analyzer, cu))))
+                            .flatMap(cu -> {
+                                // Conditionally compiled symbols carry their cfg, e.g. " [cfg(windows), inactive]"
+                                String condition = ConditionalCompilationProvider.describe(analyzer, cu);
+                                return analyzer.getDisplaySignatures(cu).stream()
+                                        .map(signature -> new SymbolSearchHit(
+                                                signature + condition,
+                                                cu.fqName(),
+                                                cu.signature(),
+                                                displayLineNumber(analyzer, cu)));
+                            })
                             .distinct()
                             .sorted(SearchTools::compareSymbolSearchHits)
                             .forEach(hit -> result.append("- ")
@@ -1090,11 +1110,19 @@ public class SearchTools {
             Use for questions like "how is X used", "who calls X", "how is X obtained/wired".
             Requires exact symbol names, usually fully qualified. Use searchSymbols to identify candidate declarations
             when you only have a partial name, then choose the exact symbol to inspect.
+            Pass kinds to keep only some usages, e.g. write and mutable-borrow to find who mutates a Rust field.
             """)
     public String scanUsages(
             @P("Fully qualified symbol names (package name, class name, optional member name) to find usages for")
                     List<String> symbols,
-            @P("Include call sites in test files in results.") boolean includeTests) {
+            @P("Include call sites in test files in results.") boolean includeTests,
+            @P(
+                            "Usage kinds to keep: read, write, mutable-borrow, move, type. Empty keeps every usage; "
+                                    + "only Rust usages carry a kind.")
+                    @Nullable
+                    List<String> kinds)
+            throws InterruptedException {
+        Set<UsageKind> usageKinds = UsageKind.parseAll(kinds == null ? List.of() : kinds);
         // Sanitize symbols: remove potential `(params)` suffix from LLM.
         symbols = stripParams(symbols);
         if (symbols.isEmpty()) {
@@ -1105,9 +1133,12 @@ public class SearchTools {
         for (String symbol : symbols) {
             if (symbol.isBlank()) continue;
 
-            var fragment = new ContextFragments.UsageFragment(
-                    contextManager, symbol, includeTests, ContextFragments.UsageMode.SAMPLE);
-            String text = fragment.text().join();
+            String text = usageKinds.isEmpty()
+                    ? new ContextFragments.UsageFragment(
+                                    contextManager, symbol, includeTests, ContextFragments.UsageMode.SAMPLE)
+                            .text()
+                            .join()
+                    : usagesOfKinds(symbol, includeTests, usageKinds);
             if (!text.isEmpty()) {
                 results.add(text);
             }
@@ -1119,6 +1150,19 @@ public class SearchTools {
         return recordResearchTokens(String.join("\n\n", results));
     }
 
+    private String usagesOfKinds(String symbol, boolean includeTests, Set<UsageKind> kinds)
+            throws InterruptedException {
+        var analyzer = getAnalyzer();
+        var overloads = ConditionalCompilationProvider.preferActive(analyzer, analyzer.getDefinitions(symbol));
+        if (overloads.isEmpty()) {
+            return "";
+        }
+        Predicate<ProjectFile> fileFilter = includeTests ? null : file -> !ContextManager.isTestFile(file, analyzer);
+        var result = UsageFinder.create(contextManager, fileFilter).findUsages(symbol, kinds);
+        var rendered = UsageRenderer.render(analyzer, symbol, overloads, result, UsageRenderer.Mode.SAMPLE);
+        return rendered.hitCount() > 0 ? rendered.text() : "";
+    }
+
     @Tool(
             """
                     Returns full source code of classes. This is the most expensive read operation (max 10 classes).
@@ -1296,6 +1340,68 @@ public class SearchTools {
         return recordResearchTokens(output);
     }
 
+    /**
+     * Returns true if the call graph tools apply to the given project.
+     */
+    public static boolean supportsCallGraphs(IProject project) {
+        return project.getAnalyzerLanguages().contains(Languages.RUST);
+    }
+
+    @Tool(
+            """
+            Returns the functions that call a Rust function, transitively up to the given depth, as a call tree.
+            Each edge shows the calling function and the line of the call.
+            Use to trace who reaches a function; scanUsages is cheaper for a flat list of direct usages.
+            """)
+    public String getCallGraphTo(
+            @P("Fully qualified name of the function whose callers to trace") String methodName,
+            @P("How many levels of callers to follow; values below 1 use the default of 3") int depth) {
+        return callGraph(methodName, depth, true);
+    }
+
+    @Tool(
+            """
+            Returns the functions a Rust function calls, transitively up to the given depth, as a tree of call sites.
+            Associated-function calls (Type::new()), self.method() calls, method calls on typed locals and free-function
+            calls are resolved; calls into code outside the project are omitted.
+            """)
+    public String getCallGraphFrom(
+            @P("Fully qualified name of the function whose callees to trace") String methodName,
+            @P("How many levels of callees to follow; values below 1 use the default of 3") int depth) {
+        return callGraph(methodName, depth, false);
+    }
+
+    private String callGraph(String methodName, int depth, boolean isCallerGraph) {
+        String name = STRIP_PARAMS_PATTERN.matcher(methodName).replaceFirst("").strip();
+        if (name.isEmpty()) {
+            throw new IllegalArgumentException("Cannot get call graph: method name is empty");
+        }
+
+        var analyzer = getAnalyzer();
+        var provider = analyzer.as(CallGraphProvider.class);
+        if (provider.isEmpty()) {
+            return "Call graphs are not supported for the languages in this project.";
+        }
+        var lookups = RelaxedSourceLookupResolver.resolveLookups(analyzer, List.of(name), CodeUnit::isFunction);
+        var lookup = requireNonNull(lookups.get(name));
+        if (lookup.isAmbiguous()) {
+            return recordResearchTokens(lookup.ambiguityMessage("method", name));
+        }
+        if (!lookup.isResolved()) {
+            return "No function found for: " + name;
+        }
+
+        var function = requireNonNull(lookup.codeUnit());
+        int levels = depth < 1 ? DEFAULT_CALL_GRAPH_DEPTH : depth;
+        var graph = isCallerGraph
+                ? provider.get().getCallGraphTo(function, levels)
+                : provider.get().getCallGraphFrom(function, levels);
+        if (graph.isEmpty()) {
+            return "No %s found for: %s".formatted(isCallerGraph ? "callers" : "callees", function.fqName());
+        }
+        return recordResearchTokens(AnalyzerUtil.formatCallGraph(graph, function.fqName(), isCallerGraph));
+    }
+
     @Tool(
             """
             Retrieves the git commit log for a file or directory path, showing the history of changes.
@@ -1903,15 +2009,7 @@ public class SearchTools {
 
         final JsonQuery compiledQuery;
         try {
-            Cache<String, JsonQuery> cache = jqQueries.get();
-            JsonQuery cached = cache.getIfPresent(filter);
-            if (cached != null) {
-                compiledQuery = cached;
-            } else {
-                JsonQuery newlyCompiled = JsonQuery.compile(filter, Versions.JQ_1_6);
-                cache.put(filter, newlyCompiled);
-                compiledQuery = newlyCompiled;
-            }
+            compiledQuery = compileJq(filter);
         } catch (JsonQueryException e) {
             logger.warn("Invalid jq filter: {}", e.getMessage(), e);
             return "Invalid jq filter: " + e.getMessage();
@@ -1924,37 +2022,253 @@ public class SearchTools {
                     var contentOpt = ProjectFiles.read(file);
                     if (contentOpt.isEmpty()) return new IndexedResult<>(idx, null, null);
 
-                    var mapper = jqMappers.get();
-                    var rootScope = jqScopes.get();
+                    JsonNode node = jqMappers.get().readTree(contentOpt.get());
+                    String block = applyJsonQuery(file, node, compiledQuery, limits.matchesPerFile(), false);
+                    if (block == null) return new IndexedResult<>(idx, null, null);
+                    return new IndexedResult<>(idx, new FileOutput(file, block), null);
+                } catch (Exception e) {
+                    String message = e.getMessage() == null ? e.toString() : e.getMessage();
+                    return new IndexedResult<>(idx, null, file + ": " + message);
+                }
+            });
+        } catch (RuntimeException e) {
+            String message = e.getMessage() == null ? e.toString() : e.getMessage();
+            logger.error("Error executing jq filter", e);
+            return "jq filter failed: " + message;
+        }
+
+        if (batchResult.results().isEmpty()) {
+            if (!batchResult.errors().isEmpty()) {
+                return "jq filter produced errors in %d of %d files: %s"
+                        .formatted(
+                                batchResult.errors().size(),
+                                files.size(),
+                                batchResult.errors().getFirst());
+            }
+            return "No results for jq filter.";
+        }
 
-                    JsonNode node = mapper.readTree(contentOpt.get());
-                    List<JsonNode> out = new ArrayList<>();
-                    Output outputWriter = out::add;
+        String output = batchResult.results().stream()
+                .sorted((a, b) -> a.file().compareTo(b.file()))
+                .map(FileOutput::output)
+                .collect(Collectors.joining("\n"))
+                .trim();
+        if (batchResult.truncatedByMaxFiles()) {
+            output += "\n\nTRUNCATED: reached maxFiles=%d".formatted(limits.maxFiles());
+        }
 
-                    compiledQuery.apply(Scope.newChildScope(rootScope), node, outputWriter);
+        return recordResearchTokens(output);
+    }
 
-                    if (out.isEmpty()) return new IndexedResult<>(idx, null, null);
+    private static JsonQuery compileJq(String filter) throws JsonQueryException {
+        Cache<String, JsonQuery> cache = jqQueries.get();
+        JsonQuery cached = cache.getIfPresent(filter);
+        if (cached != null) {
+            return cached;
+        }
+        JsonQuery compiled = JsonQuery.compile(filter, Versions.JQ_1_6);
+        cache.put(filter, compiled);
+        return compiled;
+    }
 
-                    int toTake = min(out.size(), limits.matchesPerFile());
-                    boolean hitLimit = out.size() > toTake;
-                    String matchCountLabel = hitLimit
-                            ? "first %d matches".formatted(toTake)
-                            : "%d %s".formatted(toTake, toTake == 1 ? "match" : "matches");
+    /**
+     * Applies a compiled jq query to one parsed document and renders up to {@code matchesPerFile} results under a
+     * "File:" header, or returns null if the query produced nothing. {@code skipNulls} drops null results, which is how
+     * a missing key surfaces in formats without a null value such as TOML.
+     */
+    private static @Nullable String applyJsonQuery(
+            ProjectFile file, JsonNode root, JsonQuery query, int matchesPerFile, boolean skipNulls)
+            throws IOException {
+        var mapper = jqMappers.get();
+        List<JsonNode> out = new ArrayList<>();
+        Output outputWriter = n -> {
+            if (!skipNulls || !n.isNull()) {
+                out.add(n);
+            }
+        };
+
+        query.apply(Scope.newChildScope(jqScopes.get()), root, outputWriter);
+
+        if (out.isEmpty()) return null;
+
+        int toTake = min(out.size(), matchesPerFile);
+        boolean hitLimit = out.size() > toTake;
+        String matchCountLabel = hitLimit
+                ? "first %d matches".formatted(toTake)
+                : "%d %s".formatted(toTake, toTake == 1 ? "match" : "matches");
+
+        List<String> outLines = new ArrayList<>(toTake + 1);
+        outLines.add("File: %s (%s)".formatted(file.toString().replace('\\', '/'), matchCountLabel));
+        for (int i = 0; i < toTake; i++) {
+            JsonNode n = out.get(i);
+            String rendered = mapper.writeValueAsString(n);
+            if (n.isContainerNode() && rendered.length() > XML_MAX_CHARS_PER_NODE) {
+                outLines.add("[JSON_TOO_LARGE]");
+                String skim = jsonSkimBfs(n, XML_MAX_CHARS_PER_NODE);
+                outLines.addAll(List.of(skim.split("\n", -1)));
+            } else {
+                outLines.add(truncateLine(rendered, 0, rendered.length()));
+            }
+        }
+        return String.join("\n", outLines) + "\n";
+    }
 
-                    List<String> outLines = new ArrayList<>(toTake + 1);
-                    outLines.add("File: %s (%s)".formatted(file.toString().replace('\\', '/'), matchCountLabel));
-                    for (int i = 0; i < toTake; i++) {
-                        JsonNode n = out.get(i);
-                        String rendered = mapper.writeValueAsString(n);
-                        if (n.isContainerNode() && rendered.length() > XML_MAX_CHARS_PER_NODE) {
-                            outLines.add("[JSON_TOO_LARGE]");
-                            String skim = jsonSkimBfs(n, XML_MAX_CHARS_PER_NODE);
-                            outLines.addAll(List.of(skim.split("\n", -1)));
-                        } else {
-                            outLines.add(truncateLine(rendered, 0, rendered.length()));
-                        }
-                    }
-                    return new IndexedResult<>(idx, new FileOutput(file, String.join("\n", outLines) + "\n"), null);
+    /**
+     * Converts a tomlSelect selector to a jq filter. Selectors starting with '.' are already jq; anything else is a
+     * dotted TOML key path such as {@code dependencies."serde-json".version}, where a segment may be quoted, be
+     * followed by an array index like {@code bin[0]}, or be {@code *} / {@code []} to fan out over every value of a
+     * table or array. Accesses after a fan-out are optional, so that in {@code dependencies.*.version} a plain-string
+     * dependency such as {@code serde = "1.0"} is skipped instead of failing the whole file.
+     */
+    static String tomlSelectorToJq(String selector) {
+        String trimmed = selector.strip();
+        if (trimmed.startsWith(".")) {
+            return trimmed;
+        }
+        var jq = new StringBuilder(".");
+        boolean fannedOut = false;
+        int i = 0;
+        while (i < trimmed.length()) {
+            char c = trimmed.charAt(i);
+            if (c == '.' || Character.isWhitespace(c)) {
+                i++;
+            } else if (c == '"' || c == '\'') {
+                int end = trimmed.indexOf(c, i + 1);
+                if (end < 0) {
+                    throw new IllegalArgumentException("Unterminated quoted key in TOML path: " + selector);
+                }
+                appendJqKey(jq, trimmed.substring(i + 1, end), fannedOut);
+                i = end + 1;
+            } else if (c == '[') {
+                int end = trimmed.indexOf(']', i);
+                String index = end < 0 ? "" : trimmed.substring(i + 1, end).strip();
+                if (end < 0 || !(index.isEmpty() || index.matches("-?\\d+"))) {
+                    throw new IllegalArgumentException("Invalid array index in TOML path: " + selector);
+                }
+                jq.append('[').append(index).append(']').append(fannedOut ? "?" : "");
+                fannedOut |= index.isEmpty();
+                i = end + 1;
+            } else {
+                int end = i;
+                while (end < trimmed.length() && ".[\"'".indexOf(trimmed.charAt(end)) < 0) {
+                    end++;
+                }
+                String key = trimmed.substring(i, end).strip();
+                if ("*".equals(key)) {
+                    jq.append(fannedOut ? "[]?" : "[]");
+                    fannedOut = true;
+                } else {
+                    appendJqKey(jq, key, fannedOut);
+                }
+                i = end;
+            }
+        }
+        return jq.toString();
+    }
+
+    private static void appendJqKey(StringBuilder jq, String key, boolean optional) {
+        jq.append("[\"").append(key.replace("\\", "\\\\").replace("\"", "\\\"")).append("\"]");
+        if (optional) {
+            jq.append('?');
+        }
+    }
+
+    /**
+     * Expands a path or glob to TOML files, adding the member manifests of any Cargo workspace root among them so that
+     * a query against the root {@code Cargo.toml} spans the whole workspace. Members listed under
+     * {@code workspace.exclude} are left out.
+     */
+    private List<ProjectFile> expandTomlFiles(String filepath) {
+        var project = contextManager.getProject();
+        var files = Completions.expandPath(project, filepath).stream()
+                .filter(ProjectFile.class::isInstance)
+                .map(ProjectFile.class::cast)
+                .filter(pf -> pf.isText() && "toml".equalsIgnoreCase(pf.extension()))
+                .toList();
+
+        var expanded = new LinkedHashSet<ProjectFile>(files);
+        for (ProjectFile manifest : files) {
+            if (!"Cargo.toml".equals(manifest.getFileName())) {
+                continue;
+            }
+            JsonNode workspace;
+            try {
+                var contentOpt = ProjectFiles.read(manifest);
+                if (contentOpt.isEmpty()) continue;
+                workspace = tomlMappers.get().readTree(contentOpt.get()).path("workspace");
+            } catch (IOException e) {
+                logger.debug("Could not read workspace members of {}: {}", manifest, e.getMessage());
+                continue;
+            }
+            var excluded = new HashSet<ProjectFile>();
+            for (JsonNode member : workspace.path("exclude")) {
+                excluded.addAll(cargoMemberManifests(project, manifest, member.asText()));
+            }
+            for (JsonNode member : workspace.path("members")) {
+                cargoMemberManifests(project, manifest, member.asText()).stream()
+                        .filter(pf -> !excluded.contains(pf))
+                        .forEach(expanded::add);
+            }
+        }
+        return prioritizeFilesForSelection(List.copyOf(expanded));
+    }
+
+    private static List<ProjectFile> cargoMemberManifests(
+            IProject project, ProjectFile workspaceManifest, String member) {
+        String base = toUnixPath(workspaceManifest.getParent());
+        String memberDir = toUnixPath(member.strip()).replaceAll("/+$", "");
+        if (memberDir.isEmpty()) {
+            return List.of();
+        }
+        String pattern = (base.isEmpty() ? "" : base + "/") + memberDir + "/Cargo.toml";
+        return Completions.expandPath(project, pattern).stream()
+                .filter(ProjectFile.class::isInstance)
+                .map(ProjectFile.class::cast)
+                .toList();
+    }
+
+    @Tool(
+            """
+            Skims TOML files (e.g. Cargo.toml, pyproject.toml) by walking the parsed document breadth-first (BFS) and
+            emitting compact structural summaries until an output budget is reached, like xmlSkim.
+
+            Output is grouped by file:
+            <file path="Cargo.toml">
+            $ type=object fields=2 keys=["package", "dependencies"]
+            $.package type=object fields=3 keys=["name", "version", "edition"]
+            </file>
+
+            Notes:
+            - A Cargo workspace root (a Cargo.toml with [workspace] members) also pulls in every member manifest.
+            - Output is capped to a per-file budget of 10 * MAX_CHARS_PER_LINE characters.
+            """)
+    public String tomlSkim(
+            @P("File path or glob pattern (e.g., 'Cargo.toml', '**/Cargo.toml').") String filepath,
+            @P("Maximum number of files to return results for. Capped at 100.") int maxFiles)
+            throws InterruptedException {
+        var files = expandTomlFiles(filepath);
+        if (files.isEmpty() && (filepath.startsWith("**/") || filepath.startsWith("**\\"))) {
+            files = expandTomlFiles(filepath.substring(3));
+        }
+
+        if (files.isEmpty()) {
+            return "No TOML files found matching: " + filepath;
+        }
+
+        int effectiveMaxFiles = min(max(1, maxFiles), AlmostGrep.FILE_SEARCH_LIMIT);
+
+        BatchResult<FileOutput> batchResult;
+        try {
+            batchResult = batchProcessFiles(files, effectiveMaxFiles, (file, idx) -> {
+                try {
+                    var contentOpt = ProjectFiles.read(file);
+                    if (contentOpt.isEmpty()) return new IndexedResult<>(idx, null, null);
+
+                    JsonNode root = tomlMappers.get().readTree(contentOpt.get());
+                    String skim = jsonSkimBfs(root, XML_SKIM_TOTAL_BUDGET_CHARS);
+                    String block = "<file path=\"%s\">\n%s\n</file>"
+                            .formatted(file.toString().replace('\\', '/'), skim);
+                    return new IndexedResult<>(idx, new FileOutput(file, block), null);
                 } catch (Exception e) {
                     String message = e.getMessage() == null ? e.toString() : e.getMessage();
                     return new IndexedResult<>(idx, null, file + ": " + message);
@@ -1962,19 +2276,113 @@ public class SearchTools {
             });
         } catch (RuntimeException e) {
             String message = e.getMessage() == null ? e.toString() : e.getMessage();
-            logger.error("Error executing jq filter", e);
-            return "jq filter failed: " + message;
+            logger.error("Error executing tomlSkim", e);
+            return "tomlSkim failed: " + message;
         }
 
         if (batchResult.results().isEmpty()) {
             if (!batchResult.errors().isEmpty()) {
-                return "jq filter produced errors in %d of %d files: %s"
+                return "tomlSkim produced errors in %d of %d files: %s"
                         .formatted(
                                 batchResult.errors().size(),
                                 files.size(),
                                 batchResult.errors().getFirst());
             }
-            return "No results for jq filter.";
+            return "No results for tomlSkim.";
+        }
+
+        String output = batchResult.results().stream()
+                .sorted((a, b) -> a.file().compareTo(b.file()))
+                .map(FileOutput::output)
+                .collect(Collectors.joining("\n\n"))
+                .trim();
+        if (batchResult.truncatedByMaxFiles()) {
+            output += "\n\nTRUNCATED: reached maxFiles=%d".formatted(effectiveMaxFiles);
+        }
+        if (!batchResult.errors().isEmpty()) {
+            output += "\n\nWARNINGS: errors occurred in %d files; first: %s"
+                    .formatted(batchResult.errors().size(), batchResult.errors().getFirst());
+        }
+
+        return recordResearchTokens(appendRelatedContent(
+                output, batchResult.results().stream().map(FileOutput::file).toList()));
+    }
+
+    @Tool(
+            """
+            Selects values from TOML files (e.g. Cargo.toml) using a dotted key path or a jq filter.
+
+            Selectors:
+            - Dotted key path: 'package.name', 'dependencies.serde.version', 'dependencies."serde-json"',
+              'bin[0].name'. A '*' or '[]' segment fans out over every value of a table or array,
+              e.g. 'dependencies.*.version' or 'bin[].name'.
+            - jq filter: any selector starting with '.', e.g. '.dependencies | keys'.
+            Keys missing from a file produce no output for that file.
+
+            Notes:
+            - A Cargo workspace root (a Cargo.toml with [workspace] members) also pulls in every member manifest,
+              so one query spans the whole workspace; members under workspace.exclude are skipped.
+            - Output is grouped by file like jq; large tables fall back to a BFS structural skim.
+            - maxFiles and matchesPerFile are capped at 100 each.
+            - maxFiles * matchesPerFile is forced to be <= 500.
+            """)
+    public String tomlSelect(
+            @P("File path or glob pattern (e.g., 'Cargo.toml', '**/Cargo.toml').") String filepath,
+            @P("Dotted TOML key path (e.g. 'package.name') or jq filter starting with '.'.") String selector,
+            @P("Maximum number of files to return results for. Capped at 100.") int maxFiles,
+            @P("Maximum number of values to return per file. Capped at 100.") int matchesPerFile)
+            throws InterruptedException {
+        var files = expandTomlFiles(filepath);
+
+        if (files.isEmpty()) {
+            return "No TOML files found matching: " + filepath;
+        }
+
+        if (selector.isBlank()) {
+            throw new IllegalArgumentException("Cannot tomlSelect: selector is empty");
+        }
+
+        EffectiveLimits limits = clampMaxFilesAndMatchesPerFile(maxFiles, matchesPerFile);
+
+        final JsonQuery compiledQuery;
+        try {
+            compiledQuery = compileJq(tomlSelectorToJq(selector));
+        } catch (IllegalArgumentException | JsonQueryException e) {
+            logger.warn("Invalid TOML selector: {}", e.getMessage(), e);
+            return "Invalid TOML selector: " + e.getMessage();
+        }
+
+        BatchResult<FileOutput> batchResult;
+        try {
+            batchResult = batchProcessFiles(files, limits.maxFiles(), (file, idx) -> {
+                try {
+                    var contentOpt = ProjectFiles.read(file);
+                    if (contentOpt.isEmpty()) return new IndexedResult<>(idx, null, null);
+
+                    JsonNode node = tomlMappers.get().readTree(contentOpt.get());
+                    String block = applyJsonQuery(file, node, compiledQuery, limits.matchesPerFile(), true);
+                    if (block == null) return new IndexedResult<>(idx, null, null);
+                    return new IndexedResult<>(idx, new FileOutput(file, block), null);
+                } catch (Exception e) {
+                    String message = e.getMessage() == null ? e.toString() : e.getMessage();
+                    return new IndexedResult<>(idx, null, file + ": " + message);
+                }
+            });
+        } catch (RuntimeException e) {
+            String message = e.getMessage() == null ? e.toString() : e.getMessage();
+            logger.error("Error executing tomlSelect", e);
+            return "tomlSelect failed: " + message;
+        }
+
+        if (batchResult.results().isEmpty()) {
+            if (!batchResult.errors().isEmpty()) {
+                return "tomlSelect produced errors in %d of %d files: %s"
+                        .formatted(
+                                batchResult.errors().size(),
+                                files.size(),
+                                batchResult.errors().getFirst());
+            }
+            return "No results for TOML selector.";
         }
 
         String output = batchResult.results().stream()
@@ -1985,6 +2393,10 @@ public class SearchTools {
         if (batchResult.truncatedByMaxFiles()) {
             output += "\n\nTRUNCATED: reached maxFiles=%d".formatted(limits.maxFiles());
         }
+        if (!batchResult.errors().isEmpty()) {
+            output += "\n\nWARNINGS: errors occurred in %d files; first: %s"
+                    .formatted(batchResult.errors().size(), batchResult.errors().getFirst());
+        }
 
         return recordResearchTokens(output);
     }
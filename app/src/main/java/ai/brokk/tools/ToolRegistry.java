@@ -108,6 +108,8 @@ public class ToolRegistry {
             Map.entry("searchFileContents", "Searching file contents"),
             Map.entry("xmlSkim", "Skimming XML"),
             Map.entry("xmlSelect", "Selecting XML with XPath"),
+            Map.entry("tomlSkim", "Skimming TOML"),
+            Map.entry("tomlSelect", "Selecting TOML"),
             Map.entry("jq", "Running jq filter"),
             Map.entry("dropWorkspaceFragments", "Removing from workspace"),
             Map.entry("recommendContext", "Recommending context"),
@@ -17,6 +17,7 @@ import ai.brokk.analyzer.usages.JsTsExportUsageGraphStrategy;
 import ai.brokk.analyzer.usages.PythonExportUsageGraphStrategy;
 import ai.brokk.analyzer.usages.RustExportUsageGraphStrategy;
 import ai.brokk.analyzer.usages.UsageAnalyzer;
+import ai.brokk.analyzer.usages.UsageKind;
 import ai.brokk.project.IProject;
 import ai.brokk.project.ModelProperties;
 import java.util.List;
@@ -244,6 +245,15 @@ public final class UsageFinder {
         return findUsages(fqName, DEFAULT_MAX_FILES, DEFAULT_MAX_USAGES);
     }
 
+    /**
+     * Finds usages of {@code fqName} that touch it in one of {@code kinds}, e.g. only writes and mutable borrows when
+     * looking for who mutates a field. Only analyzers that classify usages (currently Rust) produce hits here; an
+     * empty {@code kinds} applies no filter.
+     */
+    public FuzzyResult findUsages(String fqName, Set<UsageKind> kinds) throws InterruptedException {
+        return findUsages(fqName).withKinds(kinds);
+    }
+
     public UsageQueryResult queryUsages(CodeUnit target, int maxFiles, int maxUsages) throws InterruptedException {
         if (isEffectivelyEmpty()) {
             return new UsageQueryResult(Set.of(), false, new FuzzyResult.Success(Map.of()));
@@ -8,6 +8,8 @@ import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.IAnalyzer;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.RustAnalyzer;
+import ai.brokk.analyzer.rust.CargoTestSelection;
 import ai.brokk.context.Context;
 import ai.brokk.context.ContextFragment;
 import ai.brokk.project.FileFilteringService;
@@ -30,6 +32,7 @@ import java.util.Optional;
 import java.util.concurrent.CompletableFuture;
 import java.util.function.BiPredicate;
 import java.util.function.Predicate;
+import java.util.regex.Pattern;
 import java.util.stream.Collectors;
 import java.util.stream.Stream;
 import org.apache.logging.log4j.LogManager;
@@ -41,6 +44,8 @@ import org.jetbrains.annotations.VisibleForTesting;
 public class BuildTools {
     private static final Logger logger = LogManager.getLogger(BuildTools.class);
     private static final ObjectMapper OBJECT_MAPPER = new ObjectMapper();
+    private static final Pattern CARGO_SECTION =
+            Pattern.compile("\\{\\{\\s*[#^]?\\s*cargo(?:tests|packages|targets)\\b");
 
     /** Determine the best verification command using the provided Context. */
     @Blocking
@@ -234,6 +239,22 @@ public class BuildTools {
         context.put("fqclasses", MustacheTemplates.toStringElementList(fqClasses));
         context.put("classes", MustacheTemplates.toStringElementList(classes));
 
+        // 4. Cargo: exact test paths plus the packages and targets holding them
+        CargoTestSelection cargo = analyzer.subAnalyzer(Languages.RUST)
+                .filter(RustAnalyzer.class::isInstance)
+                .map(rust -> ((RustAnalyzer) rust)
+                        .cargoTestSelection(testFiles.stream()
+                                .filter(f -> Languages.fromExtension(f.extension()) == Languages.RUST)
+                                .toList()))
+                .orElse(CargoTestSelection.EMPTY);
+        context.put("cargotests", MustacheTemplates.toStringElementList(cargo.testPaths()));
+        context.put("cargopackages", MustacheTemplates.toStringElementList(cargo.packages()));
+        context.put("cargotargets", MustacheTemplates.toStringElementList(cargo.targets()));
+        if (cargo.isEmpty() && CARGO_SECTION.matcher(template).find()) {
+            // With nothing to select, a cargo template renders as a bare `cargo test`, which runs the whole suite.
+            return "";
+        }
+
         String result;
         try {
             MustacheFactory mf = new DefaultMustacheFactory();
@@ -2,13 +2,17 @@ package ai.brokk.util;
 
 import static org.checkerframework.checker.nullness.util.NullnessUtil.castNonNull;
 
+import ai.brokk.analyzer.LintResult;
+import ai.brokk.analyzer.rust.CargoDiagnostics;
 import ai.brokk.gui.dialogs.JdkSelector;
 import ai.brokk.project.IProject;
 import java.nio.file.Path;
 import java.util.ArrayDeque;
+import java.util.ArrayList;
 import java.util.Arrays;
 import java.util.Deque;
 import java.util.HashMap;
+import java.util.List;
 import java.util.Map;
 import java.util.function.Consumer;
 import java.util.stream.Collectors;
@@ -34,6 +38,14 @@ public final class BuildVerifier {
      */
     public record VerificationResult(boolean success, int exitCode, String output) {}
 
+    /**
+     * Result of a cargo lint run with structured diagnostics.
+     *
+     * @param verification the command outcome; when diagnostics were parsed, its output is their compact report
+     * @param lint the diagnostics rustc and clippy reported, empty if none could be parsed
+     */
+    public record CargoLintResult(VerificationResult verification, LintResult lint) {}
+
     private BuildVerifier() {}
 
     /**
@@ -70,7 +82,10 @@ public final class BuildVerifier {
             @Nullable Consumer<String> outputConsumer) {
         // 1. Run lint/compile first (if configured)
         if (!lintCommand.isBlank()) {
-            var lintResult = verifyStreaming(project, lintCommand, extraEnv, outputConsumer);
+            var lintResult = CargoDiagnostics.isLintCommand(lintCommand)
+                    ? verifyCargoLint(project, lintCommand, extraEnv, outputConsumer)
+                            .verification()
+                    : verifyStreaming(project, lintCommand, extraEnv, outputConsumer);
             if (!lintResult.success()) {
                 logger.debug("Lint/compile failed (exit {}); skipping tests", lintResult.exitCode());
                 return lintResult;
@@ -185,6 +200,74 @@ public final class BuildVerifier {
         }
     }
 
+    /**
+     * Run a {@code cargo check} or {@code cargo clippy} command with {@code --message-format=json} and parse the
+     * diagnostics it reports, so that exact file positions, error codes and suggested fixes can be handed to the
+     * agent without an LLM pass over the raw output.
+     *
+     * @param project the project context; cargo is expected to run at its root
+     * @param lintCommand the cargo lint command; a message format is added unless one is already given
+     * @param extraEnv optional additional environment variables
+     * @return the command outcome together with the parsed diagnostics
+     */
+    public static CargoLintResult verifyCargoLint(
+            IProject project, String lintCommand, @Nullable Map<String, String> extraEnv) {
+        return verifyCargoLint(project, lintCommand, extraEnv, null);
+    }
+
+    /**
+     * As {@link #verifyCargoLint(IProject, String, Map)}, passing cargo's own progress lines to {@code outputConsumer}
+     * as they are produced, followed by the diagnostics report once the run completes.
+     */
+    public static CargoLintResult verifyCargoLint(
+            IProject project,
+            String lintCommand,
+            @Nullable Map<String, String> extraEnv,
+            @Nullable Consumer<String> outputConsumer) {
+        String command = CargoDiagnostics.withJsonMessageFormat(lintCommand.trim());
+        List<String> jsonLines = new ArrayList<>();
+        Deque<String> otherLines = new ArrayDeque<>(MAX_OUTPUT_LINES);
+        var verification = verifyStreaming(project, command, extraEnv, line -> {
+            if (line.startsWith("{")) {
+                synchronized (jsonLines) {
+                    jsonLines.add(line);
+                }
+                return;
+            }
+            synchronized (otherLines) {
+                appendBounded(otherLines, line);
+            }
+            if (outputConsumer != null) {
+                outputConsumer.accept(line);
+            }
+        });
+
+        LintResult lint;
+        synchronized (jsonLines) {
+            lint = CargoDiagnostics.parse(String.join("\n", jsonLines), project.getRoot());
+        }
+        if (lint.diagnostics().isEmpty()) {
+            return new CargoLintResult(verification, lint);
+        }
+        String reportText = CargoDiagnostics.formatReport(lint.diagnostics());
+        if (!verification.success() && lint.getErrors().isEmpty()) {
+            // The failure is not a compiler diagnostic (a build script panic, a dependency resolution or linker
+            // error), so cargo's own output is the only place it shows up.
+            String otherOutput;
+            synchronized (otherLines) {
+                otherOutput = joinLines(otherLines);
+            }
+            if (!otherOutput.isBlank()) {
+                reportText = reportText + "\n\n" + otherOutput;
+            }
+        }
+        var report = new VerificationResult(verification.success(), verification.exitCode(), reportText);
+        if (outputConsumer != null) {
+            report.output().lines().forEach(outputConsumer);
+        }
+        return new CargoLintResult(report, lint);
+    }
+
     /**
      * Convenience overload without extra environment variables.
      */
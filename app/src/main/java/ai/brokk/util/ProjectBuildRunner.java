@@ -4,7 +4,9 @@ import ai.brokk.IAppContextManager;
 import ai.brokk.IConsoleIO.NotificationRole;
 import ai.brokk.LlmOutputMeta;
 import ai.brokk.agents.BuildAgent.BuildDetails;
+import ai.brokk.analyzer.LintResult;
 import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.rust.CargoDiagnostics;
 import ai.brokk.context.Context;
 import ai.brokk.project.IProject;
 import dev.langchain4j.data.message.ChatMessageType;
@@ -32,11 +34,20 @@ public class ProjectBuildRunner {
 
     private final IProject project;
     private final AtomicReference<CompletableFuture<Void>> warmupBuildFutureRef = new AtomicReference<>();
+    private volatile @Nullable LintResult lastCargoLint;
 
     public ProjectBuildRunner(IProject project) {
         this.project = project;
     }
 
+    /**
+     * The cargo diagnostics behind the most recent failed verification, when it ran {@code cargo check} or
+     * {@code cargo clippy}; empty after any other verification.
+     */
+    public Optional<LintResult> lastCargoLint() {
+        return Optional.ofNullable(lastCargoLint);
+    }
+
     public void cancelWarmupBuildIfRunning() {
         CompletableFuture<Void> future = warmupBuildFutureRef.getAndSet(null);
         if (future != null) {
@@ -208,10 +219,14 @@ public class ProjectBuildRunner {
         IAppContextManager cm = ctx.getContextManager();
         var io = cm.getIo();
         BuildDetails details = override != null ? override : project.awaitBuildDetails();
+        lastCargoLint = null;
         @Nullable String testRetriesEnv = System.getenv("BRK_TEST_RETRIES");
         if (testRetriesEnv != null && !testRetriesEnv.isBlank()) {
             return runBuildWithTestRetries(ctx, verificationCommand, details, Integer.parseInt(testRetriesEnv.trim()));
         }
+        if (CargoDiagnostics.isLintCommand(verificationCommand)) {
+            return runCargoLint(ctx, verificationCommand, details);
+        }
         io.commandStart("Verification", verificationCommand);
         var output = io.supportsCommandResult() ? new StringBuilder() : null;
         try {
@@ -248,6 +263,36 @@ public class ProjectBuildRunner {
         }
     }
 
+    /**
+     * Runs a {@code cargo check}/{@code cargo clippy} verification with JSON diagnostics, so the build error handed to
+     * the agent is the compact per-diagnostic report rather than an LLM summary of the raw output.
+     */
+    private Context runCargoLint(Context ctx, String verificationCommand, BuildDetails details)
+            throws InterruptedException {
+        IAppContextManager cm = ctx.getContextManager();
+        var io = cm.getIo();
+        io.commandStart("Verification", verificationCommand);
+        var output = io.supportsCommandResult() ? new StringBuilder() : null;
+        var result = BuildVerifier.verifyCargoLint(
+                project, verificationCommand, details.environmentVariables(), line -> {
+                    if (output != null) output.append(line).append("\n");
+                    io.commandOutput(line);
+                });
+        var verification = result.verification();
+        if (output != null) {
+            io.commandResult("Verification", verificationCommand, verification.success(), output.toString(), null);
+        }
+        if (verification.success()) {
+            return ctx.withBuildResult(true, "Build succeeded.");
+        }
+        lastCargoLint = result.lint();
+        // Without an error diagnostic the failure lies in cargo's raw output, which the report then carries.
+        String buildError = result.lint().getErrors().isEmpty()
+                ? BuildOutputProcessor.processForLlm(verification.output(), cm)
+                : verification.output();
+        return ctx.withBuildResult(false, buildError);
+    }
+
     private Context runBuildWithTestRetries(
             Context ctx, String verificationCommand, BuildDetails details, int maxRetries) throws InterruptedException {
         IAppContextManager cm = ctx.getContextManager();
@@ -0,0 +1,168 @@
+package ai.brokk;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import ai.brokk.EditBlock.SearchReplaceBlock;
+import ai.brokk.analyzer.LintResult;
+import ai.brokk.analyzer.LintResult.LintDiagnostic;
+import ai.brokk.analyzer.LintResult.LintDiagnostic.Severity;
+import ai.brokk.analyzer.LintResult.Suggestion;
+import ai.brokk.analyzer.LintResult.Suggestion.Applicability;
+import ai.brokk.testutil.TestConsoleIO;
+import ai.brokk.testutil.TestContextManager;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+class SuggestionEditsTest {
+    private static final String LIB_RS =
+            """
+            fn area(a: u32, b: u32) -> u32 {
+                return a * b;
+            }
+
+            fn main() {
+                let mut total = 0;
+                print_name(name);
+                print_name(name);
+            }
+            """;
+
+    @Test
+    void planTurnsMachineApplicableSuggestionsIntoUniqueBlocks() {
+        var needlessReturn = diagnostic(
+                "clippy::needless_return", 2, fix(2, 5, 17, "a * b", "remove `return`", "    return a * b;"));
+        var unusedMut = diagnostic("unused_mut", 6, fix(6, 9, 13, "", "remove this `mut`", "    let mut total = 0;"));
+        var borrow = diagnostic("E0308", 8, fix(8, 16, 16, "&", "consider borrowing here", "    print_name(name);"));
+        var maybe = diagnostic(
+                "unused_variables",
+                6,
+                suggestion(6, 13, 18, "_total", Applicability.MAYBE_INCORRECT, "prefix", "    let mut total = 0;"));
+
+        var fixes = SuggestionEdits.machineApplicableFixes(
+                new LintResult(List.of(needlessReturn, unusedMut, borrow, maybe, borrow)));
+        assertEquals(3, fixes.size(), "MaybeIncorrect and repeated fixes are not taken");
+
+        var plan = SuggestionEdits.plan(fixes, file -> Optional.of(LIB_RS));
+        assertTrue(plan.conflicts().isEmpty(), plan.conflicts().toString());
+        assertEquals(
+                List.of(
+                        new SearchReplaceBlock("src/lib.rs", "    return a * b;\n", "    a * b;\n"),
+                        new SearchReplaceBlock("src/lib.rs", "    let mut total = 0;\n", "    let total = 0;\n"),
+                        // Line 8 repeats line 7, so its block takes in the surrounding lines to be unique.
+                        new SearchReplaceBlock(
+                                "src/lib.rs",
+                                "    print_name(name);\n    print_name(name);\n}\n",
+                                "    print_name(name);\n    print_name(&name);\n}\n")),
+                plan.blocks());
+        assertEquals(List.of(fixes.get(2)), plan.fixesByBlock().get(plan.blocks().get(2)));
+    }
+
+    @Test
+    void planReportsStaleAndOverlappingFixesAsConflicts() {
+        var stale = diagnostic("E0308", 7, fix(7, 16, 16, "&", "consider borrowing here", "    print_name(title);"));
+        var removeMut = diagnostic("unused_mut", 6, fix(6, 9, 13, "", "remove this `mut`", "    let mut total = 0;"));
+        var renameMut = diagnostic("clippy::other", 6, fix(6, 9, 18, "total", "rename", "    let mut total = 0;"));
+
+        var fixes = SuggestionEdits.machineApplicableFixes(new LintResult(List.of(stale, removeMut, renameMut)));
+        var plan = SuggestionEdits.plan(fixes, file -> Optional.of(LIB_RS));
+
+        assertEquals(1, plan.blocks().size());
+        assertEquals(
+                Map.of(
+                        fixes.get(0), "source changed since the linter ran",
+                        fixes.get(2), "overlaps an earlier fix"),
+                Map.of(
+                        plan.conflicts().get(0).fix(), plan.conflicts().get(0).conflict(),
+                        plan.conflicts().get(1).fix(), plan.conflicts().get(1).conflict()));
+    }
+
+    @Test
+    void planTreatsFixesWithoutSourceTextAsStale() {
+        var withoutText = new Suggestion(
+                "src/lib.rs",
+                2,
+                5,
+                2,
+                17,
+                0,
+                0,
+                "a * b",
+                Applicability.MACHINE_APPLICABLE,
+                "remove `return`",
+                List.of());
+        var fixes = SuggestionEdits.machineApplicableFixes(
+                new LintResult(List.of(diagnostic("clippy::needless_return", 2, withoutText))));
+
+        var plan = SuggestionEdits.plan(fixes, file -> Optional.of(LIB_RS));
+
+        assertTrue(plan.blocks().isEmpty());
+        assertEquals("source changed since the linter ran", plan.conflicts().getFirst().conflict());
+    }
+
+    @Test
+    void applyEditsTheFileAndReportsOutcomes(@TempDir Path tempDir) throws Exception {
+        Files.createDirectories(tempDir.resolve("src"));
+        Path lib = tempDir.resolve("src/lib.rs");
+        Files.writeString(lib, LIB_RS);
+
+        var lint = new LintResult(List.of(
+                diagnostic(
+                        "clippy::needless_return",
+                        2,
+                        fix(2, 5, 17, "a * b", "remove `return`", "    return a * b;")),
+                diagnostic("E0308", 7, fix(7, 16, 16, "&", "consider borrowing here", "    print_name(title);"))));
+
+        var cm = new TestContextManager(tempDir, Set.of("src/lib.rs"));
+        var report = SuggestionEdits.apply(cm.liveContext(), new TestConsoleIO(), lint);
+
+        assertEquals(1, report.applied().size());
+        assertEquals(1, report.conflicted().size());
+        assertTrue(Files.readString(lib).contains("    a * b;\n"));
+        assertEquals(
+                """
+                Applied 1 of 2 machine-applicable fixes:
+                  applied: src/lib.rs:2: remove `return` [clippy::needless_return]
+                  conflicted: src/lib.rs:7: consider borrowing here [E0308] (source changed since the linter ran)""",
+                report.format());
+    }
+
+    private static LintDiagnostic diagnostic(String code, int line, Suggestion suggestion) {
+        return new LintDiagnostic(
+                "src/lib.rs", line, suggestion.columnStart(), Severity.WARNING, code, code, List.of(suggestion));
+    }
+
+    private static Suggestion fix(
+            int line, int columnStart, int columnEnd, String replacement, String message, String sourceLine) {
+        return suggestion(
+                line, columnStart, columnEnd, replacement, Applicability.MACHINE_APPLICABLE, message, sourceLine);
+    }
+
+    /** A single-line suggestion in {@code src/lib.rs}. */
+    private static Suggestion suggestion(
+            int line,
+            int columnStart,
+            int columnEnd,
+            String replacement,
+            Applicability applicability,
+            String message,
+            String sourceLine) {
+        return new Suggestion(
+                "src/lib.rs",
+                line,
+                columnStart,
+                line,
+                columnEnd,
+                0,
+                0,
+                replacement,
+                applicability,
+                message,
+                List.of(sourceLine));
+    }
+}
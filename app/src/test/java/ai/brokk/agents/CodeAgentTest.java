@@ -641,6 +641,53 @@ class CodeAgentTest {
         assertEquals(TaskResult.StopReason.SUCCESS, step.stopDetails().reason());
     }
 
+    // V-4: verifyPhase applies cargo's machine-applicable fixes and re-runs the build before asking the LLM
+    @Test
+    void testVerifyPhase_appliesCargoSuggestionsBeforeRetrying() throws IOException {
+        Path lib = projectRoot.resolve("src/lib.rs");
+        Files.createDirectories(lib.getParent());
+        Files.writeString(
+                lib,
+                """
+                fn print_name(name: &str) {}
+
+                fn main() {
+                    let name = String::new();
+                    print_name(name);
+                }
+                """);
+        project.setBuildDetails(new BuildAgent.BuildDetails("cargo check", "cargo clippy", Set.of()));
+        project.setCodeAgentTestScope(IProject.CodeAgentTestScope.ALL);
+
+        String diagnostic =
+                """
+                {"reason":"compiler-message","message":{"message":"mismatched types","code":{"code":"E0308"},\
+                "level":"error","spans":[{"file_name":"src/lib.rs","is_primary":true,"line_start":5,"line_end":5,\
+                "column_start":16,"column_end":20,"suggested_replacement":null}],"children":[{"message":\
+                "consider borrowing here","level":"help","children":[],"spans":[{"file_name":"src/lib.rs",\
+                "is_primary":true,"line_start":5,"line_end":5,"column_start":16,"column_end":16,\
+                "suggested_replacement":"&","suggestion_applicability":"MachineApplicable",\
+                "text":[{"text":"    print_name(name);"}]}]}]}}""";
+        var commands = new ArrayList<String>();
+        Environment.shellCommandRunnerFactory = (cmd, root) -> (outputConsumer, timeout) -> {
+            commands.add(cmd);
+            if (commands.size() == 1) {
+                outputConsumer.accept(diagnostic);
+                throw new Environment.FailureException("Command failed", "", 101);
+            }
+            return "";
+        };
+
+        var result = codeAgent.verifyPhase(createBasicConversationState(), createEditState(1), null);
+
+        assertInstanceOf(CodeAgent.Step.Fatal.class, result);
+        assertEquals(TaskResult.StopReason.SUCCESS, ((CodeAgent.Step.Fatal) result).stopDetails().reason());
+        assertEquals(
+                List.of("cargo clippy --message-format=json", "cargo clippy --message-format=json"), commands);
+        assertTrue(Files.readString(lib).contains("    print_name(&name);\n"));
+        assertTrue(cm.getProject().getBuildRunner().lastCargoLint().isEmpty());
+    }
+
     // INT-1: Interruption during verifyPhase (via Environment stub)
     @Test
     void testVerifyPhase_interruptionDuringBuild() {
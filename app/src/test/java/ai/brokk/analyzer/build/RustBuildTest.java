@@ -16,6 +16,10 @@ import org.junit.jupiter.api.Test;
 
 public class RustBuildTest {
 
+    private static final String CARGO_TEST_TEMPLATE = "cargo test {{#cargopackages}}-p {{value}} {{/cargopackages}}"
+            + "{{#cargotargets}}{{value}} {{/cargotargets}}"
+            + "-- --exact {{#cargotests}}{{value}}{{^last}} {{/last}}{{/cargotests}}";
+
     @Test
     void testRustTestCommandInterpolation() throws Exception {
         String code = """
@@ -36,13 +40,12 @@ public class RustBuildTest {
 
             TestContextManager cm = new TestContextManager(project, new NoOpConsoleIO(), Set.of(), project.getAnalyzer());
 
-            // Rust test filtering often uses a pattern that matches the module and/or function name.
             BuildDetails details = new BuildDetails(
                     "cargo build",
                     true,
                     "cargo test",
                     true,
-                    "cargo test {{#classes}}{{value}}{{^last}} {{/last}}{{/classes}}",
+                    CARGO_TEST_TEMPLATE,
                     true,
                     Set.of(),
                     java.util.Collections.emptyMap(),
@@ -53,11 +56,82 @@ public class RustBuildTest {
             // Act
             String command = BuildTools.getBuildLintSomeCommand(cm, details, List.of(testFile)).trim();
 
-            // Assert
-            // RustAnalyzer identifies 'tests' (module) and 'test_my_logic' (function).
-            // However, the test runner output shows it currently results in 'tests'.
-            // We adjust the expectation to match the current analyzer output.
-            assertEquals("cargo test tests", command);
+            // Assert: the test's path within the crate, with no Cargo package or target outside a Cargo project
+            assertEquals("cargo test -- --exact tests::test_my_logic", command);
+        }
+    }
+
+    @Test
+    void testCargoWorkspaceSelectsPackagesTargetsAndExactPaths() throws Exception {
+        String shapes = """
+                #[cfg(test)]
+                mod tests {
+                    #[test]
+                    fn test_my_logic() {}
+
+                    fn helper() {}
+                }
+                """;
+        String api = """
+                #[tokio::test]
+                async fn serves() {}
+                """;
+
+        try (ITestProject project = InlineTestProjectCreator.code(
+                        "[workspace]\nmembers = [\"geometry\", \"render\"]\n", "Cargo.toml")
+                .addFileContents("[package]\nname = \"geometry\"\nversion = \"0.1.0\"\n", "geometry/Cargo.toml")
+                .addFileContents("pub mod shapes;\n", "geometry/src/lib.rs")
+                .addFileContents(shapes, "geometry/src/shapes.rs")
+                .addFileContents("[package]\nname = \"render-core\"\nversion = \"0.1.0\"\n", "render/Cargo.toml")
+                .addFileContents("pub fn render() {}\n", "render/src/lib.rs")
+                .addFileContents(api, "render/tests/api.rs")
+                .build()) {
+            TestContextManager cm = new TestContextManager(project, new NoOpConsoleIO(), Set.of(), project.getAnalyzer());
+            BuildDetails details = new BuildDetails(
+                    "",
+                    true,
+                    "",
+                    true,
+                    CARGO_TEST_TEMPLATE,
+                    true,
+                    Set.of(),
+                    java.util.Collections.emptyMap(),
+                    null,
+                    "",
+                    List.of());
+            var testFiles = project.getAllFiles().stream()
+                    .filter(f -> f.toString().endsWith("shapes.rs") || f.toString().endsWith("api.rs"))
+                    .toList();
+
+            String command = BuildTools.getBuildLintSomeCommand(cm, details, testFiles);
+            assertEquals(
+                    "cargo test -p geometry -p render-core --lib --test api"
+                            + " -- --exact serves shapes::tests::test_my_logic",
+                    command.trim());
+        }
+    }
+
+    @Test
+    void testEmptyCargoSelectionFallsBackToBuildLint() throws Exception {
+        try (ITestProject project = InlineTestProjectCreator.code("pub fn area() -> u32 { 4 }\n", "src/lib.rs")
+                .build()) {
+            TestContextManager cm = new TestContextManager(project, new NoOpConsoleIO(), Set.of(), project.getAnalyzer());
+            BuildDetails details = new BuildDetails(
+                    "cargo build",
+                    true,
+                    "cargo test",
+                    true,
+                    CARGO_TEST_TEMPLATE,
+                    true,
+                    Set.of(),
+                    java.util.Collections.emptyMap(),
+                    null,
+                    "",
+                    List.of());
+
+            // No tests are selected, so the template would render `cargo test -- --exact` and run the whole suite.
+            String command = BuildTools.getBuildLintSomeCommand(cm, details, List.copyOf(project.getAllFiles()));
+            assertEquals("cargo build", command.trim());
         }
     }
 
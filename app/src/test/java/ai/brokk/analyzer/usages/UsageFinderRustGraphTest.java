@@ -123,4 +123,49 @@ final class UsageFinderRustGraphTest {
                             .hitCount());
         }
     }
+
+    @Test
+    void appUsageFinderFiltersRustFieldUsagesByKind() throws Exception {
+        String counter =
+                """
+                pub struct Counter {
+                    pub count: u32,
+                }
+
+                pub fn bump(counter: &mut Counter) {
+                    counter.count += 1;
+                }
+
+                pub fn total(counter: &Counter) -> u32 {
+                    counter.count
+                }
+                """;
+
+        try (var project =
+                InlineTestProjectCreator.code(counter, "src/counter.rs").build()) {
+            var analyzer = new RustAnalyzer(project);
+            var target = analyzer.getAllDeclarations().stream()
+                    .filter(cu -> "count".equals(cu.identifier()))
+                    .findFirst()
+                    .orElseThrow();
+            var emptyFallback = new UsageFinder(
+                    project,
+                    analyzer,
+                    UsageFinder.createDefaultProvider(),
+                    (overloads, candidates, maxUsages) ->
+                            new FuzzyResult.Success(Map.of(overloads.getFirst(), Set.of())),
+                    null);
+
+            assertEquals(2, emptyFallback.findUsages(target.fqName()).toEither().getUsages().size());
+
+            var writes = emptyFallback
+                    .findUsages(target.fqName(), Set.of(UsageKind.WRITE))
+                    .toEither()
+                    .getUsages();
+
+            assertEquals(1, writes.size());
+            assertEquals(6, writes.iterator().next().line());
+            assertEquals(UsageKind.WRITE, writes.iterator().next().kind());
+        }
+    }
 }
@@ -119,6 +119,32 @@ class CodeQualityToolsCognitiveComplexityTest {
         }
     }
 
+    @Test
+    void computeCognitiveComplexityExplainsRustFindings() throws IOException {
+        try (var project = InlineTestProjectCreator.code(
+                        """
+                pub fn parse_all(lines: &[&str]) -> Result<u32, String> {
+                    let mut total = 0;
+                    for line in lines {
+                        let Some(value) = line.strip_prefix('+') else {
+                            continue;
+                        };
+                        total += value.parse::<u32>().map_err(|e| e.to_string())?;
+                    }
+                    Ok(total)
+                }
+                """,
+                        "src/lib.rs")
+                .build()) {
+            var contextManager = new TestContextManager(project, new TestConsoleIO(), Set.of(), project.getAnalyzer());
+            var tools = new CodeQualityTools(contextManager);
+
+            String report = tools.computeCognitiveComplexity(List.of("src/lib.rs"), 3);
+
+            assertTrue(report.contains("parse_all: 4 (loop +1, let-else +2, try-operator +1)"), report);
+        }
+    }
+
     private static final class BatchOnlyAnalyzer extends TestAnalyzer {
         private final Map<CodeUnit, Integer> complexities;
         private boolean batchCalled;
@@ -5,11 +5,13 @@ import static org.junit.jupiter.api.Assertions.assertFalse;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
 import ai.brokk.analyzer.Languages;
+import ai.brokk.analyzer.rust.CargoDependencyGraph;
 import ai.brokk.testutil.TestContextManager;
 import ai.brokk.testutil.TestProject;
 import ai.brokk.util.MavenArtifactFetcher;
 import java.nio.file.Files;
 import java.nio.file.Path;
+import java.util.List;
 import java.util.Optional;
 import java.util.concurrent.atomic.AtomicInteger;
 import java.util.concurrent.atomic.AtomicReference;
@@ -331,6 +333,45 @@ public class DependencyToolsTest {
         assertTrue(result.contains("not found") || result.contains("npm install"));
     }
 
+    // ========== Cargo Dependency Graph Tests ==========
+
+    @Test
+    void cargoGraphTools_FormatPathsAndDuplicates() {
+        var app = new CargoDependencyGraph.Crate("app", "app", "0.1.0", null, true, List.of());
+        var json = new CargoDependencyGraph.Crate("json", "serde_json", "1.0.1", "registry+x", false, List.of());
+        var syn1 = new CargoDependencyGraph.Crate("syn1", "syn", "1.0.109", "registry+x", false, List.of());
+        var syn2 = new CargoDependencyGraph.Crate("syn2", "syn", "2.0.48", "registry+x", false, List.of());
+        var graph = new CargoDependencyGraph(
+                List.of(app, json, syn1, syn2),
+                List.of(
+                        new CargoDependencyGraph.Dependency(
+                                "app", "json", "normal", "cfg(unix)", true, "js", List.of("std"), false),
+                        new CargoDependencyGraph.Dependency(
+                                "json", "syn1", "build", null, false, null, List.of(), true),
+                        new CargoDependencyGraph.Dependency(
+                                "app", "syn2", "dev", null, false, null, List.of(), true)));
+
+        assertEquals(
+                """
+                ## syn 1.0.109 (registry+x)
+                - app 0.1.0 -> serde_json 1.0.1 [target cfg(unix); optional; as js; no default features; \
+                features std] -> syn 1.0.109 [build]""",
+                DependencyTools.formatCargoWhy(graph, "syn 1"));
+        assertEquals(
+                """
+                Workspace members depending on syn:
+                - app (direct): app 0.1.0 -> syn 2.0.48 [dev]""",
+                DependencyTools.formatCargoDependents(graph, "syn"));
+        assertEquals(
+                """
+                Crates built at more than one version:
+                - syn
+                  - 1.0.109, required by serde_json 1.0.1 [build]
+                  - 2.0.48, required by app 0.1.0 [dev]""",
+                DependencyTools.formatCargoDuplicates(graph));
+        assertTrue(DependencyTools.formatCargoWhy(graph, "tokio").contains("not in the Cargo dependency graph"));
+    }
+
     // ========== Integration Tests ==========
 
     @Disabled("Slow integration test - downloads from Maven Central")
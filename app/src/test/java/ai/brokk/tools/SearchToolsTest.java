@@ -10,6 +10,7 @@ import ai.brokk.analyzer.IAnalyzer.SourceLookupAlias;
 import ai.brokk.analyzer.JavaAnalyzer;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.RustAnalyzer;
 import ai.brokk.git.CommitInfo;
 import ai.brokk.git.GitDistance;
 import ai.brokk.git.GitRepo;
@@ -17,6 +18,7 @@ import ai.brokk.git.IGitRepo;
 import ai.brokk.git.TestRepo;
 import ai.brokk.project.AbstractProject;
 import ai.brokk.testutil.FileUtil;
+import ai.brokk.testutil.InlineTestProjectCreator;
 import ai.brokk.testutil.TestAnalyzer;
 import ai.brokk.testutil.TestConsoleIO;
 import ai.brokk.testutil.TestContextManager;
@@ -527,6 +529,54 @@ public class SearchToolsTest {
         }
     }
 
+    @Test
+    void scanUsages_keepsOnlyRequestedKinds() throws Exception {
+        String geometry =
+                """
+                pub struct Point {
+                    pub x: i32,
+                }
+                """;
+        String consumer =
+                """
+                use crate::geometry::Point;
+
+                fn shift(p: &mut Point) {
+                    p.x = 2;
+                }
+
+                fn show(p: &Point) -> i32 {
+                    p.x + 1
+                }
+                """;
+        try (var project = InlineTestProjectCreator.code(geometry, "src/geometry.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            CodeUnit point = analyzer.getAllDeclarations().stream()
+                    .filter(cu -> "Point".equals(cu.identifier()))
+                    .findFirst()
+                    .orElseThrow();
+            String x = analyzer.getMembersInClass(point).stream()
+                    .filter(cu -> "x".equals(cu.identifier()))
+                    .findFirst()
+                    .orElseThrow()
+                    .fqName();
+            SearchTools tools =
+                    new SearchTools(new TestContextManager(project, new TestConsoleIO(), Set.of(), analyzer));
+
+            String writes = tools.scanUsages(List.of(x), false, List.of("write"));
+            assertTrue(writes.contains("shift"), writes);
+            assertTrue(writes.contains(", write)"), writes);
+            assertFalse(writes.contains("show"), writes);
+
+            String all = tools.scanUsages(List.of(x), false, null);
+            assertTrue(all.contains("shift") && all.contains("show"), all);
+
+            assertThrows(IllegalArgumentException.class, () -> tools.scanUsages(List.of(x), false, List.of("mutate")));
+        }
+    }
+
     @Test
     void testfindFilenames_withSubdirectories() throws Exception {
         // 1. Create a file with a subdirectory path
@@ -1604,6 +1654,104 @@ public class SearchToolsTest {
         assertTrue(result.contains("attrs={id=\"a\"}"), "Should include attrs. Result:\n" + result);
     }
 
+    @Test
+    void testTomlSelectorToJq() {
+        assertEquals(".[\"package\"][\"name\"]", SearchTools.tomlSelectorToJq("package.name"));
+        assertEquals(
+                ".[\"dependencies\"][\"serde-json\"][\"version\"]",
+                SearchTools.tomlSelectorToJq("dependencies.\"serde-json\".version"));
+        assertEquals(".[\"bin\"][0][\"name\"]", SearchTools.tomlSelectorToJq("bin[0].name"));
+        assertEquals(".[\"dependencies\"][][\"version\"]?", SearchTools.tomlSelectorToJq("dependencies.*.version"));
+        assertEquals(
+                ".[\"target\"][]?[\"dependencies\"]?[]?[\"features\"]?[0]?",
+                SearchTools.tomlSelectorToJq("target.*.dependencies.*.features[0]"));
+        assertEquals(".dependencies | keys", SearchTools.tomlSelectorToJq(".dependencies | keys"));
+        assertThrows(IllegalArgumentException.class, () -> SearchTools.tomlSelectorToJq("bin[x]"));
+    }
+
+    @Test
+    void testTomlSelect_SpansCargoWorkspaceMembers() throws Exception {
+        Files.writeString(
+                projectRoot.resolve("Cargo.toml"),
+                """
+                [workspace]
+                members = ["crates/*"]
+                exclude = ["crates/scratch"]
+                """);
+        for (String member : List.of("core", "cli", "scratch")) {
+            Path dir = Files.createDirectories(projectRoot.resolve("crates").resolve(member));
+            Files.writeString(
+                    dir.resolve("Cargo.toml"),
+                    """
+                    [package]
+                    name = "%s"
+                    version = "0.1.0"
+
+                    [dependencies]
+                    serde = { version = "1.0", features = ["derive"] }
+                    """
+                            .formatted(member));
+        }
+
+        String names = searchTools.tomlSelect("Cargo.toml", "package.name", 10, 10);
+        assertTrue(names.contains("File: crates/core/Cargo.toml (1 match)"), names);
+        assertTrue(names.contains("\"cli\""), names);
+        assertFalse(names.contains("scratch"), "Excluded members should be skipped. Result:\n" + names);
+        assertFalse(names.contains("File: Cargo.toml"), "Virtual manifest has no package. Result:\n" + names);
+
+        String versions = searchTools.tomlSelect("Cargo.toml", "dependencies.*.version", 10, 10);
+        assertEquals(2, countOccurrences(versions, "\"1.0\""), versions);
+
+        String invalid = searchTools.tomlSelect("Cargo.toml", "dependencies.\"serde", 10, 10);
+        assertTrue(invalid.contains("Invalid TOML selector"), invalid);
+    }
+
+    @Test
+    void testTomlSelect_FanOutSkipsPlainStringEntries() throws Exception {
+        Files.writeString(
+                projectRoot.resolve("Cargo.toml"),
+                """
+                [package]
+                name = "demo"
+
+                [dependencies]
+                anyhow = "1.0.86"
+                serde = { version = "1.0.200", features = ["derive"] }
+                local = { path = "../local" }
+
+                [dependencies.tokio]
+                version = "1.38"
+                """);
+        mockProjectFiles.add(new ProjectFile(projectRoot, "Cargo.toml"));
+
+        String versions = searchTools.tomlSelect("Cargo.toml", "dependencies.*.version", 10, 10);
+        assertTrue(versions.contains("\"1.0.200\""), versions);
+        assertTrue(versions.contains("\"1.38\""), versions);
+        assertFalse(versions.contains("1.0.86"), "Plain-string deps have no version key. Result:\n" + versions);
+        assertFalse(versions.contains("Cannot index"), versions);
+    }
+
+    @Test
+    void testTomlSkim_Basic() throws Exception {
+        Files.writeString(
+                projectRoot.resolve("Cargo.toml"),
+                """
+                [package]
+                name = "demo"
+                edition = "2021"
+
+                [[bin]]
+                name = "demo-cli"
+                """);
+        mockProjectFiles.add(new ProjectFile(projectRoot, "Cargo.toml"));
+
+        String result = searchTools.tomlSkim("Cargo.toml", 10);
+
+        assertTrue(result.contains("<file path=\"Cargo.toml\">"), "Should include file wrapper. Result:\n" + result);
+        assertTrue(result.contains("keys=[\"package\", \"bin\"]"), "Should list top-level tables. Result:\n" + result);
+        assertTrue(result.contains("$.bin type=array len=1"), "Should summarize array tables. Result:\n" + result);
+    }
+
     private static int countOccurrences(String text, String substring) {
         int count = 0;
         int idx = 0;
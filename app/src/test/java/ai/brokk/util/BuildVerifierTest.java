@@ -7,6 +7,8 @@ import ai.brokk.testutil.TestProject;
 import java.io.IOException;
 import java.nio.file.Files;
 import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
 import java.util.Map;
 import java.util.function.BiFunction;
 import org.junit.jupiter.api.AfterEach;
@@ -115,6 +117,62 @@ class BuildVerifierTest {
         assertEquals(0, result.exitCode());
     }
 
+    @Test
+    void testVerifyCargoLintParsesJsonDiagnostics() {
+        var commands = new ArrayList<String>();
+        String diagnostic =
+                """
+                {"reason":"compiler-message","message":{"message":"mismatched types","code":{"code":"E0308"},\
+                "level":"error","children":[],"spans":[{"file_name":"src/main.rs","is_primary":true,\
+                "label":"expected `u32`, found `&str`","line_start":3,"line_end":3,"column_start":17,\
+                "column_end":22,"byte_start":40,"byte_end":45,"suggested_replacement":null}]}}""";
+        Environment.shellCommandRunnerFactory = (cmd, root) -> (outputConsumer, timeout) -> {
+            commands.add(cmd);
+            outputConsumer.accept("    Checking demo v0.1.0");
+            outputConsumer.accept(diagnostic);
+            outputConsumer.accept("{\"reason\":\"build-finished\",\"success\":false}");
+            throw new Environment.FailureException("Command failed", "", 101);
+        };
+
+        var project = createTestProject();
+        var result = BuildVerifier.verifyCargoLint(project, "cargo clippy -- -D warnings", null);
+
+        assertEquals(List.of("cargo clippy --message-format=json -- -D warnings"), commands);
+        assertFalse(result.verification().success());
+        assertEquals(101, result.verification().exitCode());
+        assertEquals(1, result.lint().getErrors().size());
+        assertEquals("E0308", result.lint().getErrors().getFirst().code());
+        assertEquals(
+                "src/main.rs:3:17: error[E0308]: mismatched types: expected `u32`, found `&str`",
+                result.verification().output());
+    }
+
+    @Test
+    void testVerifyCargoLintKeepsNonCompilerFailureNextToWarnings() {
+        String warning =
+                """
+                {"reason":"compiler-message","message":{"message":"unused variable: `x`","code":\
+                {"code":"unused_variables"},"level":"warning","children":[],"spans":[{"file_name":"src/lib.rs",\
+                "is_primary":true,"line_start":2,"line_end":2,"column_start":9,"column_end":10,\
+                "byte_start":20,"byte_end":21,"suggested_replacement":null}]}}""";
+        Environment.shellCommandRunnerFactory = (cmd, root) -> (outputConsumer, timeout) -> {
+            outputConsumer.accept(warning);
+            outputConsumer.accept("error: failed to run custom build command for `openssl-sys v0.9.102`");
+            outputConsumer.accept("{\"reason\":\"build-finished\",\"success\":false}");
+            throw new Environment.FailureException("Command failed", "", 101);
+        };
+
+        var project = createTestProject();
+        var result = BuildVerifier.verifyCargoLint(project, "cargo clippy", null);
+
+        assertFalse(result.verification().success());
+        assertTrue(result.lint().getErrors().isEmpty());
+        assertEquals(1, result.lint().diagnostics().size());
+        String output = result.verification().output();
+        assertTrue(output.contains("src/lib.rs:2:9: warning[unused_variables]: unused variable: `x`"), output);
+        assertTrue(output.contains("error: failed to run custom build command for `openssl-sys v0.9.102`"), output);
+    }
+
     @Test
     void testBuildEnvironmentJdkSentinel() {
         var project = new TestProject(tempDir).withJdk(EnvironmentJava.JAVA_HOME_SENTINEL);
@@ -424,12 +424,16 @@ public class BrokkCoreMcpServer {
                 schema(
                         Map.of(
                                 "symbols", arrayProp("Fully qualified symbol names to find usages for."),
-                                "includeTests", boolProp("Include call sites in test files.")),
+                                "includeTests", boolProp("Include call sites in test files."),
+                                "kinds",
+                                        arrayProp("Optional usage kinds to keep: read, write, mutable-borrow, move, "
+                                                + "type. Only Rust usages are classified.")),
                         List.of("symbols", "includeTests")),
                 (exchange, request) -> withReadLock(() -> {
                     var symbols = stringListArg(request, "symbols");
                     var includeTests = boolArg(request, "includeTests", false);
-                    return textResult(searchTools.scanUsages(symbols, includeTests));
+                    var kinds = stringListArgOrEmpty(request, "kinds");
+                    return textResult(searchTools.scanUsages(symbols, includeTests, kinds));
                 })));
 
         specs.add(tool(
@@ -660,6 +664,38 @@ public class BrokkCoreMcpServer {
                             searchTools.xmlSelect(filepath, xpath, output, attrName, maxFiles, matchesPerFile));
                 })));
 
+        specs.add(tool(
+                "tomlSkim",
+                "Get a structural overview of TOML files such as Cargo.toml, including Cargo workspace members.",
+                schema(
+                        Map.of(
+                                "filepath", stringProp("Path or glob pattern for TOML files."),
+                                "maxFiles", intProp("Maximum number of files to process.")),
+                        List.of("filepath", "maxFiles")),
+                (exchange, request) -> withReadLock(() -> {
+                    var filepath = stringArg(request, "filepath");
+                    var maxFiles = intArg(request, "maxFiles", 10);
+                    return textResult(searchTools.tomlSkim(filepath, maxFiles));
+                })));
+
+        specs.add(tool(
+                "tomlSelect",
+                "Query TOML files such as Cargo.toml by dotted key path or jq filter, across Cargo workspace members.",
+                schema(
+                        Map.of(
+                                "filepath", stringProp("Path or glob pattern for TOML files."),
+                                "selector", stringProp("Dotted key path (e.g. 'package.name') or jq filter."),
+                                "maxFiles", intProp("Maximum number of files to process."),
+                                "matchesPerFile", intProp("Maximum matches per file.")),
+                        List.of("filepath", "selector")),
+                (exchange, request) -> withReadLock(() -> {
+                    var filepath = stringArg(request, "filepath");
+                    var selector = stringArg(request, "selector");
+                    var maxFiles = intArg(request, "maxFiles", 10);
+                    var matchesPerFile = intArg(request, "matchesPerFile", 50);
+                    return textResult(searchTools.tomlSelect(filepath, selector, maxFiles, matchesPerFile));
+                })));
+
         // -- Code quality analysis tools --
 
         specs.add(tool(
@@ -684,7 +720,8 @@ public class BrokkCoreMcpServer {
                 "computeCognitiveComplexity",
                 "Computes heuristic cognitive complexity for methods in the given files. "
                         + "Flags methods above the threshold (typical default 15) for maintainability-focused review or refactor. "
-                        + "Returns a markdown-friendly report of flagged methods.",
+                        + "Returns a markdown-friendly report of flagged methods; "
+                        + "Rust methods list the constructs their score comes from.",
                 schema(
                         Map.of(
                                 "filePaths", arrayProp("File paths relative to the project root."),
@@ -850,6 +887,49 @@ public class BrokkCoreMcpServer {
                             intArg(request, "smallBodyMaxStatements", -1)));
                 })));
 
+        specs.add(tool(
+                "reportRustRiskSmells",
+                "Detects Rust-specific risk hot spots per function, skipping test code: unwrap()/expect() calls, "
+                        + "unsafe blocks without a // SAFETY: comment, panic!/todo!/unimplemented! in library code, "
+                        + "and clone() inside loop bodies. Each function is scored by its weighted site counts.",
+                schema(
+                        Map.ofEntries(
+                                entry("filePaths", arrayProp("File paths relative to the project root.")),
+                                entry(
+                                        "minScore",
+                                        intProp("Minimum score to include a finding; values <= 0 default to 4.")),
+                                entry("maxFindings", intProp("Maximum findings to emit; values <= 0 default to 80.")),
+                                entry("unwrapWeight", intProp("Weight per unwrap() call; values < 0 use default.")),
+                                entry("expectWeight", intProp("Weight per expect() call; values < 0 use default.")),
+                                entry(
+                                        "undocumentedUnsafeWeight",
+                                        intProp(
+                                                "Weight per unsafe block without a SAFETY comment; values < 0 use default.")),
+                                entry(
+                                        "panicWeight",
+                                        intProp("Weight per panic! in library code; values < 0 use default.")),
+                                entry(
+                                        "unfinishedWeight",
+                                        intProp(
+                                                "Weight per todo! or unimplemented! in library code; values < 0 use default.")),
+                                entry(
+                                        "cloneInLoopWeight",
+                                        intProp("Weight per clone() inside a loop body; values < 0 use default."))),
+                        List.of("filePaths")),
+                (exchange, request) -> withReadLock(() -> {
+                    var filePaths = stringListArg(request, "filePaths");
+                    return textResult(codeQualityTools.reportRustRiskSmells(
+                            filePaths,
+                            intArg(request, "minScore", -1),
+                            intArg(request, "maxFindings", -1),
+                            intArg(request, "unwrapWeight", -1),
+                            intArg(request, "expectWeight", -1),
+                            intArg(request, "undocumentedUnsafeWeight", -1),
+                            intArg(request, "panicWeight", -1),
+                            intArg(request, "unfinishedWeight", -1),
+                            intArg(request, "cloneInLoopWeight", -1)));
+                })));
+
         specs.add(tool(
                 "reportStructuralCloneSmells",
                 "Detects duplicated implementation patterns across functions using normalized token similarity. "
@@ -256,6 +256,16 @@ public final class CoreProject implements ICoreProject {
         }
     }
 
+    @Override
+    public String getLanguageSettings(Language language) {
+        return projectProps.getProperty(language.internalName() + "Settings", "");
+    }
+
+    @Override
+    public void setLanguageSettings(Language language, String json) {
+        projectProps.setProperty(language.internalName() + "Settings", json);
+    }
+
     @Override
     public boolean isGitignored(Path relPath) {
         if (!(repo instanceof GitRepo)) {
@@ -6,6 +6,7 @@ import ai.brokk.analyzer.CommentDensityStats;
 import ai.brokk.analyzer.IAnalyzer;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.RustRiskSmellProvider;
 import ai.brokk.analyzer.usages.CandidateFileProvider;
 import ai.brokk.analyzer.usages.FuzzyResult;
 import ai.brokk.analyzer.usages.RegexUsageAnalyzer;
@@ -28,6 +29,7 @@ import java.util.Locale;
 import java.util.Map;
 import java.util.Optional;
 import java.util.Set;
+import java.util.stream.Collectors;
 import org.eclipse.jgit.api.errors.GitAPIException;
 
 /**
@@ -98,8 +100,10 @@ public class CodeQualityToolsMcp {
             if (!file.exists()) continue;
 
             var complexities = analyzer.computeCognitiveComplexities(file);
+            var breakdowns = analyzer.computeCognitiveComplexityBreakdowns(file);
             for (var entry : complexities.entrySet()) {
-                foundAny |= analyzeUnitCognitiveComplexity(entry.getKey(), entry.getValue(), limit, lines);
+                var breakdown = breakdowns.getOrDefault(entry.getKey(), Map.of());
+                foundAny |= analyzeUnitCognitiveComplexity(entry.getKey(), entry.getValue(), breakdown, limit, lines);
             }
         }
 
@@ -108,14 +112,24 @@ public class CodeQualityToolsMcp {
                 : "No methods exceeded the cognitive complexity threshold of " + limit + ".";
     }
 
-    private boolean analyzeUnitCognitiveComplexity(CodeUnit cu, int complexity, int threshold, List<String> lines) {
+    private boolean analyzeUnitCognitiveComplexity(
+            CodeUnit cu, int complexity, Map<String, Integer> breakdown, int threshold, List<String> lines) {
         if (cu.isSynthetic() || complexity <= threshold) {
             return false;
         }
-        lines.add("- " + cu.fqName() + ": " + complexity);
+        lines.add("- " + cu.fqName() + ": " + complexity + formatComplexityBreakdown(breakdown));
         return true;
     }
 
+    private static String formatComplexityBreakdown(Map<String, Integer> breakdown) {
+        if (breakdown.isEmpty()) {
+            return "";
+        }
+        return breakdown.entrySet().stream()
+                .map(entry -> entry.getKey() + " +" + entry.getValue())
+                .collect(Collectors.joining(", ", " (", ")"));
+    }
+
     // -- reportCommentDensityForCodeUnit --
 
     public String reportCommentDensityForCodeUnit(String fqName, int maxLines) {
@@ -373,6 +387,84 @@ public class CodeQualityToolsMcp {
         return String.join("\n", lines);
     }
 
+    // -- reportRustRiskSmells --
+
+    public String reportRustRiskSmells(
+            List<String> filePaths,
+            int minScore,
+            int maxFindings,
+            int unwrapWeight,
+            int expectWeight,
+            int undocumentedUnsafeWeight,
+            int panicWeight,
+            int unfinishedWeight,
+            int cloneInLoopWeight) {
+
+        int threshold = minScore > 0 ? minScore : 4;
+        int findingsCap = maxFindings > 0 ? maxFindings : 80;
+        var defaults = RustRiskSmellProvider.RustRiskSmellWeights.defaults();
+        var weights = new RustRiskSmellProvider.RustRiskSmellWeights(
+                pickWeight(unwrapWeight, defaults.unwrapWeight()),
+                pickWeight(expectWeight, defaults.expectWeight()),
+                pickWeight(undocumentedUnsafeWeight, defaults.undocumentedUnsafeWeight()),
+                pickWeight(panicWeight, defaults.panicWeight()),
+                pickWeight(unfinishedWeight, defaults.unfinishedWeight()),
+                pickWeight(cloneInLoopWeight, defaults.cloneInLoopWeight()));
+
+        var provider = intelligence.getAnalyzer().as(RustRiskSmellProvider.class);
+        if (provider.isEmpty()) {
+            return "Rust risk smells are not supported for the languages in this project.";
+        }
+        var findings = new ArrayList<RustRiskSmellProvider.RustRiskSmell>();
+        for (String path : filePaths) {
+            ProjectFile file = intelligence.toFile(path);
+            if (!file.exists()) {
+                continue;
+            }
+            findings.addAll(provider.get().findRustRiskSmells(file, weights));
+        }
+
+        var filtered = findings.stream()
+                .filter(f -> f.score() >= threshold)
+                .sorted(Comparator.comparingInt(RustRiskSmellProvider.RustRiskSmell::score)
+                        .reversed()
+                        .thenComparing(f -> f.file().toString())
+                        .thenComparing(RustRiskSmellProvider.RustRiskSmell::enclosingFqName))
+                .toList();
+
+        if (filtered.isEmpty()) {
+            return "No Rust risk smells met minScore " + threshold + ".";
+        }
+        int shown = Math.min(findingsCap, filtered.size());
+        var lines = new ArrayList<String>();
+        lines.add("## Rust risk smells");
+        lines.add("");
+        lines.add("- Min score: %d".formatted(threshold));
+        lines.add("- Findings shown: %d of %d".formatted(shown, filtered.size()));
+        lines.add("- Weights: %s".formatted(formatRustRiskWeights(weights)));
+        lines.add("");
+        lines.add("| Score | Sites | Symbol | File | Reasons | First Site |");
+        lines.add("|------:|------:|--------|------|---------|------------|");
+        for (RustRiskSmellProvider.RustRiskSmell finding : filtered.subList(0, shown)) {
+            String reasons = sanitizeTableCell(String.join(", ", finding.reasons()));
+            String symbol = sanitizeTableCell(finding.enclosingFqName());
+            String file = sanitizeTableCell(finding.file().toString());
+            lines.add("| %d | %d | `%s` | `%s` | %s | `%s` |"
+                    .formatted(
+                            finding.score(),
+                            finding.siteCount(),
+                            symbol,
+                            file,
+                            "`" + reasons + "`",
+                            sanitizeTableCell(finding.excerpt())));
+        }
+        if (filtered.size() > shown) {
+            lines.add("");
+            lines.add("- Note: output truncated; increase maxFindings to see more.");
+        }
+        return String.join("\n", lines);
+    }
+
     // -- reportStructuralCloneSmells --
 
     public String reportStructuralCloneSmells(
@@ -975,6 +1067,17 @@ public class CodeQualityToolsMcp {
                                 w.largeLiteralLengthThreshold());
     }
 
+    private static String formatRustRiskWeights(RustRiskSmellProvider.RustRiskSmellWeights w) {
+        return "unwrap=%d, expect=%d, undocumentedUnsafe=%d, panic=%d, todoOrUnimplemented=%d, cloneInLoop=%d"
+                .formatted(
+                        w.unwrapWeight(),
+                        w.expectWeight(),
+                        w.undocumentedUnsafeWeight(),
+                        w.panicWeight(),
+                        w.unfinishedWeight(),
+                        w.cloneInLoopWeight());
+    }
+
     private static String formatExceptionWeights(IAnalyzer.ExceptionSmellWeights w) {
         return "Throwable=%d, Exception=%d, RuntimeException=%d, empty=%d, commentOnly=%d, small=%d, logOnly=%d,"
                         .formatted(
@@ -9,6 +9,7 @@ import static java.util.Objects.requireNonNull;
 import ai.brokk.AnalyzerUtil;
 import ai.brokk.ICodeIntelligence;
 import ai.brokk.analyzer.CodeUnit;
+import ai.brokk.analyzer.ConditionalCompilationProvider;
 import ai.brokk.analyzer.IAnalyzer;
 import ai.brokk.analyzer.Languages;
 import ai.brokk.analyzer.MultiAnalyzer;
@@ -17,12 +18,14 @@ import ai.brokk.analyzer.RelaxedSourceLookupResolver;
 import ai.brokk.analyzer.RelaxedSourceLookupResolver.RelaxedSourceLookup;
 import ai.brokk.analyzer.TestDetectionProvider;
 import ai.brokk.analyzer.usages.UsageAnalyzerSelector;
+import ai.brokk.analyzer.usages.UsageKind;
 import ai.brokk.analyzer.usages.UsageRenderer;
 import ai.brokk.concurrent.LoggingFuture;
 import ai.brokk.git.CommitInfo;
 import ai.brokk.git.GitRepo;
 import ai.brokk.git.GitRepoFactory;
 import ai.brokk.git.IGitRepo;
+import ai.brokk.project.ICoreProject;
 import ai.brokk.util.AlmostGrep;
 import ai.brokk.util.FileTargetHeuristic;
 import ai.brokk.util.FilenamePatternMatcher;
@@ -33,9 +36,11 @@ import ai.brokk.util.SearchToolSupport;
 import ai.brokk.util.TextMatcher;
 import com.fasterxml.jackson.databind.JsonNode;
 import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.dataformat.toml.TomlMapper;
 import com.github.benmanes.caffeine.cache.Cache;
 import com.github.benmanes.caffeine.cache.Caffeine;
 import java.io.ByteArrayInputStream;
+import java.io.IOException;
 import java.io.StringWriter;
 import java.nio.charset.StandardCharsets;
 import java.nio.file.Files;
@@ -175,6 +180,8 @@ public class SearchTools {
 
     private static final ThreadLocal<ObjectMapper> jqMappers = ThreadLocal.withInitial(ObjectMapper::new);
 
+    private static final ThreadLocal<TomlMapper> tomlMappers = ThreadLocal.withInitial(TomlMapper::new);
+
     private static final ThreadLocal<Scope> jqScopes = ThreadLocal.withInitial(() -> {
         Scope rootScope = Scope.newEmptyScope();
         BuiltinFunctionLoader.getInstance().loadFunctions(Versions.JQ_1_6, rootScope);
@@ -1013,15 +1020,22 @@ public class SearchTools {
                     .append("\">\n");
 
             // Emit kind sections in a stable order based on analyzer's CodeUnitType
-            var kindOrder = List.of("CLASS", "FUNCTION", "FIELD", "MODULE");
+            var kindOrder = List.of("CLASS", "FUNCTION", "MACRO", "FIELD", "MODULE");
             kindOrder.forEach(kind -> {
                 var symbols = kindGroups.get(kind);
                 if (symbols != null && !symbols.isEmpty()) {
                     result.append("[").append(kind).append("]\n");
                     symbols.stream()
-                            .flatMap(cu -> analyzer.getDisplaySignatures(cu).stream()
-                                    .map(signature -> new SymbolSearchHit(
-                                            signature, cu.fqName(), cu.signature(), displayLineNumber(analyzer, cu))))
+                            .flatMap(cu -> {
+                                // Conditionally compiled symbols carry their cfg, e.g. " [cfg(windows), inactive]"
+                                String condition = ConditionalCompilationProvider.describe(analyzer, cu);
+                                return analyzer.getDisplaySignatures(cu).stream()
+                                        .map(signature -> new SymbolSearchHit(
+                                                signature + condition,
+                                                cu.fqName(),
+                                                cu.signature(),
+                                                displayLineNumber(analyzer, cu)));
+                            })
                             .distinct()
                             .sorted(SearchTools::compareSymbolSearchHits)
                             .forEach(hit -> result.append("- ")
@@ -1060,6 +1074,17 @@ public class SearchTools {
     }
 
     public String scanUsages(List<String> symbols, boolean includeTests) throws InterruptedException {
+        return scanUsages(symbols, includeTests, List.of());
+    }
+
+    /**
+     * Like {@link #scanUsages(List, boolean)}, keeping only usages of the given kinds ({@code read}, {@code write},
+     * {@code mutable-borrow}, {@code move}, {@code type}); an empty list keeps every usage. Only analyzers that
+     * classify usages, currently Rust, have hits that survive a filter.
+     */
+    public String scanUsages(List<String> symbols, boolean includeTests, List<String> kinds)
+            throws InterruptedException {
+        Set<UsageKind> usageKinds = UsageKind.parseAll(kinds);
         // Sanitize symbols: remove potential `(params)` suffix from LLM.
         symbols = stripParams(symbols);
         if (symbols.isEmpty()) {
@@ -1080,6 +1105,7 @@ public class SearchTools {
                     .filter(cu -> includeTests || !isTestFile(cu.source(), analyzer))
                     .toList();
             if (filteredDefs.isEmpty()) continue;
+            filteredDefs = ConditionalCompilationProvider.preferActive(analyzer, filteredDefs);
 
             if (candidates == null) {
                 candidates = codeIntelligence.getProject().getAllFiles().stream()
@@ -1089,7 +1115,8 @@ public class SearchTools {
 
             var usageAnalyzer =
                     UsageAnalyzerSelector.forTarget(filteredDefs.getFirst(), analyzer, codeIntelligence.getProject());
-            var usageResult = UsageAnalyzerSelector.findUsages(usageAnalyzer, analyzer, filteredDefs, candidates);
+            var usageResult = UsageAnalyzerSelector.findUsages(usageAnalyzer, analyzer, filteredDefs, candidates)
+                    .withKinds(usageKinds);
             var rendered = UsageRenderer.render(analyzer, symbol, filteredDefs, usageResult, UsageRenderer.Mode.SAMPLE);
             if (rendered.hasUsages()
                     && rendered.hitCount() > 0
@@ -1814,15 +1841,7 @@ public class SearchTools {
 
         final JsonQuery compiledQuery;
         try {
-            Cache<String, JsonQuery> cache = jqQueries.get();
-            JsonQuery cached = cache.getIfPresent(filter);
-            if (cached != null) {
-                compiledQuery = cached;
-            } else {
-                JsonQuery newlyCompiled = JsonQuery.compile(filter, Versions.JQ_1_6);
-                cache.put(filter, newlyCompiled);
-                compiledQuery = newlyCompiled;
-            }
+            compiledQuery = compileJq(filter);
         } catch (JsonQueryException e) {
             logger.warn("Invalid jq filter: {}", e.getMessage(), e);
             return "Invalid jq filter: " + e.getMessage();
@@ -1835,37 +1854,235 @@ public class SearchTools {
                     var contentOpt = file.read();
                     if (contentOpt.isEmpty()) return new IndexedResult<>(idx, null, null);
 
-                    var mapper = jqMappers.get();
-                    var rootScope = jqScopes.get();
+                    JsonNode node = jqMappers.get().readTree(contentOpt.get());
+                    String block = applyJsonQuery(file, node, compiledQuery, limits.matchesPerFile(), false);
+                    if (block == null) return new IndexedResult<>(idx, null, null);
+                    return new IndexedResult<>(idx, new FileOutput(file, block), null);
+                } catch (Exception e) {
+                    String message = e.getMessage() == null ? e.toString() : e.getMessage();
+                    return new IndexedResult<>(idx, null, file + ": " + message);
+                }
+            });
+        } catch (RuntimeException e) {
+            String message = e.getMessage() == null ? e.toString() : e.getMessage();
+            logger.error("Error executing jq filter", e);
+            return "jq filter failed: " + message;
+        }
 
-                    JsonNode node = mapper.readTree(contentOpt.get());
-                    List<JsonNode> out = new ArrayList<>();
-                    Output outputWriter = out::add;
+        if (batchResult.results().isEmpty()) {
+            if (!batchResult.errors().isEmpty()) {
+                return "jq filter produced errors in %d of %d files: %s"
+                        .formatted(
+                                batchResult.errors().size(),
+                                files.size(),
+                                batchResult.errors().getFirst());
+            }
+            return "No results for jq filter.";
+        }
 
-                    compiledQuery.apply(Scope.newChildScope(rootScope), node, outputWriter);
+        String output = batchResult.results().stream()
+                .sorted((a, b) -> a.file().compareTo(b.file()))
+                .map(FileOutput::output)
+                .collect(Collectors.joining("\n"))
+                .trim();
+        if (batchResult.truncatedByMaxFiles()) {
+            output += "\n\nTRUNCATED: reached maxFiles=%d".formatted(limits.maxFiles());
+        }
 
-                    if (out.isEmpty()) return new IndexedResult<>(idx, null, null);
+        return recordResearchTokens(output);
+    }
 
-                    int toTake = min(out.size(), limits.matchesPerFile());
-                    boolean hitLimit = out.size() > toTake;
-                    String matchCountLabel = hitLimit
-                            ? "first %d matches".formatted(toTake)
-                            : "%d %s".formatted(toTake, toTake == 1 ? "match" : "matches");
+    private static JsonQuery compileJq(String filter) throws JsonQueryException {
+        Cache<String, JsonQuery> cache = jqQueries.get();
+        JsonQuery cached = cache.getIfPresent(filter);
+        if (cached != null) {
+            return cached;
+        }
+        JsonQuery compiled = JsonQuery.compile(filter, Versions.JQ_1_6);
+        cache.put(filter, compiled);
+        return compiled;
+    }
 
-                    List<String> outLines = new ArrayList<>(toTake + 1);
-                    outLines.add("File: %s (%s)".formatted(file.toString().replace('\\', '/'), matchCountLabel));
-                    for (int i = 0; i < toTake; i++) {
-                        JsonNode n = out.get(i);
-                        String rendered = mapper.writeValueAsString(n);
-                        if (n.isContainerNode() && rendered.length() > XML_MAX_CHARS_PER_NODE) {
-                            outLines.add("[JSON_TOO_LARGE]");
-                            String skim = jsonSkimBfs(n, XML_MAX_CHARS_PER_NODE);
-                            outLines.addAll(List.of(skim.split("\n", -1)));
-                        } else {
-                            outLines.add(truncateLine(rendered, 0, rendered.length()));
-                        }
-                    }
-                    return new IndexedResult<>(idx, new FileOutput(file, String.join("\n", outLines) + "\n"), null);
+    /**
+     * Applies a compiled jq query to one parsed document and renders up to {@code matchesPerFile} results under a
+     * "File:" header, or returns null if the query produced nothing. {@code skipNulls} drops null results, which is how
+     * a missing key surfaces in formats without a null value such as TOML.
+     */
+    private static @Nullable String applyJsonQuery(
+            ProjectFile file, JsonNode root, JsonQuery query, int matchesPerFile, boolean skipNulls)
+            throws IOException {
+        var mapper = jqMappers.get();
+        List<JsonNode> out = new ArrayList<>();
+        Output outputWriter = n -> {
+            if (!skipNulls || !n.isNull()) {
+                out.add(n);
+            }
+        };
+
+        query.apply(Scope.newChildScope(jqScopes.get()), root, outputWriter);
+
+        if (out.isEmpty()) return null;
+
+        int toTake = min(out.size(), matchesPerFile);
+        boolean hitLimit = out.size() > toTake;
+        String matchCountLabel = hitLimit
+                ? "first %d matches".formatted(toTake)
+                : "%d %s".formatted(toTake, toTake == 1 ? "match" : "matches");
+
+        List<String> outLines = new ArrayList<>(toTake + 1);
+        outLines.add("File: %s (%s)".formatted(file.toString().replace('\\', '/'), matchCountLabel));
+        for (int i = 0; i < toTake; i++) {
+            JsonNode n = out.get(i);
+            String rendered = mapper.writeValueAsString(n);
+            if (n.isContainerNode() && rendered.length() > XML_MAX_CHARS_PER_NODE) {
+                outLines.add("[JSON_TOO_LARGE]");
+                String skim = jsonSkimBfs(n, XML_MAX_CHARS_PER_NODE);
+                outLines.addAll(List.of(skim.split("\n", -1)));
+            } else {
+                outLines.add(truncateLine(rendered, 0, rendered.length()));
+            }
+        }
+        return String.join("\n", outLines) + "\n";
+    }
+
+    /**
+     * Converts a tomlSelect selector to a jq filter. Selectors starting with '.' are already jq; anything else is a
+     * dotted TOML key path such as {@code dependencies."serde-json".version}, where a segment may be quoted, be
+     * followed by an array index like {@code bin[0]}, or be {@code *} / {@code []} to fan out over every value of a
+     * table or array. Accesses after a fan-out are optional, so that in {@code dependencies.*.version} a plain-string
+     * dependency such as {@code serde = "1.0"} is skipped instead of failing the whole file.
+     */
+    static String tomlSelectorToJq(String selector) {
+        String trimmed = selector.strip();
+        if (trimmed.startsWith(".")) {
+            return trimmed;
+        }
+        var jq = new StringBuilder(".");
+        boolean fannedOut = false;
+        int i = 0;
+        while (i < trimmed.length()) {
+            char c = trimmed.charAt(i);
+            if (c == '.' || Character.isWhitespace(c)) {
+                i++;
+            } else if (c == '"' || c == '\'') {
+                int end = trimmed.indexOf(c, i + 1);
+                if (end < 0) {
+                    throw new IllegalArgumentException("Unterminated quoted key in TOML path: " + selector);
+                }
+                appendJqKey(jq, trimmed.substring(i + 1, end), fannedOut);
+                i = end + 1;
+            } else if (c == '[') {
+                int end = trimmed.indexOf(']', i);
+                String index = end < 0 ? "" : trimmed.substring(i + 1, end).strip();
+                if (end < 0 || !(index.isEmpty() || index.matches("-?\\d+"))) {
+                    throw new IllegalArgumentException("Invalid array index in TOML path: " + selector);
+                }
+                jq.append('[').append(index).append(']').append(fannedOut ? "?" : "");
+                fannedOut |= index.isEmpty();
+                i = end + 1;
+            } else {
+                int end = i;
+                while (end < trimmed.length() && ".[\"'".indexOf(trimmed.charAt(end)) < 0) {
+                    end++;
+                }
+                String key = trimmed.substring(i, end).strip();
+                if ("*".equals(key)) {
+                    jq.append(fannedOut ? "[]?" : "[]");
+                    fannedOut = true;
+                } else {
+                    appendJqKey(jq, key, fannedOut);
+                }
+                i = end;
+            }
+        }
+        return jq.toString();
+    }
+
+    private static void appendJqKey(StringBuilder jq, String key, boolean optional) {
+        jq.append("[\"").append(key.replace("\\", "\\\\").replace("\"", "\\\"")).append("\"]");
+        if (optional) {
+            jq.append('?');
+        }
+    }
+
+    /**
+     * Expands a path or glob to TOML files, adding the member manifests of any Cargo workspace root among them so that
+     * a query against the root {@code Cargo.toml} spans the whole workspace. Members listed under
+     * {@code workspace.exclude} are left out.
+     */
+    private List<ProjectFile> expandTomlFiles(String filepath) {
+        var project = codeIntelligence.getProject();
+        var files = PathExpander.expandPath(project, filepath).stream()
+                .filter(ProjectFile.class::isInstance)
+                .map(ProjectFile.class::cast)
+                .filter(pf -> pf.isText() && "toml".equalsIgnoreCase(pf.extension()))
+                .toList();
+
+        var expanded = new LinkedHashSet<ProjectFile>(files);
+        for (ProjectFile manifest : files) {
+            if (!"Cargo.toml".equals(manifest.getFileName())) {
+                continue;
+            }
+            JsonNode workspace;
+            try {
+                var contentOpt = manifest.read();
+                if (contentOpt.isEmpty()) continue;
+                workspace = tomlMappers.get().readTree(contentOpt.get()).path("workspace");
+            } catch (IOException e) {
+                logger.debug("Could not read workspace members of {}: {}", manifest, e.getMessage());
+                continue;
+            }
+            var excluded = new HashSet<ProjectFile>();
+            for (JsonNode member : workspace.path("exclude")) {
+                excluded.addAll(cargoMemberManifests(project, manifest, member.asText()));
+            }
+            for (JsonNode member : workspace.path("members")) {
+                cargoMemberManifests(project, manifest, member.asText()).stream()
+                        .filter(pf -> !excluded.contains(pf))
+                        .forEach(expanded::add);
+            }
+        }
+        return prioritizeFilesForSelection(List.copyOf(expanded));
+    }
+
+    private static List<ProjectFile> cargoMemberManifests(
+            ICoreProject project, ProjectFile workspaceManifest, String member) {
+        String base = toUnixPath(workspaceManifest.getParent());
+        String memberDir = toUnixPath(member.strip()).replaceAll("/+$", "");
+        if (memberDir.isEmpty()) {
+            return List.of();
+        }
+        String pattern = (base.isEmpty() ? "" : base + "/") + memberDir + "/Cargo.toml";
+        return PathExpander.expandPath(project, pattern).stream()
+                .filter(ProjectFile.class::isInstance)
+                .map(ProjectFile.class::cast)
+                .toList();
+    }
+
+    public String tomlSkim(String filepath, int maxFiles) throws InterruptedException {
+        var files = expandTomlFiles(filepath);
+        if (files.isEmpty() && (filepath.startsWith("**/") || filepath.startsWith("**\\"))) {
+            files = expandTomlFiles(filepath.substring(3));
+        }
+
+        if (files.isEmpty()) {
+            return "No TOML files found matching: " + filepath;
+        }
+
+        int effectiveMaxFiles = min(max(1, maxFiles), AlmostGrep.FILE_SEARCH_LIMIT);
+
+        BatchResult<FileOutput> batchResult;
+        try {
+            batchResult = batchProcessFiles(files, effectiveMaxFiles, (file, idx) -> {
+                try {
+                    var contentOpt = file.read();
+                    if (contentOpt.isEmpty()) return new IndexedResult<>(idx, null, null);
+
+                    JsonNode root = tomlMappers.get().readTree(contentOpt.get());
+                    String skim = jsonSkimBfs(root, XML_SKIM_TOTAL_BUDGET_CHARS);
+                    String block = "<file path=\"%s\">\n%s\n</file>"
+                            .formatted(file.toString().replace('\\', '/'), skim);
+                    return new IndexedResult<>(idx, new FileOutput(file, block), null);
                 } catch (Exception e) {
                     String message = e.getMessage() == null ? e.toString() : e.getMessage();
                     return new IndexedResult<>(idx, null, file + ": " + message);
@@ -1873,19 +2090,91 @@ public class SearchTools {
             });
         } catch (RuntimeException e) {
             String message = e.getMessage() == null ? e.toString() : e.getMessage();
-            logger.error("Error executing jq filter", e);
-            return "jq filter failed: " + message;
+            logger.error("Error executing tomlSkim", e);
+            return "tomlSkim failed: " + message;
         }
 
         if (batchResult.results().isEmpty()) {
             if (!batchResult.errors().isEmpty()) {
-                return "jq filter produced errors in %d of %d files: %s"
+                return "tomlSkim produced errors in %d of %d files: %s"
                         .formatted(
                                 batchResult.errors().size(),
                                 files.size(),
                                 batchResult.errors().getFirst());
             }
-            return "No results for jq filter.";
+            return "No results for tomlSkim.";
+        }
+
+        String output = batchResult.results().stream()
+                .sorted((a, b) -> a.file().compareTo(b.file()))
+                .map(FileOutput::output)
+                .collect(Collectors.joining("\n\n"))
+                .trim();
+        if (batchResult.truncatedByMaxFiles()) {
+            output += "\n\nTRUNCATED: reached maxFiles=%d".formatted(effectiveMaxFiles);
+        }
+        if (!batchResult.errors().isEmpty()) {
+            output += "\n\nWARNINGS: errors occurred in %d files; first: %s"
+                    .formatted(batchResult.errors().size(), batchResult.errors().getFirst());
+        }
+
+        return recordResearchTokens(appendRelatedContent(
+                output, batchResult.results().stream().map(FileOutput::file).toList()));
+    }
+
+    public String tomlSelect(String filepath, String selector, int maxFiles, int matchesPerFile)
+            throws InterruptedException {
+        var files = expandTomlFiles(filepath);
+
+        if (files.isEmpty()) {
+            return "No TOML files found matching: " + filepath;
+        }
+
+        if (selector.isBlank()) {
+            throw new IllegalArgumentException("Cannot tomlSelect: selector is empty");
+        }
+
+        EffectiveLimits limits = clampMaxFilesAndMatchesPerFile(maxFiles, matchesPerFile);
+
+        final JsonQuery compiledQuery;
+        try {
+            compiledQuery = compileJq(tomlSelectorToJq(selector));
+        } catch (IllegalArgumentException | JsonQueryException e) {
+            logger.warn("Invalid TOML selector: {}", e.getMessage(), e);
+            return "Invalid TOML selector: " + e.getMessage();
+        }
+
+        BatchResult<FileOutput> batchResult;
+        try {
+            batchResult = batchProcessFiles(files, limits.maxFiles(), (file, idx) -> {
+                try {
+                    var contentOpt = file.read();
+                    if (contentOpt.isEmpty()) return new IndexedResult<>(idx, null, null);
+
+                    JsonNode node = tomlMappers.get().readTree(contentOpt.get());
+                    String block = applyJsonQuery(file, node, compiledQuery, limits.matchesPerFile(), true);
+                    if (block == null) return new IndexedResult<>(idx, null, null);
+                    return new IndexedResult<>(idx, new FileOutput(file, block), null);
+                } catch (Exception e) {
+                    String message = e.getMessage() == null ? e.toString() : e.getMessage();
+                    return new IndexedResult<>(idx, null, file + ": " + message);
+                }
+            });
+        } catch (RuntimeException e) {
+            String message = e.getMessage() == null ? e.toString() : e.getMessage();
+            logger.error("Error executing tomlSelect", e);
+            return "tomlSelect failed: " + message;
+        }
+
+        if (batchResult.results().isEmpty()) {
+            if (!batchResult.errors().isEmpty()) {
+                return "tomlSelect produced errors in %d of %d files: %s"
+                        .formatted(
+                                batchResult.errors().size(),
+                                files.size(),
+                                batchResult.errors().getFirst());
+            }
+            return "No results for TOML selector.";
         }
 
         String output = batchResult.results().stream()
@@ -1896,6 +2185,10 @@ public class SearchTools {
         if (batchResult.truncatedByMaxFiles()) {
             output += "\n\nTRUNCATED: reached maxFiles=%d".formatted(limits.maxFiles());
         }
+        if (!batchResult.errors().isEmpty()) {
+            output += "\n\nWARNINGS: errors occurred in %d files; first: %s"
+                    .formatted(batchResult.errors().size(), batchResult.errors().getFirst());
+        }
 
         return recordResearchTokens(output);
     }
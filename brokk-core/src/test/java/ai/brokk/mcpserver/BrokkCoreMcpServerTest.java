@@ -100,12 +100,15 @@ class BrokkCoreMcpServerTest {
                 "jq",
                 "xmlSkim",
                 "xmlSelect",
+                "tomlSkim",
+                "tomlSelect",
                 "computeCyclomaticComplexity",
                 "computeCognitiveComplexity",
                 "reportCommentDensityForCodeUnit",
                 "reportCommentDensityForFiles",
                 "reportLongMethodAndGodObjectSmells",
                 "reportExceptionHandlingSmells",
+                "reportRustRiskSmells",
                 "reportStructuralCloneSmells",
                 "reportTestAssertionSmells",
                 "reportDeadCodeAndUnusedAbstractionSmells",
@@ -556,6 +559,14 @@ class BrokkCoreMcpServerTest {
         assertFalse(result.content().isEmpty());
     }
 
+    @Test
+    void reportRustRiskSmellsRunsWithoutError() {
+        var result = callTool("reportRustRiskSmells", Map.of("filePaths", List.of("README.md")));
+        assertNotNull(result);
+        assertFalse(result.isError() != null && result.isError());
+        assertFalse(result.content().isEmpty());
+    }
+
     @Test
     void reportStructuralCloneSmellsRunsWithoutError() {
         var result = callTool("reportStructuralCloneSmells", Map.of("filePaths", List.of("README.md")));
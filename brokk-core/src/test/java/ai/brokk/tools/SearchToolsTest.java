@@ -2,6 +2,7 @@ package ai.brokk.tools;
 
 import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
 import ai.brokk.ICodeIntelligence;
@@ -285,6 +286,99 @@ class SearchToolsTest {
         assertFalse(result.contains("No usages found"), "Should not miss Java field usages");
     }
 
+    @Test
+    void scanUsages_FiltersRustFieldUsagesByKind() throws Exception {
+        Path projectRoot = initRepo();
+        commitTrackedFiles(
+                projectRoot,
+                Map.of(
+                        "src/geometry.rs",
+                        """
+                        pub struct Point {
+                            pub x: i32,
+                        }
+                        """
+                                .stripIndent(),
+                        "src/main.rs",
+                        """
+                        use crate::geometry::Point;
+
+                        fn shift(p: &mut Point) {
+                            p.x += 1;
+                        }
+
+                        fn describe(p: &Point) -> i32 {
+                            p.x * 2
+                        }
+                        """
+                                .stripIndent()),
+                Instant.parse("2025-01-01T00:00:00Z"),
+                "Add Rust field usages");
+
+        project = new CoreProject(projectRoot);
+        IAnalyzer analyzer = Languages.RUST.createAnalyzer(project);
+        SearchTools tools = new SearchTools(new StandaloneCodeIntelligence(project, analyzer));
+
+        String all = tools.scanUsages(List.of("geometry.Point.x"), true);
+        assertTrue(all.contains("src/main.rs:4, write"), all);
+        assertTrue(all.contains("src/main.rs:8, read"), all);
+
+        String writes = tools.scanUsages(List.of("geometry.Point.x"), true, List.of("write", "MUTABLE_BORROW"));
+        assertTrue(writes.contains("src/main.rs:4, write"), writes);
+        assertFalse(writes.contains("describe"), writes);
+
+        assertThrows(
+                IllegalArgumentException.class,
+                () -> tools.scanUsages(List.of("geometry.Point.x"), true, List.of("mutate")));
+    }
+
+    @Test
+    void scanUsages_FindsRustEnumVariantFieldUsages() throws Exception {
+        Path projectRoot = initRepo();
+        commitTrackedFiles(
+                projectRoot,
+                Map.of(
+                        "src/geometry.rs",
+                        """
+                        pub enum Shape {
+                            Named { name: String },
+                            Sized(u32, u32),
+                        }
+                        """
+                                .stripIndent(),
+                        "src/main.rs",
+                        """
+                        use crate::geometry::Shape;
+
+                        fn label(shape: &Shape) -> String {
+                            match shape {
+                                Shape::Named { name } => name.clone(),
+                                Shape::Sized(width, _) => width.to_string(),
+                            }
+                        }
+
+                        fn named() -> Shape {
+                            Shape::Named { name: String::new() }
+                        }
+                        """
+                                .stripIndent()),
+                Instant.parse("2025-01-01T00:00:00Z"),
+                "Add Rust enum variant fields");
+
+        project = new CoreProject(projectRoot);
+        IAnalyzer analyzer = Languages.RUST.createAnalyzer(project);
+        SearchTools tools = new SearchTools(new StandaloneCodeIntelligence(project, analyzer));
+
+        String name = tools.scanUsages(List.of("geometry.Shape.Named.name"), true);
+        assertTrue(name.contains("src/main.rs:5"), name);
+        assertTrue(name.contains("src/main.rs:11"), name);
+        assertFalse(name.contains("src/main.rs:6"), name);
+
+        String width = tools.scanUsages(List.of("geometry.Shape.Sized.0"), true);
+        assertTrue(width.contains("src/main.rs:6"), width);
+        assertFalse(width.contains("src/main.rs:5"), width);
+    }
+
     @Test
     void scanUsages_SkipsSuccessfulResultsWithNoHits() throws Exception {
         Path projectRoot = initRepo();
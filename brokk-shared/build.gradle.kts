@@ -24,6 +24,7 @@ configurations.all {
         force("com.fasterxml.jackson.core:jackson-annotations:2.18.3")
         force("com.fasterxml.jackson.dataformat:jackson-dataformat-smile:2.18.3")
         force("com.fasterxml.jackson.dataformat:jackson-dataformat-yaml:2.18.3")
+        force("com.fasterxml.jackson.dataformat:jackson-dataformat-toml:2.18.3")
     }
 }
 
@@ -41,6 +42,7 @@ dependencies {
     // Serialization
     implementation(libs.jackson.databind)
     implementation(libs.jackson.smile)
+    implementation(libs.jackson.toml)
     api(libs.jackson.annotations)
     implementation(libs.lz4)
 
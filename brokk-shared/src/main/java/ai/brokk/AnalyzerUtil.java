@@ -264,7 +264,7 @@ public class AnalyzerUtil {
 
     public static Optional<String> getSource(IAnalyzer analyzer, String fqName, boolean includeComments) {
         return analyzer.getDefinitions(fqName).stream()
-                .filter(cu -> cu.isFunction() || cu.isClass())
+                .filter(cu -> cu.isFunction() || cu.isClass() || cu.isMacro())
                 .flatMap(cu -> analyzer.getSource(cu, includeComments).stream())
                 .reduce((srcA, srcB) -> srcA + "\n\n" + srcB);
     }
@@ -0,0 +1,65 @@
+package ai.brokk.analyzer;
+
+import java.util.ArrayDeque;
+import java.util.HashSet;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.function.Function;
+
+/**
+ * Capability provider for analyzers that can resolve call sites to the functions they call.
+ *
+ * <p>Call graphs are keyed by the fully qualified name of each visited function, in the shape
+ * {@code AnalyzerUtil.formatCallGraph} renders.
+ */
+public interface CallGraphProvider extends CapabilityProvider {
+
+    /** Returns the call sites that call {@code function}; each {@link CallSite#target()} is the calling function. */
+    List<CallSite> getCallers(CodeUnit function);
+
+    /** Returns the call sites in {@code function}'s body; each {@link CallSite#target()} is the called function. */
+    List<CallSite> getCallees(CodeUnit function);
+
+    /** Returns the callers of {@code function} up to {@code depth} levels away, keyed by the called function. */
+    default Map<String, List<CallSite>> getCallGraphTo(CodeUnit function, int depth) {
+        return traverseCallGraph(function, depth, this::getCallers);
+    }
+
+    /** Returns the callees of {@code function} up to {@code depth} levels away, keyed by the calling function. */
+    default Map<String, List<CallSite>> getCallGraphFrom(CodeUnit function, int depth) {
+        return traverseCallGraph(function, depth, this::getCallees);
+    }
+
+    /**
+     * Breadth-first walk from {@code root}, expanding each function at most once so recursion and cycles terminate.
+     * Functions without call sites are left out of the graph. Implementations that resolve a whole file to find the
+     * edges of one function can pass {@code edges} that reuse those files across the walk.
+     */
+    static Map<String, List<CallSite>> traverseCallGraph(
+            CodeUnit root, int depth, Function<CodeUnit, List<CallSite>> edges) {
+        var graph = new LinkedHashMap<String, List<CallSite>>();
+        var visited = new HashSet<String>();
+        var frontier = new ArrayDeque<CodeUnit>();
+        frontier.add(root);
+        visited.add(root.fqName());
+        for (int level = 0; level < depth && !frontier.isEmpty(); level++) {
+            var next = new ArrayDeque<CodeUnit>();
+            while (!frontier.isEmpty()) {
+                CodeUnit current = frontier.removeFirst();
+                List<CallSite> sites = edges.apply(current);
+                if (sites.isEmpty()) {
+                    continue;
+                }
+                graph.put(current.fqName(), sites);
+                for (CallSite site : sites) {
+                    if (visited.add(site.target().fqName())) {
+                        next.addLast(site.target());
+                    }
+                }
+            }
+            frontier = next;
+        }
+        return graph;
+    }
+}
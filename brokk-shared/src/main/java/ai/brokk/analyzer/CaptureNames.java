@@ -30,6 +30,7 @@ public final class CaptureNames {
     public static final String LAMBDA_DEFINITION = "lambda.definition";
     public static final String ARROW_FUNCTION_DEFINITION = "arrow_function.definition";
     public static final String DESTRUCTOR_DEFINITION = "destructor.definition";
+    public static final String MACRO_DEFINITION = "macro.definition";
 
     // Attribute/metadata captures
     public static final String ANNOTATION_DEFINITION = "annotation.definition";
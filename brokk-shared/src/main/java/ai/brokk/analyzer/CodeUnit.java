@@ -5,7 +5,7 @@ import com.fasterxml.jackson.annotation.JsonProperty;
 import java.util.Objects;
 import org.jetbrains.annotations.Nullable;
 
-/** Represents a named code element (class, function, field, or module). */
+/** Represents a named code element (class, function, field, module, or macro). */
 public class CodeUnit implements Comparable<CodeUnit> {
 
     @JsonProperty("source")
@@ -104,7 +104,7 @@ public class CodeUnit implements Comparable<CodeUnit> {
                 yield lastSep >= 0 ? shortName.substring(lastSep + 1) : shortName;
             }
             case MODULE -> shortName; // The module's own short name, e.g., "_module_"
-            default -> { // FUNCTION or FIELD
+            default -> { // FUNCTION, FIELD or MACRO
                 // shortName format is "Class.member" or "simpleFunction"
                 int lastDot = shortName.lastIndexOf('.');
                 yield lastDot >= 0 ? shortName.substring(lastDot + 1) : shortName;
@@ -120,6 +120,8 @@ public class CodeUnit implements Comparable<CodeUnit> {
      *   <li>For {@link CodeUnitType#FUNCTION} or {@link CodeUnitType#FIELD}, this is "className.memberName" (e.g.,
      *       "MyClass.myMethod", "Outer$Inner.myMethod") or just "functionName".
      *   <li>For {@link CodeUnitType#MODULE}, this is typically a placeholder like "_module_" or a file-derived name.
+     *   <li>For {@link CodeUnitType#MACRO}, this is the macro name, prefixed by any enclosing module chain (e.g.,
+     *       "my_macro" or "helpers.my_macro").
      * </ul>
      *
      * @return The short name.
@@ -144,6 +146,10 @@ public class CodeUnit implements Comparable<CodeUnit> {
         return kind == CodeUnitType.FIELD;
     }
 
+    public boolean isMacro() {
+        return kind == CodeUnitType.MACRO;
+    }
+
     /**
      * Returns the code unit kind, i.e., Class, module, field, function, etc.
      *
@@ -270,6 +276,7 @@ public class CodeUnit implements Comparable<CodeUnit> {
             case FUNCTION -> "FUNCTION[" + fqName() + "]";
             case FIELD -> "FIELD[" + fqName() + "]";
             case MODULE -> "MODULE[" + fqName() + "]";
+            case MACRO -> "MACRO[" + fqName() + "]";
         };
     }
 
@@ -329,4 +336,16 @@ public class CodeUnit implements Comparable<CodeUnit> {
     public static CodeUnit module(ProjectFile source, String packageName, String shortName) {
         return new CodeUnit(source, CodeUnitType.MODULE, packageName, shortName, null, false);
     }
+
+    /**
+     * Factory method to create a CodeUnit of type MACRO. Assumes correct arguments. Used for declarative macro
+     * definitions such as Rust's {@code macro_rules!}.
+     *
+     * @param source The source file.
+     * @param packageName The package name (e.g., "com.example", or "" for default package).
+     * @param shortName The macro name, optionally prefixed by its enclosing container (e.g., "my_macro").
+     */
+    public static CodeUnit macro(ProjectFile source, String packageName, String shortName) {
+        return new CodeUnit(source, CodeUnitType.MACRO, packageName, shortName, null, false);
+    }
 }
@@ -7,7 +7,8 @@ public enum CodeUnitType {
     CLASS,
     FIELD,
     FUNCTION,
-    MODULE;
+    MODULE,
+    MACRO;
 
-    public static final Set<CodeUnitType> ALL = EnumSet.of(CLASS, FIELD, FUNCTION, MODULE);
+    public static final Set<CodeUnitType> ALL = EnumSet.of(CLASS, FIELD, FUNCTION, MODULE, MACRO);
 }
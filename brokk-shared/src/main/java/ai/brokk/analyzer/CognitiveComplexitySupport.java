@@ -9,6 +9,8 @@ import static ai.brokk.analyzer.ASTTraversalUtils.typeOf;
 import java.util.ArrayDeque;
 import java.util.ArrayList;
 import java.util.HashSet;
+import java.util.LinkedHashMap;
+import java.util.Map;
 import java.util.Set;
 import java.util.function.BiPredicate;
 import java.util.function.Predicate;
@@ -17,8 +19,29 @@ import org.treesitter.TSNode;
 
 public final class CognitiveComplexitySupport {
 
+    // Constructs the shared rules score, as keyed in a breakdown.
+    public static final String IF = "if";
+    public static final String ELSE_IF = "else-if";
+    public static final String LOOP = "loop";
+    public static final String CATCH = "catch";
+    public static final String CONDITIONAL = "conditional";
+    public static final String CASE = "case";
+    public static final String LOGICAL_OPERATORS = "logical-operators";
+    public static final String LABELED_JUMP = "labeled-jump";
+
     private CognitiveComplexitySupport() {}
 
+    /**
+     * Scores a node ahead of the shared rules, for constructs a language scores differently or on top of them. A hook
+     * that handles the node adds its increments and pushes whichever children are still to be walked, then returns
+     * true; returning false leaves the node to the shared rules.
+     */
+    @FunctionalInterface
+    public interface NodeHook {
+        boolean visit(
+                TSNode node, int nesting, boolean elseIfContinuation, SourceContent sourceContent, Walker walker);
+    }
+
     public record Config(
             Set<String> ifTypes,
             Set<String> alternateIfTypes,
@@ -34,7 +57,9 @@ public final class CognitiveComplexitySupport {
             Set<String> anonymousFunctionTypes,
             Set<String> elseClauseTypes,
             BiPredicate<TSNode, SourceContent> defaultCasePredicate,
-            Predicate<TSNode> namedFunctionBoundaryPredicate) {
+            Predicate<TSNode> namedFunctionBoundaryPredicate,
+            Predicate<TSNode> labeledJumpPredicate,
+            NodeHook nodeHook) {
         Set<String> allIfTypes() {
             var allTypes = new HashSet<>(ifTypes);
             allTypes.addAll(alternateIfTypes);
@@ -62,6 +87,8 @@ public final class CognitiveComplexitySupport {
         private Set<String> elseClauseTypes = Set.of();
         private BiPredicate<TSNode, SourceContent> defaultCasePredicate = (node, sourceContent) -> false;
         private Predicate<TSNode> namedFunctionBoundaryPredicate = node -> false;
+        private Predicate<TSNode> labeledJumpPredicate = node -> node.getNamedChildCount() > 0;
+        private NodeHook nodeHook = (node, nesting, elseIfContinuation, sourceContent, walker) -> false;
 
         private ConfigBuilder() {}
 
@@ -140,6 +167,17 @@ public final class CognitiveComplexitySupport {
             return this;
         }
 
+        /** Whether a jump counts as labeled; by default any jump with a named child does. */
+        public ConfigBuilder labeledJumpPredicate(Predicate<TSNode> predicate) {
+            labeledJumpPredicate = predicate;
+            return this;
+        }
+
+        public ConfigBuilder nodeHook(NodeHook hook) {
+            nodeHook = hook;
+            return this;
+        }
+
         public Config build() {
             return new Config(
                     ifTypes,
@@ -156,48 +194,72 @@ public final class CognitiveComplexitySupport {
                     anonymousFunctionTypes,
                     elseClauseTypes,
                     defaultCasePredicate,
-                    namedFunctionBoundaryPredicate);
+                    namedFunctionBoundaryPredicate,
+                    labeledJumpPredicate,
+                    nodeHook);
         }
     }
 
     public static int compute(TSNode root, SourceContent sourceContent, Config config) {
-        int complexity = 0;
-        var work = new ArrayDeque<CognitiveFrame>();
-        work.push(new CognitiveFrame(root, 0, false, true));
+        return breakdown(root, sourceContent, config).values().stream()
+                .mapToInt(Integer::intValue)
+                .sum();
+    }
 
-        while (!work.isEmpty()) {
-            var frame = work.pop();
+    /**
+     * Returns the non-zero contributions to {@link #compute}, keyed by construct in the order first scored: the
+     * shared constants such as {@link #IF} and whatever a {@link NodeHook} adds.
+     */
+    public static Map<String, Integer> breakdown(TSNode root, SourceContent sourceContent, Config config) {
+        var walker = new Walker(config);
+        walker.work.push(new CognitiveFrame(root, 0, false, true));
+
+        while (!walker.work.isEmpty()) {
+            var frame = walker.work.pop();
             TSNode node = frame.node();
             String type = typeOf(node);
             if (type == null) {
                 continue;
             }
+            if (config.nodeHook().visit(node, frame.nesting(), frame.elseIfContinuation(), sourceContent, walker)) {
+                continue;
+            }
 
             if (config.ifTypes().contains(type) || config.alternateIfTypes().contains(type)) {
-                complexity += frame.elseIfContinuation() ? 1 : controlFlowIncrement(frame.nesting());
-                pushIfChildren(work, node, frame.nesting(), config);
+                if (frame.elseIfContinuation()) {
+                    walker.add(ELSE_IF, 1);
+                } else {
+                    walker.add(IF, controlFlowIncrement(frame.nesting()));
+                }
+                walker.pushIfChildren(node, frame.nesting());
             } else if (config.loopTypes().contains(type)
                     || config.catchTypes().contains(type)
                     || config.conditionalTypes().contains(type)) {
-                complexity += controlFlowIncrement(frame.nesting());
-                pushNamedChildren(work, node, frame.nesting() + 1);
+                String construct = config.loopTypes().contains(type)
+                        ? LOOP
+                        : config.catchTypes().contains(type) ? CATCH : CONDITIONAL;
+                walker.add(construct, controlFlowIncrement(frame.nesting()));
+                walker.pushNamedChildren(node, frame.nesting() + 1);
             } else if (config.caseTypes().contains(type)) {
                 if (!config.defaultCasePredicate().test(node, sourceContent)) {
-                    complexity += controlFlowIncrement(frame.nesting());
+                    walker.add(CASE, controlFlowIncrement(frame.nesting()));
                 }
-                pushNamedChildren(work, node, frame.nesting() + 1);
+                walker.pushNamedChildren(node, frame.nesting() + 1);
             } else if (config.defaultCaseTypes().contains(type)) {
-                pushNamedChildren(work, node, frame.nesting());
+                walker.pushNamedChildren(node, frame.nesting());
             } else if (config.binaryTypes().contains(type)) {
                 if (!isNestedType(node, config.binaryTypes())) {
-                    complexity += logicalOperatorSequenceCount(node, sourceContent, config);
+                    walker.add(
+                            LOGICAL_OPERATORS,
+                            logicalOperatorSequenceCount(
+                                    node, sourceContent, config.binaryTypes(), config.logicalOperators()));
                 }
-                pushNamedChildren(work, node, frame.nesting());
+                walker.pushNamedChildren(node, frame.nesting());
             } else if (config.jumpTypes().contains(type)) {
-                if (isLabeledJump(node)) {
-                    complexity++;
+                if (config.labeledJumpPredicate().test(node)) {
+                    walker.add(LABELED_JUMP, 1);
                 }
-                pushNamedChildren(work, node, frame.nesting());
+                walker.pushNamedChildren(node, frame.nesting());
             } else {
                 boolean namedFunctionBoundary =
                         config.namedFunctionBoundaryTypes().contains(type)
@@ -207,54 +269,75 @@ public final class CognitiveComplexitySupport {
                 }
                 int childNesting =
                         config.anonymousFunctionTypes().contains(type) ? frame.nesting() + 1 : frame.nesting();
-                pushNamedChildren(work, node, childNesting, frame.root() && !namedFunctionBoundary);
+                walker.pushNamedChildren(node, childNesting, frame.root() && !namedFunctionBoundary);
             }
         }
-        return complexity;
+        return walker.scores;
     }
 
-    private static void pushIfChildren(ArrayDeque<CognitiveFrame> work, TSNode node, int nesting, Config config) {
-        var children = namedChildren(node);
-        for (int i = children.size() - 1; i >= 0; i--) {
-            TSNode child = children.get(i);
-            String type = typeOf(child);
-            if (config.elseClauseTypes().contains(type)) {
-                TSNode elseIf = directNamedChildOfAnyType(child, config.allIfTypes());
-                if (elseIf != null) {
-                    work.push(new CognitiveFrame(elseIf, nesting, true, false));
-                    pushNamedChildrenExcept(work, child, elseIf, nesting + 1);
+    /** The walk in progress: the nodes still to visit and the score so far, for a {@link NodeHook} to extend. */
+    public static final class Walker {
+        private final Config config;
+        private final ArrayDeque<CognitiveFrame> work = new ArrayDeque<>();
+        private final Map<String, Integer> scores = new LinkedHashMap<>();
+
+        private Walker(Config config) {
+            this.config = config;
+        }
+
+        public void add(String construct, int increment) {
+            if (increment > 0) {
+                scores.merge(construct, increment, Integer::sum);
+            }
+        }
+
+        /** Walks an if's branches one level deeper, continuing an {@code else if} chain at the if's own nesting. */
+        public void pushIfChildren(TSNode node, int nesting) {
+            var children = namedChildren(node);
+            for (int i = children.size() - 1; i >= 0; i--) {
+                TSNode child = children.get(i);
+                String type = typeOf(child);
+                if (config.elseClauseTypes().contains(type)) {
+                    TSNode elseIf = directNamedChildOfAnyType(child, config.allIfTypes());
+                    if (elseIf != null) {
+                        work.push(new CognitiveFrame(elseIf, nesting, true, false));
+                        pushNamedChildrenExcept(child, elseIf, nesting + 1);
+                    } else {
+                        work.push(new CognitiveFrame(child, nesting + 1, false, false));
+                    }
+                } else if (config.ifTypes().contains(type)
+                        || config.alternateIfTypes().contains(type)) {
+                    work.push(new CognitiveFrame(child, nesting, true, false));
                 } else {
                     work.push(new CognitiveFrame(child, nesting + 1, false, false));
                 }
-            } else if (config.ifTypes().contains(type)
-                    || config.alternateIfTypes().contains(type)) {
-                work.push(new CognitiveFrame(child, nesting, true, false));
-            } else {
-                work.push(new CognitiveFrame(child, nesting + 1, false, false));
             }
         }
-    }
 
-    private static void pushNamedChildren(ArrayDeque<CognitiveFrame> work, TSNode node, int nesting) {
-        pushNamedChildren(work, node, nesting, false);
-    }
+        public void push(TSNode node, int nesting) {
+            work.push(new CognitiveFrame(node, nesting, false, false));
+        }
 
-    private static void pushNamedChildren(ArrayDeque<CognitiveFrame> work, TSNode node, int nesting, boolean root) {
-        var children = namedChildren(node);
-        for (int i = children.size() - 1; i >= 0; i--) {
-            work.push(new CognitiveFrame(children.get(i), nesting, false, root));
+        public void pushNamedChildren(TSNode node, int nesting) {
+            pushNamedChildren(node, nesting, false);
         }
-    }
 
-    private static void pushNamedChildrenExcept(
-            ArrayDeque<CognitiveFrame> work, TSNode node, @Nullable TSNode except, int nesting) {
-        var children = namedChildren(node);
-        for (int i = children.size() - 1; i >= 0; i--) {
-            TSNode child = children.get(i);
-            if (except != null && sameRange(child, except)) {
-                continue;
+        private void pushNamedChildren(TSNode node, int nesting, boolean root) {
+            var children = namedChildren(node);
+            for (int i = children.size() - 1; i >= 0; i--) {
+                work.push(new CognitiveFrame(children.get(i), nesting, false, root));
+            }
+        }
+
+        public void pushNamedChildrenExcept(TSNode node, @Nullable TSNode except, int nesting) {
+            var children = namedChildren(node);
+            for (int i = children.size() - 1; i >= 0; i--) {
+                TSNode child = children.get(i);
+                if (except != null && sameRange(child, except)) {
+                    continue;
+                }
+                work.push(new CognitiveFrame(child, nesting, false, false));
             }
-            work.push(new CognitiveFrame(child, nesting, false, false));
         }
     }
 
@@ -266,11 +349,11 @@ public final class CognitiveComplexitySupport {
         return types.contains(typeOf(node.getParent()));
     }
 
-    private static boolean isLabeledJump(TSNode node) {
-        return node.getNamedChildCount() > 0;
-    }
-
-    private static int logicalOperatorSequenceCount(TSNode node, SourceContent sourceContent, Config config) {
+    /**
+     * Counts the runs of like logical operators in a binary expression tree, so {@code a && b && c || d} scores two.
+     */
+    public static int logicalOperatorSequenceCount(
+            TSNode node, SourceContent sourceContent, Set<String> binaryTypes, Set<String> logicalOperators) {
         var operators = new ArrayList<String>();
         var work = new ArrayDeque<TSNode>();
         work.push(node);
@@ -280,15 +363,15 @@ public final class CognitiveComplexitySupport {
             for (int i = children.size() - 1; i >= 0; i--) {
                 TSNode child = children.get(i);
                 String type = typeOf(child);
-                if (config.binaryTypes().contains(type)) {
+                if (binaryTypes.contains(type)) {
                     work.push(child);
                     continue;
                 }
-                if (config.logicalOperators().contains(type)) {
+                if (logicalOperators.contains(type)) {
                     operators.add(type);
                     continue;
                 }
-                if (isLogicalOperatorToken(child, sourceContent, config.logicalOperators())) {
+                if (isLogicalOperatorToken(child, sourceContent, logicalOperators)) {
                     operators.add(sourceContent.substringFrom(child));
                 }
             }
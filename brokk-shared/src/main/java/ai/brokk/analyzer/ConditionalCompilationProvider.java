@@ -0,0 +1,44 @@
+package ai.brokk.analyzer;
+
+import java.util.Collection;
+import java.util.List;
+import java.util.Optional;
+
+/**
+ * Capability for analyzers of languages with conditional compilation, such as Rust's {@code #[cfg(...)]}, where a
+ * declaration exists only under some build configurations.
+ */
+public interface ConditionalCompilationProvider extends CapabilityProvider {
+    /** The condition under which the code unit is compiled, in source syntax, or empty if it is unconditional. */
+    Optional<String> compilationCondition(CodeUnit cu);
+
+    /** Returns true if the code unit is compiled under the project's configured build configuration. */
+    boolean isActive(CodeUnit cu);
+
+    /**
+     * The active units among {@code units}, or all of them when none is active, so that mutually exclusive variants of
+     * a declaration (e.g. {@code #[cfg(unix)]} and {@code #[cfg(windows)]} versions of one function) resolve to the
+     * configured one without losing results for code that is never compiled here.
+     */
+    static List<CodeUnit> preferActive(IAnalyzer analyzer, Collection<CodeUnit> units) {
+        var provider = analyzer.as(ConditionalCompilationProvider.class);
+        if (provider.isEmpty()) {
+            return List.copyOf(units);
+        }
+        List<CodeUnit> active = units.stream().filter(provider.get()::isActive).toList();
+        return active.isEmpty() ? List.copyOf(units) : active;
+    }
+
+    /**
+     * A suffix describing the condition of a code unit for tool output, e.g. {@code " [cfg(windows), inactive]"}, or
+     * "" for unconditional code units and analyzers without conditional compilation.
+     */
+    static String describe(IAnalyzer analyzer, CodeUnit cu) {
+        return analyzer.as(ConditionalCompilationProvider.class)
+                .flatMap(provider -> provider.compilationCondition(cu).map(condition -> {
+                    String state = provider.isActive(cu) ? "" : ", inactive";
+                    return " [cfg(" + condition + ")" + state + "]";
+                }))
+                .orElse("");
+    }
+}
@@ -201,17 +201,25 @@ public class FileAnalysisAccumulator {
         return this;
     }
 
-    private void removeFromSymbolIndex(CodeUnit cu) {
-        String[] keys = {cu.identifier(), cu.shortName()};
-        for (String key : keys) {
-            Set<CodeUnit> set = codeUnitsBySymbol.get(key);
-            if (set != null) {
-                set.remove(cu);
-                if (set.isEmpty()) {
-                    codeUnitsBySymbol.remove(key);
-                }
+    /**
+     * Stops indexing a CodeUnit by a symbol; its other symbols are kept. Mutations should only be performed via these
+     * APIs.
+     * @return this accumulator for chaining.
+     */
+    public FileAnalysisAccumulator removeSymbolIndex(String symbol, CodeUnit cu) {
+        Set<CodeUnit> set = codeUnitsBySymbol.get(symbol);
+        if (set != null) {
+            set.remove(cu);
+            if (set.isEmpty()) {
+                codeUnitsBySymbol.remove(symbol);
             }
         }
+        return this;
+    }
+
+    private void removeFromSymbolIndex(CodeUnit cu) {
+        removeSymbolIndex(cu.identifier(), cu);
+        removeSymbolIndex(cu.shortName(), cu);
     }
 
     /**
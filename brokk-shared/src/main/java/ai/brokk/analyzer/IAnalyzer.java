@@ -594,7 +594,7 @@ public interface IAnalyzer {
     static Comparator<CodeUnit> autocompleteDefinitionsSortComparator() {
         return Comparator.comparingInt((CodeUnit cu) -> switch (cu.kind()) {
                     case CLASS -> 0;
-                    case FUNCTION -> 1;
+                    case FUNCTION, MACRO -> 1;
                     case FIELD -> 2;
                     case MODULE -> 3;
                 })
@@ -713,6 +713,7 @@ public interface IAnalyzer {
                     case FUNCTION -> functionDisplay;
                     case FIELD -> codeUnit.identifier();
                     case MODULE -> codeUnit.shortName();
+                    case MACRO -> codeUnit.identifier() + "!";
                 });
     }
 
@@ -1122,6 +1123,15 @@ public interface IAnalyzer {
         return complexities;
     }
 
+    /**
+     * Explains the cognitive complexity of each function in the given file as per-construct contributions, keyed by
+     * construct label (e.g. {@code if}, {@code match-arm}, {@code try-operator}) and summing to the function's score.
+     * Analyzers without a construct-level model return an empty map.
+     */
+    default Map<CodeUnit, Map<String, Integer>> computeCognitiveComplexityBreakdowns(ProjectFile file) {
+        return Map.of();
+    }
+
     /**
      * Comment density for a single declaration. Language-specific analyzers may override; default is unsupported.
      */
@@ -149,6 +149,14 @@ public interface Language {
         return List.of();
     }
 
+    /**
+     * Whether a change to {@code file} can change what this language's analyzer reports: its source files by default,
+     * plus e.g. build manifests for languages whose module layout they define.
+     */
+    default boolean affectsAnalysis(ProjectFile file) {
+        return getExtensions().contains(file.extension());
+    }
+
     /**
      * Checks if this language is equivalent to, or contains, the other language.
      * Default implementation delegates to equals().
@@ -252,6 +260,11 @@ public interface Language {
             throw new UnsupportedOperationException(); // should only be called on single languages
         }
 
+        @Override
+        public boolean affectsAnalysis(ProjectFile file) {
+            return languages.stream().anyMatch(l -> l.affectsAnalysis(file));
+        }
+
         @Override
         public boolean contains(Language other) {
             if (this.equals(other)) return true;
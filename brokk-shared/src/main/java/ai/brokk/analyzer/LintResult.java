@@ -25,8 +25,19 @@ public record LintResult(List<LintDiagnostic> diagnostics) {
         return diagnostics.stream().filter(d -> filePaths.contains(d.file())).toList();
     }
 
-    /** Individual diagnostic message from linting. */
-    public record LintDiagnostic(String file, int line, int column, Severity severity, String message, String code) {
+    /** Individual diagnostic message from linting, with any replacements the linter proposes for fixing it. */
+    public record LintDiagnostic(
+            String file,
+            int line,
+            int column,
+            Severity severity,
+            String message,
+            String code,
+            List<Suggestion> suggestions) {
+        public LintDiagnostic(String file, int line, int column, Severity severity, String message, String code) {
+            this(file, line, column, severity, message, code, List.of());
+        }
+
         public enum Severity {
             ERROR,
             WARNING,
@@ -34,4 +45,31 @@ public record LintResult(List<LintDiagnostic> diagnostics) {
             HINT
         }
     }
+
+    /**
+     * A replacement the linter proposes for a span of a file. Lines and columns are 1-based and the end column is
+     * exclusive; byte offsets are 0-based UTF-8 offsets into the file, as reported by the tool. {@code sourceLines}
+     * holds the lines the span covers as the linter saw them, so that a stale suggestion can be detected.
+     */
+    public record Suggestion(
+            String file,
+            int lineStart,
+            int columnStart,
+            int lineEnd,
+            int columnEnd,
+            int byteStart,
+            int byteEnd,
+            String replacement,
+            Applicability applicability,
+            String message,
+            List<String> sourceLines) {
+
+        /** How confident the linter is that the replacement is correct, following rustc's levels. */
+        public enum Applicability {
+            MACHINE_APPLICABLE,
+            MAYBE_INCORRECT,
+            HAS_PLACEHOLDERS,
+            UNSPECIFIED
+        }
+    }
 }
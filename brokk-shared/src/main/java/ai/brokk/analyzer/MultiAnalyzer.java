@@ -11,14 +11,24 @@ import org.slf4j.Logger;
 import org.slf4j.LoggerFactory;
 
 public class MultiAnalyzer
-        implements IAnalyzer, TypeAliasProvider, ImportAnalysisProvider, TypeHierarchyProvider, TestDetectionProvider {
+        implements IAnalyzer,
+                TypeAliasProvider,
+                ImportAnalysisProvider,
+                TypeHierarchyProvider,
+                TestDetectionProvider,
+                ConditionalCompilationProvider,
+                CallGraphProvider,
+                RustRiskSmellProvider {
     private static final Logger log = LoggerFactory.getLogger(MultiAnalyzer.class);
 
     private static final Set<Class<? extends CapabilityProvider>> SUPPORTED_CAPABILITIES = Set.of(
             ImportAnalysisProvider.class,
             TypeHierarchyProvider.class,
             TypeAliasProvider.class,
-            TestDetectionProvider.class);
+            TestDetectionProvider.class,
+            ConditionalCompilationProvider.class,
+            CallGraphProvider.class,
+            RustRiskSmellProvider.class);
 
     private final Map<Language, IAnalyzer> delegates;
     private final Collection<ITemplateAnalyzer> templateAnalyzers;
@@ -237,6 +247,13 @@ public class MultiAnalyzer
                 .orElse(Map.of());
     }
 
+    @Override
+    public Map<CodeUnit, Map<String, Integer>> computeCognitiveComplexityBreakdowns(ProjectFile file) {
+        return delegateFor(file)
+                .map(delegate -> delegate.computeCognitiveComplexityBreakdowns(file))
+                .orElse(Map.of());
+    }
+
     @Override
     public Optional<CommentDensityStats> commentDensity(CodeUnit cu) {
         return delegateFor(cu).flatMap(delegate -> delegate.commentDensity(cu));
@@ -264,6 +281,14 @@ public class MultiAnalyzer
                 .orElse(List.of());
     }
 
+    @Override
+    public List<RustRiskSmell> findRustRiskSmells(ProjectFile file, RustRiskSmellWeights weights) {
+        return delegateFor(file)
+                .flatMap(delegate -> delegate.as(RustRiskSmellProvider.class))
+                .map(provider -> provider.findRustRiskSmells(file, weights))
+                .orElse(List.of());
+    }
+
     @Override
     public List<CloneSmell> findStructuralCloneSmells(ProjectFile file, CloneSmellWeights weights) {
         return delegateFor(file)
@@ -409,11 +434,9 @@ public class MultiAnalyzer
             var delegateKey = entry.getKey();
             var analyzer = entry.getValue();
 
-            // Filter files by language extensions
-            var languageExtensions = delegateKey.getExtensions();
-            var relevantFiles = changedFiles.stream()
-                    .filter(pf -> languageExtensions.contains(pf.extension()))
-                    .collect(Collectors.toSet());
+            // Filter files by what the language's analyzer depends on
+            var relevantFiles =
+                    changedFiles.stream().filter(delegateKey::affectsAnalysis).collect(Collectors.toSet());
 
             if (relevantFiles.isEmpty()) {
                 newDelegates.put(delegateKey, analyzer);
@@ -569,6 +592,13 @@ public class MultiAnalyzer
         return false;
     }
 
+    @Override
+    public Optional<CodeUnit> resolveTypeAlias(CodeUnit alias) {
+        return delegateFor(alias)
+                .flatMap(analyzer -> analyzer.as(TypeAliasProvider.class))
+                .flatMap(provider -> provider.resolveTypeAlias(alias));
+    }
+
     /**
      * @return a copy of the delegates of this analyzer.
      */
@@ -607,6 +637,21 @@ public class MultiAnalyzer
         return delegateFor(file).map(delegate -> delegate.containsTests(file)).orElse(false);
     }
 
+    @Override
+    public Optional<String> compilationCondition(CodeUnit cu) {
+        return delegateFor(cu)
+                .flatMap(delegate -> delegate.as(ConditionalCompilationProvider.class))
+                .flatMap(provider -> provider.compilationCondition(cu));
+    }
+
+    @Override
+    public boolean isActive(CodeUnit cu) {
+        return delegateFor(cu)
+                .flatMap(delegate -> delegate.as(ConditionalCompilationProvider.class))
+                .map(provider -> provider.isActive(cu))
+                .orElse(true);
+    }
+
     @Override
     public Set<CodeUnit> getDirectDescendants(CodeUnit cu) {
         return delegateFor(cu)
@@ -615,6 +660,38 @@ public class MultiAnalyzer
                 .orElse(Set.of());
     }
 
+    @Override
+    public List<CallSite> getCallers(CodeUnit function) {
+        return delegateFor(function)
+                .flatMap(analyzer -> analyzer.as(CallGraphProvider.class))
+                .map(provider -> provider.getCallers(function))
+                .orElse(List.of());
+    }
+
+    @Override
+    public List<CallSite> getCallees(CodeUnit function) {
+        return delegateFor(function)
+                .flatMap(analyzer -> analyzer.as(CallGraphProvider.class))
+                .map(provider -> provider.getCallees(function))
+                .orElse(List.of());
+    }
+
+    @Override
+    public Map<String, List<CallSite>> getCallGraphTo(CodeUnit function, int depth) {
+        return delegateFor(function)
+                .flatMap(analyzer -> analyzer.as(CallGraphProvider.class))
+                .map(provider -> provider.getCallGraphTo(function, depth))
+                .orElse(Map.of());
+    }
+
+    @Override
+    public Map<String, List<CallSite>> getCallGraphFrom(CodeUnit function, int depth) {
+        return delegateFor(function)
+                .flatMap(analyzer -> analyzer.as(CallGraphProvider.class))
+                .map(provider -> provider.getCallGraphFrom(function, depth))
+                .orElse(Map.of());
+    }
+
     @Override
     public List<String> getTestModules(Collection<ProjectFile> files) {
         Map<Language, List<ProjectFile>> grouped =
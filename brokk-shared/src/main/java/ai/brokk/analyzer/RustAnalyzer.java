@@ -4,9 +4,20 @@ import static ai.brokk.analyzer.rust.Constants.*;
 import static java.util.Objects.requireNonNull;
 import static org.treesitter.RustNodeType.*;
 
+import ai.brokk.AnalyzerUtil;
 import ai.brokk.analyzer.cache.AnalyzerCache;
 import ai.brokk.analyzer.cache.RustAnalyzerCache;
+import ai.brokk.analyzer.rust.CargoTestSelection;
+import ai.brokk.analyzer.rust.CfgPredicate;
 import ai.brokk.analyzer.rust.CognitiveComplexityAnalysis;
+import ai.brokk.analyzer.rust.RustCfgConfiguration;
+import ai.brokk.analyzer.rust.RustCfgIndex;
+import ai.brokk.analyzer.rust.RustCfgSettings;
+import ai.brokk.analyzer.rust.RustCrateLayout;
+import ai.brokk.analyzer.rust.RustCrateLayout.CrateTarget;
+import ai.brokk.analyzer.rust.RustCrateLayout.ModuleDeclaration;
+import ai.brokk.analyzer.rust.RustCrateLayout.ModuleFileOwner;
+import ai.brokk.analyzer.rust.RustCrateLayout.TargetKind;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.AssociatedFunctionKey;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.FieldKey;
@@ -15,26 +26,42 @@ import ai.brokk.analyzer.rust.RustExportUsageExtractor.MemberKey;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.RustTypeRef;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.RustUsageCandidateIndex;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.RustUsageFacts;
+import ai.brokk.analyzer.rust.RustExportUsageExtractor.TraitImplIndex;
+import ai.brokk.analyzer.rust.RustUsageKinds;
 import ai.brokk.analyzer.usages.ExportIndex;
+import ai.brokk.analyzer.usages.ExportUsageReferenceGraphEngine;
 import ai.brokk.analyzer.usages.ImportBinder;
 import ai.brokk.analyzer.usages.ReferenceCandidate;
+import ai.brokk.analyzer.usages.ReceiverTargetRef;
+import ai.brokk.analyzer.usages.ReferenceHit;
+import ai.brokk.analyzer.usages.ReferenceKind;
 import ai.brokk.analyzer.usages.ResolvedReceiverCandidate;
+import ai.brokk.analyzer.usages.RustExportUsageGraphAdapter;
+import ai.brokk.analyzer.usages.UsageKind;
 import ai.brokk.project.ICoreProject;
 import ai.brokk.util.PathNormalizer;
 import java.nio.file.Files;
 import java.nio.file.Path;
 import java.util.ArrayDeque;
 import java.util.ArrayList;
+import java.util.Collection;
 import java.util.Collections;
 import java.util.Comparator;
 import java.util.Deque;
+import java.util.EnumMap;
 import java.util.HashMap;
 import java.util.HashSet;
+import java.util.LinkedHashMap;
 import java.util.List;
 import java.util.Locale;
 import java.util.Map;
 import java.util.Optional;
+import java.util.SequencedSet;
 import java.util.Set;
+import java.util.TreeSet;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.function.Function;
+import java.util.regex.Matcher;
 import java.util.regex.Pattern;
 import java.util.stream.Collectors;
 import org.jetbrains.annotations.Nullable;
@@ -43,8 +70,32 @@ import org.slf4j.LoggerFactory;
 import org.treesitter.*;
 import org.treesitter.RustNodeField;
 
-public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnalysisProvider {
+public final class RustAnalyzer extends TreeSitterAnalyzer
+        implements ImportAnalysisProvider,
+                TypeHierarchyProvider,
+                TypeAliasProvider,
+                ConditionalCompilationProvider,
+                CallGraphProvider,
+                RustRiskSmellProvider {
     private static final Logger log = LoggerFactory.getLogger(RustAnalyzer.class);
+    private static final Pattern WHITESPACE_RUN = Pattern.compile("\\s+");
+    private static final Pattern POSITIONAL_FIELD_NAME = Pattern.compile("\\d+");
+    // A `#[test]` function in the token tree of a `proptest! { ... }` block, possibly with further attributes.
+    private static final Pattern PROPTEST_TEST_FUNCTION =
+            Pattern.compile("#\\s*\\[\\s*test\\s*]\\s*(?:#\\s*\\[[^\\]]*]\\s*)*(?:async\\s+)?fn\\s+(?:r#)?(\\w+)");
+    private static final Pattern TRAILING_LIST_COMMA = Pattern.compile(",\\s*([)>\\]])");
+    private static final Pattern SPACE_AFTER_OPENER = Pattern.compile("([(<\\[])\\s+");
+    private static final Pattern SPACE_BEFORE_CLOSER = Pattern.compile("\\s+([)>\\]])");
+    // The comment clippy's `undocumented_unsafe_blocks` lint expects to justify an `unsafe` block.
+    private static final Pattern SAFETY_COMMENT = Pattern.compile("\\bSAFETY:");
+    // Bounds alias chains such as `type A = B; type B = Arc<C>;` that are followed during receiver inference.
+    private static final int MAX_TYPE_ALIAS_DEPTH = 8;
+    private static final double RETURN_TYPE_RECEIVER_CONFIDENCE = 0.9;
+    private static final Set<String> VALUE_DECLARATION_TYPES =
+            Set.of(nodeType(FIELD_DECLARATION), nodeType(CONST_ITEM), nodeType(STATIC_ITEM));
+
+    /** Joins the implementing type and trait in the name of a trait impl unit, e.g. {@code Point as Shape}. */
+    public static final String TRAIT_IMPL_SEPARATOR = " as ";
 
     @Override
     public boolean isFileLevelModule(CodeUnit cu, boolean topLevel) {
@@ -82,6 +133,7 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                     nodeType(IMPL_ITEM),
                     nodeType(TRAIT_ITEM),
                     nodeType(STRUCT_ITEM),
+                    nodeType(UNION_ITEM),
                     nodeType(ENUM_ITEM),
                     nodeType(MOD_ITEM)),
             Set.of(nodeType(FUNCTION_ITEM), nodeType(FUNCTION_SIGNATURE_ITEM)),
@@ -99,6 +151,7 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                     CaptureNames.IMPL_DEFINITION, SkeletonType.CLASS_LIKE,
                     CaptureNames.MODULE_DEFINITION, SkeletonType.MODULE_STATEMENT,
                     CaptureNames.FUNCTION_DEFINITION, SkeletonType.FUNCTION_LIKE,
+                    CaptureNames.MACRO_DEFINITION, SkeletonType.FUNCTION_LIKE,
                     CaptureNames.FIELD_DEFINITION, SkeletonType.FIELD_LIKE,
                     CaptureNames.TYPEALIAS_DEFINITION, SkeletonType.ALIAS_LIKE),
             "",
@@ -132,6 +185,20 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return new RustAnalyzerCache();
     }
 
+    /**
+     * A manifest or lockfile change may move crate targets, and with them the module path of every file, so it
+     * re-analyzes all Rust files and rebuilds the crate layout.
+     */
+    @Override
+    public IAnalyzer update(Set<ProjectFile> changedFiles) {
+        if (changedFiles.stream().noneMatch(RustCrateLayout::isCargoManifest)) {
+            return super.update(changedFiles);
+        }
+        var files = new HashSet<>(changedFiles);
+        files.addAll(getAnalyzedFiles());
+        return super.update(files);
+    }
+
     @Override
     protected AnalyzerCache createFilteredCache(AnalyzerCache previous, Set<ProjectFile> changedFiles) {
         return previous instanceof RustAnalyzerCache rustCache
@@ -168,19 +235,193 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return computeCognitiveComplexities(file, CognitiveComplexityAnalysis::compute);
     }
 
+    @Override
+    public Map<CodeUnit, Map<String, Integer>> computeCognitiveComplexityBreakdowns(ProjectFile file) {
+        return computeCognitiveComplexities(file, CognitiveComplexityAnalysis::breakdown);
+    }
+
     /**
-     * Determines the Rust module path for a given file. This considers common Rust project structures like `src/`
-     * layouts, `lib.rs`, `main.rs` as crate roots, and `mod.rs` for directory modules.
+     * Determines the Rust module path for a given file. Files loaded through {@code #[path = "..."]} module
+     * declarations take the path of the declaring module; other files in a Cargo target are prefixed with the target's
+     * crate name (see {@link RustCrateLayout}). Projects without Cargo targets fall back to the conventional layout:
+     * `src/` as the module root, `lib.rs`, `main.rs` as crate roots, and `mod.rs` for directory modules.
      *
      * @param file     The project file being analyzed.
      * @param defNode  The TSNode representing the definition (unused in this implementation).
      * @param rootNode The root TSNode of the file's syntax tree (unused in this implementation).
      * @param sourceContent The source code of the file (unused in this implementation).
-     * @return The module path string (e.g., "foo.bar"), or an empty string for the crate root.
+     * @return The module path string (e.g., "my_crate.foo.bar"), or an empty string for a crate root outside any
+     *     Cargo target.
      */
     @Override
     protected String determinePackageName(
             ProjectFile file, TSNode defNode, TSNode rootNode, SourceContent sourceContent) {
+        return rustModulePathOf(file, new HashSet<>());
+    }
+
+    private String rustModulePathOf(ProjectFile file, Set<ProjectFile> visiting) {
+        ModuleFileOwner owner = pathAttributeModules().get(file);
+        if (owner != null && visiting.add(file)) {
+            String parent = rustModulePathOf(owner.declaringFile(), visiting);
+            return parent.isEmpty() ? owner.moduleName() : parent + "." + owner.moduleName();
+        }
+        return crateLayout().modulePathOf(file.absPath()).orElseGet(() -> conventionalModulePath(file));
+    }
+
+    /** The crate name that {@code crate::} paths in {@code file} refer to, or "" outside any Cargo target. */
+    private String crateRootOf(ProjectFile file) {
+        return crateTargetOf(file).map(CrateTarget::crateName).orElse("");
+    }
+
+    /** The Cargo target compiling {@code file}, following {@code #[path]} module declarations to their crate. */
+    private Optional<CrateTarget> crateTargetOf(ProjectFile file) {
+        var visited = new HashSet<ProjectFile>();
+        ProjectFile current = file;
+        ModuleFileOwner owner;
+        while ((owner = pathAttributeModules().get(current)) != null && visited.add(current)) {
+            current = owner.declaringFile();
+        }
+        return crateLayout().targetFor(current.absPath());
+    }
+
+    private RustCrateLayout crateLayout() {
+        RustAnalyzerCache cache = rustCache();
+        RustCrateLayout cached = cache.crateLayout();
+        if (cached != null) {
+            return cached;
+        }
+        synchronized (cache) {
+            cached = cache.crateLayout();
+            if (cached == null) {
+                cached = ((RustLanguage) Languages.RUST).getCrateLayout(getProject());
+                cache.crateLayout(cached);
+            }
+            return cached;
+        }
+    }
+
+    /**
+     * Files loaded through {@code #[path = "..."]} module declarations, plus the out-of-line submodules they declare in
+     * turn, mapped to their declaring file and module path relative to it. Path attributes resolve relative to the
+     * directory of the declaring file, or inside inline {@code mod a { ... }} blocks relative to that module's
+     * directory; files loaded that way own their directory like {@code mod.rs}.
+     */
+    private Map<ProjectFile, ModuleFileOwner> pathAttributeModules() {
+        RustAnalyzerCache cache = rustCache();
+        Map<ProjectFile, ModuleFileOwner> cached = cache.pathAttributeModules();
+        if (cached != null && cache.unverifiedModuleDeclarations().isEmpty()) {
+            return cached;
+        }
+        synchronized (cache) {
+            cached = cache.pathAttributeModules();
+            if (cached != null && moduleDeclarationsUnchanged(cache.unverifiedModuleDeclarations())) {
+                cache.unverifiedModuleDeclarations().clear();
+                return cached;
+            }
+            cache.unverifiedModuleDeclarations().clear();
+            Set<ProjectFile> rustFiles = getProject().getAnalyzableFiles(Languages.RUST);
+            Set<Path> targetRoots = crateLayout().targets().stream()
+                    .map(CrateTarget::rootFile)
+                    .collect(Collectors.toSet());
+            var owners = new HashMap<ProjectFile, ModuleFileOwner>();
+            Deque<ProjectFile> pending = new ArrayDeque<>();
+            for (ProjectFile file : rustFiles) {
+                for (ModuleDeclaration declaration : moduleDeclarationsOf(file)) {
+                    String path = declaration.path();
+                    if (path == null) {
+                        continue;
+                    }
+                    Path base = declaration.inlineModules().isEmpty()
+                            ? requireNonNull(file.absPath().getParent())
+                            : inlineModuleDirectory(file, targetRoots.contains(file.absPath()), declaration);
+                    Optional<ProjectFile> target = projectFileAt(base.resolve(path))
+                            .filter(loaded -> rustFiles.contains(loaded) && !loaded.equals(file));
+                    if (target.isPresent()
+                            && owners.putIfAbsent(
+                                            target.get(),
+                                            new ModuleFileOwner(file, declaration.relativeModulePath()))
+                                    == null) {
+                        pending.add(target.get());
+                    }
+                }
+            }
+            while (!pending.isEmpty()) {
+                ProjectFile file = pending.removeFirst();
+                for (ModuleDeclaration declaration : moduleDeclarationsOf(file)) {
+                    if (declaration.path() != null) {
+                        continue;
+                    }
+                    Path dir = inlineModuleDirectory(file, true, declaration);
+                    String name = declaration.name();
+                    for (Path candidate : List.of(dir.resolve(name + ".rs"), dir.resolve(name).resolve("mod.rs"))) {
+                        projectFileAt(candidate)
+                                .filter(target -> rustFiles.contains(target) && !owners.containsKey(target))
+                                .ifPresent(target -> {
+                                    owners.put(target, new ModuleFileOwner(file, declaration.relativeModulePath()));
+                                    pending.add(target);
+                                });
+                    }
+                }
+            }
+            var immutable = Map.copyOf(owners);
+            cache.pathAttributeModules(immutable);
+            return immutable;
+        }
+    }
+
+    /**
+     * The directory of the inline modules enclosing {@code declaration}: below the declaring file's directory when the
+     * file owns it ({@code mod.rs}, a crate root or a {@code #[path]}-loaded file), and below the directory named after
+     * the file otherwise.
+     */
+    private static Path inlineModuleDirectory(
+            ProjectFile file, boolean ownsDirectory, ModuleDeclaration declaration) {
+        Path absPath = file.absPath();
+        Path dir = requireNonNull(absPath.getParent());
+        if (!ownsDirectory && !"mod.rs".equals(absPath.getFileName().toString())) {
+            dir = dir.resolve(absPath.getFileName().toString().replaceFirst("\\.rs$", ""));
+        }
+        for (String module : declaration.inlineModules()) {
+            dir = dir.resolve(module);
+        }
+        return dir;
+    }
+
+    private boolean moduleDeclarationsUnchanged(Map<ProjectFile, List<ModuleDeclaration>> previous) {
+        return previous.entrySet().stream()
+                .allMatch(entry -> moduleDeclarationsOf(entry.getKey()).equals(entry.getValue()));
+    }
+
+    private List<ModuleDeclaration> moduleDeclarationsOf(ProjectFile file) {
+        List<ModuleDeclaration> cached = rustCache().moduleDeclarationsCache().getIfPresent(file);
+        if (cached != null) {
+            return cached;
+        }
+        List<ModuleDeclaration> computed = withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return List.of();
+                    }
+                    return withSource(file, source -> RustCrateLayout.moduleDeclarations(root, source), List.of());
+                },
+                List.of());
+        rustCache().moduleDeclarationsCache().put(file, computed);
+        return computed;
+    }
+
+    private Optional<ProjectFile> projectFileAt(Path absPath) {
+        Path root = getProject().getRoot();
+        Path normalized = absPath.normalize();
+        if (!normalized.startsWith(root)) {
+            return Optional.empty();
+        }
+        return getProject().getFileByRelPath(root.relativize(normalized));
+    }
+
+    /** Module path under the conventional single-crate layout rooted at {@code src/} (or the project root). */
+    private String conventionalModulePath(ProjectFile file) {
         Path projectRoot = getProject().getRoot();
         Path absFilePath = file.absPath();
         Path fileParentDir = absFilePath.getParent();
@@ -274,9 +515,11 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                 packageName,
                 classChain);
         return switch (captureName) {
-            // "class.definition" is for struct, trait, enum.
+            // "class.definition" is for struct, union, trait, enum.
             // "impl.definition" is for impl blocks. Both create class-like CodeUnits.
-            // simpleName for "impl.definition" will be the type being implemented (e.g., "Point").
+            // simpleName for "impl.definition" is the implemented type for inherent impls (e.g., "Point"), merging
+            // with the type's own unit, and "Point as Shape" for trait impls, whose members are then named
+            // "Point as Shape.area" so that `fmt` of `impl Display` and of `impl Debug` stay distinct.
             case CaptureNames.CLASS_DEFINITION, CaptureNames.IMPL_DEFINITION ->
                 CodeUnit.cls(file, packageName, simpleName);
             // "module.definition" is for mod blocks.
@@ -292,13 +535,24 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                 String fqSimpleName = classChain.isEmpty() ? simpleName : classChain + "." + simpleName;
                 yield CodeUnit.fn(file, packageName, fqSimpleName);
             }
+            case CaptureNames.MACRO_DEFINITION -> {
+                // macro_rules! definitions are textually scoped; classChain only carries enclosing inline modules.
+                String macroShortName = classChain.isEmpty() ? simpleName : classChain + "." + simpleName;
+                yield CodeUnit.macro(file, packageName, macroShortName);
+            }
             case CaptureNames.FIELD_DEFINITION -> {
                 // For struct fields, classChain is the struct name.
                 // For top-level const/static, classChain is empty (or contains module names).
                 String fieldShortName = classChain.isEmpty() ? "_module_." + simpleName : classChain + "." + simpleName;
                 yield CodeUnit.field(file, packageName, fieldShortName);
             }
-            case CaptureNames.TYPEALIAS_DEFINITION -> CodeUnit.cls(file, packageName, simpleName);
+            case CaptureNames.TYPEALIAS_DEFINITION -> {
+                // Associated types are members of their trait or impl, e.g. `Counter.Item`.
+                boolean associated = definitionNode != null && isAssociatedItem(definitionNode);
+                String aliasShortName =
+                        associated && !classChain.isEmpty() ? classChain + "." + simpleName : simpleName;
+                yield CodeUnit.cls(file, packageName, aliasShortName);
+            }
             default -> {
                 log.warn(
                         "Unhandled capture name in RustAnalyzer.createCodeUnit: '{}' for simple name '{}' in file '{}'. Returning null.",
@@ -310,6 +564,41 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         };
     }
 
+    private static boolean isAssociatedItem(TSNode node) {
+        TSNode owner = declarationListOwner(node);
+        return owner != null
+                && (nodeType(IMPL_ITEM).equals(owner.getType()) || nodeType(TRAIT_ITEM).equals(owner.getType()));
+    }
+
+    @Override
+    protected String buildClassChain(TSNode node, TSNode rootNode, SourceContent sourceContent) {
+        String classChain = super.buildClassChain(node, rootNode, sourceContent);
+        // Fields of `Named { name: String }` and `Pair(u32, u32)` variants are nested under their variant.
+        return enclosingEnumVariant(node)
+                .map(variant -> variant.getChildByFieldName(nodeField(RustNodeField.NAME)))
+                .map(name -> classChain + "." + sourceContent.substringFrom(name).strip())
+                .orElse(classChain);
+    }
+
+    private static Optional<TSNode> enclosingEnumVariant(TSNode field) {
+        TSNode list = field.getParent();
+        if (list == null
+                || !(nodeType(FIELD_DECLARATION_LIST).equals(list.getType())
+                        || nodeType(ORDERED_FIELD_DECLARATION_LIST).equals(list.getType()))) {
+            return Optional.empty();
+        }
+        TSNode variant = list.getParent();
+        return variant != null && nodeType(ENUM_VARIANT).equals(variant.getType())
+                ? Optional.of(variant)
+                : Optional.empty();
+    }
+
+    /** Variant fields are children of their enum variant, whose signature already spells them out. */
+    @Override
+    protected List<CodeUnit> orderChildrenForSkeleton(CodeUnit parent, List<CodeUnit> children) {
+        return parent.isField() ? List.of() : children;
+    }
+
     @Override
     protected String bodyPlaceholder() {
         return "...";
@@ -343,11 +632,20 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
             String returnTypeText,
             String indent) {
         String rt = returnTypeText.isBlank() ? "" : " -> " + returnTypeText;
+        String whereText = whereClauseOf(fnNode)
+                .map(whereClause -> " " + sourceContent.substringFrom(whereClause))
+                .orElse("");
         // exportPrefix is from getVisibilityPrefix. asyncPrefix from base class logic.
-        String header = String.format(
-                        "%s%s%sfn %s%s%s%s",
-                        indent, exportPrefix, asyncPrefix, functionName, typeParamsText, paramsText, rt)
-                .stripLeading();
+        String header = normalizeSignatureText(String.format(
+                "%s%s%sfn %s%s%s%s%s",
+                exportPrefix,
+                asyncPrefix,
+                externAbiPrefix(fnNode, sourceContent),
+                functionName,
+                typeParamsText,
+                paramsText,
+                rt,
+                whereText));
 
         TSNode bodyNode = fnNode.getChildByFieldName(getLanguageSyntaxProfile().bodyFieldName());
         if (bodyNode != null) {
@@ -359,6 +657,55 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         }
     }
 
+    @Override
+    protected void buildFunctionSkeleton(
+            TSNode funcNode,
+            Optional<String> providedNameOpt,
+            SourceContent sourceContent,
+            String indent,
+            List<String> lines,
+            String exportPrefix,
+            boolean includePresentationDetails) {
+        if (nodeType(MACRO_DEFINITION).equals(funcNode.getType())) {
+            lines.addAll(renderMacroSkeleton(funcNode, providedNameOpt, sourceContent, indent));
+            return;
+        }
+        super.buildFunctionSkeleton(
+                funcNode, providedNameOpt, sourceContent, indent, lines, exportPrefix, includePresentationDetails);
+    }
+
+    /**
+     * Renders a {@code macro_rules!} definition as its matcher arms, eliding each transcriber. For example:
+     *
+     * <pre>
+     * macro_rules! square {
+     *     ($x:expr) => { ... };
+     * }
+     * </pre>
+     */
+    private List<String> renderMacroSkeleton(
+            TSNode macroNode, Optional<String> providedNameOpt, SourceContent sourceContent, String indent) {
+        TSNode nameNode = macroNode.getChildByFieldName(nodeField(RustNodeField.NAME));
+        String macroName = nameNode != null ? sourceContent.substringFrom(nameNode) : providedNameOpt.orElse("");
+        var lines = new ArrayList<String>();
+        lines.add(indent + "macro_rules! " + macroName + " {");
+        for (TSNode rule : macroNode.getNamedChildren()) {
+            if (!nodeType(MACRO_RULE).equals(rule.getType())) {
+                continue;
+            }
+            TSNode matcher = rule.getChildByFieldName(nodeField(RustNodeField.LEFT));
+            if (matcher == null) {
+                continue;
+            }
+            String matcherText = WHITESPACE_RUN
+                    .matcher(sourceContent.substringFrom(matcher).strip())
+                    .replaceAll(" ");
+            lines.add(indent + getLanguageSpecificIndent() + matcherText + " => { " + bodyPlaceholder() + " };");
+        }
+        lines.add(indent + "}");
+        return lines;
+    }
+
     @Override
     protected String renderClassHeader(
             TSNode classNode,
@@ -367,13 +714,76 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
             String signatureText,
             String baseIndent) {
         // signatureText is derived by TreeSitterAnalyzer using textSlice up to the body or end of node.
-        // For Rust, this text (e.g. "struct Foo", "impl Point for Bar") is what we want, prefixed by visibility.
-        return baseIndent + exportPrefix + signatureText + " {";
+        // For Rust, this text (e.g. "struct Foo<T: Clone>", "impl<T> Point for Bar<T> where T: Hash") is what we want,
+        // prefixed by visibility. Tuple structs put their where-clause after the body, so it is appended here.
+        String header = signatureText;
+        TSNode body = classNode.getChildByFieldName(nodeField(RustNodeField.BODY));
+        Optional<TSNode> trailingWhere = whereClauseOf(classNode)
+                .filter(whereClause -> body != null && whereClause.getStartByte() >= body.getEndByte());
+        if (trailingWhere.isPresent()) {
+            header = header + " " + sourceContent.substringFrom(trailingWhere.get());
+        }
+        return baseIndent + normalizeSignatureText(exportPrefix + header) + " {";
+    }
+
+    /**
+     * Returns the ABI marker of an {@code extern "C" fn} or of an item declared in an {@code extern "C" { ... }} block,
+     * with a trailing space, or an empty string for ordinary items.
+     */
+    private static String externAbiPrefix(TSNode item, SourceContent sourceContent) {
+        Optional<TSNode> externModifier = Optional.empty();
+        for (TSNode child : item.getNamedChildren()) {
+            if (nodeType(FUNCTION_MODIFIERS).equals(child.getType())) {
+                externModifier = namedChildOfType(child, nodeType(EXTERN_MODIFIER));
+            }
+        }
+        TSNode owner = declarationListOwner(item);
+        if (externModifier.isEmpty() && owner != null && nodeType(FOREIGN_MOD_ITEM).equals(owner.getType())) {
+            externModifier = namedChildOfType(owner, nodeType(EXTERN_MODIFIER));
+        }
+        return externModifier
+                .map(modifier -> normalizeSignatureText(sourceContent.substringFrom(modifier)) + " ")
+                .orElse("");
+    }
+
+    /** The impl, trait, mod or extern block whose {@code { ... }} body directly contains {@code item}, if any. */
+    private static @Nullable TSNode declarationListOwner(TSNode item) {
+        TSNode parent = item.getParent();
+        return parent != null && nodeType(DECLARATION_LIST).equals(parent.getType()) ? parent.getParent() : null;
+    }
+
+    private static Optional<TSNode> namedChildOfType(TSNode node, String type) {
+        for (TSNode child : node.getNamedChildren()) {
+            if (type.equals(child.getType())) {
+                return Optional.of(child);
+            }
+        }
+        return Optional.empty();
+    }
+
+    private static Optional<TSNode> whereClauseOf(TSNode declaration) {
+        return namedChildOfType(declaration, nodeType(WHERE_CLAUSE));
+    }
+
+    /**
+     * Collapses a possibly multi-line declaration header onto one line, e.g.
+     * {@code impl<T: Clone> Cache<T> where T: Hash}. Whitespace just inside brackets and trailing commas in parameter,
+     * generic and where lists are dropped.
+     */
+    static String normalizeSignatureText(String text) {
+        String normalized = WHITESPACE_RUN.matcher(text.strip()).replaceAll(" ");
+        normalized = TRAILING_LIST_COMMA.matcher(normalized).replaceAll("$1");
+        normalized = SPACE_AFTER_OPENER.matcher(normalized).replaceAll("$1");
+        normalized = SPACE_BEFORE_CLOSER.matcher(normalized).replaceAll("$1");
+        return normalized.endsWith(",")
+                ? normalized.substring(0, normalized.length() - 1).stripTrailing()
+                : normalized;
     }
 
     @Override
     protected String getLanguageSpecificCloser(CodeUnit cu) {
-        return cu.isClass() ? "}" : "";
+        // Type aliases and associated types are class units too, but `type Item = u32;` has no body to close.
+        return cu.isClass() && !isTypeAlias(cu) ? "}" : "";
     }
 
     @Override
@@ -456,7 +866,12 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
             if (typeNode != null) {
                 Optional<String> name = extractCoreTypeName(typeNode, sourceContent);
                 if (name.isPresent()) {
-                    return name;
+                    // A trait impl is its own unit, `Type as Trait`; an inherent impl merges into `Type`.
+                    Optional<String> traitName =
+                            implTraitUnitName(decl.getChildByFieldName(nodeField(RustNodeField.TRAIT)), sourceContent);
+                    return traitName.isPresent()
+                            ? Optional.of(name.get() + TRAIT_IMPL_SEPARATOR + traitName.get())
+                            : name;
                 }
             }
             String errorContext = String.format(
@@ -473,6 +888,11 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                             + errorContext);
         }
 
+        TSNode parent = decl.getParent();
+        if (parent != null && nodeType(ORDERED_FIELD_DECLARATION_LIST).equals(parent.getType())) {
+            return positionalFieldIndex(parent, decl).map(String::valueOf);
+        }
+
         // For all other node types, defer to the base class implementation.
         // If super returns empty, throw.
         Optional<String> nameFromSuper = super.extractSimpleName(decl, sourceContent);
@@ -492,6 +912,96 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return nameFromSuper;
     }
 
+    /**
+     * The trait of a trait impl as its unit names it. Type arguments are kept, so {@code impl From<A> for X} and
+     * {@code impl From<B> for X} are the separate units {@code X as From<A>} and {@code X as From<B>}.
+     */
+    private Optional<String> implTraitUnitName(@Nullable TSNode traitNode, SourceContent sourceContent) {
+        Optional<String> name = extractCoreTypeName(traitNode, sourceContent);
+        if (name.isEmpty() || traitNode == null || !nodeType(GENERIC_TYPE).equals(traitNode.getType())) {
+            return name;
+        }
+        TSNode arguments = traitNode.getChildByFieldName(nodeField(RustNodeField.TYPE_ARGUMENTS));
+        return arguments == null
+                ? name
+                : Optional.of(name.get() + normalizeSignatureText(sourceContent.substringFrom(arguments)));
+    }
+
+    /**
+     * Positional tuple fields stay field units, found by their qualified name such as {@code Color.Rgb.0}, but their
+     * bare indices are not indexed as symbols.
+     */
+    @Override
+    protected void postProcessFileAnalysis(
+            ProjectFile file,
+            FileAnalysisAccumulator acc,
+            TSNode rootNode,
+            SourceContent sourceContent,
+            Map<CodeUnit, String> cuToCaptureName) {
+        acc.cuByFqName().values().stream()
+                .filter(cu -> cu.isField() && POSITIONAL_FIELD_NAME.matcher(cu.identifier()).matches())
+                .forEach(cu -> acc.removeSymbolIndex(cu.identifier(), cu));
+    }
+
+    /** Leaves out the indices of positional tuple fields, which are no symbols anyone searches for. */
+    @Override
+    public Set<String> getSymbols(Set<CodeUnit> sources) {
+        return super.getSymbols(sources).stream()
+                .filter(symbol -> !POSITIONAL_FIELD_NAME.matcher(symbol).matches())
+                .collect(Collectors.toSet());
+    }
+
+    private static Optional<Integer> positionalFieldIndex(TSNode fieldList, TSNode field) {
+        String typeField = nodeField(RustNodeField.TYPE);
+        int index = 0;
+        for (int i = 0; i < fieldList.getChildCount(); i++) {
+            if (!typeField.equals(fieldList.getFieldNameForChild(i))) {
+                continue;
+            }
+            if (fieldList.getChild(i).equals(field)) {
+                return Optional.of(index);
+            }
+            index++;
+        }
+        return Optional.empty();
+    }
+
+    /**
+     * Returns the implementing type of a trait impl unit name, e.g. {@code Point} for {@code Point as Shape}. Other
+     * names are returned unchanged.
+     */
+    public static String implSelfTypeName(String name) {
+        int separator = name.indexOf(TRAIT_IMPL_SEPARATOR);
+        return separator >= 0 ? name.substring(0, separator) : name;
+    }
+
+    /**
+     * Drops the trait from a name qualified by a trait impl unit, e.g. {@code Point.area} for {@code Point as
+     * Shape.area} and {@code Point} for {@code Point as Shape}. Other names are returned unchanged.
+     */
+    public static String withoutImplTrait(String name) {
+        int separator = name.indexOf(TRAIT_IMPL_SEPARATOR);
+        if (separator < 0) {
+            return name;
+        }
+        int memberDot = name.indexOf('.', separator);
+        return memberDot >= 0 ? name.substring(0, separator) + name.substring(memberDot) : name.substring(0, separator);
+    }
+
+    /**
+     * Returns the implemented trait of a trait impl unit name without its type arguments, e.g. {@code Shape} for
+     * {@code Point as Shape} and {@code From} for {@code Point as From<(u32, u32)>}.
+     */
+    public static Optional<String> implTraitName(String name) {
+        int separator = name.indexOf(TRAIT_IMPL_SEPARATOR);
+        if (separator < 0) {
+            return Optional.empty();
+        }
+        String trait = name.substring(separator + TRAIT_IMPL_SEPARATOR.length());
+        int arguments = trait.indexOf('<');
+        return Optional.of(arguments >= 0 ? trait.substring(0, arguments) : trait);
+    }
+
     @Override
     protected String formatFieldSignature(
             TSNode fieldNode,
@@ -501,21 +1011,52 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
             String simpleName,
             String baseIndent,
             ProjectFile file) {
+        TSNode list = fieldNode.getParent();
+        if (list != null && nodeType(ORDERED_FIELD_DECLARATION_LIST).equals(list.getType())) {
+            // Positional fields render as `pub 0: f64`, with the visibility that precedes the type in the tuple.
+            TSNode previous = fieldNode.getPrevNamedSibling();
+            String visibility = previous != null && nodeType(VISIBILITY_MODIFIER).equals(previous.getType())
+                    ? sourceContent.substringFrom(previous).strip() + " "
+                    : "";
+            return baseIndent
+                    + visibility
+                    + simpleName
+                    + ": "
+                    + normalizeSignatureText(sourceContent.substringFrom(fieldNode));
+        }
+
         String sig = signatureText.strip();
         String pref = exportPrefix.strip();
+        String declaration = !pref.isEmpty() && sig.startsWith(pref)
+                ? sig.substring(pref.length()).strip()
+                : sig;
 
-        String fullSignature;
-        if (!pref.isEmpty() && sig.startsWith(pref)) {
-            fullSignature = sig;
-        } else {
-            fullSignature = (exportPrefix.stripTrailing() + " " + sig).strip();
-        }
+        // Statics declared in an `extern "C" { ... }` block carry the block's ABI,
+        // e.g. `pub extern "C" static errno: i32;`
+        String fullSignature = (pref + " " + externAbiPrefix(fieldNode, sourceContent) + declaration).strip();
 
         // Rust fields like "pub x: i32," and "const ORIGIN: Point = ..." should not have semicolons added in skeleton
         // format
         return baseIndent + fullSignature;
     }
 
+    @Override
+    protected String renderAliasSignature(
+            TSNode node,
+            SourceContent sourceContent,
+            String exportPrefix,
+            String simpleName,
+            LanguageSyntaxProfile profile,
+            ProjectFile file) {
+        // Covers `pub type Alias<T> = Vec<T>;`, impl `type Output = Meters;` and trait `type Item: Clone;` alike.
+        String declaration = normalizeSignatureText(sourceContent.substringFrom(node));
+        String pref = exportPrefix.strip();
+        if (!pref.isEmpty() && !declaration.startsWith(pref)) {
+            declaration = pref + " " + declaration;
+        }
+        return declaration.endsWith(";") ? declaration : declaration + ";";
+    }
+
     @Override
     protected boolean requiresSemicolons() {
         return false; // Rust fields like "pub x: i32," should not have semicolons added
@@ -536,57 +1077,407 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return performReferencingFilesOf(file);
     }
 
-    public ExportIndex exportIndexOf(ProjectFile file) {
-        ExportIndex cached = cache().exportIndex().get(file);
+    /**
+     * The traits a type implements or derives, or a trait's supertraits. A derived trait outside the project, as in
+     * {@code #[derive(Debug, serde::Serialize)]}, is returned as a synthetic code unit named by its path, e.g.
+     * {@code serde.Serialize}.
+     */
+    @Override
+    public List<CodeUnit> getDirectAncestors(CodeUnit cu) {
+        return performGetDirectAncestors(cu);
+    }
+
+    /** The implementors of a trait; for a synthetic trait outside the project, the types deriving it. */
+    @Override
+    public Set<CodeUnit> getDirectDescendants(CodeUnit cu) {
+        if (cu.isSynthetic() && cu.isClass()) {
+            return typesDerivingExternalTrait(cu.fqName().replace(".", "::"));
+        }
+        return performGetDirectDescendants(cu);
+    }
+
+    /**
+     * Members of trait impls are named by their impl unit, e.g. {@code Point as Display.fmt}; looked up as members of
+     * the type ({@code Point.fmt}), they are found when the type declares no such member itself.
+     */
+    @Override
+    public SequencedSet<CodeUnit> getDefinitions(String fqName) {
+        SequencedSet<CodeUnit> definitions = super.getDefinitions(fqName);
+        if (!definitions.isEmpty()) {
+            return definitions;
+        }
+        Set<CodeUnit> implMembers =
+                traitImplMembersByTypeMemberName().getOrDefault(normalizeFullName(fqName), Set.of());
+        return implMembers.isEmpty() ? definitions : sortDefinitions(implMembers);
+    }
+
+    private Map<String, Set<CodeUnit>> traitImplMembersByTypeMemberName() {
+        Map<String, Set<CodeUnit>> cached = rustCache().traitImplMembersByTypeMemberName();
         if (cached != null) {
             return cached;
         }
-        ExportIndex computed = withTreeOf(
-                file,
-                tree -> {
-                    TSNode root = tree.getRootNode();
-                    if (root == null) {
-                        return ExportIndex.empty();
-                    }
-                    return withSource(
-                            file,
-                            source -> RustExportUsageExtractor.computeExportIndex(this, file, root, source),
-                            ExportIndex.empty());
-                },
-                ExportIndex.empty());
-        cache().exportIndex().put(file, computed);
-        return computed;
+        var computed = new HashMap<String, Set<CodeUnit>>();
+        for (CodeUnit cu : getAllDeclarations()) {
+            if (cu.shortName().contains(TRAIT_IMPL_SEPARATOR) && !cu.identifier().contains(TRAIT_IMPL_SEPARATOR)) {
+                computed.computeIfAbsent(withoutImplTrait(cu.fqName()), ignored -> new HashSet<>()).add(cu);
+            }
+        }
+        var immutable = computed.entrySet().stream()
+                .collect(Collectors.toUnmodifiableMap(Map.Entry::getKey, entry -> Set.copyOf(entry.getValue())));
+        rustCache().traitImplMembersByTypeMemberName(immutable);
+        return immutable;
     }
 
-    public ImportBinder importBinderOf(ProjectFile file) {
-        ImportBinder cached = cache().importBinder().get(file);
+    /**
+     * The conjunction of the {@code #[cfg(...)]} conditions a code unit is compiled under: those of the module file it
+     * is declared in and of the enclosing items carrying a cfg attribute. A code unit declared several times, e.g. once
+     * per platform, is compiled when any of its declarations is.
+     */
+    @Override
+    public Optional<String> compilationCondition(CodeUnit cu) {
+        return compilationConditionOf(cu).map(CfgPredicate::toString);
+    }
+
+    @Override
+    public boolean isActive(CodeUnit cu) {
+        Optional<CfgPredicate> condition = compilationConditionOf(cu);
+        return condition.isEmpty()
+                || condition.get().evaluate(cfgConfiguration().environmentFor(cu.source().absPath()));
+    }
+
+    /**
+     * Definitions compiled under the configured {@link RustCfgSettings} come first, so that of several per-platform
+     * variants of an item, clients taking the first definition get the one that is built.
+     */
+    @Override
+    protected Comparator<CodeUnit> prioritizingComparator() {
+        // Evaluated once per code unit for the lifetime of the comparator, i.e. one sort.
+        Map<CodeUnit, Boolean> inactive = new ConcurrentHashMap<>();
+        return Comparator.comparing((CodeUnit cu) -> inactive.computeIfAbsent(cu, unit -> !isActive(unit)));
+    }
+
+    /** Of a code unit declared once per configuration, the skeleton shows only the active declarations. */
+    @Override
+    protected List<String> signaturesForSkeleton(CodeUnit codeUnit) {
+        List<String> signatures = super.signaturesForSkeleton(codeUnit);
+        List<Range> ranges = rangesOf(codeUnit);
+        if (signatures.size() < 2 || signatures.size() != ranges.size()) {
+            return signatures;
+        }
+        RustCfgIndex index = cfgIndexOf(codeUnit.source());
+        if (index.spans().isEmpty()) {
+            return signatures;
+        }
+        var environment = cfgConfiguration().environmentFor(codeUnit.source().absPath());
+        var active = new ArrayList<String>();
+        for (int i = 0; i < signatures.size(); i++) {
+            Optional<CfgPredicate> condition =
+                    CfgPredicate.allOf(index.conditionsAt(ranges.get(i).startByte()));
+            if (condition.isEmpty() || condition.get().evaluate(environment)) {
+                active.add(signatures.get(i));
+            }
+        }
+        return active.isEmpty() ? signatures : List.copyOf(active);
+    }
+
+    private Optional<CfgPredicate> compilationConditionOf(CodeUnit cu) {
+        var conditions = new ArrayList<CfgPredicate>();
+        moduleFileCondition(cu.source()).ifPresent(conditions::add);
+        RustCfgIndex index = cfgIndexOf(cu.source());
+        List<Range> ranges = rangesOf(cu);
+        if (!index.spans().isEmpty() && !ranges.isEmpty()) {
+            var alternatives = new ArrayList<CfgPredicate>();
+            for (Range range : ranges) {
+                Optional<CfgPredicate> condition = CfgPredicate.allOf(index.conditionsAt(range.startByte()));
+                if (condition.isEmpty()) {
+                    // One unconditional declaration makes the code unit unconditional within its file.
+                    alternatives.clear();
+                    break;
+                }
+                alternatives.add(condition.get());
+            }
+            if (!alternatives.isEmpty()) {
+                conditions.add(CfgPredicate.anyOf(alternatives));
+            }
+        }
+        return CfgPredicate.allOf(conditions);
+    }
+
+    private RustCfgIndex cfgIndexOf(ProjectFile file) {
+        RustCfgIndex cached = rustCache().cfgIndexCache().getIfPresent(file);
         if (cached != null) {
             return cached;
         }
-        Set<String> localTopLevelNames = getTopLevelDeclarations(file).stream()
-                .map(CodeUnit::identifier)
-                .collect(java.util.stream.Collectors.toSet());
-        ImportBinder computed = withTreeOf(
+        RustCfgIndex computed = withTreeOf(
                 file,
                 tree -> {
                     TSNode root = tree.getRootNode();
                     if (root == null) {
-                        return ImportBinder.empty();
+                        return RustCfgIndex.EMPTY;
                     }
-                    return withSource(
-                            file,
-                            source -> RustExportUsageExtractor.computeImportBinder(
-                                    this, file, root, source, localTopLevelNames),
-                            ImportBinder.empty());
+                    return withSource(file, source -> RustCfgIndex.of(root, source), RustCfgIndex.EMPTY);
                 },
-                ImportBinder.empty());
-        cache().importBinder().put(file, computed);
+                RustCfgIndex.EMPTY);
+        rustCache().cfgIndexCache().put(file, computed);
         return computed;
     }
 
-    public Set<ReferenceCandidate> exportUsageCandidatesOf(ProjectFile file, ImportBinder binder) {
-        return usageFactsOf(file, binder).referenceCandidates();
-    }
+    /** The cfg configuration of the project's stored settings, rebuilt only when the stored JSON changes. */
+    private RustCfgConfiguration cfgConfiguration() {
+        String settingsJson = getProject().getLanguageSettings(Languages.RUST);
+        RustAnalyzerCache cache = rustCache();
+        RustCfgConfiguration cached = cache.cfgConfiguration();
+        if (cached != null && settingsJson.equals(cache.cfgSettingsJson())) {
+            return cached;
+        }
+        synchronized (cache) {
+            cached = cache.cfgConfiguration();
+            if (cached == null || !settingsJson.equals(cache.cfgSettingsJson())) {
+                RustCfgSettings settings = RustCfgSettings.parse(settingsJson);
+                if (cached == null || !cached.settings().equals(settings)) {
+                    cached = new RustCfgConfiguration(settings, getProject().getRoot());
+                }
+                cache.cfgConfiguration(settingsJson, cached);
+            }
+            return cached;
+        }
+    }
+
+    /**
+     * The cfg condition of a module file: a file is compiled when the file declaring its module is, under the cfg
+     * attributes of the {@code mod} declaration and the file's own {@code #![cfg(...)]} attributes. Crate root files
+     * and files no module declares only have their inner attributes.
+     */
+    private Optional<CfgPredicate> moduleFileCondition(ProjectFile file) {
+        return moduleFileCondition(file, new HashSet<>());
+    }
+
+    private Optional<CfgPredicate> moduleFileCondition(ProjectFile file, Set<ProjectFile> visiting) {
+        Map<ProjectFile, Optional<CfgPredicate>> memo = rustCache().moduleFileConditions();
+        Optional<CfgPredicate> known = memo.get(file);
+        if (known != null) {
+            return known;
+        }
+        var conditions = new ArrayList<CfgPredicate>();
+        if (visiting.add(file)) {
+            declaringModuleOf(file).ifPresent(owner -> {
+                moduleFileCondition(owner.declaringFile(), visiting).ifPresent(conditions::add);
+                Optional.ofNullable(cfgIndexOf(owner.declaringFile()).moduleConditions().get(owner.moduleName()))
+                        .ifPresent(conditions::add);
+            });
+        }
+        conditions.addAll(cfgIndexOf(file).fileConditions());
+        Optional<CfgPredicate> condition = CfgPredicate.allOf(conditions);
+        memo.put(file, condition);
+        return condition;
+    }
+
+    /**
+     * The {@code mod} declaration loading {@code file}: its {@code #[path]} owner, or else the file owning its
+     * directory ({@code mod.rs}, a crate root, or the sibling file named after the directory) that declares the module
+     * without a path. Empty for crate roots and files no module declares.
+     */
+    private Optional<ModuleFileOwner> declaringModuleOf(ProjectFile file) {
+        ModuleFileOwner pathOwner = pathAttributeModules().get(file);
+        if (pathOwner != null) {
+            return Optional.of(pathOwner);
+        }
+        Path absPath = file.absPath();
+        List<CrateTarget> targets = crateLayout().targets();
+        if (targets.stream().anyMatch(target -> target.rootFile().equals(absPath))) {
+            return Optional.empty();
+        }
+        Path dir = absPath.getParent();
+        if (dir == null) {
+            return Optional.empty();
+        }
+        String fileName = absPath.getFileName().toString();
+        boolean modRs = "mod.rs".equals(fileName);
+        Path moduleDir = modRs ? dir.getParent() : dir;
+        Path dirName = dir.getFileName();
+        if (moduleDir == null || (modRs && dirName == null)) {
+            return Optional.empty();
+        }
+        String name = modRs ? requireNonNull(dirName).toString() : fileName.replaceFirst("\\.rs$", "");
+        var candidates = new ArrayList<Path>(List.of(
+                moduleDir.resolve("mod.rs"), moduleDir.resolve("lib.rs"), moduleDir.resolve("main.rs")));
+        Path moduleDirParent = moduleDir.getParent();
+        Path moduleDirName = moduleDir.getFileName();
+        if (moduleDirParent != null && moduleDirName != null) {
+            candidates.add(moduleDirParent.resolve(moduleDirName + ".rs"));
+        }
+        targets.stream()
+                .map(CrateTarget::rootFile)
+                .filter(root -> moduleDir.equals(root.getParent()))
+                .forEach(candidates::add);
+        return candidates.stream()
+                .distinct()
+                .flatMap(candidate -> projectFileAt(candidate).stream())
+                .filter(candidate -> !candidate.equals(file))
+                .filter(candidate -> moduleDeclarationsOf(candidate).stream()
+                        .anyMatch(declaration -> declaration.inlineModules().isEmpty()
+                                && declaration.name().equals(name)
+                                && declaration.path() == null))
+                .findFirst()
+                .map(candidate -> new ModuleFileOwner(candidate, name));
+    }
+
+    /**
+     * A type's supertypes are the traits it implements ({@code impl Trait for Type}) and a trait's supertypes are its
+     * supertraits ({@code trait A: B + C}). Both come from the crate-wide heritage index, since impls may live in any
+     * file. Traits outside the project (e.g. {@code std::fmt::Display}) have no code unit and are omitted.
+     */
+    @Override
+    protected List<CodeUnit> computeSupertypes(CodeUnit cu) {
+        if (!cu.isClass()) {
+            return List.of();
+        }
+        Set<String> parentKeys = heritageParentKeys(cu);
+        if (parentKeys.isEmpty()) {
+            return List.of();
+        }
+        Map<String, CodeUnit> classesByKey = classesByQualifiedKey();
+        var supertypes = new ArrayList<CodeUnit>();
+        parentKeys.stream()
+                .sorted()
+                .flatMap(key -> Optional.ofNullable(classesByKey.get(key)).stream())
+                .filter(parent -> !parent.equals(cu))
+                .forEach(supertypes::add);
+        externalDerivedTraits().getOrDefault(qualifiedClassKey(cu.source(), cu.identifier()), Set.of()).stream()
+                .sorted()
+                .map(traitPath -> externalTraitUnit(cu.source(), traitPath))
+                .forEach(supertypes::add);
+        return List.copyOf(supertypes);
+    }
+
+    /**
+     * The derived traits declared outside the crate, by the qualified class key of the deriving type. Each is named by
+     * its path as imported, e.g. {@code serde::Serialize} for a {@code Serialize} brought in by
+     * {@code use serde::Serialize}; prelude traits such as {@code Debug} keep their bare name.
+     */
+    private Map<String, Set<String>> externalDerivedTraits() {
+        Map<String, Set<String>> cached = rustCache().externalDerivedTraits();
+        if (cached != null) {
+            return cached;
+        }
+        var computed = new HashMap<String, Set<String>>();
+        for (ProjectFile file : getAnalyzedFiles()) {
+            List<RustExportUsageExtractor.DerivedTrait> derived = withTreeOf(
+                    file,
+                    tree -> {
+                        TSNode root = tree.getRootNode();
+                        if (root == null) {
+                            return List.of();
+                        }
+                        return withSource(
+                                file, source -> RustExportUsageExtractor.derivedTraits(root, source), List.of());
+                    },
+                    List.of());
+            if (derived.isEmpty()) {
+                continue;
+            }
+            ImportBinder binder = importBinderOf(file);
+            for (RustExportUsageExtractor.DerivedTrait trait : derived) {
+                externalTraitPath(file, trait.traitPath(), binder)
+                        .ifPresent(path -> computed.computeIfAbsent(
+                                        qualifiedClassKey(file, trait.typeName()), ignored -> new HashSet<>())
+                                .add(path));
+            }
+        }
+        var immutable = computed.entrySet().stream()
+                .collect(Collectors.toUnmodifiableMap(Map.Entry::getKey, entry -> Set.copyOf(entry.getValue())));
+        rustCache().externalDerivedTraits(immutable);
+        return immutable;
+    }
+
+    /** The path of the trait {@code rustPath} names in {@code file}; empty when the trait is declared in the crate. */
+    private Optional<String> externalTraitPath(ProjectFile file, String rustPath, ImportBinder binder) {
+        if (classesByQualifiedKey().containsKey(rustTypeKey(file, rustPath, binder))) {
+            return Optional.empty();
+        }
+        String path = rustPath.startsWith("::") ? rustPath.substring(2) : rustPath;
+        ImportBinder.ImportBinding binding = path.contains("::") ? null : binder.bindings().get(path);
+        if (binding != null && binding.importedName() != null) {
+            path = binding.moduleSpecifier() + "::" + binding.importedName();
+        }
+        // An unresolved `crate::`, `self::` or `super::` path names a missing crate item, not an external trait.
+        return RUST_PATH_KEYWORDS.contains(path.split("::")[0]) ? Optional.empty() : Optional.of(path);
+    }
+
+    /** A synthetic class standing for a trait outside the project, reported with the file of the deriving type. */
+    private static CodeUnit externalTraitUnit(ProjectFile derivingFile, String traitPath) {
+        int separator = traitPath.lastIndexOf("::");
+        String packageName = separator < 0 ? "" : traitPath.substring(0, separator).replace("::", ".");
+        return CodeUnit.cls(derivingFile, packageName, traitPath.substring(separator < 0 ? 0 : separator + 2))
+                .withSynthetic(true);
+    }
+
+    private Set<CodeUnit> typesDerivingExternalTrait(String traitPath) {
+        Map<String, CodeUnit> classesByKey = classesByQualifiedKey();
+        return externalDerivedTraits().entrySet().stream()
+                .filter(entry -> entry.getValue().contains(traitPath))
+                .flatMap(entry -> Optional.ofNullable(classesByKey.get(entry.getKey())).stream())
+                .collect(Collectors.toUnmodifiableSet());
+    }
+
+    @Override
+    protected List<String> extractRawSupertypesForClassLike(
+            CodeUnit cu, TSNode classNode, String signature, SourceContent sourceContent) {
+        // Used as the cheap pre-filter for descendant lookups; the declaration node itself does not name its traits.
+        return computeSupertypes(cu).stream().map(CodeUnit::shortName).toList();
+    }
+
+    public ExportIndex exportIndexOf(ProjectFile file) {
+        ExportIndex cached = cache().exportIndex().get(file);
+        if (cached != null) {
+            return cached;
+        }
+        ExportIndex computed = withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return ExportIndex.empty();
+                    }
+                    return withSource(
+                            file,
+                            source -> RustExportUsageExtractor.computeExportIndex(this, file, root, source),
+                            ExportIndex.empty());
+                },
+                ExportIndex.empty());
+        cache().exportIndex().put(file, computed);
+        return computed;
+    }
+
+    public ImportBinder importBinderOf(ProjectFile file) {
+        ImportBinder cached = cache().importBinder().get(file);
+        if (cached != null) {
+            return cached;
+        }
+        Set<String> localTopLevelNames = getTopLevelDeclarations(file).stream()
+                .map(CodeUnit::identifier)
+                .collect(java.util.stream.Collectors.toSet());
+        ImportBinder computed = withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return ImportBinder.empty();
+                    }
+                    return withSource(
+                            file,
+                            source -> RustExportUsageExtractor.computeImportBinder(
+                                    this, file, root, source, localTopLevelNames),
+                            ImportBinder.empty());
+                },
+                ImportBinder.empty());
+        cache().importBinder().put(file, computed);
+        return computed;
+    }
+
+    public Set<ReferenceCandidate> exportUsageCandidatesOf(ProjectFile file, ImportBinder binder) {
+        return usageFactsOf(file, binder).referenceCandidates();
+    }
 
     public Set<ResolvedReceiverCandidate> resolvedReceiverCandidatesOf(ProjectFile file, ImportBinder binder) {
         return usageFactsOf(file, binder).receiverCandidates();
@@ -624,6 +1515,242 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                 .collect(Collectors.toUnmodifiableSet());
     }
 
+    /**
+     * Functions whose calls resolve to {@code function}, found by resolving the references of every file that
+     * mentions its name, so path, {@code self.method()} and typed-receiver calls count alike.
+     */
+    @Override
+    public List<CallSite> getCallers(CodeUnit function) {
+        return callers(function, this::resolvedCallsIn);
+    }
+
+    /** Calls made in {@code function}'s own body, each resolved to the called function. */
+    @Override
+    public List<CallSite> getCallees(CodeUnit function) {
+        return callees(function, this::resolvedCallsIn);
+    }
+
+    /** Resolves each file at most once however many of the visited functions it holds calls for. */
+    @Override
+    public Map<String, List<CallSite>> getCallGraphTo(CodeUnit function, int depth) {
+        Function<ProjectFile, List<ReferenceHit>> callsIn = memoizedCallsIn();
+        return CallGraphProvider.traverseCallGraph(function, depth, caller -> callers(caller, callsIn));
+    }
+
+    /** Resolves each file at most once however many of the visited functions it holds calls for. */
+    @Override
+    public Map<String, List<CallSite>> getCallGraphFrom(CodeUnit function, int depth) {
+        Function<ProjectFile, List<ReferenceHit>> callsIn = memoizedCallsIn();
+        return CallGraphProvider.traverseCallGraph(function, depth, callee -> callees(callee, callsIn));
+    }
+
+    private List<CallSite> callers(CodeUnit function, Function<ProjectFile, List<ReferenceHit>> callsIn) {
+        if (!function.isFunction()) {
+            return List.of();
+        }
+        var files = new HashSet<>(rustUsageCandidateFiles(Set.of(), function));
+        files.add(function.source());
+        var hits = files.stream()
+                .sorted()
+                .flatMap(file -> callsIn.apply(file).stream())
+                .filter(hit -> hit.resolved().equals(function) && hit.enclosingUnit().isFunction())
+                .toList();
+        return callSites(hits, true);
+    }
+
+    private List<CallSite> callees(CodeUnit function, Function<ProjectFile, List<ReferenceHit>> callsIn) {
+        if (!function.isFunction()) {
+            return List.of();
+        }
+        var hits = callsIn.apply(function.source()).stream()
+                .filter(hit -> hit.enclosingUnit().equals(function))
+                .toList();
+        return callSites(hits, false);
+    }
+
+    private Function<ProjectFile, List<ReferenceHit>> memoizedCallsIn() {
+        var callsByFile = new HashMap<ProjectFile, List<ReferenceHit>>();
+        return file -> callsByFile.computeIfAbsent(file, this::resolvedCallsIn);
+    }
+
+    /**
+     * References in {@code file} that call the function they resolve to, in source order. A function named without
+     * being called, such as one passed as a value or imported, is not a call.
+     */
+    private List<ReferenceHit> resolvedCallsIn(ProjectFile file) {
+        var references = ExportUsageReferenceGraphEngine.resolveReferencesIn(
+                        file, new RustExportUsageGraphAdapter(this), ExportUsageReferenceGraphEngine.Limits.defaults())
+                .stream()
+                .filter(hit -> hit.resolved().isFunction())
+                .sorted(Comparator.comparingInt((ReferenceHit hit) -> hit.range().startByte()))
+                .toList();
+        if (references.isEmpty()) {
+            return List.of();
+        }
+        return withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return List.<ReferenceHit>of();
+                    }
+                    return references.stream()
+                            .filter(hit -> {
+                                TSNode reference = root.getDescendantForByteRange(
+                                        hit.range().startByte(), hit.range().endByte());
+                                return reference != null && RustUsageKinds.isCallee(reference);
+                            })
+                            .toList();
+                },
+                List.of());
+    }
+
+    private List<CallSite> callSites(List<ReferenceHit> hits, boolean callerSites) {
+        var linesByFile = new HashMap<ProjectFile, List<String>>();
+        return hits.stream()
+                .map(hit -> {
+                    List<String> lines = linesByFile.computeIfAbsent(
+                            hit.file(),
+                            file -> withSource(file, source -> source.text().lines().toList(), List.<String>of()));
+                    int line = hit.range().startLine();
+                    String sourceLine = line < lines.size() ? lines.get(line).strip() : "";
+                    return new CallSite(callerSites ? hit.enclosingUnit() : hit.resolved(), sourceLine);
+                })
+                .toList();
+    }
+
+    /**
+     * How each reference in {@code hits} uses what it resolves to. Types are used in type position and struct literal
+     * initializers write; a call uses its receiver as the method's {@code self} parameter says, and a field, static or
+     * constant is classified by the place expression around it, moving when taken by value unless its declared type is
+     * {@code Copy}. A place that is the receiver of a method call, as in {@code self.items.push(x)}, is used the way
+     * the called method uses its receiver; a hit whose method is neither resolved nor a known standard library method
+     * is left out of the result, i.e. unclassified. Each file is parsed once however many of its references are
+     * classified.
+     */
+    public Map<ReferenceHit, UsageKind> usageKindsOf(Collection<ReferenceHit> hits) {
+        var kinds = new HashMap<ReferenceHit, UsageKind>();
+        var placeHitsByFile = new LinkedHashMap<ProjectFile, List<ReferenceHit>>();
+        var receiverUses = new HashMap<CodeUnit, Optional<UsageKind>>();
+        var movesOnUse = new HashMap<CodeUnit, Boolean>();
+        var unclassified = new HashSet<ReferenceHit>();
+        Function<ProjectFile, List<ReferenceHit>> callsIn = memoizedCallsIn();
+        for (ReferenceHit hit : hits) {
+            CodeUnit target = hit.resolved();
+            if (target.isClass()
+                    || hit.kind() == ReferenceKind.TYPE_REFERENCE
+                    || hit.kind() == ReferenceKind.INHERITANCE) {
+                kinds.put(hit, UsageKind.TYPE);
+                continue;
+            }
+            if (hit.kind() == ReferenceKind.FIELD_WRITE) {
+                kinds.put(hit, UsageKind.WRITE);
+                continue;
+            }
+            if (target.isFunction()) {
+                receiverUses.computeIfAbsent(target, this::receiverUseOf);
+            } else if (target.isField()) {
+                movesOnUse.computeIfAbsent(target, this::movesOnUse);
+            }
+            placeHitsByFile.computeIfAbsent(hit.file(), ignored -> new ArrayList<>()).add(hit);
+        }
+        placeHitsByFile.forEach((file, fileHits) -> kinds.putAll(withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    var fileKinds = new HashMap<ReferenceHit, UsageKind>();
+                    for (ReferenceHit hit : fileHits) {
+                        TSNode reference = root == null
+                                ? null
+                                : root.getDescendantForByteRange(hit.range().startByte(), hit.range().endByte());
+                        if (reference == null) {
+                            continue;
+                        }
+                        CodeUnit target = hit.resolved();
+                        TSNode method = target.isFunction() ? null : RustUsageKinds.methodCalledOn(reference);
+                        if (method != null) {
+                            Optional<UsageKind> use = methodReceiverUse(file, method, callsIn, receiverUses)
+                                    .map(kind -> kind == UsageKind.MOVE && !movesOnUse.getOrDefault(target, false)
+                                            ? UsageKind.READ
+                                            : kind);
+                            use.ifPresentOrElse(kind -> fileKinds.put(hit, kind), () -> unclassified.add(hit));
+                        } else if (!target.isFunction()) {
+                            fileKinds.put(
+                                    hit, RustUsageKinds.placeUse(reference, movesOnUse.getOrDefault(target, false)));
+                        } else if (RustUsageKinds.isCallee(reference)) {
+                            fileKinds.put(
+                                    hit, receiverUses.getOrDefault(target, Optional.empty()).orElse(UsageKind.READ));
+                        }
+                    }
+                    return fileKinds;
+                },
+                Map.<ReferenceHit, UsageKind>of())));
+        hits.stream()
+                .filter(hit -> !unclassified.contains(hit))
+                .forEach(hit -> kinds.putIfAbsent(hit, UsageKind.READ));
+        return kinds;
+    }
+
+    /**
+     * How the method call named by {@code method} uses its receiver: as the resolved method's {@code self} parameter
+     * says, or for a method outside the project, as the standard library method of that name does.
+     */
+    private Optional<UsageKind> methodReceiverUse(
+            ProjectFile file,
+            TSNode method,
+            Function<ProjectFile, List<ReferenceHit>> callsIn,
+            Map<CodeUnit, Optional<UsageKind>> receiverUses) {
+        Optional<ReferenceHit> call = callsIn.apply(file).stream()
+                .filter(hit -> hit.range().endByte() == method.getEndByte())
+                .findFirst();
+        if (call.isPresent()) {
+            return receiverUses.computeIfAbsent(call.get().resolved(), this::receiverUseOf);
+        }
+        return withSource(
+                file,
+                source -> RustUsageKinds.standardReceiverUse(source.substringFrom(method)),
+                Optional.<UsageKind>empty());
+    }
+
+    private Optional<UsageKind> receiverUseOf(CodeUnit function) {
+        return withTreeOf(
+                function.source(),
+                tree -> Optional.ofNullable(primaryNodeForCodeUnit(tree, function))
+                        .flatMap(RustUsageKinds::receiverUse),
+                Optional.empty());
+    }
+
+    /**
+     * Whether taking the field, static or constant {@code value} by value moves it: its declared type is not
+     * {@code Copy}, either structurally or through a {@code #[derive(Copy)]} or {@code impl Copy} on a crate type.
+     */
+    private boolean movesOnUse(CodeUnit value) {
+        ProjectFile file = value.source();
+        return withTreeOf(
+                file,
+                tree -> {
+                    TSNode declaration = primaryNodeForCodeUnit(tree, value);
+                    if (declaration == null || !VALUE_DECLARATION_TYPES.contains(declaration.getType())) {
+                        return false;
+                    }
+                    TSNode type = declaration.getChildByFieldName(nodeField(RustNodeField.TYPE));
+                    return withSource(
+                            file,
+                            source -> !RustUsageKinds.isCopyType(type, source, name -> derivesCopy(file, name)),
+                            false);
+                },
+                false);
+    }
+
+    private boolean derivesCopy(ProjectFile contextFile, String typeName) {
+        return resolveRustTypeName(contextFile, typeName)
+                .map(type -> heritageIndex()
+                        .getOrDefault(qualifiedClassKey(type.source(), type.identifier()), Set.of())
+                        .stream()
+                        .anyMatch(parent -> parent.endsWith(":Copy")))
+                .orElse(false);
+    }
+
     public Map<String, Set<String>> heritageIndex() {
         Map<String, Set<String>> cached = cache().heritageIndex();
         if (cached != null) {
@@ -637,6 +1764,11 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                 String childKey = rustTypeKey(file, edge.childName(), binder);
                 String parentKey = rustTypeKey(file, edge.parentName(), binder);
                 computed.computeIfAbsent(childKey, ignored -> new HashSet<>()).add(parentKey);
+                // Members of an impl for an imported type are owned by the type's simple name in the impl's file.
+                String localKey = qualifiedClassKey(file, lastPathSegment(edge.childName()));
+                if (!localKey.equals(childKey)) {
+                    computed.computeIfAbsent(localKey, ignored -> new HashSet<>()).add(parentKey);
+                }
             }
         }
         var immutable = computed.entrySet().stream()
@@ -645,9 +1777,140 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return immutable;
     }
 
+    /**
+     * Resolves a member declared for {@code ownerClassName} in {@code sourceFile}. Trait impls may live in any module
+     * of the crate, so a member the type's own file does not declare is looked up in the files implementing traits
+     * for that type, e.g. {@code impl Drawable for Point} next to the trait.
+     */
     public @Nullable CodeUnit exactMember(
             ProjectFile sourceFile, String ownerClassName, String memberName, boolean instanceReceiver) {
-        return exactMembersByFile(sourceFile).get(new MemberKey(ownerClassName, memberName, instanceReceiver));
+        int variantDot = memberName.lastIndexOf('.');
+        if (variantDot > 0) {
+            // `Named.name` on `Shape` is the field `name` of the variant `Shape::Named`.
+            return exactMember(
+                    sourceFile,
+                    ownerClassName + "." + memberName.substring(0, variantDot),
+                    memberName.substring(variantDot + 1),
+                    instanceReceiver);
+        }
+        var key = new MemberKey(ownerClassName, memberName, instanceReceiver);
+        CodeUnit declared = exactMembersByFile(sourceFile).get(key);
+        if (declared != null) {
+            return declared;
+        }
+        List<ProjectFile> implFiles = traitImplIndex()
+                .implFilesByType()
+                .getOrDefault(qualifiedClassKey(sourceFile, ownerClassName), List.of());
+        for (ProjectFile implFile : implFiles) {
+            if (implFile.equals(sourceFile)) {
+                continue;
+            }
+            CodeUnit implemented = exactMembersByFile(implFile).get(key);
+            if (implemented != null) {
+                return implemented;
+            }
+        }
+        return null;
+    }
+
+    /**
+     * The enum declaring the variant that {@code segments} name in {@code contextFile}, e.g. {@code Shape::Named}, as
+     * the receiver of the variant's fields; empty when the path does not end in a variant of an enum in the crate.
+     */
+    public Optional<ReceiverTargetRef> enumVariantOwner(
+            ProjectFile contextFile, List<String> segments, ImportBinder binder) {
+        if (segments.size() < 2) {
+            return Optional.empty();
+        }
+        String variant = segments.getLast();
+        return resolveRustType(contextFile, segments.subList(0, segments.size() - 1), binder)
+                .filter(type -> exportIndexOf(type.file())
+                        .classMembers()
+                        .contains(new ExportIndex.ClassMember(type.name(), variant, true, CodeUnitType.FIELD)))
+                .map(type -> new ReceiverTargetRef(null, type.name(), true, 1.0, type.file()));
+    }
+
+    /**
+     * The heritage index for matching usages: {@link #heritageIndex()} plus the trait impl units, so a use of a member
+     * of {@code Point as Shape} counts for queries on both {@code Point} and {@code Shape}.
+     */
+    public Map<String, Set<String>> usageHeritageIndex() {
+        return traitImplIndex().usageHeritage();
+    }
+
+    /**
+     * Returns whether {@code segments}, as written in {@code contextFile}, name a trait that some type in the crate
+     * implements. Used to read {@code Trait::method(&value)} as a method call on {@code value}.
+     */
+    public boolean isImplementedTrait(ProjectFile contextFile, List<String> segments, ImportBinder binder) {
+        if (segments.isEmpty()) {
+            return false;
+        }
+        return traitImplIndex().traitKeys().contains(rustTypeKey(contextFile, String.join("::", segments), binder));
+    }
+
+    /**
+     * Resolves a type or trait name as written in {@code contextFile} (e.g. the self type of an impl in another
+     * module) to its declaring class-like code unit.
+     */
+    public Optional<CodeUnit> resolveRustTypeName(ProjectFile contextFile, String rustPath) {
+        return Optional.ofNullable(
+                classesByQualifiedKey().get(rustTypeKey(contextFile, rustPath, importBinderOf(contextFile))));
+    }
+
+    private TraitImplIndex traitImplIndex() {
+        TraitImplIndex cached = rustCache().traitImplIndex();
+        if (cached != null) {
+            return cached;
+        }
+        var implFilesByType = new HashMap<String, Set<ProjectFile>>();
+        var traitKeys = new HashSet<String>();
+        var usageHeritage = new HashMap<String, Set<String>>();
+        heritageIndex().forEach((child, parents) -> usageHeritage.put(child, new HashSet<>(parents)));
+        for (ProjectFile file : getAnalyzedFiles()) {
+            ExportIndex index = exportIndexOf(file);
+            if (index.heritageEdges().isEmpty()) {
+                continue;
+            }
+            ImportBinder binder = importBinderOf(file);
+            for (ExportIndex.HeritageEdge edge : index.heritageEdges()) {
+                String childKey = rustTypeKey(file, edge.childName(), binder);
+                String parentKey = rustTypeKey(file, edge.parentName(), binder);
+                implFilesByType.computeIfAbsent(childKey, ignored -> new HashSet<>()).add(file);
+                traitKeys.add(parentKey);
+                // The members of `impl Shape for Point` are owned by `Point as Shape`: a use of them is a use of both.
+                String implName =
+                        lastPathSegment(edge.childName()) + TRAIT_IMPL_SEPARATOR + lastPathSegment(edge.parentName());
+                for (String implUnitName : implUnitNames(file, implName)) {
+                    usageHeritage
+                            .computeIfAbsent(qualifiedClassKey(file, implUnitName), ignored -> new HashSet<>())
+                            .addAll(List.of(childKey, parentKey));
+                }
+            }
+        }
+        var computed = new TraitImplIndex(
+                implFilesByType.entrySet().stream()
+                        .collect(Collectors.toUnmodifiableMap(Map.Entry::getKey, entry -> entry.getValue().stream()
+                                .sorted()
+                                .toList())),
+                Set.copyOf(traitKeys),
+                usageHeritage.entrySet().stream()
+                        .collect(Collectors.toUnmodifiableMap(
+                                Map.Entry::getKey, entry -> Set.copyOf(entry.getValue()))));
+        rustCache().traitImplIndex(computed);
+        return computed;
+    }
+
+    /** The trait impl units of {@code file} named {@code implName}, with any type arguments of the trait. */
+    private Set<String> implUnitNames(ProjectFile file, String implName) {
+        var names = new HashSet<String>();
+        names.add(implName);
+        getDeclarations(file).stream()
+                .filter(CodeUnit::isClass)
+                .map(CodeUnit::identifier)
+                .filter(identifier -> identifier.startsWith(implName + "<"))
+                .forEach(names::add);
+        return names;
     }
 
     private Map<MemberKey, CodeUnit> exactMembersByFile(ProjectFile sourceFile) {
@@ -661,7 +1924,7 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                         Collectors.toSet()));
         var computed = new HashMap<MemberKey, CodeUnit>();
         for (CodeUnit cu : getDeclarations(sourceFile)) {
-            if (!cu.isFunction() && !cu.isField()) {
+            if (!cu.isFunction() && !cu.isField() && !isTypeAlias(cu)) {
                 continue;
             }
             ownerNameFromShortName(cu).ifPresent(ownerName -> {
@@ -697,6 +1960,171 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return Optional.ofNullable(structFieldTypesByFile(file).get(new FieldKey(ownerClassName, fieldName)));
     }
 
+    /**
+     * Receivers for the value reached through {@code memberName} on {@code receiver}, a receiver seeded in {@code
+     * contextFile}: a method's declared return type, or else a struct field's type, with {@code Option}/{@code Result}
+     * unwrapped.
+     */
+    public Set<ReceiverTargetRef> memberValueReceivers(
+            ProjectFile contextFile, ReceiverTargetRef receiver, String memberName) {
+        ProjectFile ownerFile = receiver.localFile();
+        String moduleSpecifier = receiver.moduleSpecifier();
+        if (ownerFile == null && moduleSpecifier != null) {
+            ownerFile = resolveRustModuleOutcome(contextFile, moduleSpecifier)
+                    .resolved()
+                    .orElse(null);
+        }
+        if (ownerFile == null) {
+            return Set.of();
+        }
+        Set<ReceiverTargetRef> returned =
+                memberReturnReceivers(ownerFile, receiver.exportedName(), memberName, receiver.instanceReceiver());
+        if (!returned.isEmpty() || !receiver.instanceReceiver()) {
+            return returned;
+        }
+        RustTypeRef fieldType =
+                structFieldTypesByFile(ownerFile).get(new FieldKey(receiver.exportedName(), memberName));
+        if (fieldType == null) {
+            return Set.of();
+        }
+        return typeReceivers(ownerFile, RustExportUsageExtractor.unwrapSimpleWrapperType(fieldType.segments()));
+    }
+
+    /**
+     * Receivers for the value returned by a call to the function written as {@code segments} in {@code contextFile}:
+     * an associated function such as {@code Pool::connect}, or a free function named directly, through an import or
+     * by module path.
+     */
+    public Set<ReceiverTargetRef> callReturnReceivers(
+            ProjectFile contextFile, List<String> segments, ImportBinder binder) {
+        if (segments.isEmpty()) {
+            return Set.of();
+        }
+        String functionName = segments.getLast();
+        if (segments.size() >= 2) {
+            List<String> ownerSegments = segments.subList(0, segments.size() - 1);
+            Optional<ResolvedRustType> owner = followTypeAliases(contextFile, ownerSegments, binder)
+                    .or(() -> resolveRustType(contextFile, ownerSegments, binder));
+            if (owner.isPresent()) {
+                Set<ReceiverTargetRef> returned = memberReturnReceivers(
+                        owner.orElseThrow().file(), owner.orElseThrow().name(), functionName, false);
+                if (!returned.isEmpty()) {
+                    return returned;
+                }
+            }
+        }
+        return resolveRustType(contextFile, segments, binder)
+                .map(function -> returnReceivers(function.file(), "", function.name()))
+                .orElseGet(() -> segments.size() == 1 ? returnReceivers(contextFile, "", functionName) : Set.of());
+    }
+
+    private Set<ReceiverTargetRef> memberReturnReceivers(
+            ProjectFile ownerFile, String ownerClassName, String memberName, boolean instanceReceiver) {
+        // Impl blocks may live in another module; private methods are only found in the owner's own file.
+        CodeUnit member = exactMember(ownerFile, ownerClassName, memberName, instanceReceiver);
+        ProjectFile declaringFile = member != null ? member.source() : ownerFile;
+        return returnReceivers(declaringFile, ownerClassName, memberName);
+    }
+
+    private Set<ReceiverTargetRef> returnReceivers(ProjectFile file, String ownerClassName, String functionName) {
+        RustTypeRef returnType =
+                functionReturnTypesByFile(file).get(new AssociatedFunctionKey(ownerClassName, functionName));
+        if (returnType == null) {
+            return Set.of();
+        }
+        return typeReceivers(
+                file,
+                !ownerClassName.isEmpty() && returnType.segments().equals(List.of("Self"))
+                        ? List.of(ownerClassName)
+                        : returnType.segments());
+    }
+
+    private Set<ReceiverTargetRef> typeReceivers(ProjectFile file, List<String> segments) {
+        ImportBinder binder = importBinderOf(file);
+        return followTypeAliases(file, segments, binder)
+                .or(() -> resolveRustType(file, segments, binder))
+                .map(type -> Set.of(
+                        new ReceiverTargetRef(null, type.name(), true, RETURN_TYPE_RECEIVER_CONFIDENCE, type.file())))
+                .orElse(Set.of());
+    }
+
+    @Override
+    public Optional<CodeUnit> resolveTypeAlias(CodeUnit alias) {
+        if (!isTypeAlias(alias)) {
+            return Optional.empty();
+        }
+        ProjectFile file = alias.source();
+        return followTypeAliases(file, List.of(alias.identifier()), importBinderOf(file))
+                .flatMap(type -> definitionsOf(type.file(), type.name()).stream()
+                        .filter(cu -> cu.isClass() && !isTypeAlias(cu))
+                        .findFirst());
+    }
+
+    /**
+     * Follows {@code type} aliases from a receiver type written as {@code segments} in {@code contextFile}, through
+     * imports and chains of aliases, to the receiver for the type they stand for. Empty when the segments do not name
+     * an alias, or when the alias stands for a trait object, loops, or names a type outside the crate.
+     */
+    public Optional<ReceiverTargetRef> receiverTargetThroughTypeAliases(
+            ProjectFile contextFile, List<String> segments, ImportBinder binder) {
+        return followTypeAliases(contextFile, segments, binder)
+                .map(type -> new ReceiverTargetRef(null, type.name(), true, 0.9, type.file()));
+    }
+
+    private Optional<ResolvedRustType> followTypeAliases(
+            ProjectFile contextFile, List<String> segments, ImportBinder binder) {
+        ProjectFile file = contextFile;
+        ImportBinder fileBinder = binder;
+        List<String> current = segments;
+        boolean followed = false;
+        var visited = new HashSet<String>();
+        for (int depth = 0; depth <= MAX_TYPE_ALIAS_DEPTH; depth++) {
+            // Private aliases are not exported, so a bare name is first looked up among the file's own aliases.
+            Optional<ResolvedRustType> resolved =
+                    current.size() == 1 && typeAliasesByFile(file).containsKey(current.getFirst())
+                            ? Optional.of(new ResolvedRustType(file, null, current.getFirst()))
+                            : resolveRustType(file, current, fileBinder);
+            if (resolved.isEmpty()) {
+                return Optional.empty();
+            }
+            ResolvedRustType type = resolved.orElseThrow();
+            List<String> aliased = typeAliasesByFile(type.file()).get(type.name());
+            if (aliased == null) {
+                return followed ? resolved : Optional.empty();
+            }
+            if (aliased.isEmpty() || !visited.add(type.file() + "::" + type.name())) {
+                return Optional.empty();
+            }
+            file = type.file();
+            fileBinder = importBinderOf(file);
+            current = aliased;
+            followed = true;
+        }
+        return Optional.empty();
+    }
+
+    private Map<String, List<String>> typeAliasesByFile(ProjectFile file) {
+        Map<String, List<String>> cached = rustCache().typeAliasesCache().getIfPresent(file);
+        if (cached != null) {
+            return cached;
+        }
+        Map<String, List<String>> computed = withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return Map.<String, List<String>>of();
+                    }
+                    return withSource(
+                            file,
+                            source -> RustExportUsageExtractor.computeTypeAliases(root, source),
+                            Map.<String, List<String>>of());
+                },
+                Map.<String, List<String>>of());
+        rustCache().typeAliasesCache().put(file, computed);
+        return computed;
+    }
+
     private Map<AssociatedFunctionKey, Boolean> selfLikeAssociatedFunctionsByFile(ProjectFile file) {
         Map<AssociatedFunctionKey, Boolean> cached =
                 rustCache().selfLikeAssociatedFunctionsCache().getIfPresent(file);
@@ -720,6 +2148,29 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return computed;
     }
 
+    private Map<AssociatedFunctionKey, RustTypeRef> functionReturnTypesByFile(ProjectFile file) {
+        Map<AssociatedFunctionKey, RustTypeRef> cached =
+                rustCache().functionReturnTypesCache().getIfPresent(file);
+        if (cached != null) {
+            return cached;
+        }
+        Map<AssociatedFunctionKey, RustTypeRef> computed = withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return Map.<AssociatedFunctionKey, RustTypeRef>of();
+                    }
+                    return withSource(
+                            file,
+                            source -> RustExportUsageExtractor.computeFunctionReturnTypes(root, source),
+                            Map.<AssociatedFunctionKey, RustTypeRef>of());
+                },
+                Map.<AssociatedFunctionKey, RustTypeRef>of());
+        rustCache().functionReturnTypesCache().put(file, computed);
+        return computed;
+    }
+
     private Map<FieldKey, RustTypeRef> structFieldTypesByFile(ProjectFile file) {
         Map<FieldKey, RustTypeRef> cached = rustCache().structFieldTypesCache().getIfPresent(file);
         if (cached != null) {
@@ -772,9 +2223,12 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
 
     public ai.brokk.analyzer.usages.ExportUsageGraphLanguageAdapter.ResolutionOutcome resolveRustModuleOutcome(
             ProjectFile importingFile, String moduleSpecifier) {
-        String fqn = resolveRustPathToFqn(moduleSpecifier, packageNameOf(importingFile));
-        return rustModuleFileForFqn(fqn)
-                .or(() -> inlineRustModuleResolution(importingFile, fqn, false).map(InlineModuleResolution::file))
+        return rustPathFqnCandidates(importingFile, moduleSpecifier).stream()
+                .flatMap(fqn -> rustModuleFileForFqn(fqn)
+                        .or(() -> inlineRustModuleResolution(importingFile, fqn, false)
+                                .map(InlineModuleResolution::file))
+                        .stream())
+                .findFirst()
                 .map(ai.brokk.analyzer.usages.ExportUsageGraphLanguageAdapter.ResolutionOutcome::resolved)
                 .orElseGet(() -> ai.brokk.analyzer.usages.ExportUsageGraphLanguageAdapter.ResolutionOutcome.external(
                         moduleSpecifier));
@@ -788,8 +2242,10 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
 
     public Optional<String> inlineRustModuleExportPrefix(
             ProjectFile importingFile, String moduleSpecifier, boolean allowPrivate) {
-        String fqn = resolveRustPathToFqn(moduleSpecifier, packageNameOf(importingFile));
-        return inlineRustModuleResolution(importingFile, fqn, allowPrivate).map(InlineModuleResolution::exportPrefix);
+        return rustPathFqnCandidates(importingFile, moduleSpecifier).stream()
+                .flatMap(fqn -> inlineRustModuleResolution(importingFile, fqn, allowPrivate).stream())
+                .findFirst()
+                .map(InlineModuleResolution::exportPrefix);
     }
 
     public String packageNameOf(ProjectFile file) {
@@ -797,14 +2253,7 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         if (cached != null) {
             return cached;
         }
-        String computed = withTreeOf(
-                file,
-                tree -> {
-                    TSNode root = tree.getRootNode();
-                    if (root == null) return "";
-                    return withSource(file, sc -> determinePackageName(file, root, root, sc), "");
-                },
-                "");
+        String computed = rustModulePathOf(file, new HashSet<>());
         rustCache().packageNamesByFileCache().put(file, computed);
         return computed;
     }
@@ -817,6 +2266,10 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
             for (CodeUnit cu : getDeclarations(file)) {
                 addDefinitionKey(computed, cu.identifier(), cu);
                 addDefinitionKey(computed, cu.shortName(), cu);
+                if (!cu.identifier().contains(TRAIT_IMPL_SEPARATOR)) {
+                    // Trait impl members are also found as members of their type: `Point.area`.
+                    addDefinitionKey(computed, withoutImplTrait(cu.shortName()), cu);
+                }
             }
             index = computed.entrySet().stream()
                     .collect(Collectors.toUnmodifiableMap(Map.Entry::getKey, entry -> Set.copyOf(entry.getValue())));
@@ -898,22 +2351,23 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
 
     @Override
     protected Set<CodeUnit> resolveImports(ProjectFile file, List<String> importStatements) {
-        String currentPackage = packageNameOf(file);
         Set<CodeUnit> resolved = new HashSet<>();
 
         for (var spec : rustUseSpecsOf(file)) {
             if (spec.wildcard()) {
                 String wildcardPath = stripWildcardSegment(spec.path());
                 if (!wildcardPath.isEmpty()) {
-                    String packageFqn = resolveRustPathToFqn(wildcardPath, currentPackage);
-                    // Search for all definitions that belong to this package
-                    resolved.addAll(searchDefinitions("^" + Pattern.quote(packageFqn) + "\\.[^.]+$", false));
+                    for (String packageFqn : rustPathFqnCandidates(file, wildcardPath)) {
+                        // Search for all definitions that belong to this package
+                        resolved.addAll(searchDefinitions("^" + Pattern.quote(packageFqn) + "\\.[^.]+$", false));
+                    }
                 }
                 continue;
             }
 
-            String fqn = resolveRustPathToFqn(spec.path(), currentPackage);
-            resolved.addAll(getDefinitions(fqn));
+            for (String fqn : rustPathFqnCandidates(file, spec.path())) {
+                resolved.addAll(getDefinitions(fqn));
+            }
         }
 
         return Collections.unmodifiableSet(resolved);
@@ -949,19 +2403,51 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         }
     }
 
+    private static String lastPathSegment(String rustPath) {
+        int separator = rustPath.lastIndexOf("::");
+        return separator >= 0 ? rustPath.substring(separator + "::".length()) : rustPath;
+    }
+
     private static String stripWildcardSegment(String rustPath) {
         return rustPath.endsWith("::*") ? rustPath.substring(0, rustPath.length() - 3) : rustPath;
     }
 
+    /**
+     * FQN candidates for a Rust path used in {@code file}: the path as resolved, then, for paths without a
+     * {@code crate}, {@code self} or {@code super} prefix, the same path under the file's crate name, since the crate's
+     * own top-level modules can be named without a prefix.
+     */
+    private List<String> rustPathFqnCandidates(ProjectFile file, String rustPath) {
+        String crateRoot = crateRootOf(file);
+        String fqn = resolveRustPathToFqn(rustPath, packageNameOf(file), crateRoot);
+        String firstSegment = rustPath.trim().split("::", 2)[0];
+        if (crateRoot.isEmpty()
+                || Set.of("crate", "self", "super").contains(firstSegment)
+                || fqn.equals(crateRoot)
+                || fqn.startsWith(crateRoot + ".")) {
+            return List.of(fqn);
+        }
+        return List.of(fqn, crateRoot + "." + fqn);
+    }
+
     public String resolveRustPathToFqn(String rustPath, String currentPackage) {
+        return resolveRustPathToFqn(rustPath, currentPackage, "");
+    }
+
+    /**
+     * Resolves a Rust path written in the module {@code currentPackage} to a dotted FQN, with {@code crate} standing
+     * for {@code crateRoot}: the crate name that prefixes module paths in a Cargo target, or "" outside one.
+     */
+    public String resolveRustPathToFqn(String rustPath, String currentPackage, String crateRoot) {
         String trimmedPath = rustPath.trim();
 
         if ("crate".equals(trimmedPath)) {
-            return "";
+            return crateRoot;
         }
 
         if (trimmedPath.startsWith("crate::")) {
-            return trimmedPath.substring("crate::".length()).replace("::", ".");
+            String path = trimmedPath.substring("crate::".length()).replace("::", ".");
+            return crateRoot.isEmpty() ? path : crateRoot + "." + path;
         }
 
         if (trimmedPath.startsWith("self::")) {
@@ -1005,6 +2491,19 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
     }
 
     private Optional<ProjectFile> rustModuleFileForFqn(String fqn) {
+        Optional<ProjectFile> pathAttributeModule = pathAttributeModules().keySet().stream()
+                .filter(file -> packageNameOf(file).equals(fqn))
+                .findFirst();
+        if (pathAttributeModule.isPresent()) {
+            return pathAttributeModule;
+        }
+        RustCrateLayout layout = crateLayout();
+        if (!layout.isEmpty()) {
+            return layout.moduleFileCandidates(fqn).stream()
+                    .map(this::projectFileAt)
+                    .flatMap(Optional::stream)
+                    .findFirst();
+        }
         String slashPath = fqn.replace('.', '/');
         var candidates = new ArrayList<Path>();
         if (slashPath.isBlank()) {
@@ -1090,6 +2589,59 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return immutable;
     }
 
+    /**
+     * Returns every {@code #[macro_export]} macro in the analyzed crate, mapped to the {@code crate::} path of the file
+     * module that defines it. Exported macros can be invoked by bare name from any module of the crate.
+     */
+    public Map<String, String> exportedMacroModules() {
+        Map<String, String> cached = rustCache().exportedMacroModules();
+        if (cached != null) {
+            return cached;
+        }
+        var computed = new HashMap<String, String>();
+        for (ProjectFile file : getAnalyzedFiles()) {
+            String modulePath = crateRelativeModulePath(file);
+            String moduleSpecifier = modulePath.isEmpty() ? "crate" : "crate::" + modulePath.replace(".", "::");
+            Set<String> names = withTreeOf(
+                    file,
+                    tree -> {
+                        TSNode root = tree.getRootNode();
+                        if (root == null) {
+                            return Set.<String>of();
+                        }
+                        return withSource(
+                                file,
+                                source -> RustExportUsageExtractor.computeExportedMacroNames(root, source),
+                                Set.<String>of());
+                    },
+                    Set.<String>of());
+            names.forEach(name -> computed.putIfAbsent(name, moduleSpecifier));
+        }
+        var immutable = Map.copyOf(computed);
+        rustCache().exportedMacroModules(immutable);
+        return immutable;
+    }
+
+    private String crateRelativeModulePath(ProjectFile file) {
+        String packageName = packageNameOf(file);
+        String crateRoot = crateRootOf(file);
+        if (crateRoot.isEmpty()) {
+            return packageName;
+        }
+        if (packageName.equals(crateRoot)) {
+            return "";
+        }
+        return packageName.startsWith(crateRoot + ".") ? packageName.substring(crateRoot.length() + 1) : packageName;
+    }
+
+    /** Names of the {@code macro_rules!} macros declared at the top level of {@code file}. */
+    public Set<String> macroNamesOf(ProjectFile file) {
+        return getTopLevelDeclarations(file).stream()
+                .filter(CodeUnit::isMacro)
+                .map(CodeUnit::identifier)
+                .collect(Collectors.toUnmodifiableSet());
+    }
+
     private static void collectInlineRustModules(
             TSNode node,
             SourceContent source,
@@ -1139,7 +2691,27 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         if (lastDot <= 0) {
             return Optional.empty();
         }
-        return Optional.of(shortName.substring(0, lastDot));
+        // Members of `impl Shape for Point` resolve as members of `Point`.
+        return Optional.of(withoutImplTrait(shortName.substring(0, lastDot)));
+    }
+
+    private Set<String> heritageParentKeys(CodeUnit cu) {
+        return heritageIndex().getOrDefault(qualifiedClassKey(cu.source(), cu.identifier()), Set.of());
+    }
+
+    private Map<String, CodeUnit> classesByQualifiedKey() {
+        Map<String, CodeUnit> cached = rustCache().classesByQualifiedKey();
+        if (cached != null) {
+            return cached;
+        }
+        var computed = new HashMap<String, CodeUnit>();
+        getAllDeclarations().stream()
+                .filter(CodeUnit::isClass)
+                .sorted(Comparator.comparing(CodeUnit::fqName))
+                .forEach(cu -> computed.putIfAbsent(qualifiedClassKey(cu.source(), cu.identifier()), cu));
+        var immutable = Map.copyOf(computed);
+        rustCache().classesByQualifiedKey(immutable);
+        return immutable;
     }
 
     private static String qualifiedClassKey(ProjectFile file, String className) {
@@ -1419,6 +2991,289 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return RUST_LOG_MACRO_NAMES.contains(lastIdent) || RUST_PRINT_MACRO_NAMES.contains(lastIdent);
     }
 
+    /** A kind of Rust risk site, with the reason it is reported under. */
+    private enum RustRiskKind {
+        UNWRAP("unwrap"),
+        EXPECT("expect"),
+        UNDOCUMENTED_UNSAFE("unsafe-without-safety-comment"),
+        PANIC("panic"),
+        UNFINISHED("todo-or-unimplemented"),
+        CLONE_IN_LOOP("clone-in-loop");
+
+        private final String reason;
+
+        RustRiskKind(String reason) {
+            this.reason = reason;
+        }
+
+        int weight(RustRiskSmellWeights weights) {
+            return switch (this) {
+                case UNWRAP -> weights.unwrapWeight();
+                case EXPECT -> weights.expectWeight();
+                case UNDOCUMENTED_UNSAFE -> weights.undocumentedUnsafeWeight();
+                case PANIC -> weights.panicWeight();
+                case UNFINISHED -> weights.unfinishedWeight();
+                case CLONE_IN_LOOP -> weights.cloneInLoopWeight();
+            };
+        }
+    }
+
+    private record RustRiskSite(RustRiskKind kind, TSNode node) {}
+
+    @Override
+    public List<RustRiskSmell> findRustRiskSmells(ProjectFile file, RustRiskSmellWeights weights) {
+        checkStale("findRustRiskSmells");
+        @Nullable TargetKind targetKind = crateTargetOf(file).map(CrateTarget::kind).orElse(null);
+        if (targetKind == TargetKind.TEST || targetKind == TargetKind.BENCH) {
+            return List.of();
+        }
+        // Whether to panic is for a binary to decide; panic!, todo! and unimplemented! are only flagged in libraries.
+        boolean libraryCode = targetKind == null || targetKind == TargetKind.LIB;
+        return withTreeOf(
+                file,
+                tree -> {
+                    TSNode root = tree.getRootNode();
+                    if (root == null) {
+                        return List.of();
+                    }
+                    return withSource(
+                            file,
+                            source -> detectRustRiskSmells(file, root, source, weights, libraryCode),
+                            List.of());
+                },
+                List.of());
+    }
+
+    private List<RustRiskSmell> detectRustRiskSmells(
+            ProjectFile file,
+            TSNode root,
+            SourceContent sourceContent,
+            RustRiskSmellWeights weights,
+            boolean libraryCode) {
+        Set<TSNode> testItems = rustTestContextItems(root, sourceContent);
+        var candidates = new ArrayList<TSNode>();
+        collectNodesByType(
+                root,
+                Set.of(nodeType(CALL_EXPRESSION), nodeType(MACRO_INVOCATION), nodeType(UNSAFE_BLOCK)),
+                candidates);
+
+        // Sites outside any function, e.g. in static initializers, are reported against the file.
+        var sitesByFunction = new LinkedHashMap<TSNode, List<RustRiskSite>>();
+        for (TSNode candidate : candidates) {
+            Optional<RustRiskKind> kind = rustRiskKind(candidate, sourceContent, libraryCode);
+            if (kind.isEmpty() || isInRustTestContext(candidate, testItems)) {
+                continue;
+            }
+            TSNode function = enclosingRustFunction(candidate);
+            sitesByFunction
+                    .computeIfAbsent(function != null ? function : root, k -> new ArrayList<>())
+                    .add(new RustRiskSite(kind.orElseThrow(), candidate));
+        }
+
+        var findings = new ArrayList<RustRiskSmell>();
+        sitesByFunction.forEach((function, sites) -> {
+            var counts = new EnumMap<RustRiskKind, Integer>(RustRiskKind.class);
+            sites.forEach(site -> counts.merge(site.kind(), 1, Integer::sum));
+            int score = 0;
+            var reasons = new ArrayList<String>();
+            for (var entry : counts.entrySet()) {
+                score += entry.getKey().weight(weights) * entry.getValue();
+                reasons.add(entry.getKey().reason + ":" + entry.getValue());
+            }
+            if (score <= 0) {
+                return;
+            }
+            String enclosing = function.equals(root)
+                    ? file.toString()
+                    : enclosingCodeUnit(
+                                    file,
+                                    function.getStartPoint().getRow(),
+                                    function.getEndPoint().getRow())
+                            .map(CodeUnit::fqName)
+                            .orElse(file.toString());
+            findings.add(new RustRiskSmell(
+                    file,
+                    enclosing,
+                    score,
+                    sites.size(),
+                    List.copyOf(reasons),
+                    compactExcerptForTable(sourceContent.substringFrom(sites.getFirst().node()))));
+        });
+        return findings.stream()
+                .sorted(Comparator.comparingInt(RustRiskSmell::score)
+                        .reversed()
+                        .thenComparing(RustRiskSmell::enclosingFqName))
+                .toList();
+    }
+
+    private static Optional<RustRiskKind> rustRiskKind(TSNode node, SourceContent sourceContent, boolean libraryCode) {
+        String type = node.getType();
+        if (nodeType(UNSAFE_BLOCK).equals(type)) {
+            return hasSafetyComment(node, sourceContent)
+                    ? Optional.empty()
+                    : Optional.of(RustRiskKind.UNDOCUMENTED_UNSAFE);
+        }
+        if (nodeType(MACRO_INVOCATION).equals(type)) {
+            if (!libraryCode) {
+                return Optional.empty();
+            }
+            String name = rustMacroLastIdent(node, sourceContent);
+            if ("panic".equals(name)) {
+                return Optional.of(RustRiskKind.PANIC);
+            }
+            return RUST_UNFINISHED_MACRO_NAMES.contains(name) ? Optional.of(RustRiskKind.UNFINISHED) : Optional.empty();
+        }
+
+        TSNode function = node.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
+        if (function == null || !nodeType(FIELD_EXPRESSION).equals(function.getType())) {
+            return Optional.empty();
+        }
+        String method = "";
+        for (TSNode child : function.getNamedChildren()) {
+            if (nodeType(FIELD_IDENTIFIER).equals(child.getType())) {
+                method = sourceContent.substringFrom(child).strip();
+            }
+        }
+        TSNode arguments = node.getChildByFieldName(nodeField(RustNodeField.ARGUMENTS));
+        int argumentCount = arguments == null ? 0 : arguments.getNamedChildCount();
+        return switch (method) {
+            case "unwrap" -> argumentCount == 0 ? Optional.of(RustRiskKind.UNWRAP) : Optional.empty();
+            case "expect" -> argumentCount == 1 ? Optional.of(RustRiskKind.EXPECT) : Optional.empty();
+            case "clone" ->
+                argumentCount == 0 && isInRustLoopBody(node)
+                        ? Optional.of(RustRiskKind.CLONE_IN_LOOP)
+                        : Optional.empty();
+            default -> Optional.empty();
+        };
+    }
+
+    /**
+     * Returns true if {@code node} runs once per iteration of a loop in its function: anywhere in a {@code loop} or
+     * {@code while}, but only in the body of a {@code for}, whose iterator expression is evaluated once.
+     */
+    private static boolean isInRustLoopBody(TSNode node) {
+        TSNode child = node;
+        TSNode parent = node.getParent();
+        while (parent != null && !nodeType(FUNCTION_ITEM).equals(parent.getType())) {
+            String type = parent.getType();
+            if (nodeType(LOOP_EXPRESSION).equals(type) || nodeType(WHILE_EXPRESSION).equals(type)) {
+                return true;
+            }
+            if (nodeType(FOR_EXPRESSION).equals(type)) {
+                TSNode body = parent.getChildByFieldName(nodeField(RustNodeField.BODY));
+                if (body != null && body.getStartByte() == child.getStartByte()) {
+                    return true;
+                }
+            }
+            child = parent;
+            parent = parent.getParent();
+        }
+        return false;
+    }
+
+    /**
+     * Returns true if a {@code // SAFETY:} comment justifies {@code unsafeBlock}: inside it, directly above it, or
+     * above the statement or item containing it.
+     */
+    private static boolean hasSafetyComment(TSNode unsafeBlock, SourceContent sourceContent) {
+        var comments = new ArrayList<TSNode>();
+        collectNodesByType(unsafeBlock, COMMENT_NODE_TYPES, comments);
+        if (comments.stream().anyMatch(comment -> SAFETY_COMMENT
+                .matcher(sourceContent.substringFrom(comment))
+                .find())) {
+            return true;
+        }
+        TSNode statement = unsafeBlock;
+        TSNode parent = unsafeBlock.getParent();
+        while (parent != null
+                && !nodeType(BLOCK).equals(parent.getType())
+                && !nodeType(DECLARATION_LIST).equals(parent.getType())
+                && !nodeType(SOURCE_FILE).equals(parent.getType())) {
+            statement = parent;
+            parent = parent.getParent();
+        }
+        return hasSafetyCommentAbove(unsafeBlock, sourceContent) || hasSafetyCommentAbove(statement, sourceContent);
+    }
+
+    /**
+     * Looks for a safety comment among the comment and attribute siblings directly before {@code node}. A comment
+     * trailing code on an earlier line belongs to that code and ends the search.
+     */
+    private static boolean hasSafetyCommentAbove(TSNode node, SourceContent sourceContent) {
+        int row = node.getStartPoint().getRow();
+        for (TSNode sibling = node.getPrevSibling(); sibling != null; sibling = sibling.getPrevSibling()) {
+            String type = sibling.getType();
+            if (nodeType(ATTRIBUTE_ITEM).equals(type)) {
+                continue;
+            }
+            if (!COMMENT_NODE_TYPES.contains(type)
+                    || (sibling.getEndPoint().getRow() != row && !startsOwnLine(sibling, sourceContent))) {
+                return false;
+            }
+            if (SAFETY_COMMENT.matcher(sourceContent.substringFrom(sibling)).find()) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    private static boolean startsOwnLine(TSNode node, SourceContent sourceContent) {
+        String text = sourceContent.text();
+        int start = sourceContent.byteOffsetToCharPosition(node.getStartByte());
+        int lineStart = text.lastIndexOf('\n', start - 1) + 1;
+        return text.substring(lineStart, start).isBlank();
+    }
+
+    private static @Nullable TSNode enclosingRustFunction(TSNode node) {
+        TSNode parent = node.getParent();
+        while (parent != null && !nodeType(FUNCTION_ITEM).equals(parent.getType())) {
+            parent = parent.getParent();
+        }
+        return parent;
+    }
+
+    /**
+     * The items marked as test code by a test or bench attribute or {@code #[cfg(test)]}, e.g. test functions and
+     * test modules.
+     */
+    private Set<TSNode> rustTestContextItems(TSNode root, SourceContent sourceContent) {
+        var items = new HashSet<TSNode>();
+        for (TSNode attrItem : testAttributeItems(root, sourceContent)) {
+            // Shape 1: attribute_item is nested under the item.
+            TSNode parent = attrItem.getParent();
+            if (parent != null
+                    && !nodeType(SOURCE_FILE).equals(parent.getType())
+                    && !nodeType(DECLARATION_LIST).equals(parent.getType())
+                    && !nodeType(BLOCK).equals(parent.getType())) {
+                items.add(parent);
+                continue;
+            }
+            // Shape 2: attribute_item is a sibling preceding the item, possibly with further attributes between.
+            TSNode next = attrItem.getNextSibling();
+            while (next != null
+                    && (nodeType(ATTRIBUTE_ITEM).equals(next.getType())
+                            || COMMENT_NODE_TYPES.contains(next.getType()))) {
+                next = next.getNextSibling();
+            }
+            if (next != null) {
+                items.add(next);
+            }
+        }
+        return items;
+    }
+
+    private static boolean isInRustTestContext(TSNode node, Set<TSNode> testItems) {
+        if (testItems.isEmpty()) {
+            return false;
+        }
+        for (@Nullable TSNode current = node; current != null; current = current.getParent()) {
+            if (testItems.contains(current)) {
+                return true;
+            }
+        }
+        return false;
+    }
+
     private static String rustMacroLastIdent(TSNode macroInvocation, SourceContent sourceContent) {
         TSNode path = macroInvocation.getNamedChildCount() > 0 ? macroInvocation.getNamedChild(0) : null;
         if (path == null) {
@@ -1465,6 +3320,120 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return last;
     }
 
+    /**
+     * The test units of Rust files: functions with a test or bench attribute, the items enclosing
+     * {@code proptest! { ... }} blocks, and the items whose doc comments hold doc tests. Files without such units, e.g.
+     * with only crate-level doc tests, fall back to their top-level declarations.
+     */
+    @Override
+    public Set<CodeUnit> testFilesToCodeUnits(Collection<ProjectFile> files) {
+        var units = new HashSet<CodeUnit>();
+        for (ProjectFile file : files) {
+            List<CodeUnit> fileUnits = withTreeOf(
+                    file,
+                    tree -> withSource(file, source -> rustTestUnits(file, tree.getRootNode(), source), List.of()),
+                    List.of());
+            if (fileUnits.isEmpty()) {
+                fileUnits = AnalyzerUtil.getTestDeclarationsWithLogging(this, List.of(file))
+                        .filter(cu -> cu.isClass() || cu.isFunction() || cu.isModule())
+                        .toList();
+            }
+            units.addAll(fileUnits);
+        }
+        return AnalyzerUtil.coalesceNestedUnits(this, units);
+    }
+
+    private List<CodeUnit> rustTestUnits(ProjectFile file, @Nullable TSNode root, SourceContent sourceContent) {
+        if (root == null) {
+            return List.of();
+        }
+        var anchors = new ArrayList<TSNode>(rustFunctionsWithDirectTestAttribute(root, sourceContent, true));
+        anchors.addAll(rustProptestInvocations(root, sourceContent));
+        anchors.addAll(rustDocTestAnchors(root, sourceContent));
+        return anchors.stream()
+                .map(anchor -> enclosingCodeUnit(
+                        file, anchor.getStartPoint().getRow(), anchor.getEndPoint().getRow()))
+                .flatMap(Optional::stream)
+                .distinct()
+                .toList();
+    }
+
+    /**
+     * The {@code cargo test} arguments that run exactly the test functions of {@code files}, including those in
+     * {@code proptest!} blocks. Doc tests cannot be selected by an exact path and are left out.
+     */
+    public CargoTestSelection cargoTestSelection(Collection<ProjectFile> files) {
+        var packages = new TreeSet<String>();
+        var targets = new TreeSet<String>();
+        var testPaths = new TreeSet<String>();
+        for (ProjectFile file : files) {
+            List<String> paths = withTreeOf(
+                    file,
+                    tree -> withSource(file, source -> rustTestPaths(file, tree.getRootNode(), source), List.of()),
+                    List.of());
+            if (paths.isEmpty()) {
+                continue;
+            }
+            testPaths.addAll(paths);
+            crateTargetOf(file).ifPresent(target -> {
+                packages.add(target.packageName());
+                targets.add(CargoTestSelection.targetSelector(target));
+            });
+        }
+        return new CargoTestSelection(List.copyOf(packages), List.copyOf(targets), List.copyOf(testPaths));
+    }
+
+    private List<String> rustTestPaths(ProjectFile file, @Nullable TSNode root, SourceContent sourceContent) {
+        if (root == null) {
+            return List.of();
+        }
+        String fileModule = crateRelativeModulePath(file);
+        var paths = new ArrayList<String>();
+        for (TSNode function : rustFunctionsWithDirectTestAttribute(root, sourceContent, true)) {
+            TSNode name = function.getChildByFieldName(nodeField(RustNodeField.NAME));
+            if (name != null) {
+                paths.add(rustItemPath(fileModule, function, sourceContent.substringFrom(name).strip(), sourceContent));
+            }
+        }
+        for (TSNode proptest : rustProptestInvocations(root, sourceContent)) {
+            Matcher testFunction = PROPTEST_TEST_FUNCTION.matcher(sourceContent.substringFrom(proptest));
+            while (testFunction.find()) {
+                paths.add(rustItemPath(fileModule, proptest, testFunction.group(1), sourceContent));
+            }
+        }
+        return paths;
+    }
+
+    /** The {@code ::} path of the module of {@code file} within its crate, e.g. {@code shapes} for src/shapes.rs. */
+    private String crateRelativeModulePath(ProjectFile file) {
+        String modulePath = rustModulePathOf(file, new HashSet<>());
+        String crateName = crateRootOf(file);
+        if (!crateName.isEmpty() && modulePath.equals(crateName)) {
+            modulePath = "";
+        } else if (!crateName.isEmpty() && modulePath.startsWith(crateName + ".")) {
+            modulePath = modulePath.substring(crateName.length() + 1);
+        }
+        return modulePath.replace(".", "::");
+    }
+
+    /** The path of an item named {@code name} at {@code node}, through the inline modules enclosing it. */
+    private static String rustItemPath(String fileModule, TSNode node, String name, SourceContent sourceContent) {
+        var segments = new ArrayDeque<String>();
+        segments.addFirst(name);
+        for (@Nullable TSNode parent = node.getParent(); parent != null; parent = parent.getParent()) {
+            if (nodeType(MOD_ITEM).equals(parent.getType())) {
+                TSNode moduleName = parent.getChildByFieldName(nodeField(RustNodeField.NAME));
+                if (moduleName != null) {
+                    segments.addFirst(sourceContent.substringFrom(moduleName).strip());
+                }
+            }
+        }
+        if (!fileModule.isEmpty()) {
+            segments.addFirst(fileModule);
+        }
+        return String.join("::", segments);
+    }
+
     @Override
     public List<TestAssertionSmell> findTestAssertionSmells(ProjectFile file, TestAssertionWeights weights) {
         checkStale("findTestAssertionSmells");
@@ -1490,7 +3459,7 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
             ProjectFile file, TSNode root, SourceContent sourceContent, TestAssertionWeights weights) {
         var functions = new ArrayList<TSNode>();
         collectNodesByType(root, Set.of(nodeType(FUNCTION_ITEM)), functions);
-        var testFunctions = rustFunctionsWithDirectTestAttribute(root, sourceContent);
+        var testFunctions = rustFunctionsWithDirectTestAttribute(root, sourceContent, false);
 
         var candidates = new ArrayList<TestSmellCandidate>();
         for (TSNode function : functions) {
@@ -1859,10 +3828,12 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return false;
     }
 
-    private Set<TSNode> rustFunctionsWithDirectTestAttribute(TSNode root, SourceContent sourceContent) {
+    private Set<TSNode> rustFunctionsWithDirectTestAttribute(
+            TSNode root, SourceContent sourceContent, boolean includeBenches) {
         var testFunctions = new HashSet<TSNode>();
         for (TSNode attrItem : testAttributeItems(root, sourceContent)) {
-            if (!isRustTestAttributeItem(attrItem, sourceContent)) {
+            if (!isRustTestAttributeItem(attrItem, sourceContent)
+                    && !(includeBenches && isRustBenchAttributeItem(attrItem, sourceContent))) {
                 continue;
             }
 
@@ -1890,6 +3861,121 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
         return testFunctions;
     }
 
+    /** {@code proptest! { ... }} invocations, whose bodies declare {@code #[test]} functions the parser cannot see. */
+    private static List<TSNode> rustProptestInvocations(TSNode root, SourceContent sourceContent) {
+        var macros = new ArrayList<TSNode>();
+        collectNodesByType(root, Set.of(nodeType(MACRO_INVOCATION)), macros);
+        return macros.stream()
+                .filter(macro -> RUST_PROPTEST_MACRO_NAME.equals(rustMacroName(macro, sourceContent)))
+                .toList();
+    }
+
+    /**
+     * The items whose doc comments hold a doc test. A doc test in an inner doc comment ({@code //!}) belongs to the
+     * enclosing module or function, or, at the top of a file, is anchored on the comment itself.
+     */
+    private static List<TSNode> rustDocTestAnchors(TSNode root, SourceContent sourceContent) {
+        if (!sourceContent.text().contains("```")) {
+            return List.of();
+        }
+        var anchors = new ArrayList<TSNode>();
+        collectRustDocTestAnchors(root, sourceContent, anchors);
+        return anchors;
+    }
+
+    private static void collectRustDocTestAnchors(TSNode node, SourceContent sourceContent, List<TSNode> anchors) {
+        var outerDocs = new StringBuilder();
+        var innerDocs = new StringBuilder();
+        @Nullable TSNode firstInnerDoc = null;
+        for (TSNode child : node.getNamedChildren()) {
+            String type = child.getType();
+            if (COMMENT_NODE_TYPES.contains(type)) {
+                String text = sourceContent.substringFrom(child);
+                if (isRustInnerDocComment(text)) {
+                    innerDocs.append(rustDocCommentText(text)).append('\n');
+                    firstInnerDoc = firstInnerDoc == null ? child : firstInnerDoc;
+                } else if (isRustOuterDocComment(text)) {
+                    outerDocs.append(rustDocCommentText(text)).append('\n');
+                }
+                continue;
+            }
+            // Attributes may sit between an item's doc comments and the item.
+            if (nodeType(ATTRIBUTE_ITEM).equals(type)) {
+                continue;
+            }
+            if (!outerDocs.isEmpty() && containsRustDocTest(outerDocs.toString())) {
+                anchors.add(child);
+            }
+            outerDocs.setLength(0);
+            collectRustDocTestAnchors(child, sourceContent, anchors);
+        }
+        if (firstInnerDoc != null && containsRustDocTest(innerDocs.toString())) {
+            @Nullable TSNode owner = nodeType(SOURCE_FILE).equals(node.getType()) ? null : node.getParent();
+            anchors.add(owner != null ? owner : firstInnerDoc);
+        }
+    }
+
+    private static boolean isRustOuterDocComment(String commentText) {
+        return (commentText.startsWith("///") && !commentText.startsWith("////"))
+                || (commentText.startsWith("/**") && !commentText.startsWith("/***") && !commentText.equals("/**/"));
+    }
+
+    private static boolean isRustInnerDocComment(String commentText) {
+        return commentText.startsWith("//!") || commentText.startsWith("/*!");
+    }
+
+    /** The text of a doc comment without its comment markers, and without the leading {@code *} of block lines. */
+    private static String rustDocCommentText(String commentText) {
+        if (!commentText.startsWith("/*")) {
+            return commentText.substring(3);
+        }
+        String body = commentText.substring(3, Math.max(3, commentText.length() - 2));
+        return body.lines()
+                .map(String::strip)
+                .map(line -> line.startsWith("*") ? line.substring(1) : line)
+                .collect(Collectors.joining("\n"));
+    }
+
+    /** Returns true if doc text holds a fenced code block that rustdoc compiles and runs as a doc test. */
+    private static boolean containsRustDocTest(String docText) {
+        boolean inCodeBlock = false;
+        for (String line : docText.split("\\R")) {
+            String stripped = line.strip();
+            if (!stripped.startsWith("```")) {
+                continue;
+            }
+            if (inCodeBlock) {
+                inCodeBlock = false;
+                continue;
+            }
+            inCodeBlock = true;
+            if (isRustDocTestFence(stripped.substring(3))) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    /**
+     * Returns true if a fence's info string marks a Rust code block: no info, or only rustdoc attributes such as
+     * {@code ignore} or {@code edition2021}. Like rustdoc, any other word (e.g. {@code text}) makes the block a
+     * non-Rust one unless {@code rust} is also given.
+     */
+    private static boolean isRustDocTestFence(String info) {
+        boolean sawOther = false;
+        boolean sawRust = false;
+        for (String word : info.strip().split("[\\s,]+")) {
+            if (word.isEmpty()) {
+                continue;
+            }
+            sawRust |= "rust".equals(word);
+            sawOther |= !RUSTDOC_CODE_BLOCK_ATTRIBUTES.contains(word)
+                    && !word.startsWith("edition")
+                    && !word.startsWith("ignore-");
+        }
+        return !sawOther || sawRust;
+    }
+
     private static boolean isRustNoneExpr(TSNode expr, SourceContent sourceContent) {
         return "None".equals(sourceContent.substringFrom(expr).strip());
     }
@@ -1980,24 +4066,44 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
                 List.of());
     }
 
+    /** Returns true for the attributes of test harness functions, e.g. {@code #[test]} or {@code #[tokio::test]}. */
     private static boolean isRustTestAttributeItem(TSNode attrItemNode, SourceContent sourceContent) {
-        var ids = rustAttributeIdentifiers(attrItemNode, sourceContent);
-        // `#[test]` parses to identifier list containing `test` (typically as the first identifier).
-        return !ids.isEmpty() && "test".equals(ids.getFirst());
+        return rustAttributePath(attrItemNode, sourceContent)
+                .filter(RUST_TEST_ATTRIBUTE_PATHS::contains)
+                .isPresent();
+    }
+
+    private static boolean isRustBenchAttributeItem(TSNode attrItemNode, SourceContent sourceContent) {
+        return rustAttributePath(attrItemNode, sourceContent)
+                .filter(RUST_BENCH_ATTRIBUTE_PATH::equals)
+                .isPresent();
     }
 
     private static boolean isRustTestContextAttributeItem(TSNode attrItemNode, SourceContent sourceContent) {
-        var ids = rustAttributeIdentifiers(attrItemNode, sourceContent);
-        if (ids.isEmpty()) {
+        var path = rustAttributePath(attrItemNode, sourceContent);
+        if (path.isEmpty()) {
             return false;
         }
         // test context is either:
-        //  - `#[test]` (first identifier is test), or
-        //  - `#[cfg(test)]` (first identifier cfg and one nested identifier test).
-        if ("test".equals(ids.getFirst())) {
+        //  - a test or bench function attribute (`#[test]`, `#[tokio::test]`, `#[rstest]`, `#[bench]`, ...), or
+        //  - `#[cfg(test)]` (cfg with a nested identifier test).
+        if (RUST_TEST_ATTRIBUTE_PATHS.contains(path.get()) || RUST_BENCH_ATTRIBUTE_PATH.equals(path.get())) {
             return true;
         }
-        return "cfg".equals(ids.getFirst()) && ids.stream().anyMatch("test"::equals);
+        return "cfg".equals(path.get())
+                && rustAttributeIdentifiers(attrItemNode, sourceContent).stream()
+                        .anyMatch("test"::equals);
+    }
+
+    /** The path of an attribute item without whitespace, e.g. {@code tokio::test} for {@code #[tokio::test]}. */
+    private static Optional<String> rustAttributePath(TSNode attrItemNode, SourceContent sourceContent) {
+        for (TSNode attribute : attrItemNode.getNamedChildren()) {
+            if (nodeType(ATTRIBUTE).equals(attribute.getType()) && attribute.getNamedChildCount() > 0) {
+                String path = sourceContent.substringFrom(attribute.getNamedChild(0));
+                return Optional.of(WHITESPACE_RUN.matcher(path).replaceAll(""));
+            }
+        }
+        return Optional.empty();
     }
 
     private static List<String> rustAttributeIdentifiers(TSNode attrItemNode, SourceContent sourceContent) {
@@ -2099,6 +4205,8 @@ public final class RustAnalyzer extends TreeSitterAnalyzer implements ImportAnal
     protected boolean containsTestMarkers(TSTree tree, SourceContent sourceContent) {
         TSNode root = tree.getRootNode();
         if (root == null) return false;
-        return !testAttributeItems(root, sourceContent).isEmpty();
+        return !testAttributeItems(root, sourceContent).isEmpty()
+                || !rustProptestInvocations(root, sourceContent).isEmpty()
+                || !rustDocTestAnchors(root, sourceContent).isEmpty();
     }
 }
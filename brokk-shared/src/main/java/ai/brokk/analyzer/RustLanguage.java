@@ -1,5 +1,8 @@
 package ai.brokk.analyzer;
 
+import ai.brokk.analyzer.rust.CargoDependencyGraph;
+import ai.brokk.analyzer.rust.CargoOfflineSources;
+import ai.brokk.analyzer.rust.RustCrateLayout;
 import ai.brokk.project.ICoreProject;
 import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
 import com.fasterxml.jackson.databind.DeserializationFeature;
@@ -10,19 +13,26 @@ import java.io.InputStreamReader;
 import java.nio.charset.StandardCharsets;
 import java.nio.file.Files;
 import java.nio.file.Path;
+import java.nio.file.attribute.FileTime;
 import java.util.ArrayList;
 import java.util.Comparator;
 import java.util.LinkedHashMap;
 import java.util.LinkedHashSet;
 import java.util.List;
 import java.util.Locale;
+import java.util.Map;
 import java.util.Objects;
 import java.util.Set;
 import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.ConcurrentHashMap;
 import org.jetbrains.annotations.Nullable;
 
 public class RustLanguage implements Language {
     private final Set<String> extensions = Set.of("rs");
+    private final Map<Path, LockedDependencyGraph> dependencyGraphsByRoot = new ConcurrentHashMap<>();
+
+    /** A dependency graph with the modification times of the {@code Cargo.lock} files it was resolved against. */
+    private record LockedDependencyGraph(CargoDependencyGraph graph, Map<Path, FileTime> lockfiles) {}
 
     RustLanguage() {}
 
@@ -31,6 +41,12 @@ public class RustLanguage implements Language {
         return extensions;
     }
 
+    /** Cargo targets define every file's module path, so manifests and lockfiles feed the analyzer too. */
+    @Override
+    public boolean affectsAnalysis(ProjectFile file) {
+        return Language.super.affectsAnalysis(file) || RustCrateLayout.isCargoManifest(file);
+    }
+
     @Override
     public String name() {
         return "Rust";
@@ -77,6 +93,11 @@ public class RustLanguage implements Language {
                     "<\\s*$ident\\s*>", // as generic type argument
                     "\\buse\\s+[^{\\n]*::$ident\\b" // import statements
                     );
+        } else if (type == CodeUnitType.MACRO) {
+            return Set.of(
+                    "\\b$ident!\\s*[\\(\\[\\{]", // macro invocations
+                    "\\buse\\s+[^{\\n]*::$ident\\b" // macro imports
+                    );
         }
         return Language.super.getSearchPatterns(type);
     }
@@ -173,9 +194,87 @@ public class RustLanguage implements Language {
         return DependencyKind.UNKNOWN;
     }
 
+    /**
+     * The crate targets of the project's own Cargo packages, read from its {@code Cargo.toml} files without running
+     * cargo. Empty for projects without a manifest.
+     */
+    public RustCrateLayout getCrateLayout(ICoreProject project) {
+        return RustCrateLayout.fromManifests(findCargoManifests(project));
+    }
+
+    /**
+     * The resolved dependency graph of the project's Cargo packages, from {@code cargo metadata --offline} when cargo
+     * is available and from the {@code Cargo.lock} files otherwise. The graph is kept until a {@code Cargo.lock}
+     * changes, so repeated lookups neither run cargo again nor reach the network.
+     */
+    public CargoDependencyGraph getDependencyGraph(ICoreProject project) {
+        Map<Path, FileTime> lockfiles = lockfileTimes(cargoDirectories(project.getRoot(), findCargoManifests(project)));
+        var cached = dependencyGraphsByRoot.get(project.getRoot());
+        if (cached != null && cached.lockfiles().equals(lockfiles)) {
+            return cached.graph();
+        }
+        var meta = getMergedMetadata(project, true);
+        var graph =
+                CargoDependencyGraph.fromCargoMetadata(meta.packages.isEmpty() ? getOfflineMetadata(project) : meta);
+        dependencyGraphsByRoot.put(project.getRoot(), new LockedDependencyGraph(graph, lockfiles));
+        return graph;
+    }
+
     // ---- helpers (moved/adapted from ImportRustPanel) ----
 
     private CargoMetadata getMergedMetadata(ICoreProject project) {
+        var meta = getMergedMetadata(project, false);
+        return meta.packages.isEmpty() ? getOfflineMetadata(project) : meta;
+    }
+
+    /**
+     * Dependency metadata read from the project's {@code Cargo.lock} files and manifests, with crate sources located in
+     * {@code vendor/} and the local Cargo caches, for when {@code cargo metadata} cannot run.
+     */
+    private CargoMetadata getOfflineMetadata(ICoreProject project) {
+        List<Path> manifests = findCargoManifests(project);
+        var directories = cargoDirectories(project.getRoot(), manifests);
+        var lockfiles = directories.stream()
+                .map(dir -> dir.resolve("Cargo.lock"))
+                .filter(Files::isRegularFile)
+                .toList();
+        if (lockfiles.isEmpty()) {
+            return new CargoMetadata();
+        }
+        var vendorDirs = directories.stream()
+                .map(dir -> dir.resolve("vendor"))
+                .filter(Files::isDirectory)
+                .toList();
+        var sources = new CargoOfflineSources(CargoOfflineSources.defaultCargoHome(), vendorDirs);
+        return sources.metadata(manifests, lockfiles);
+    }
+
+    /** The project root and the directories of its manifests, where {@code Cargo.lock} and {@code vendor/} live. */
+    private static Set<Path> cargoDirectories(Path root, List<Path> manifests) {
+        var directories = new LinkedHashSet<Path>();
+        directories.add(root);
+        for (var manifest : manifests) {
+            var parent = manifest.getParent();
+            if (parent != null) directories.add(parent);
+        }
+        return directories;
+    }
+
+    private static Map<Path, FileTime> lockfileTimes(Set<Path> directories) {
+        var times = new LinkedHashMap<Path, FileTime>();
+        for (var directory : directories) {
+            var lockfile = directory.resolve("Cargo.lock");
+            if (!Files.isRegularFile(lockfile)) continue;
+            try {
+                times.put(lockfile, Files.getLastModifiedTime(lockfile));
+            } catch (IOException e) {
+                logger.debug("Could not read the modification time of {}: {}", lockfile, e.toString());
+            }
+        }
+        return times;
+    }
+
+    private CargoMetadata getMergedMetadata(ICoreProject project, boolean offline) {
         var rootManifest = project.getRoot().resolve("Cargo.toml");
         List<Path> manifests = findCargoManifests(project);
 
@@ -183,7 +282,7 @@ public class RustLanguage implements Language {
         Set<Path> rootCoveredManifests = new LinkedHashSet<>();
         if (Files.isRegularFile(rootManifest)) {
             try {
-                rootMeta = runCargoMetadata(rootManifest);
+                rootMeta = runCargoMetadata(rootManifest, offline);
                 rootCoveredManifests.add(rootManifest.normalize());
                 for (var pkg : rootMeta.packages) {
                     if (pkg.manifest_path == null || pkg.manifest_path.isEmpty()) continue;
@@ -226,7 +325,7 @@ public class RustLanguage implements Language {
 
             CargoMetadata meta;
             try {
-                meta = runCargoMetadata(manifest);
+                meta = runCargoMetadata(manifest, offline);
             } catch (Exception e) {
                 logger.warn("Failed to run cargo metadata for " + manifest, e);
                 continue;
@@ -242,6 +341,16 @@ public class RustLanguage implements Language {
                 }
             }
             memberIds.addAll(meta.workspace_members);
+            var resolve = meta.resolve;
+            if (resolve != null) {
+                var mergedResolve = merged.resolve != null ? merged.resolve : new CargoResolve();
+                var nodes = new ArrayList<>(mergedResolve.nodes);
+                var nodeIds = new LinkedHashSet<String>();
+                nodes.forEach(node -> nodeIds.add(node.id));
+                resolve.nodes.stream().filter(node -> nodeIds.add(node.id)).forEach(nodes::add);
+                mergedResolve.nodes = nodes;
+                merged.resolve = mergedResolve;
+            }
         }
 
         merged.workspace_members.addAll(memberIds);
@@ -275,12 +384,17 @@ public class RustLanguage implements Language {
         return true;
     }
 
-    private CargoMetadata runCargoMetadata(Path manifestPath) throws IOException, InterruptedException {
+    private CargoMetadata runCargoMetadata(Path manifestPath, boolean offline)
+            throws IOException, InterruptedException {
         Path workingDir = manifestPath.getParent();
         if (workingDir == null) workingDir = manifestPath;
 
-        var pb = new ProcessBuilder(
-                "cargo", "metadata", "--format-version", "1", "--manifest-path", manifestPath.toString());
+        var command = new ArrayList<>(
+                List.of("cargo", "metadata", "--format-version", "1", "--manifest-path", manifestPath.toString()));
+        if (offline) {
+            command.add("--offline");
+        }
+        var pb = new ProcessBuilder(command);
         pb.directory(workingDir.toFile());
         var process = pb.start();
 
@@ -376,6 +490,7 @@ public class RustLanguage implements Language {
     public static class CargoMetadata {
         public List<CargoPackage> packages = List.of();
         public List<String> workspace_members = List.of();
+        public @Nullable CargoResolve resolve;
     }
 
     @JsonIgnoreProperties(ignoreUnknown = true)
@@ -392,5 +507,36 @@ public class RustLanguage implements Language {
     public static class CargoDependency {
         public String name = "";
         public @Nullable String kind;
+        public @Nullable String rename;
+        public @Nullable String target;
+        public boolean optional;
+        public boolean uses_default_features = true;
+        public List<String> features = List.of();
+    }
+
+    /** The resolved dependency graph of {@code cargo metadata}, absent with {@code --no-deps}. */
+    @JsonIgnoreProperties(ignoreUnknown = true)
+    public static class CargoResolve {
+        public List<CargoNode> nodes = List.of();
+    }
+
+    @JsonIgnoreProperties(ignoreUnknown = true)
+    public static class CargoNode {
+        public String id = "";
+        public List<CargoNodeDep> deps = List.of();
+        public List<String> features = List.of();
+    }
+
+    @JsonIgnoreProperties(ignoreUnknown = true)
+    public static class CargoNodeDep {
+        public String name = "";
+        public String pkg = "";
+        public List<CargoDepKind> dep_kinds = List.of();
+    }
+
+    @JsonIgnoreProperties(ignoreUnknown = true)
+    public static class CargoDepKind {
+        public @Nullable String kind;
+        public @Nullable String target;
     }
 }
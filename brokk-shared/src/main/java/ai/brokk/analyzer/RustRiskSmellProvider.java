@@ -0,0 +1,48 @@
+package ai.brokk.analyzer;
+
+import java.util.List;
+
+/**
+ * Capability for analyzers that flag Rust-specific risk sites: {@code unwrap()}/{@code expect()} calls, {@code unsafe}
+ * without a {@code SAFETY:} comment, panics in library code and clones in loop bodies.
+ */
+public interface RustRiskSmellProvider extends CapabilityProvider {
+
+    record RustRiskSmellWeights(
+            int unwrapWeight,
+            int expectWeight,
+            int undocumentedUnsafeWeight,
+            int panicWeight,
+            int unfinishedWeight,
+            int cloneInLoopWeight) {
+
+        public static RustRiskSmellWeights defaults() {
+            return new RustRiskSmellWeights(
+                    2, // unwrap() panics without saying which assumption failed
+                    1, // expect() still panics, but at least documents the assumption
+                    5, // unsafe without a SAFETY comment leaves its invariants unstated
+                    3, // panic! in library code takes the decision away from callers
+                    4, // todo!/unimplemented! are unfinished paths that panic at runtime
+                    2 // clone() in a loop body is a per-iteration allocation hot spot
+                    );
+        }
+    }
+
+    /**
+     * Rust-specific risk sites of one function outside test code, scored together.
+     *
+     * @param siteCount the number of flagged sites in the function
+     * @param reasons the flagged site kinds with their counts, e.g. {@code unwrap:3}
+     * @param excerpt the first flagged site
+     */
+    record RustRiskSmell(
+            ProjectFile file,
+            String enclosingFqName,
+            int score,
+            int siteCount,
+            List<String> reasons,
+            String excerpt) {}
+
+    /** Returns the functions of a Rust file whose non-test code is risky, for quality triage. */
+    List<RustRiskSmell> findRustRiskSmells(ProjectFile file, RustRiskSmellWeights weights);
+}
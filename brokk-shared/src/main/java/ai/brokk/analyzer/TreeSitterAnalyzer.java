@@ -124,14 +124,14 @@ public abstract class TreeSitterAnalyzer implements IAnalyzer, TypeAliasProvider
         return computeCognitiveComplexities(cu.source(), scorer).getOrDefault(cu, 0);
     }
 
-    protected Map<CodeUnit, Integer> computeCognitiveComplexities(
-            ProjectFile file, BiFunction<TSNode, SourceContent, Integer> scorer) {
-        Map<CodeUnit, Integer> result = withTreeOf(
+    protected <T> Map<CodeUnit, T> computeCognitiveComplexities(
+            ProjectFile file, BiFunction<TSNode, SourceContent, T> scorer) {
+        Map<CodeUnit, T> result = withTreeOf(
                 file,
                 tree -> withSource(
                         file,
                         content -> {
-                            var complexities = new LinkedHashMap<CodeUnit, Integer>();
+                            var complexities = new LinkedHashMap<CodeUnit, T>();
                             for (CodeUnit cu : functionCodeUnitsInFile(file)) {
                                 TSNode cuNode = primaryNodeForCodeUnit(tree, cu);
                                 if (cuNode != null) {
@@ -1888,14 +1888,15 @@ public abstract class TreeSitterAnalyzer implements IAnalyzer, TypeAliasProvider
         if (codeUnit.isFunction()) {
             return getSourcesForFunction(codeUnit, includeComments);
         }
-        if (codeUnit.isClass()) {
+        if (codeUnit.isClass() || codeUnit.isMacro()) {
             return getSourceForClass(codeUnit, includeComments).map(Set::of).orElse(Set.of());
         }
         return Set.of();
     }
 
     private Optional<String> getSourceForClass(CodeUnit cu, boolean includeComments) {
-        if (!cu.isClass()) {
+        // Macros, like classes, have a single definition range
+        if (!cu.isClass() && !cu.isMacro()) {
             return Optional.empty();
         }
 
@@ -2877,7 +2878,7 @@ public abstract class TreeSitterAnalyzer implements IAnalyzer, TypeAliasProvider
                             }
 
                             var rangeNode = adjustSourceRangeNode(node, primaryCaptureName);
-                            var finalRange = (cu.isClass() || cu.isFunction())
+                            var finalRange = (cu.isClass() || cu.isFunction() || cu.isMacro())
                                     ? expandRangeWithComments(rangeNode, sourceContent)
                                     : new Range(
                                             rangeNode.getStartByte(),
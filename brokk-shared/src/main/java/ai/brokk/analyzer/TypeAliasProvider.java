@@ -1,7 +1,17 @@
 package ai.brokk.analyzer;
 
+import java.util.Optional;
+
 /** Capability for analyzers that can identify whether a CodeUnit represents a type alias. */
 public interface TypeAliasProvider extends CapabilityProvider {
     /** Returns true if the given CodeUnit represents a type alias in the underlying language. */
     boolean isTypeAlias(CodeUnit cu);
+
+    /**
+     * Returns the declared type that {@code alias} stands for, following chains of aliases. Empty if {@code alias} is
+     * not a type alias or its target is not a type declared in the project.
+     */
+    default Optional<CodeUnit> resolveTypeAlias(CodeUnit alias) {
+        return Optional.empty();
+    }
 }
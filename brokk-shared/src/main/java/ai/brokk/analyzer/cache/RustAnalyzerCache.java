@@ -1,7 +1,15 @@
 package ai.brokk.analyzer.cache;
 
+import static java.util.Objects.requireNonNull;
+
 import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.rust.CfgPredicate;
+import ai.brokk.analyzer.rust.RustCfgConfiguration;
+import ai.brokk.analyzer.rust.RustCfgIndex;
+import ai.brokk.analyzer.rust.RustCrateLayout;
+import ai.brokk.analyzer.rust.RustCrateLayout.ModuleDeclaration;
+import ai.brokk.analyzer.rust.RustCrateLayout.ModuleFileOwner;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.AssociatedFunctionKey;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.FieldKey;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.InlineModuleResolution;
@@ -9,17 +17,22 @@ import ai.brokk.analyzer.rust.RustExportUsageExtractor.MemberKey;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.RustTypeRef;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.RustUsageCandidateIndex;
 import ai.brokk.analyzer.rust.RustExportUsageExtractor.RustUsageFacts;
+import ai.brokk.analyzer.rust.RustExportUsageExtractor.TraitImplIndex;
 import com.github.benmanes.caffeine.cache.Cache;
 import com.github.benmanes.caffeine.cache.Caffeine;
+import java.util.List;
 import java.util.Map;
+import java.util.Optional;
 import java.util.Set;
+import java.util.concurrent.ConcurrentHashMap;
 import org.jetbrains.annotations.Nullable;
 
 /**
  * Rust-specific cache extending the base analyzer cache.
  *
  * <p>Persists file package names and snapshot-level inline module resolution data to avoid repeated all-file
- * Tree-sitter scans during Rust usage analysis.
+ * Tree-sitter scans during Rust usage analysis, along with the Cargo crate layout and {@code #[path]} module files
+ * that module paths are derived from, and the {@code #[cfg(...)]} conditions of files and items.
  */
 public final class RustAnalyzerCache extends AnalyzerCache {
     private final Cache<ProjectFile, String> packageNamesByFileCache;
@@ -27,9 +40,24 @@ public final class RustAnalyzerCache extends AnalyzerCache {
     private final Cache<ProjectFile, Map<MemberKey, CodeUnit>> exactMembersByFileCache;
     private final Cache<ProjectFile, Map<AssociatedFunctionKey, Boolean>> selfLikeAssociatedFunctionsCache;
     private final Cache<ProjectFile, Map<FieldKey, RustTypeRef>> structFieldTypesCache;
+    private final Cache<ProjectFile, Map<String, List<String>>> typeAliasesCache;
+    private final Cache<ProjectFile, Map<AssociatedFunctionKey, RustTypeRef>> functionReturnTypesCache;
     private final Cache<ProjectFile, RustUsageFacts> usageFactsByFileCache;
+    private final Cache<ProjectFile, List<ModuleDeclaration>> moduleDeclarationsCache;
+    private final Cache<ProjectFile, RustCfgIndex> cfgIndexCache;
+    private @Nullable RustCrateLayout crateLayout;
+    private @Nullable Map<ProjectFile, ModuleFileOwner> pathAttributeModules;
+    private final Map<ProjectFile, List<ModuleDeclaration>> unverifiedModuleDeclarations = new ConcurrentHashMap<>();
     private @Nullable Map<String, InlineModuleResolution> inlineModuleIndex;
+    private @Nullable Map<String, String> exportedMacroModules;
+    private @Nullable Map<String, CodeUnit> classesByQualifiedKey;
+    private @Nullable Map<String, Set<CodeUnit>> traitImplMembersByTypeMemberName;
+    private @Nullable TraitImplIndex traitImplIndex;
+    private @Nullable Map<String, Set<String>> externalDerivedTraits;
     private @Nullable RustUsageCandidateIndex usageCandidateIndex;
+    private Map<ProjectFile, Optional<CfgPredicate>> moduleFileConditions = new ConcurrentHashMap<>();
+    private @Nullable RustCfgConfiguration cfgConfiguration;
+    private @Nullable String cfgSettingsJson;
 
     public RustAnalyzerCache() {
         super();
@@ -40,7 +68,11 @@ public final class RustAnalyzerCache extends AnalyzerCache {
         this.selfLikeAssociatedFunctionsCache =
                 Caffeine.newBuilder().maximumSize(10_000).build();
         this.structFieldTypesCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.typeAliasesCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.functionReturnTypesCache = Caffeine.newBuilder().maximumSize(10_000).build();
         this.usageFactsByFileCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.moduleDeclarationsCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.cfgIndexCache = Caffeine.newBuilder().maximumSize(10_000).build();
     }
 
     public RustAnalyzerCache(RustAnalyzerCache previous, Set<ProjectFile> changedFiles) {
@@ -52,7 +84,11 @@ public final class RustAnalyzerCache extends AnalyzerCache {
         this.selfLikeAssociatedFunctionsCache =
                 Caffeine.newBuilder().maximumSize(10_000).build();
         this.structFieldTypesCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.typeAliasesCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.functionReturnTypesCache = Caffeine.newBuilder().maximumSize(10_000).build();
         this.usageFactsByFileCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.moduleDeclarationsCache = Caffeine.newBuilder().maximumSize(10_000).build();
+        this.cfgIndexCache = Caffeine.newBuilder().maximumSize(10_000).build();
 
         previous.packageNamesByFileCache.asMap().forEach((file, packageName) -> {
             if (!changedFiles.contains(file)) {
@@ -79,14 +115,55 @@ public final class RustAnalyzerCache extends AnalyzerCache {
                 this.structFieldTypesCache.put(file, Map.copyOf(index));
             }
         });
+        previous.typeAliasesCache.asMap().forEach((file, aliases) -> {
+            if (!changedFiles.contains(file)) {
+                this.typeAliasesCache.put(file, aliases);
+            }
+        });
+        previous.functionReturnTypesCache.asMap().forEach((file, returnTypes) -> {
+            if (!changedFiles.contains(file)) {
+                this.functionReturnTypesCache.put(file, returnTypes);
+            }
+        });
         previous.usageFactsByFileCache.asMap().forEach((file, facts) -> {
             if (!changedFiles.contains(file)) {
                 this.usageFactsByFileCache.put(file, facts);
             }
         });
+        previous.moduleDeclarationsCache.asMap().forEach((file, declarations) -> {
+            if (!changedFiles.contains(file)) {
+                this.moduleDeclarationsCache.put(file, declarations);
+            }
+        });
+        previous.cfgIndexCache.asMap().forEach((file, index) -> {
+            if (!changedFiles.contains(file)) {
+                this.cfgIndexCache.put(file, index);
+            }
+        });
         if (changedFiles.isEmpty()) {
             this.inlineModuleIndex = previous.inlineModuleIndex;
+            this.exportedMacroModules = previous.exportedMacroModules;
+            this.classesByQualifiedKey = previous.classesByQualifiedKey;
+            this.traitImplMembersByTypeMemberName = previous.traitImplMembersByTypeMemberName;
+            this.traitImplIndex = previous.traitImplIndex;
+            this.externalDerivedTraits = previous.externalDerivedTraits;
             this.usageCandidateIndex = previous.usageCandidateIndex;
+            this.moduleFileConditions = previous.moduleFileConditions;
+            this.cfgConfiguration = previous.cfgConfiguration;
+            this.cfgSettingsJson = previous.cfgSettingsJson;
+        }
+        if (changedFiles.stream().noneMatch(RustCrateLayout::isCargoManifest)) {
+            // Only a manifest or lockfile edit can move crate targets, so the parsed manifests stay valid.
+            this.crateLayout = previous.crateLayout;
+            // #[path] modules stay put unless a changed file now declares different modules; a file the previous
+            // snapshot never read may be the target of an existing declaration, so it forces a rebuild.
+            var previousDeclarations = previous.moduleDeclarationsCache.asMap();
+            if (previous.pathAttributeModules != null && previousDeclarations.keySet().containsAll(changedFiles)) {
+                this.pathAttributeModules = previous.pathAttributeModules;
+                for (ProjectFile file : changedFiles) {
+                    this.unverifiedModuleDeclarations.put(file, requireNonNull(previousDeclarations.get(file)));
+                }
+            }
         }
     }
 
@@ -110,10 +187,50 @@ public final class RustAnalyzerCache extends AnalyzerCache {
         return structFieldTypesCache;
     }
 
+    public Cache<ProjectFile, Map<String, List<String>>> typeAliasesCache() {
+        return typeAliasesCache;
+    }
+
+    public Cache<ProjectFile, Map<AssociatedFunctionKey, RustTypeRef>> functionReturnTypesCache() {
+        return functionReturnTypesCache;
+    }
+
     public Cache<ProjectFile, RustUsageFacts> usageFactsByFileCache() {
         return usageFactsByFileCache;
     }
 
+    public Cache<ProjectFile, List<ModuleDeclaration>> moduleDeclarationsCache() {
+        return moduleDeclarationsCache;
+    }
+
+    public Cache<ProjectFile, RustCfgIndex> cfgIndexCache() {
+        return cfgIndexCache;
+    }
+
+    public @Nullable RustCrateLayout crateLayout() {
+        return crateLayout;
+    }
+
+    public void crateLayout(RustCrateLayout crateLayout) {
+        this.crateLayout = crateLayout;
+    }
+
+    public @Nullable Map<ProjectFile, ModuleFileOwner> pathAttributeModules() {
+        return pathAttributeModules;
+    }
+
+    public void pathAttributeModules(Map<ProjectFile, ModuleFileOwner> pathAttributeModules) {
+        this.pathAttributeModules = pathAttributeModules;
+    }
+
+    /**
+     * The module declarations, as of the previous snapshot, of the changed files whose {@link #pathAttributeModules()}
+     * were carried over; the carried map only holds while each file still declares the same modules.
+     */
+    public Map<ProjectFile, List<ModuleDeclaration>> unverifiedModuleDeclarations() {
+        return unverifiedModuleDeclarations;
+    }
+
     public @Nullable Map<String, InlineModuleResolution> inlineModuleIndex() {
         return inlineModuleIndex;
     }
@@ -122,6 +239,46 @@ public final class RustAnalyzerCache extends AnalyzerCache {
         this.inlineModuleIndex = inlineModuleIndex;
     }
 
+    public @Nullable Map<String, String> exportedMacroModules() {
+        return exportedMacroModules;
+    }
+
+    public void exportedMacroModules(Map<String, String> exportedMacroModules) {
+        this.exportedMacroModules = exportedMacroModules;
+    }
+
+    public @Nullable Map<String, CodeUnit> classesByQualifiedKey() {
+        return classesByQualifiedKey;
+    }
+
+    public void classesByQualifiedKey(Map<String, CodeUnit> classesByQualifiedKey) {
+        this.classesByQualifiedKey = classesByQualifiedKey;
+    }
+
+    public @Nullable Map<String, Set<CodeUnit>> traitImplMembersByTypeMemberName() {
+        return traitImplMembersByTypeMemberName;
+    }
+
+    public void traitImplMembersByTypeMemberName(Map<String, Set<CodeUnit>> traitImplMembersByTypeMemberName) {
+        this.traitImplMembersByTypeMemberName = traitImplMembersByTypeMemberName;
+    }
+
+    public @Nullable TraitImplIndex traitImplIndex() {
+        return traitImplIndex;
+    }
+
+    public void traitImplIndex(TraitImplIndex traitImplIndex) {
+        this.traitImplIndex = traitImplIndex;
+    }
+
+    public @Nullable Map<String, Set<String>> externalDerivedTraits() {
+        return externalDerivedTraits;
+    }
+
+    public void externalDerivedTraits(Map<String, Set<String>> externalDerivedTraits) {
+        this.externalDerivedTraits = externalDerivedTraits;
+    }
+
     public @Nullable RustUsageCandidateIndex usageCandidateIndex() {
         return usageCandidateIndex;
     }
@@ -129,4 +286,23 @@ public final class RustAnalyzerCache extends AnalyzerCache {
     public void usageCandidateIndex(RustUsageCandidateIndex usageCandidateIndex) {
         this.usageCandidateIndex = usageCandidateIndex;
     }
+
+    /** Memoized cfg conditions of module files; they depend on the declaring files, so are only kept unchanged. */
+    public Map<ProjectFile, Optional<CfgPredicate>> moduleFileConditions() {
+        return moduleFileConditions;
+    }
+
+    public @Nullable RustCfgConfiguration cfgConfiguration() {
+        return cfgConfiguration;
+    }
+
+    /** The stored settings JSON that {@link #cfgConfiguration()} was built from. */
+    public @Nullable String cfgSettingsJson() {
+        return cfgSettingsJson;
+    }
+
+    public void cfgConfiguration(String settingsJson, RustCfgConfiguration cfgConfiguration) {
+        this.cfgSettingsJson = settingsJson;
+        this.cfgConfiguration = cfgConfiguration;
+    }
 }
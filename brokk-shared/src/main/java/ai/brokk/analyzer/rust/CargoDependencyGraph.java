@@ -0,0 +1,345 @@
+package ai.brokk.analyzer.rust;
+
+import ai.brokk.analyzer.RustLanguage.CargoDepKind;
+import ai.brokk.analyzer.RustLanguage.CargoDependency;
+import ai.brokk.analyzer.RustLanguage.CargoMetadata;
+import ai.brokk.analyzer.RustLanguage.CargoNode;
+import ai.brokk.analyzer.RustLanguage.CargoNodeDep;
+import ai.brokk.analyzer.RustLanguage.CargoPackage;
+import ai.brokk.analyzer.RustLanguage.CargoResolve;
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.Comparator;
+import java.util.HashMap;
+import java.util.LinkedHashMap;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Locale;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Set;
+import java.util.TreeMap;
+import java.util.stream.Collectors;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * The resolved dependency graph of a Cargo workspace, answering the questions usually put to {@code cargo tree}: why a
+ * crate is in the tree ({@code cargo tree -i}), which workspace members depend on it, and which crates are built at
+ * more than one version ({@code cargo tree -d}).
+ *
+ * <p>Edges carry what the depending package declared: the dependency kind, the target platform it is restricted to,
+ * whether it is optional, the features it enables and the name it is renamed to.
+ */
+public final class CargoDependencyGraph {
+    public static final CargoDependencyGraph EMPTY = new CargoDependencyGraph(List.of(), List.of());
+
+    /**
+     * A package in the graph.
+     *
+     * @param source the package source, e.g. {@code registry+https://github.com/rust-lang/crates.io-index}; null for
+     *     path packages
+     * @param features the features enabled on the package in the resolved build
+     */
+    public record Crate(
+            String id,
+            String name,
+            String version,
+            @Nullable String source,
+            boolean workspaceMember,
+            List<String> features) {
+        public Crate {
+            features = List.copyOf(features);
+        }
+
+        /** The name and version, e.g. {@code serde 1.0.200}. */
+        public String display() {
+            return version.isEmpty() ? name : name + " " + version;
+        }
+    }
+
+    /**
+     * A dependency edge from one package to the package it resolved to.
+     *
+     * @param kind {@code normal}, {@code dev} or {@code build}
+     * @param target the platform the dependency is restricted to, e.g. {@code cfg(unix)}, or null if unconditional
+     * @param rename the name the dependency is renamed to with {@code package = "..."}, or null
+     * @param features the features the dependency declaration enables
+     */
+    public record Dependency(
+            String from,
+            String to,
+            String kind,
+            @Nullable String target,
+            boolean optional,
+            @Nullable String rename,
+            List<String> features,
+            boolean defaultFeatures) {
+        public Dependency {
+            features = List.copyOf(features);
+        }
+    }
+
+    /**
+     * A workspace member depending on a crate, with the shortest chain of dependencies leading from the member to it.
+     */
+    public record Dependent(Crate member, List<Dependency> path) {
+        public Dependent {
+            path = List.copyOf(path);
+        }
+
+        public boolean isDirect() {
+            return path.size() == 1;
+        }
+    }
+
+    private final Map<String, Crate> crates = new LinkedHashMap<>();
+    private final Map<String, List<Dependency>> outgoing = new HashMap<>();
+    private final Map<String, List<Dependency>> incoming = new HashMap<>();
+
+    public CargoDependencyGraph(Collection<Crate> crates, Collection<Dependency> dependencies) {
+        crates.forEach(crate -> this.crates.put(crate.id(), crate));
+        for (Dependency dependency : dependencies) {
+            if (!this.crates.containsKey(dependency.from()) || !this.crates.containsKey(dependency.to())) {
+                continue;
+            }
+            outgoing.computeIfAbsent(dependency.from(), k -> new ArrayList<>()).add(dependency);
+            incoming.computeIfAbsent(dependency.to(), k -> new ArrayList<>()).add(dependency);
+        }
+    }
+
+    /**
+     * Builds the graph from {@code cargo metadata} output. Edges come from the resolve graph, matched to the
+     * declarations of the depending package for their details; without a resolve graph (as with {@code --no-deps})
+     * the declared dependencies are resolved by name to the highest version present.
+     */
+    public static CargoDependencyGraph fromCargoMetadata(CargoMetadata metadata) {
+        if (metadata.packages.isEmpty()) {
+            return EMPTY;
+        }
+        Set<String> members = new LinkedHashSet<>(metadata.workspace_members);
+        Map<String, CargoPackage> packages = new LinkedHashMap<>();
+        metadata.packages.forEach(pkg -> packages.putIfAbsent(pkg.id, pkg));
+        Map<String, CargoNode> nodes = new LinkedHashMap<>();
+        CargoResolve resolve = metadata.resolve;
+        if (resolve != null) {
+            resolve.nodes.forEach(node -> nodes.put(node.id, node));
+        }
+
+        var crates = new ArrayList<Crate>();
+        for (CargoPackage pkg : packages.values()) {
+            CargoNode node = nodes.get(pkg.id);
+            List<String> features = node != null ? node.features : List.of();
+            crates.add(new Crate(pkg.id, pkg.name, pkg.version, pkg.source, members.contains(pkg.id), features));
+        }
+
+        var dependencies = new ArrayList<Dependency>();
+        if (!nodes.isEmpty()) {
+            for (CargoNode node : nodes.values()) {
+                CargoPackage from = packages.get(node.id);
+                if (from == null) {
+                    continue;
+                }
+                for (CargoNodeDep dep : node.deps) {
+                    CargoPackage to = packages.get(dep.pkg);
+                    if (to == null) {
+                        continue;
+                    }
+                    List<CargoDepKind> kinds = dep.dep_kinds.isEmpty() ? List.of(new CargoDepKind()) : dep.dep_kinds;
+                    for (CargoDepKind kind : kinds) {
+                        CargoDependency declared = findDeclaration(from, to.name, dep.name, kind);
+                        dependencies.add(dependency(from.id, to.id, kind.kind, kind.target, declared));
+                    }
+                }
+            }
+        } else {
+            Map<String, CargoPackage> latestByName = new HashMap<>();
+            for (CargoPackage pkg : packages.values()) {
+                latestByName.merge(pkg.name, pkg, (a, b) -> compareVersions(a.version, b.version) >= 0 ? a : b);
+            }
+            for (CargoPackage from : packages.values()) {
+                for (CargoDependency declared : from.dependencies) {
+                    CargoPackage to = latestByName.get(declared.name);
+                    if (to != null && !to.id.equals(from.id)) {
+                        dependencies.add(dependency(from.id, to.id, declared.kind, declared.target, declared));
+                    }
+                }
+            }
+        }
+        return new CargoDependencyGraph(crates, dependencies);
+    }
+
+    private static @Nullable CargoDependency findDeclaration(
+            CargoPackage from, String packageName, String externName, CargoDepKind kind) {
+        @Nullable CargoDependency fallback = null;
+        for (CargoDependency declared : from.dependencies) {
+            if (!declared.name.equals(packageName)) {
+                continue;
+            }
+            String declaredName = declared.rename != null ? declared.rename : declared.name;
+            if (!externName.isEmpty() && !crateNameOf(declaredName).equals(crateNameOf(externName))) {
+                continue;
+            }
+            if (Objects.equals(declared.kind, kind.kind)
+                    && Objects.equals(declared.target, kind.target)) {
+                return declared;
+            }
+            if (fallback == null) {
+                fallback = declared;
+            }
+        }
+        return fallback;
+    }
+
+    private static Dependency dependency(
+            String from,
+            String to,
+            @Nullable String kind,
+            @Nullable String target,
+            @Nullable CargoDependency declared) {
+        return new Dependency(
+                from,
+                to,
+                kind == null ? "normal" : kind,
+                target,
+                declared != null && declared.optional,
+                declared != null ? declared.rename : null,
+                declared != null ? declared.features : List.of(),
+                declared == null || declared.uses_default_features);
+    }
+
+    public List<Crate> crates() {
+        return List.copyOf(crates.values());
+    }
+
+    public List<Crate> members() {
+        return crates.values().stream().filter(Crate::workspaceMember).toList();
+    }
+
+    public @Nullable Crate crate(String id) {
+        return crates.get(id);
+    }
+
+    /** The crates named {@code name}, matching {@code -} and {@code _} alike, lowest version first. */
+    public List<Crate> cratesNamed(String name) {
+        String wanted = crateNameOf(name);
+        return crates.values().stream()
+                .filter(crate -> crateNameOf(crate.name()).equals(wanted))
+                .sorted((a, b) -> compareVersions(a.version(), b.version()))
+                .toList();
+    }
+
+    public List<Dependency> dependenciesOf(String id) {
+        return List.copyOf(outgoing.getOrDefault(id, List.of()));
+    }
+
+    public List<Dependency> dependentsOf(String id) {
+        return List.copyOf(incoming.getOrDefault(id, List.of()));
+    }
+
+    /**
+     * Why {@code target} is in the tree: for each workspace member that reaches it, the shortest chain of dependencies
+     * from the member to it. A member that is the target itself is not listed.
+     */
+    public List<Dependent> why(Crate target) {
+        return shortestPathsFromMembers(Set.of(target.id()));
+    }
+
+    /** The workspace members depending directly or transitively on any version of the crate named {@code name}. */
+    public List<Dependent> dependentMembers(String name) {
+        Set<String> targets = cratesNamed(name).stream().map(Crate::id).collect(Collectors.toSet());
+        return shortestPathsFromMembers(targets);
+    }
+
+    /** The crates present at more than one version, by name, lowest version first. */
+    public Map<String, List<Crate>> duplicates() {
+        var byName = new TreeMap<String, List<Crate>>();
+        for (Crate crate : crates.values()) {
+            byName.computeIfAbsent(crate.name(), k -> new ArrayList<>()).add(crate);
+        }
+        var duplicates = new LinkedHashMap<String, List<Crate>>();
+        byName.forEach((name, versions) -> {
+            if (versions.stream().map(Crate::version).distinct().count() > 1) {
+                duplicates.put(
+                        name,
+                        versions.stream()
+                                .sorted((a, b) -> compareVersions(a.version(), b.version()))
+                                .toList());
+            }
+        });
+        return duplicates;
+    }
+
+    /**
+     * Walks the graph backwards from {@code targets}, so the first time a member is reached its path to a target is a
+     * shortest one.
+     */
+    private List<Dependent> shortestPathsFromMembers(Set<String> targets) {
+        Map<String, Dependency> towardsTarget = new HashMap<>();
+        var visited = new LinkedHashSet<String>(targets);
+        var queue = new ArrayDeque<String>(targets.stream().sorted().toList());
+        var dependents = new ArrayList<Dependent>();
+        while (!queue.isEmpty()) {
+            String current = queue.removeFirst();
+            for (Dependency edge : incoming.getOrDefault(current, List.of())) {
+                if (!visited.add(edge.from())) {
+                    continue;
+                }
+                towardsTarget.put(edge.from(), edge);
+                queue.addLast(edge.from());
+                Crate from = crates.get(edge.from());
+                if (from != null && from.workspaceMember()) {
+                    var path = new ArrayList<Dependency>();
+                    for (@Nullable Dependency step = edge; step != null; step = towardsTarget.get(step.to())) {
+                        path.add(step);
+                    }
+                    dependents.add(new Dependent(from, path));
+                }
+            }
+        }
+        dependents.sort(Comparator.comparing((Dependent d) -> d.member().name()));
+        return dependents;
+    }
+
+    private static String crateNameOf(String name) {
+        return name.replace('-', '_').toLowerCase(Locale.ROOT);
+    }
+
+    /**
+     * Compares versions the way semver orders them: numerically by their dot-separated components, e.g.
+     * {@code 1.10.0} after {@code 1.9.3}, with a pre-release such as {@code 1.0.0-alpha} before its release. Build
+     * metadata is ignored.
+     */
+    static int compareVersions(String a, String b) {
+        String left = a.split("\\+", 2)[0];
+        String right = b.split("\\+", 2)[0];
+        int leftDash = left.indexOf('-');
+        int rightDash = right.indexOf('-');
+        int result = compareComponents(
+                leftDash < 0 ? left : left.substring(0, leftDash),
+                rightDash < 0 ? right : right.substring(0, rightDash));
+        if (result != 0 || (leftDash < 0 && rightDash < 0)) {
+            return result;
+        }
+        if (leftDash < 0 || rightDash < 0) {
+            return leftDash < 0 ? 1 : -1;
+        }
+        return compareComponents(left.substring(leftDash + 1), right.substring(rightDash + 1));
+    }
+
+    private static int compareComponents(String a, String b) {
+        String[] left = a.split("\\.");
+        String[] right = b.split("\\.");
+        for (int i = 0; i < Math.max(left.length, right.length); i++) {
+            String l = i < left.length ? left[i] : "";
+            String r = i < right.length ? right[i] : "";
+            int result = l.matches("\\d+") && r.matches("\\d+")
+                    ? Long.compare(Long.parseLong(l), Long.parseLong(r))
+                    : l.compareTo(r);
+            if (result != 0) {
+                return result;
+            }
+        }
+        return 0;
+    }
+}
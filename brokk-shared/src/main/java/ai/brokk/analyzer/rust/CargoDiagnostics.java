@@ -0,0 +1,266 @@
+package ai.brokk.analyzer.rust;
+
+import ai.brokk.analyzer.LintResult;
+import ai.brokk.analyzer.LintResult.LintDiagnostic;
+import ai.brokk.analyzer.LintResult.LintDiagnostic.Severity;
+import ai.brokk.analyzer.LintResult.Suggestion;
+import ai.brokk.analyzer.LintResult.Suggestion.Applicability;
+import com.fasterxml.jackson.core.JsonProcessingException;
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Locale;
+import java.util.Optional;
+import java.util.regex.Matcher;
+import java.util.regex.Pattern;
+import java.util.stream.Collectors;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * Parses the JSON diagnostics emitted by {@code cargo check --message-format=json} (and {@code cargo clippy}) into a
+ * {@link LintResult}.
+ *
+ * <p>Cargo prints one JSON object per line. Only {@code compiler-message} lines carry rustc diagnostics; each one is
+ * mapped to a {@link LintDiagnostic} positioned at its primary span, with rustc's error code ({@code E0308}) or lint
+ * name ({@code clippy::needless_return}) as the code. Replacement spans proposed by the diagnostic or its
+ * {@code help} children are kept as {@link Suggestion}s. Summary messages without spans ("aborting due to ...",
+ * "N warnings emitted") are dropped, as are the duplicates cargo prints when several targets share a file.
+ */
+public final class CargoDiagnostics {
+    private static final Logger logger = LogManager.getLogger(CargoDiagnostics.class);
+
+    private static final ObjectMapper MAPPER = new ObjectMapper();
+    private static final String MESSAGE_FORMAT_FLAG = "--message-format";
+    private static final Pattern ARGUMENT_SEPARATOR = Pattern.compile("\\s--(\\s|$)");
+    private static final Pattern LINT_COMMAND =
+            Pattern.compile("^cargo(?:\\s+\\+\\S+)?\\s+(?:check|clippy)(?:\\s.*)?$");
+
+    private CargoDiagnostics() {}
+
+    /**
+     * Parses cargo's line-delimited JSON output. Lines that are not JSON (progress output, or a wrapper script's own
+     * logging) are skipped. File names are made relative to {@code projectRoot} when they lie under it; rustc reports
+     * workspace files relative to the directory cargo ran in, which is expected to be {@code projectRoot}.
+     */
+    public static LintResult parse(String output, Path projectRoot) {
+        var root = projectRoot.toAbsolutePath().normalize();
+        var diagnostics = new LinkedHashSet<LintDiagnostic>();
+        for (String line : output.split("\\R")) {
+            String trimmed = line.strip();
+            if (!trimmed.startsWith("{")) {
+                continue;
+            }
+            JsonNode node;
+            try {
+                node = MAPPER.readTree(trimmed);
+            } catch (JsonProcessingException e) {
+                logger.debug("Skipping non-JSON cargo output line: {}", trimmed);
+                continue;
+            }
+            if (!"compiler-message".equals(node.path("reason").asText())) {
+                continue;
+            }
+            toDiagnostic(node.path("message"), root).ifPresent(diagnostics::add);
+        }
+        return new LintResult(List.copyOf(diagnostics));
+    }
+
+    /**
+     * Whether {@code command} is a single {@code cargo check} or {@code cargo clippy} invocation, optionally with a
+     * {@code +toolchain}, whose diagnostics can be read as JSON. Chained shell commands are left alone.
+     */
+    public static boolean isLintCommand(String command) {
+        String trimmed = command.strip();
+        return LINT_COMMAND.matcher(trimmed).matches()
+                && !trimmed.contains("&&")
+                && !trimmed.contains(";")
+                && !trimmed.contains("|");
+    }
+
+    /**
+     * Adds {@code --message-format=json} to a cargo command unless it already selects a message format. The flag is
+     * placed before a {@code --} separator so that it is read by cargo rather than passed on to rustc or clippy.
+     */
+    public static String withJsonMessageFormat(String command) {
+        if (command.contains(MESSAGE_FORMAT_FLAG)) {
+            return command;
+        }
+        Matcher separator = ARGUMENT_SEPARATOR.matcher(command);
+        if (separator.find()) {
+            return command.substring(0, separator.start()) + " " + MESSAGE_FORMAT_FLAG + "=json"
+                    + command.substring(separator.start());
+        }
+        return command.stripTrailing() + " " + MESSAGE_FORMAT_FLAG + "=json";
+    }
+
+    /**
+     * Renders diagnostics as compact {@code file:line:column: severity[code]: message} lines, each followed by its
+     * suggested replacements, suitable for handing to an agent verbatim.
+     */
+    public static String formatReport(List<LintDiagnostic> diagnostics) {
+        return diagnostics.stream().map(CargoDiagnostics::formatDiagnostic).collect(Collectors.joining("\n"));
+    }
+
+    private static String formatDiagnostic(LintDiagnostic diagnostic) {
+        var sb = new StringBuilder();
+        sb.append(diagnostic.file())
+                .append(':')
+                .append(diagnostic.line())
+                .append(':')
+                .append(diagnostic.column())
+                .append(": ")
+                .append(diagnostic.severity().name().toLowerCase(Locale.ROOT));
+        if (!diagnostic.code().isEmpty()) {
+            sb.append('[').append(diagnostic.code()).append(']');
+        }
+        sb.append(": ").append(diagnostic.message());
+        for (Suggestion suggestion : diagnostic.suggestions()) {
+            sb.append("\n    ");
+            if (!suggestion.message().isEmpty()) {
+                sb.append(suggestion.message()).append(": ");
+            }
+            sb.append("replace ")
+                    .append(suggestion.file())
+                    .append(':')
+                    .append(suggestion.lineStart())
+                    .append(':')
+                    .append(suggestion.columnStart())
+                    .append('-')
+                    .append(suggestion.lineEnd())
+                    .append(':')
+                    .append(suggestion.columnEnd())
+                    .append(" with `")
+                    .append(suggestion.replacement())
+                    .append("` (")
+                    .append(suggestion.applicability().name().toLowerCase(Locale.ROOT))
+                    .append(')');
+        }
+        return sb.toString();
+    }
+
+    private static Optional<LintDiagnostic> toDiagnostic(JsonNode message, Path root) {
+        JsonNode primary = primarySpan(message.path("spans"));
+        if (primary == null) {
+            return Optional.empty();
+        }
+        JsonNode located = projectSpan(primary, root);
+        var text = new StringBuilder(message.path("message").asText());
+        String label = primary.path("label").asText("");
+        if (!label.isEmpty()) {
+            text.append(": ").append(label);
+        }
+        var suggestions = new ArrayList<Suggestion>();
+        collectSuggestions(message, "", root, suggestions);
+        return Optional.of(new LintDiagnostic(
+                fileName(located, root),
+                located.path("line_start").asInt(),
+                located.path("column_start").asInt(),
+                severity(message.path("level").asText()),
+                text.toString(),
+                message.path("code").path("code").asText(""),
+                List.copyOf(suggestions)));
+    }
+
+    private static @Nullable JsonNode primarySpan(JsonNode spans) {
+        for (JsonNode span : spans) {
+            if (span.path("is_primary").asBoolean()) {
+                return span;
+            }
+        }
+        return spans.isEmpty() ? null : spans.get(0);
+    }
+
+    /**
+     * Walks a span's macro expansion chain until it reaches a span in a project file, so that a diagnostic raised
+     * inside a macro from a dependency or the standard library points at the invocation site.
+     */
+    private static JsonNode projectSpan(JsonNode span, Path root) {
+        JsonNode current = span;
+        while (!isProjectFile(current.path("file_name").asText(), root)) {
+            JsonNode parent = current.path("expansion").path("span");
+            if (parent.isMissingNode() || parent.isNull()) {
+                return span;
+            }
+            current = parent;
+        }
+        return current;
+    }
+
+    private static void collectSuggestions(JsonNode message, String parentMessage, Path root, List<Suggestion> out) {
+        String text = message.path("message").asText(parentMessage);
+        for (JsonNode span : message.path("spans")) {
+            JsonNode replacement = span.path("suggested_replacement");
+            if (replacement.isMissingNode() || replacement.isNull()) {
+                continue;
+            }
+            out.add(new Suggestion(
+                    fileName(span, root),
+                    span.path("line_start").asInt(),
+                    span.path("column_start").asInt(),
+                    span.path("line_end").asInt(),
+                    span.path("column_end").asInt(),
+                    span.path("byte_start").asInt(),
+                    span.path("byte_end").asInt(),
+                    replacement.asText(),
+                    applicability(span.path("suggestion_applicability").asText("")),
+                    text,
+                    sourceLines(span)));
+        }
+        for (JsonNode child : message.path("children")) {
+            collectSuggestions(child, text, root, out);
+        }
+    }
+
+    private static List<String> sourceLines(JsonNode span) {
+        var lines = new ArrayList<String>();
+        for (JsonNode line : span.path("text")) {
+            lines.add(line.path("text").asText());
+        }
+        return List.copyOf(lines);
+    }
+
+    private static boolean isProjectFile(String fileName, Path root) {
+        if (fileName.isEmpty() || fileName.startsWith("<")) {
+            return false;
+        }
+        Path path = Path.of(fileName);
+        return !path.isAbsolute() || path.normalize().startsWith(root);
+    }
+
+    private static String fileName(JsonNode span, Path root) {
+        String fileName = span.path("file_name").asText();
+        if (fileName.isEmpty() || fileName.startsWith("<")) {
+            return fileName;
+        }
+        Path path = Path.of(fileName);
+        Path relative = path.isAbsolute() && path.normalize().startsWith(root)
+                ? root.relativize(path.normalize())
+                : path.normalize();
+        return relative.toString();
+    }
+
+    private static Severity severity(String level) {
+        if (level.startsWith("error")) {
+            return Severity.ERROR;
+        }
+        return switch (level) {
+            case "warning" -> Severity.WARNING;
+            case "help" -> Severity.HINT;
+            default -> Severity.INFO;
+        };
+    }
+
+    private static Applicability applicability(String value) {
+        return switch (value) {
+            case "MachineApplicable" -> Applicability.MACHINE_APPLICABLE;
+            case "MaybeIncorrect" -> Applicability.MAYBE_INCORRECT;
+            case "HasPlaceholders" -> Applicability.HAS_PLACEHOLDERS;
+            default -> Applicability.UNSPECIFIED;
+        };
+    }
+}
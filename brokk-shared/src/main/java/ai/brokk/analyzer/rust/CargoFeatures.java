@@ -0,0 +1,69 @@
+package ai.brokk.analyzer.rust;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayDeque;
+import java.util.Deque;
+import java.util.LinkedHashMap;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+
+/**
+ * The {@code [features]} table of a {@code Cargo.toml}, used to work out which {@code feature = "..."} cfg options are
+ * set when a package is built.
+ *
+ * <p>Only the forms that enable features of the package itself are followed: {@code "other"} enables another feature
+ * (or the implicit feature of an optional dependency), and {@code "dep/feature"} enables the optional dependency
+ * {@code dep}. {@code "dep:name"} and {@code "dep?/feature"} enable no feature of this package.
+ */
+public record CargoFeatures(String packageName, Map<String, List<String>> table) {
+    public CargoFeatures {
+        table = Map.copyOf(table);
+    }
+
+    /** Parses a manifest; returns empty for virtual workspace manifests, which have no {@code [package]}. */
+    public static Optional<CargoFeatures> parse(String manifestText) {
+        return of(CargoToml.parse(manifestText));
+    }
+
+    static Optional<CargoFeatures> of(JsonNode manifest) {
+        JsonNode pkg = manifest.path("package");
+        if (!pkg.isObject()) {
+            return Optional.empty();
+        }
+        var table = new LinkedHashMap<String, List<String>>();
+        manifest.path("features")
+                .properties()
+                .forEach(entry -> table.put(entry.getKey(), CargoToml.strings(entry.getValue())));
+        return Optional.of(new CargoFeatures(CargoToml.string(pkg.path("name")), table));
+    }
+
+    /** The features enabled when building this package under {@code settings}, following the feature table. */
+    public Set<String> enabled(RustCfgSettings settings) {
+        Deque<String> pending = new ArrayDeque<>(settings.featuresFor(packageName));
+        if (settings.defaultFeatures() && table.containsKey("default")) {
+            pending.addFirst("default");
+        }
+        var enabled = new LinkedHashSet<String>();
+        while (!pending.isEmpty()) {
+            String feature = pending.removeFirst();
+            if (!enabled.add(feature)) {
+                continue;
+            }
+            for (String member : table.getOrDefault(feature, List.of())) {
+                if (member.startsWith("dep:")) {
+                    continue;
+                }
+                int slash = member.indexOf('/');
+                if (slash < 0) {
+                    pending.add(member);
+                } else if (slash > 0 && member.charAt(slash - 1) != '?') {
+                    pending.add(member.substring(0, slash));
+                }
+            }
+        }
+        return Set.copyOf(enabled);
+    }
+}
@@ -0,0 +1,90 @@
+package ai.brokk.analyzer.rust;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.List;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * The {@code [[package]]} entries of a {@code Cargo.lock}: every package in the resolved dependency graph, with the
+ * source it was resolved from.
+ */
+public record CargoLockfile(List<LockedPackage> packages) {
+    public static final CargoLockfile EMPTY = new CargoLockfile(List.of());
+
+    /**
+     * A locked package.
+     *
+     * @param source where the package comes from, e.g. {@code registry+https://github.com/rust-lang/crates.io-index} or
+     *     {@code git+https://github.com/o/r?branch=main#<commit>}; null for path packages such as workspace members
+     * @param dependencies the locked dependencies as written in the lockfile: {@code name}, or {@code name version}
+     *     when several versions of the crate are locked
+     */
+    public record LockedPackage(String name, String version, @Nullable String source, List<String> dependencies) {
+        public LockedPackage {
+            dependencies = List.copyOf(dependencies);
+        }
+
+        public boolean isLocal() {
+            return source == null;
+        }
+
+        public boolean isRegistry() {
+            return source != null && (source.startsWith("registry+") || source.startsWith("sparse+"));
+        }
+
+        public boolean isGit() {
+            return source != null && source.startsWith("git+");
+        }
+
+        /** The full commit a git package is locked to, i.e. the fragment of its source URL, or "" if none. */
+        public String gitCommit() {
+            String src = source;
+            if (src == null || !src.startsWith("git+")) {
+                return "";
+            }
+            int hash = src.lastIndexOf('#');
+            return hash < 0 ? "" : src.substring(hash + 1);
+        }
+
+        /** The last path segment of a git package's repository URL without {@code .git}, or "" if not a git package. */
+        public String gitRepositoryName() {
+            String src = source;
+            if (src == null || !src.startsWith("git+")) {
+                return "";
+            }
+            String url = src.substring("git+".length());
+            int end = url.length();
+            for (char stop : new char[] {'?', '#'}) {
+                int index = url.indexOf(stop);
+                if (index >= 0) {
+                    end = Math.min(end, index);
+                }
+            }
+            url = url.substring(0, end).replaceAll("/+$", "");
+            String name = url.substring(url.lastIndexOf('/') + 1);
+            return name.endsWith(".git") ? name.substring(0, name.length() - ".git".length()) : name;
+        }
+    }
+
+    public CargoLockfile {
+        packages = List.copyOf(packages);
+    }
+
+    public static CargoLockfile parse(String lockfileText) {
+        var packages = new ArrayList<LockedPackage>();
+        for (JsonNode pkg : CargoToml.parse(lockfileText).path("package")) {
+            String name = CargoToml.string(pkg.path("name"));
+            if (name.isEmpty()) {
+                continue;
+            }
+            JsonNode source = pkg.path("source");
+            packages.add(new LockedPackage(
+                    name,
+                    CargoToml.string(pkg.path("version")),
+                    source.isTextual() ? source.asText() : null,
+                    CargoToml.strings(pkg.path("dependencies"))));
+        }
+        return packages.isEmpty() ? EMPTY : new CargoLockfile(packages);
+    }
+}
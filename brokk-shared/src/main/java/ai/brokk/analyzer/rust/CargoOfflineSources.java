@@ -0,0 +1,336 @@
+package ai.brokk.analyzer.rust;
+
+import ai.brokk.analyzer.RustLanguage.CargoDepKind;
+import ai.brokk.analyzer.RustLanguage.CargoDependency;
+import ai.brokk.analyzer.RustLanguage.CargoMetadata;
+import ai.brokk.analyzer.RustLanguage.CargoNode;
+import ai.brokk.analyzer.RustLanguage.CargoNodeDep;
+import ai.brokk.analyzer.RustLanguage.CargoPackage;
+import ai.brokk.analyzer.RustLanguage.CargoResolve;
+import ai.brokk.analyzer.rust.CargoLockfile.LockedPackage;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.LinkedHashMap;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+import java.util.stream.Stream;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * Locates the sources of locked Cargo dependencies without running cargo or touching the network, for machines that
+ * are offline or have no cargo on PATH. A package from {@code Cargo.lock} is looked up in the vendored directories
+ * first ({@code vendor/name} or {@code vendor/name-version}, as written by {@code cargo vendor}), then in the registry
+ * cache under {@code $CARGO_HOME/registry/src/<index>/name-version}, and for git packages in
+ * {@code $CARGO_HOME/git/checkouts/<repo>-<hash>/<short commit>}.
+ */
+public final class CargoOfflineSources {
+    private static final int GIT_CHECKOUT_SEARCH_DEPTH = 4;
+
+    private final Path cargoHome;
+    private final List<Path> vendorDirectories;
+
+    public CargoOfflineSources(Path cargoHome, List<Path> vendorDirectories) {
+        this.cargoHome = cargoHome;
+        this.vendorDirectories = List.copyOf(vendorDirectories);
+    }
+
+    /** {@code $CARGO_HOME}, or {@code ~/.cargo} when it is not set. */
+    public static Path defaultCargoHome() {
+        String cargoHome = System.getenv("CARGO_HOME");
+        if (cargoHome != null && !cargoHome.isBlank()) {
+            return Path.of(cargoHome);
+        }
+        return Path.of(System.getProperty("user.home"), ".cargo");
+    }
+
+    /** The {@code Cargo.toml} of a locked package's sources on this machine, if they are available. */
+    public Optional<Path> locateManifest(LockedPackage pkg) {
+        if (pkg.isLocal()) {
+            return Optional.empty();
+        }
+        Optional<Path> vendored = locateVendored(pkg);
+        if (vendored.isPresent()) {
+            return vendored;
+        }
+        if (pkg.isRegistry()) {
+            return locateInRegistryCache(pkg);
+        }
+        if (pkg.isGit()) {
+            return locateInGitCheckouts(pkg);
+        }
+        return Optional.empty();
+    }
+
+    /**
+     * Builds the equivalent of {@code cargo metadata} output from lockfiles and the manifests of the workspace members:
+     * one package per member with its declared dependencies, one per other locked package with the manifest path of
+     * its sources, or no manifest path if they could not be located, and the resolve graph recorded in the lockfiles.
+     */
+    public CargoMetadata metadata(Collection<Path> memberManifests, Collection<Path> lockfiles) {
+        var metadata = new CargoMetadata();
+        metadata.packages = new ArrayList<>();
+        metadata.workspace_members = new ArrayList<>();
+
+        for (Path manifest : memberManifests) {
+            String text;
+            try {
+                text = Files.readString(manifest);
+            } catch (IOException e) {
+                continue;
+            }
+            JsonNode toml = CargoToml.parse(text);
+            Optional<CargoFeatures> features = CargoFeatures.of(toml);
+            if (features.isEmpty()) {
+                continue;
+            }
+            var member = new CargoPackage();
+            member.id = "path+" + manifest.toAbsolutePath().normalize().toUri();
+            member.name = features.get().packageName();
+            member.version = packageVersion(toml);
+            member.manifest_path = manifest.toString();
+            var dependencies = new ArrayList<CargoDependency>();
+            directDependencyKinds(toml).forEach((name, kinds) -> {
+                for (String kind : kinds) {
+                    var dependency = new CargoDependency();
+                    dependency.name = name;
+                    dependency.kind = "normal".equals(kind) ? null : kind;
+                    dependencies.add(dependency);
+                }
+            });
+            member.dependencies = dependencies;
+            metadata.packages.add(member);
+            metadata.workspace_members.add(member.id);
+        }
+
+        Map<String, CargoPackage> membersByName = new LinkedHashMap<>();
+        metadata.packages.forEach(member -> membersByName.putIfAbsent(member.name, member));
+        var nodes = new LinkedHashMap<String, CargoNode>();
+        for (Path lockfile : lockfiles) {
+            CargoLockfile locked;
+            try {
+                locked = CargoLockfile.parse(Files.readString(lockfile));
+            } catch (IOException e) {
+                continue;
+            }
+            var ids = new LinkedHashMap<LockedPackage, String>();
+            for (LockedPackage pkg : locked.packages()) {
+                @Nullable CargoPackage member = pkg.isLocal() ? membersByName.get(pkg.name()) : null;
+                String id = member != null
+                        ? member.id
+                        : "%s %s (%s)".formatted(pkg.name(), pkg.version(), pkg.isLocal() ? "path" : pkg.source());
+                ids.put(pkg, id);
+                if (member != null || nodes.containsKey(id)) {
+                    continue;
+                }
+                var external = new CargoPackage();
+                external.id = id;
+                external.name = pkg.name();
+                external.version = pkg.version();
+                external.source = pkg.source();
+                external.manifest_path = locateManifest(pkg).map(Path::toString).orElse(null);
+                metadata.packages.add(external);
+                nodes.put(id, new CargoNode());
+            }
+            for (LockedPackage pkg : locked.packages()) {
+                String id = ids.get(pkg);
+                if (id == null) {
+                    continue;
+                }
+                @Nullable CargoPackage member = pkg.isLocal() ? membersByName.get(pkg.name()) : null;
+                var node = nodes.computeIfAbsent(id, k -> new CargoNode());
+                node.id = id;
+                var deps = new ArrayList<CargoNodeDep>(node.deps);
+                for (String spec : pkg.dependencies()) {
+                    lockedDependency(locked, spec).map(ids::get).ifPresent(depId -> {
+                        var dep = new CargoNodeDep();
+                        dep.pkg = depId;
+                        dep.name = spec.split(" ", 2)[0].replace('-', '_');
+                        dep.dep_kinds = lockedDependencyKinds(member, spec.split(" ", 2)[0]);
+                        deps.add(dep);
+                    });
+                }
+                node.deps = deps;
+            }
+        }
+        var resolve = new CargoResolve();
+        resolve.nodes = new ArrayList<>(nodes.values());
+        metadata.resolve = resolve;
+        return metadata;
+    }
+
+    /** Resolves a lockfile dependency entry, {@code name}, {@code name version} or {@code name version (source)}. */
+    private static Optional<LockedPackage> lockedDependency(CargoLockfile lockfile, String spec) {
+        String[] parts = spec.split(" ", 3);
+        List<LockedPackage> candidates = lockfile.packages().stream()
+                .filter(pkg -> pkg.name().equals(parts[0]))
+                .filter(pkg -> parts.length < 2 || pkg.version().equals(parts[1]))
+                .filter(pkg -> parts.length < 3 || parts[2].equals("(" + pkg.source() + ")"))
+                .toList();
+        return candidates.size() == 1 ? Optional.of(candidates.getFirst()) : Optional.empty();
+    }
+
+    /**
+     * The kinds of a locked dependency edge. Lockfiles do not record kinds, so edges from workspace members take the
+     * kinds declared in the member's manifest and all other edges are normal.
+     */
+    private static List<CargoDepKind> lockedDependencyKinds(@Nullable CargoPackage member, String dependencyName) {
+        var kinds = new ArrayList<CargoDepKind>();
+        if (member != null) {
+            for (CargoDependency declared : member.dependencies) {
+                if (declared.name.equals(dependencyName)) {
+                    var kind = new CargoDepKind();
+                    kind.kind = declared.kind;
+                    kinds.add(kind);
+                }
+            }
+        }
+        if (kinds.isEmpty()) {
+            kinds.add(new CargoDepKind());
+        }
+        return kinds;
+    }
+
+    /**
+     * The dependencies a manifest declares, by package name, with their kinds: {@code normal}, {@code dev} or
+     * {@code build}. Covers the inline and table forms, target-specific tables such as
+     * {@code [target.'cfg(unix)'.dependencies]}, and renames via {@code package = "..."}.
+     */
+    static Map<String, Set<String>> directDependencyKinds(String manifestText) {
+        return directDependencyKinds(CargoToml.parse(manifestText));
+    }
+
+    private static Map<String, Set<String>> directDependencyKinds(JsonNode manifest) {
+        var kinds = new LinkedHashMap<String, Set<String>>();
+        addDependencies(manifest, kinds);
+        for (JsonNode target : manifest.path("target")) {
+            addDependencies(target, kinds);
+        }
+        return kinds;
+    }
+
+    /** Adds the dependencies of the dependency tables directly under {@code tables}. */
+    private static void addDependencies(JsonNode tables, Map<String, Set<String>> kinds) {
+        for (var table : tables.properties()) {
+            @Nullable String kind = dependencyKindOf(table.getKey());
+            if (kind == null) {
+                continue;
+            }
+            for (var dependency : table.getValue().properties()) {
+                String rename = CargoToml.string(dependency.getValue().path("package"));
+                String name = rename.isEmpty() ? dependency.getKey() : rename;
+                kinds.computeIfAbsent(name, k -> new LinkedHashSet<>()).add(kind);
+            }
+        }
+    }
+
+    private static @Nullable String dependencyKindOf(String table) {
+        return switch (table) {
+            case "dependencies" -> "normal";
+            case "dev-dependencies", "dev_dependencies" -> "dev";
+            case "build-dependencies", "build_dependencies" -> "build";
+            default -> null;
+        };
+    }
+
+    /** The {@code version} of a manifest's {@code [package]} section, or "" if it has none. */
+    private static String packageVersion(JsonNode manifest) {
+        return CargoToml.string(manifest.path("package").path("version"));
+    }
+
+    private Optional<Path> locateVendored(LockedPackage pkg) {
+        for (Path vendor : vendorDirectories) {
+            Path versioned = vendor.resolve(pkg.name() + "-" + pkg.version()).resolve("Cargo.toml");
+            if (Files.isRegularFile(versioned)) {
+                return Optional.of(versioned);
+            }
+            Path unversioned = vendor.resolve(pkg.name()).resolve("Cargo.toml");
+            if (Files.isRegularFile(unversioned) && pkg.version().equals(readPackageVersion(unversioned))) {
+                return Optional.of(unversioned);
+            }
+        }
+        return Optional.empty();
+    }
+
+    private Optional<Path> locateInRegistryCache(LockedPackage pkg) {
+        Path registrySources = cargoHome.resolve("registry").resolve("src");
+        String directory = pkg.name() + "-" + pkg.version();
+        return subdirectories(registrySources).stream()
+                .map(index -> index.resolve(directory).resolve("Cargo.toml"))
+                .filter(Files::isRegularFile)
+                .findFirst();
+    }
+
+    private Optional<Path> locateInGitCheckouts(LockedPackage pkg) {
+        String repository = pkg.gitRepositoryName();
+        String commit = pkg.gitCommit();
+        if (repository.isEmpty() || commit.isEmpty()) {
+            return Optional.empty();
+        }
+        Path checkouts = cargoHome.resolve("git").resolve("checkouts");
+        for (Path checkout : subdirectories(checkouts)) {
+            if (!checkout.getFileName().toString().startsWith(repository + "-")) {
+                continue;
+            }
+            for (Path revision : subdirectories(checkout)) {
+                if (!commit.startsWith(revision.getFileName().toString())) {
+                    continue;
+                }
+                Optional<Path> manifest = findPackageManifest(revision, pkg.name());
+                if (manifest.isPresent()) {
+                    return manifest;
+                }
+            }
+        }
+        return Optional.empty();
+    }
+
+    /** The manifest of package {@code name} within a git checkout, which may be a workspace of several packages. */
+    private static Optional<Path> findPackageManifest(Path checkout, String name) {
+        try (Stream<Path> stream = Files.walk(checkout, GIT_CHECKOUT_SEARCH_DEPTH)) {
+            return stream.filter(p -> p.getFileName().toString().equals("Cargo.toml"))
+                    .filter(p -> !checkout.relativize(p).toString().startsWith("target"))
+                    .filter(Files::isRegularFile)
+                    .sorted()
+                    .filter(p -> readPackageName(p).equals(name))
+                    .findFirst();
+        } catch (IOException e) {
+            return Optional.empty();
+        }
+    }
+
+    private static String readPackageName(Path manifest) {
+        try {
+            return CargoFeatures.parse(Files.readString(manifest))
+                    .map(CargoFeatures::packageName)
+                    .orElse("");
+        } catch (IOException e) {
+            return "";
+        }
+    }
+
+    private static String readPackageVersion(Path manifest) {
+        try {
+            return packageVersion(CargoToml.parse(Files.readString(manifest)));
+        } catch (IOException e) {
+            return "";
+        }
+    }
+
+    private static List<Path> subdirectories(Path directory) {
+        if (!Files.isDirectory(directory)) {
+            return List.of();
+        }
+        try (Stream<Path> stream = Files.list(directory)) {
+            return stream.filter(Files::isDirectory).sorted().toList();
+        } catch (IOException e) {
+            return List.of();
+        }
+    }
+}
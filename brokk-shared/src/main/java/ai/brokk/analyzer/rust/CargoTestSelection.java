@@ -0,0 +1,37 @@
+package ai.brokk.analyzer.rust;
+
+import ai.brokk.analyzer.rust.RustCrateLayout.CrateTarget;
+import java.util.List;
+
+/**
+ * The arguments of a {@code cargo test} invocation that runs exactly the tests of some files.
+ *
+ * @param packages the packages owning the tests, for {@code -p <package>}
+ * @param targets the target selectors of the test binaries, e.g. {@code --lib} or {@code --test <name>}
+ * @param testPaths the tests' paths within their crates, e.g. {@code shapes::tests::test_my_logic}, to be passed after
+ *     {@code -- --exact}
+ */
+public record CargoTestSelection(List<String> packages, List<String> targets, List<String> testPaths) {
+    public static final CargoTestSelection EMPTY = new CargoTestSelection(List.of(), List.of(), List.of());
+
+    public CargoTestSelection {
+        packages = List.copyOf(packages);
+        targets = List.copyOf(targets);
+        testPaths = List.copyOf(testPaths);
+    }
+
+    public boolean isEmpty() {
+        return testPaths.isEmpty();
+    }
+
+    /** The cargo option selecting {@code target}, e.g. {@code --lib}, {@code --bin app} or {@code --test api}. */
+    public static String targetSelector(CrateTarget target) {
+        return switch (target.kind()) {
+            case LIB -> "--lib";
+            case BIN -> "--bin " + target.targetName();
+            case EXAMPLE -> "--example " + target.targetName();
+            case TEST -> "--test " + target.targetName();
+            case BENCH -> "--bench " + target.targetName();
+        };
+    }
+}
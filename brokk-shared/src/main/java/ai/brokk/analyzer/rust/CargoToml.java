@@ -0,0 +1,49 @@
+package ai.brokk.analyzer.rust;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.dataformat.toml.TomlMapper;
+import java.io.IOException;
+import java.util.ArrayList;
+import java.util.List;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+/**
+ * Reads {@code Cargo.toml} and {@code Cargo.lock} files into Jackson trees. Tables become object nodes in document
+ * order, so {@code [target.'cfg(unix)'.dependencies]} is {@code target -> cfg(unix) -> dependencies} and
+ * {@code [[bin]]} is an array under {@code bin}.
+ */
+final class CargoToml {
+    private static final Logger logger = LogManager.getLogger(CargoToml.class);
+    private static final TomlMapper MAPPER = new TomlMapper();
+
+    private CargoToml() {}
+
+    /** Parses TOML text; text that is not valid TOML reads as an empty table, as cargo itself would reject it. */
+    static JsonNode parse(String text) {
+        try {
+            return MAPPER.readTree(text);
+        } catch (IOException e) {
+            logger.debug("Ignoring malformed Cargo TOML: {}", e.getMessage());
+            return MAPPER.createObjectNode();
+        }
+    }
+
+    /** The value of a string node, or "" for any other node, e.g. {@code version.workspace = true}. */
+    static String string(JsonNode node) {
+        return node.isTextual() ? node.asText() : "";
+    }
+
+    /** The string elements of an array node; other elements and non-array nodes are skipped. */
+    static List<String> strings(JsonNode node) {
+        var values = new ArrayList<String>();
+        if (node.isArray()) {
+            for (JsonNode element : node) {
+                if (element.isTextual()) {
+                    values.add(element.asText());
+                }
+            }
+        }
+        return List.copyOf(values);
+    }
+}
@@ -0,0 +1,237 @@
+package ai.brokk.analyzer.rust;
+
+import java.util.ArrayList;
+import java.util.LinkedHashSet;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+import java.util.stream.Collectors;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * A Rust configuration predicate, the argument of {@code #[cfg(...)]}: a name ({@code unix}, {@code test}), a
+ * key-value option ({@code feature = "serde"}, {@code target_os = "windows"}), or an {@code all}, {@code any} or
+ * {@code not} combination of predicates. Predicates render back to source syntax with {@link #toString()}.
+ */
+public sealed interface CfgPredicate {
+
+    /** The names and key-value options set in one build configuration. */
+    record Environment(Set<String> names, Map<String, Set<String>> values) {
+        public Environment {
+            names = Set.copyOf(names);
+            values = Map.copyOf(values);
+        }
+
+        public boolean isSet(String name, @Nullable String value) {
+            return value == null
+                    ? names.contains(name)
+                    : values.getOrDefault(name, Set.of()).contains(value);
+        }
+    }
+
+    boolean evaluate(Environment environment);
+
+    /** {@code name} or {@code name = "value"}. */
+    record Option(String name, @Nullable String value) implements CfgPredicate {
+        @Override
+        public boolean evaluate(Environment environment) {
+            return environment.isSet(name, value);
+        }
+
+        @Override
+        public String toString() {
+            return value == null ? name : name + " = \"" + value + "\"";
+        }
+    }
+
+    record All(List<CfgPredicate> predicates) implements CfgPredicate {
+        public All {
+            predicates = List.copyOf(predicates);
+        }
+
+        @Override
+        public boolean evaluate(Environment environment) {
+            return predicates.stream().allMatch(predicate -> predicate.evaluate(environment));
+        }
+
+        @Override
+        public String toString() {
+            return "all(" + join(predicates) + ")";
+        }
+    }
+
+    record Any(List<CfgPredicate> predicates) implements CfgPredicate {
+        public Any {
+            predicates = List.copyOf(predicates);
+        }
+
+        @Override
+        public boolean evaluate(Environment environment) {
+            return predicates.stream().anyMatch(predicate -> predicate.evaluate(environment));
+        }
+
+        @Override
+        public String toString() {
+            return "any(" + join(predicates) + ")";
+        }
+    }
+
+    record Not(CfgPredicate predicate) implements CfgPredicate {
+        @Override
+        public boolean evaluate(Environment environment) {
+            return !predicate.evaluate(environment);
+        }
+
+        @Override
+        public String toString() {
+            return "not(" + predicate + ")";
+        }
+    }
+
+    /**
+     * Conjunction of the given predicates, flattening nested {@code all}s and dropping repeats; a single predicate is
+     * returned as is. Returns empty when there are no predicates, i.e. the condition always holds.
+     */
+    static Optional<CfgPredicate> allOf(List<CfgPredicate> predicates) {
+        var flattened = new LinkedHashSet<CfgPredicate>();
+        for (CfgPredicate predicate : predicates) {
+            if (predicate instanceof All all) {
+                flattened.addAll(all.predicates());
+            } else {
+                flattened.add(predicate);
+            }
+        }
+        if (flattened.size() <= 1) {
+            return flattened.stream().findFirst();
+        }
+        return Optional.of(new All(List.copyOf(flattened)));
+    }
+
+    /** Disjunction of the given predicates; a single distinct predicate is returned as is. */
+    static CfgPredicate anyOf(List<CfgPredicate> predicates) {
+        var distinct = new LinkedHashSet<>(predicates);
+        return distinct.size() == 1 ? distinct.iterator().next() : new Any(List.copyOf(distinct));
+    }
+
+    /**
+     * Parses the contents of a {@code cfg(...)} attribute, e.g. {@code all(unix, not(feature = "tokio"))}. Returns
+     * empty for malformed input.
+     */
+    static Optional<CfgPredicate> parse(String text) {
+        var parser = new Parser(text);
+        @Nullable CfgPredicate predicate = parser.predicate();
+        return predicate != null && parser.atEnd() ? Optional.of(predicate) : Optional.empty();
+    }
+
+    private static String join(List<CfgPredicate> predicates) {
+        return predicates.stream().map(CfgPredicate::toString).collect(Collectors.joining(", "));
+    }
+
+    /** Recursive-descent parser over the token-tree text of a cfg predicate. */
+    final class Parser {
+        private final String text;
+        private int pos;
+
+        private Parser(String text) {
+            this.text = text;
+        }
+
+        private @Nullable CfgPredicate predicate() {
+            skipWhitespace();
+            String name = identifier();
+            if (name.isEmpty()) {
+                return null;
+            }
+            skipWhitespace();
+            if (consume('=')) {
+                skipWhitespace();
+                @Nullable String value = stringLiteral();
+                return value == null ? null : new Option(name, value);
+            }
+            if (!consume('(')) {
+                return new Option(name, null);
+            }
+            @Nullable List<CfgPredicate> arguments = arguments();
+            if (arguments == null) {
+                return null;
+            }
+            return switch (name) {
+                case "all" -> new All(arguments);
+                case "any" -> new Any(arguments);
+                case "not" -> arguments.size() == 1 ? new Not(arguments.getFirst()) : null;
+                default -> null;
+            };
+        }
+
+        /** Comma-separated predicates up to the closing parenthesis, allowing a trailing comma. */
+        private @Nullable List<CfgPredicate> arguments() {
+            var arguments = new ArrayList<CfgPredicate>();
+            while (true) {
+                skipWhitespace();
+                if (consume(')')) {
+                    return arguments;
+                }
+                @Nullable CfgPredicate argument = predicate();
+                if (argument == null) {
+                    return null;
+                }
+                arguments.add(argument);
+                skipWhitespace();
+                if (!consume(',') && !(pos < text.length() && text.charAt(pos) == ')')) {
+                    return null;
+                }
+            }
+        }
+
+        private String identifier() {
+            int start = pos;
+            if (text.startsWith("r#", pos)) {
+                pos += 2;
+            }
+            while (pos < text.length()
+                    && (Character.isLetterOrDigit(text.charAt(pos)) || text.charAt(pos) == '_')) {
+                pos++;
+            }
+            String name = text.substring(start, pos);
+            return name.startsWith("r#") ? name.substring(2) : name;
+        }
+
+        private @Nullable String stringLiteral() {
+            if (!consume('"')) {
+                return null;
+            }
+            var value = new StringBuilder();
+            while (pos < text.length()) {
+                char c = text.charAt(pos++);
+                if (c == '"') {
+                    return value.toString();
+                }
+                if (c == '\\' && pos < text.length()) {
+                    c = text.charAt(pos++);
+                }
+                value.append(c);
+            }
+            return null;
+        }
+
+        private boolean consume(char expected) {
+            if (pos < text.length() && text.charAt(pos) == expected) {
+                pos++;
+                return true;
+            }
+            return false;
+        }
+
+        private void skipWhitespace() {
+            while (pos < text.length() && Character.isWhitespace(text.charAt(pos))) {
+                pos++;
+            }
+        }
+
+        private boolean atEnd() {
+            skipWhitespace();
+            return pos == text.length();
+        }
+    }
+}
@@ -1,25 +1,66 @@
 package ai.brokk.analyzer.rust;
 
+import static ai.brokk.analyzer.ASTTraversalUtils.directNamedChildOfAnyType;
+import static ai.brokk.analyzer.ASTTraversalUtils.typeOf;
+import static ai.brokk.analyzer.rust.Constants.RUST_ITERATOR_ADAPTOR_NAMES;
+import static ai.brokk.analyzer.rust.Constants.nodeField;
 import static ai.brokk.analyzer.rust.Constants.nodeType;
 import static org.treesitter.RustNodeType.*;
 
 import ai.brokk.analyzer.CognitiveComplexitySupport;
 import ai.brokk.analyzer.SourceContent;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.Set;
+import org.treesitter.RustNodeField;
 import org.treesitter.TSNode;
 
+/**
+ * Rust cognitive complexity. On top of the usual {@code if}/loop/match scoring this counts {@code ?} propagation,
+ * {@code let ... else} divergence, {@code if let}/{@code while let} chains, labeled jumps, closures handed to iterator
+ * adaptors and async blocks, and keeps a per-construct breakdown so a score can be explained.
+ */
 public final class CognitiveComplexityAnalysis {
 
+    /** Constructs that add to a function's score, in the order a breakdown lists them. */
+    public enum Construct {
+        IF(CognitiveComplexitySupport.IF),
+        IF_LET("if-let"),
+        ELSE_IF(CognitiveComplexitySupport.ELSE_IF),
+        LOOP(CognitiveComplexitySupport.LOOP),
+        WHILE_LET("while-let"),
+        MATCH_ARM("match-arm"),
+        LET_ELSE("let-else"),
+        LOGICAL_OPERATORS(CognitiveComplexitySupport.LOGICAL_OPERATORS),
+        LET_CHAIN("let-chain"),
+        LABELED_JUMP(CognitiveComplexitySupport.LABELED_JUMP),
+        TRY_OPERATOR("try-operator"),
+        ADAPTOR_CLOSURE("iterator-closure"),
+        ASYNC_BLOCK("async-block");
+
+        private final String label;
+
+        Construct(String label) {
+            this.label = label;
+        }
+
+        public String label() {
+            return label;
+        }
+    }
+
+    private static final Set<String> LABEL_TYPES = Set.of(nodeType(LABEL));
+
     private static final CognitiveComplexitySupport.Config CONFIG = CognitiveComplexitySupport.config()
             .ifTypes(nodeType(IF_EXPRESSION))
             .loopTypes(nodeType(FOR_EXPRESSION), nodeType(WHILE_EXPRESSION), nodeType(LOOP_EXPRESSION))
-            .caseTypes(nodeType(MATCH_ARM))
             .binaryTypes(nodeType(BINARY_EXPRESSION))
             .logicalOperators("&&", "||")
             .jumpTypes(nodeType(BREAK_EXPRESSION), nodeType(CONTINUE_EXPRESSION))
+            .labeledJumpPredicate(node -> directNamedChildOfAnyType(node, LABEL_TYPES) != null)
             .namedFunctionBoundaryTypes(nodeType(FUNCTION_ITEM))
-            .anonymousFunctionTypes(nodeType(CLOSURE_EXPRESSION))
             .elseClauseTypes(nodeType(ELSE_CLAUSE))
-            .defaultCasePredicate(CognitiveComplexitySupport::isWildcardCase)
+            .nodeHook(CognitiveComplexityAnalysis::visit)
             .build();
 
     private CognitiveComplexityAnalysis() {}
@@ -27,4 +68,96 @@ public final class CognitiveComplexityAnalysis {
     public static int compute(TSNode root, SourceContent sourceContent) {
         return CognitiveComplexitySupport.compute(root, sourceContent, CONFIG);
     }
+
+    /** Returns the non-zero contributions to {@link #compute}, keyed by {@link Construct#label()}. */
+    public static Map<String, Integer> breakdown(TSNode root, SourceContent sourceContent) {
+        var scores = CognitiveComplexitySupport.breakdown(root, sourceContent, CONFIG);
+        var breakdown = new LinkedHashMap<String, Integer>();
+        for (Construct construct : Construct.values()) {
+            Integer score = scores.get(construct.label());
+            if (score != null) {
+                breakdown.put(construct.label(), score);
+            }
+        }
+        return breakdown;
+    }
+
+    /** Scores the constructs the shared rules do not know, or that Rust splits into finer ones such as if-let. */
+    private static boolean visit(
+            TSNode node,
+            int nesting,
+            boolean elseIfContinuation,
+            SourceContent sourceContent,
+            CognitiveComplexitySupport.Walker walker) {
+        String type = typeOf(node);
+        if (nodeType(IF_EXPRESSION).equals(type) && !elseIfContinuation && hasLetCondition(node)) {
+            add(walker, Construct.IF_LET, 1 + nesting);
+            walker.pushIfChildren(node, nesting);
+        } else if (nodeType(WHILE_EXPRESSION).equals(type) && hasLetCondition(node)) {
+            add(walker, Construct.WHILE_LET, 1 + nesting);
+            walker.pushNamedChildren(node, nesting + 1);
+        } else if (nodeType(MATCH_ARM).equals(type)) {
+            if (!CognitiveComplexitySupport.isWildcardCase(node, sourceContent)) {
+                add(walker, Construct.MATCH_ARM, 1 + nesting);
+            }
+            walker.pushNamedChildren(node, nesting + 1);
+        } else if (nodeType(LET_CHAIN).equals(type)) {
+            // Chains nest to the left, so only the outermost one stands for the run of `&&`s.
+            if (!nodeType(LET_CHAIN).equals(typeOf(node.getParent()))) {
+                add(walker, Construct.LET_CHAIN, 1);
+            }
+            walker.pushNamedChildren(node, nesting);
+        } else if (nodeType(LET_DECLARATION).equals(type)) {
+            TSNode alternative = node.getChildByFieldName(nodeField(RustNodeField.ALTERNATIVE));
+            if (typeOf(alternative) != null) {
+                add(walker, Construct.LET_ELSE, 1 + nesting);
+                walker.push(alternative, nesting + 1);
+            }
+            walker.pushNamedChildrenExcept(node, alternative, nesting);
+        } else if (nodeType(TRY_EXPRESSION).equals(type)) {
+            add(walker, Construct.TRY_OPERATOR, 1);
+            walker.pushNamedChildren(node, nesting);
+        } else if (nodeType(ASYNC_BLOCK).equals(type)) {
+            add(walker, Construct.ASYNC_BLOCK, 1 + nesting);
+            walker.pushNamedChildren(node, nesting + 1);
+        } else if (nodeType(CLOSURE_EXPRESSION).equals(type)) {
+            if (isIteratorAdaptorArgument(node, sourceContent)) {
+                add(walker, Construct.ADAPTOR_CLOSURE, 1 + nesting);
+            }
+            walker.pushNamedChildren(node, nesting + 1);
+        } else {
+            return false;
+        }
+        return true;
+    }
+
+    private static void add(CognitiveComplexitySupport.Walker walker, Construct construct, int increment) {
+        walker.add(construct.label(), increment);
+    }
+
+    private static boolean hasLetCondition(TSNode node) {
+        String conditionType = typeOf(node.getChildByFieldName(nodeField(RustNodeField.CONDITION)));
+        return nodeType(LET_CONDITION).equals(conditionType) || nodeType(LET_CHAIN).equals(conditionType);
+    }
+
+    /** True for a closure passed straight to a method such as {@code map} or {@code filter}. */
+    private static boolean isIteratorAdaptorArgument(TSNode closure, SourceContent sourceContent) {
+        TSNode arguments = closure.getParent();
+        if (!nodeType(ARGUMENTS).equals(typeOf(arguments))) {
+            return false;
+        }
+        TSNode call = arguments.getParent();
+        if (!nodeType(CALL_EXPRESSION).equals(typeOf(call))) {
+            return false;
+        }
+        TSNode function = call.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
+        if (nodeType(GENERIC_FUNCTION).equals(typeOf(function))) {
+            function = function.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
+        }
+        if (!nodeType(FIELD_EXPRESSION).equals(typeOf(function))) {
+            return false;
+        }
+        TSNode method = function.getChildByFieldName(nodeField(RustNodeField.FIELD));
+        return typeOf(method) != null && RUST_ITERATOR_ADAPTOR_NAMES.contains(sourceContent.substringFrom(method));
+    }
 }
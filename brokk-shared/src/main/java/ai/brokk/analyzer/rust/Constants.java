@@ -2,6 +2,8 @@ package ai.brokk.analyzer.rust;
 
 import static java.util.Objects.requireNonNull;
 
+import java.util.List;
+import java.util.Map;
 import java.util.Set;
 import org.treesitter.RustNodeField;
 import org.treesitter.RustNodeType;
@@ -27,8 +29,75 @@ public final class Constants {
 
     public static final Set<String> RUST_LOG_MACRO_NAMES = Set.of("trace", "debug", "info", "warn", "error");
     public static final Set<String> RUST_PRINT_MACRO_NAMES = Set.of("println", "eprintln");
+    public static final Set<String> RUST_UNFINISHED_MACRO_NAMES = Set.of("todo", "unimplemented");
+    // Iterator methods whose closure argument runs per element, scored like a loop body by cognitive complexity.
+    public static final Set<String> RUST_ITERATOR_ADAPTOR_NAMES = Set.of(
+            "map",
+            "filter",
+            "filter_map",
+            "flat_map",
+            "for_each",
+            "try_for_each",
+            "fold",
+            "try_fold",
+            "reduce",
+            "scan",
+            "inspect",
+            "any",
+            "all",
+            "find",
+            "find_map",
+            "position",
+            "take_while",
+            "skip_while",
+            "map_while",
+            "partition",
+            "max_by",
+            "min_by",
+            "max_by_key",
+            "min_by_key");
     public static final Set<String> RUST_PATH_KEYWORDS = Set.of("crate", "self", "super");
     public static final Set<String> SIMPLE_WRAPPER_TYPES = Set.of("Option", "Result", "Box", "Arc", "Rc");
+    // Calls that hand back the receiver's own type, or the value inside an Option/Result, when following call chains.
+    public static final Set<String> RUST_PASS_THROUGH_METHOD_NAMES = Set.of(
+            "unwrap",
+            "expect",
+            "unwrap_or",
+            "unwrap_or_else",
+            "unwrap_or_default",
+            "clone",
+            "to_owned",
+            "as_ref",
+            "as_mut",
+            "borrow",
+            "borrow_mut");
+
+    // Attribute paths marking a function run by the test harness, beyond the built-in `#[test]`.
+    public static final Set<String> RUST_TEST_ATTRIBUTE_PATHS = Set.of(
+            "test",
+            "tokio::test",
+            "async_std::test",
+            "rstest",
+            "rstest::rstest",
+            "test_case",
+            "test_case::test_case");
+    public static final String RUST_BENCH_ATTRIBUTE_PATH = "bench";
+    public static final String RUST_PROPTEST_MACRO_NAME = "proptest";
+    // Fence attributes rustdoc accepts on Rust code blocks; a block with any other word is not a doc test.
+    public static final Set<String> RUSTDOC_CODE_BLOCK_ATTRIBUTES = Set.of(
+            "rust", "ignore", "should_panic", "no_run", "compile_fail", "test_harness", "standalone_crate");
+
+    // Methods that commonly derived traits provide on the deriving type, keyed by trait name.
+    public static final Map<String, List<String>> DERIVED_TRAIT_METHODS = Map.of(
+            "Clone", List.of("clone", "clone_from"),
+            "Debug", List.of("fmt"),
+            "PartialEq", List.of("eq", "ne"),
+            "PartialOrd", List.of("partial_cmp", "lt", "le", "gt", "ge"),
+            "Ord", List.of("cmp", "max", "min", "clamp"),
+            "Hash", List.of("hash"),
+            "Serialize", List.of("serialize"));
+    public static final Map<String, List<String>> DERIVED_TRAIT_ASSOCIATED_FUNCTIONS =
+            Map.of("Default", List.of("default"), "Deserialize", List.of("deserialize"));
 
     // Test assertion smell labels (shared string values)
     // These are semantic labels used in IAnalyzer.TestAssertionSmell.
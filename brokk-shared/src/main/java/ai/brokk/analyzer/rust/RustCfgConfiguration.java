@@ -0,0 +1,89 @@
+package ai.brokk.analyzer.rust;
+
+import ai.brokk.analyzer.rust.CfgPredicate.Environment;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.attribute.FileTime;
+import java.util.HashSet;
+import java.util.Map;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.concurrent.ConcurrentHashMap;
+import org.jetbrains.annotations.Nullable;
+
+/**
+ * The cfg environments of the Cargo packages in a project under one {@link RustCfgSettings}. A file is evaluated in
+ * the environment of the package whose {@code Cargo.toml} is nearest above it, with that package's enabled features;
+ * files outside any package only see the unqualified features of the settings. An environment is read again once
+ * the modification time of its {@code Cargo.toml} changes.
+ */
+public final class RustCfgConfiguration {
+    private final RustCfgSettings settings;
+    private final Path projectRoot;
+    private final Map<Path, ManifestEnvironment> environmentsByDirectory = new ConcurrentHashMap<>();
+
+    /** An environment with the manifest it was read from, if any, and that manifest's modification time. */
+    private record ManifestEnvironment(Environment environment, @Nullable Path manifest, @Nullable FileTime modified) {
+        boolean isCurrent() {
+            return manifest == null || Objects.equals(modified, lastModified(manifest));
+        }
+    }
+
+    public RustCfgConfiguration(RustCfgSettings settings, Path projectRoot) {
+        this.settings = settings;
+        this.projectRoot = projectRoot.toAbsolutePath().normalize();
+    }
+
+    public RustCfgSettings settings() {
+        return settings;
+    }
+
+    public Environment environmentFor(Path file) {
+        Path normalized = file.toAbsolutePath().normalize();
+        Path directory = normalized.getParent() != null ? normalized.getParent() : projectRoot;
+        ManifestEnvironment cached = environmentsByDirectory.get(directory);
+        if (cached == null || !cached.isCurrent()) {
+            cached = computeEnvironment(directory);
+            environmentsByDirectory.put(directory, cached);
+        }
+        return cached.environment();
+    }
+
+    private ManifestEnvironment computeEnvironment(Path directory) {
+        for (@Nullable Path current = directory;
+                current != null && current.startsWith(projectRoot);
+                current = current.getParent()) {
+            Path manifest = current.resolve("Cargo.toml");
+            FileTime modified = lastModified(manifest);
+            if (modified == null) {
+                continue;
+            }
+            Optional<CargoFeatures> features = readFeatures(manifest);
+            if (features.isPresent()) {
+                return new ManifestEnvironment(
+                        settings.environment(features.get().enabled(settings)), manifest, modified);
+            }
+        }
+        return new ManifestEnvironment(settings.environment(new HashSet<>(settings.featuresFor(""))), null, null);
+    }
+
+    private static @Nullable FileTime lastModified(Path file) {
+        if (!Files.isRegularFile(file)) {
+            return null;
+        }
+        try {
+            return Files.getLastModifiedTime(file);
+        } catch (IOException e) {
+            return null;
+        }
+    }
+
+    private static Optional<CargoFeatures> readFeatures(Path manifest) {
+        try {
+            return CargoFeatures.parse(Files.readString(manifest));
+        } catch (IOException e) {
+            return Optional.empty();
+        }
+    }
+}
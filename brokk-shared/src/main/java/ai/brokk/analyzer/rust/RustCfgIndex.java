@@ -0,0 +1,148 @@
+package ai.brokk.analyzer.rust;
+
+import static ai.brokk.analyzer.rust.Constants.COMMENT_NODE_TYPES;
+import static ai.brokk.analyzer.rust.Constants.nodeField;
+import static ai.brokk.analyzer.rust.Constants.nodeType;
+import static org.treesitter.RustNodeType.*;
+
+import ai.brokk.analyzer.SourceContent;
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import org.jetbrains.annotations.Nullable;
+import org.treesitter.RustNodeField;
+import org.treesitter.TSNode;
+
+/**
+ * The {@code #[cfg(...)]} conditions found in one Rust file.
+ *
+ * @param fileConditions predicates of the file's inner {@code #![cfg(...)]} attributes, which apply to the whole file
+ * @param spans the source spans of items carrying an outer {@code #[cfg(...)]} attribute, from the attribute to the
+ *     end of the item; an inner attribute of an inline module or function covers that item
+ * @param moduleConditions the conditions of out-of-line {@code mod name;} declarations, including those of enclosing
+ *     inline modules, by module path relative to the file (e.g. {@code a.b} for {@code mod b;} inside {@code mod a})
+ */
+public record RustCfgIndex(
+        List<CfgPredicate> fileConditions, List<ConditionalSpan> spans, Map<String, CfgPredicate> moduleConditions) {
+    public static final RustCfgIndex EMPTY = new RustCfgIndex(List.of(), List.of(), Map.of());
+
+    /** A byte range compiled only when {@code predicate} holds. */
+    public record ConditionalSpan(int startByte, int endByte, CfgPredicate predicate) {}
+
+    public RustCfgIndex {
+        fileConditions = List.copyOf(fileConditions);
+        spans = List.copyOf(spans);
+        moduleConditions = Map.copyOf(moduleConditions);
+    }
+
+    public static RustCfgIndex of(TSNode root, SourceContent source) {
+        var fileConditions = new ArrayList<CfgPredicate>();
+        var spans = new ArrayList<ConditionalSpan>();
+        var moduleStarts = new HashMap<String, Integer>();
+        collect(root, List.of(), source, fileConditions, spans, moduleStarts);
+        if (fileConditions.isEmpty() && spans.isEmpty()) {
+            return EMPTY;
+        }
+        var modules = new HashMap<String, CfgPredicate>();
+        moduleStarts.forEach((path, startByte) -> CfgPredicate.allOf(conditionsAt(spans, startByte))
+                .ifPresent(predicate -> modules.put(path, predicate)));
+        return new RustCfgIndex(fileConditions, spans, modules);
+    }
+
+    /** The predicates of the conditional spans containing {@code startByte}, outermost first. */
+    public List<CfgPredicate> conditionsAt(int startByte) {
+        return conditionsAt(spans, startByte);
+    }
+
+    private static List<CfgPredicate> conditionsAt(List<ConditionalSpan> spans, int startByte) {
+        return spans.stream()
+                .filter(span -> span.startByte() <= startByte && startByte < span.endByte())
+                .map(ConditionalSpan::predicate)
+                .toList();
+    }
+
+    private static void collect(
+            TSNode node,
+            List<String> inlineModules,
+            SourceContent source,
+            List<CfgPredicate> fileConditions,
+            List<ConditionalSpan> spans,
+            Map<String, Integer> moduleStarts) {
+        for (TSNode child : node.getNamedChildren()) {
+            String type = child.getType();
+            List<String> childModules = inlineModules;
+            if (nodeType(INNER_ATTRIBUTE_ITEM).equals(type)) {
+                Optional<CfgPredicate> predicate = cfgPredicateOf(child, source);
+                if (predicate.isPresent()) {
+                    @Nullable TSNode owner = nodeType(SOURCE_FILE).equals(node.getType()) ? null : node.getParent();
+                    if (owner == null) {
+                        fileConditions.add(predicate.get());
+                    } else {
+                        spans.add(new ConditionalSpan(owner.getStartByte(), owner.getEndByte(), predicate.get()));
+                    }
+                }
+            } else if (nodeType(ATTRIBUTE_ITEM).equals(type)) {
+                Optional<CfgPredicate> predicate = cfgPredicateOf(child, source);
+                @Nullable TSNode item = attributedItem(child);
+                if (predicate.isPresent() && item != null) {
+                    spans.add(new ConditionalSpan(child.getStartByte(), item.getEndByte(), predicate.get()));
+                }
+            } else if (nodeType(MOD_ITEM).equals(type)) {
+                Optional<String> name = moduleName(child, source);
+                if (name.isPresent() && child.getChildByFieldName(nodeField(RustNodeField.BODY)) == null) {
+                    moduleStarts.putIfAbsent(modulePath(inlineModules, name.get()), child.getStartByte());
+                } else if (name.isPresent()) {
+                    childModules = new ArrayList<>(inlineModules);
+                    childModules.add(name.get());
+                }
+            }
+            collect(child, childModules, source, fileConditions, spans, moduleStarts);
+        }
+    }
+
+    private static String modulePath(List<String> inlineModules, String name) {
+        return inlineModules.isEmpty() ? name : String.join(".", inlineModules) + "." + name;
+    }
+
+    /** The item an outer attribute applies to: the next sibling that is not another attribute or a comment. */
+    private static @Nullable TSNode attributedItem(TSNode attribute) {
+        TSNode current = attribute.getNextNamedSibling();
+        while (current != null
+                && (nodeType(ATTRIBUTE_ITEM).equals(current.getType())
+                        || COMMENT_NODE_TYPES.contains(current.getType()))) {
+            current = current.getNextNamedSibling();
+        }
+        return current;
+    }
+
+    private static Optional<String> moduleName(TSNode item, SourceContent source) {
+        TSNode name = item.getChildByFieldName(nodeField(RustNodeField.NAME));
+        if (name == null) {
+            return Optional.empty();
+        }
+        String text = source.substringFrom(name).strip();
+        return Optional.of(text.startsWith("r#") ? text.substring(2) : text);
+    }
+
+    /** The predicate of a {@code #[cfg(...)]} or {@code #![cfg(...)]} attribute item. */
+    private static Optional<CfgPredicate> cfgPredicateOf(TSNode attributeItem, SourceContent source) {
+        for (TSNode attribute : attributeItem.getNamedChildren()) {
+            if (!nodeType(ATTRIBUTE).equals(attribute.getType()) || attribute.getNamedChildCount() == 0) {
+                continue;
+            }
+            TSNode path = attribute.getNamedChild(0);
+            if (!"cfg".equals(source.substringFrom(path).strip())) {
+                return Optional.empty();
+            }
+            String arguments = source.substringFromBytes(path.getEndByte(), attribute.getEndByte())
+                    .strip();
+            if (arguments.length() < 2 || !arguments.startsWith("(") || !arguments.endsWith(")")) {
+                return Optional.empty();
+            }
+            return CfgPredicate.parse(arguments.substring(1, arguments.length() - 1));
+        }
+        return Optional.empty();
+    }
+}
@@ -0,0 +1,140 @@
+package ai.brokk.analyzer.rust;
+
+import ai.brokk.analyzer.Languages;
+import ai.brokk.analyzer.rust.CfgPredicate.Environment;
+import ai.brokk.project.ICoreProject;
+import com.fasterxml.jackson.core.JsonProcessingException;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.HashSet;
+import java.util.List;
+import java.util.Locale;
+import java.util.Map;
+import java.util.Set;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+/**
+ * The build configuration that Rust {@code #[cfg(...)]} conditions are evaluated against, mirroring the options of
+ * {@code cargo build}. A project stores them as its Rust {@linkplain ICoreProject#getLanguageSettings language
+ * settings}.
+ *
+ * @param defaultFeatures whether each package's {@code default} feature is enabled ({@code --no-default-features}
+ *     clears it)
+ * @param features extra features to enable ({@code --features}); {@code package/feature} enables a feature of one
+ *     package only
+ * @param targetOs the {@code target_os} to build for, e.g. {@code linux} or {@code windows}; blank means the host's
+ * @param test whether {@code cfg(test)} is set, as when building unit tests
+ * @param cfgs extra {@code --cfg} options, as {@code name} or {@code name="value"}
+ */
+public record RustCfgSettings(
+        boolean defaultFeatures, List<String> features, String targetOs, boolean test, List<String> cfgs) {
+    /** A plain {@code cargo build} for the host with each package's default features. */
+    public static final RustCfgSettings DEFAULT = new RustCfgSettings(true, List.of(), "", false, List.of());
+
+    private static final Logger logger = LogManager.getLogger(RustCfgSettings.class);
+    private static final ObjectMapper MAPPER = new ObjectMapper();
+
+    public RustCfgSettings {
+        features = List.copyOf(features);
+        cfgs = List.copyOf(cfgs);
+    }
+
+    /**
+     * The settings serialized as {@code json} by {@link #store}, i.e. a project's stored Rust language settings, or
+     * {@link #DEFAULT} if it is blank or invalid.
+     */
+    public static RustCfgSettings parse(String json) {
+        if (json.isBlank()) {
+            return DEFAULT;
+        }
+        try {
+            return MAPPER.readValue(json, RustCfgSettings.class);
+        } catch (JsonProcessingException e) {
+            logger.error("Failed to deserialize Rust cfg settings from JSON: {}", json, e);
+            return DEFAULT;
+        }
+    }
+
+    /** Stores {@code settings} as the Rust settings of {@code project}. */
+    public static void store(ICoreProject project, RustCfgSettings settings) {
+        try {
+            project.setLanguageSettings(Languages.RUST, MAPPER.writeValueAsString(settings));
+        } catch (JsonProcessingException e) {
+            throw new RuntimeException("Failed to serialize Rust cfg settings", e);
+        }
+    }
+
+    /** The requested features that apply to {@code packageName}, with any {@code package/} prefix removed. */
+    public List<String> featuresFor(String packageName) {
+        var result = new ArrayList<String>();
+        for (String feature : features) {
+            int slash = feature.indexOf('/');
+            if (slash < 0) {
+                result.add(feature);
+            } else if (feature.substring(0, slash).equals(packageName)) {
+                result.add(feature.substring(slash + 1));
+            }
+        }
+        return List.copyOf(result);
+    }
+
+    /** The cfg environment of a crate compiled under these settings with {@code enabledFeatures}. */
+    public Environment environment(Set<String> enabledFeatures) {
+        var names = new HashSet<String>();
+        var values = new HashMap<String, Set<String>>();
+        String os = targetOs.isBlank() ? hostOs() : targetOs.strip().toLowerCase(Locale.ROOT);
+        String family = "windows".equals(os) ? "windows" : "unix";
+        names.add(family);
+        names.add("debug_assertions");
+        if (test) {
+            names.add("test");
+        }
+        values.put("target_os", new HashSet<>(Set.of(os)));
+        values.put("target_family", new HashSet<>(Set.of(family)));
+        values.put("target_arch", new HashSet<>(Set.of(hostArch())));
+        values.put("target_pointer_width", new HashSet<>(Set.of("64")));
+        values.put("target_endian", new HashSet<>(Set.of("little")));
+        values.put("panic", new HashSet<>(Set.of("unwind")));
+        values.put("feature", new HashSet<>(enabledFeatures));
+        for (String cfg : cfgs) {
+            int eq = cfg.indexOf('=');
+            if (eq < 0) {
+                names.add(cfg.strip());
+                continue;
+            }
+            String value = cfg.substring(eq + 1).strip();
+            if (value.length() >= 2 && value.startsWith("\"") && value.endsWith("\"")) {
+                value = value.substring(1, value.length() - 1);
+            }
+            values.computeIfAbsent(cfg.substring(0, eq).strip(), k -> new HashSet<>())
+                    .add(value);
+        }
+        values.replaceAll((key, set) -> Set.copyOf(set));
+        return new Environment(names, values);
+    }
+
+    private static String hostOs() {
+        String name = System.getProperty("os.name", "").toLowerCase(Locale.ROOT);
+        if (name.startsWith("windows")) {
+            return "windows";
+        }
+        if (name.startsWith("mac") || name.startsWith("darwin")) {
+            return "macos";
+        }
+        if (name.contains("freebsd")) {
+            return "freebsd";
+        }
+        return "linux";
+    }
+
+    private static String hostArch() {
+        String arch = System.getProperty("os.arch", "").toLowerCase(Locale.ROOT);
+        return switch (arch) {
+            case "amd64", "x86_64" -> "x86_64";
+            case "aarch64", "arm64" -> "aarch64";
+            default -> arch;
+        };
+    }
+}
@@ -0,0 +1,343 @@
+package ai.brokk.analyzer.rust;
+
+import static ai.brokk.analyzer.rust.Constants.COMMENT_NODE_TYPES;
+import static ai.brokk.analyzer.rust.Constants.nodeField;
+import static ai.brokk.analyzer.rust.Constants.nodeType;
+import static org.treesitter.RustNodeType.*;
+
+import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.SourceContent;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Collection;
+import java.util.Comparator;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Locale;
+import java.util.Map;
+import java.util.Optional;
+import java.util.stream.Stream;
+import org.jetbrains.annotations.Nullable;
+import org.treesitter.RustNodeField;
+import org.treesitter.TSNode;
+
+/**
+ * The crate targets of the Cargo packages in a project, used to map Rust source files to module paths.
+ *
+ * <p>A file's module path is the crate name of the target whose root directory contains it, followed by the file's
+ * directories relative to that root and its stem ({@code mod.rs} names its directory). For example
+ * {@code crates/foo/src/bar/baz.rs} in package {@code foo} maps to {@code foo.bar.baz}. Targets come from the
+ * manifests plus Cargo's target auto-discovery conventions; cargo itself is never run.
+ */
+public final class RustCrateLayout {
+    public static final RustCrateLayout EMPTY = new RustCrateLayout(List.of());
+
+    public enum TargetKind {
+        LIB,
+        BIN,
+        EXAMPLE,
+        TEST,
+        BENCH
+    }
+
+    /**
+     * A compilation target: its package, its name as Cargo selects it (e.g. {@code --test name}), its kind and its root
+     * file.
+     */
+    public record CrateTarget(String packageName, String targetName, TargetKind kind, Path rootFile) {
+        /** The crate name as used in paths, with {@code -} replaced by {@code _}. */
+        public String crateName() {
+            return crateNameOf(targetName);
+        }
+
+        public Path moduleRoot() {
+            Path parent = rootFile.getParent();
+            return parent != null ? parent : rootFile;
+        }
+    }
+
+    /**
+     * An out-of-line {@code mod name;} declaration, with the value of its {@code #[path = "..."]} attribute if it has
+     * one.
+     *
+     * @param inlineModules the names of the inline {@code mod a { ... }} blocks enclosing the declaration, outermost
+     *     first; empty for declarations at the file level
+     */
+    public record ModuleDeclaration(List<String> inlineModules, String name, @Nullable String path) {
+        public ModuleDeclaration {
+            inlineModules = List.copyOf(inlineModules);
+        }
+
+        /** The declared module's path relative to the declaring file's module, e.g. {@code a.b} for {@code b}. */
+        public String relativeModulePath() {
+            return inlineModules.isEmpty() ? name : String.join(".", inlineModules) + "." + name;
+        }
+    }
+
+    /**
+     * The file and the relative module path ({@link ModuleDeclaration#relativeModulePath()}) of the {@code mod}
+     * declaration that loads a module file.
+     */
+    public record ModuleFileOwner(ProjectFile declaringFile, String moduleName) {}
+
+    private final List<CrateTarget> targets;
+
+    private RustCrateLayout(List<CrateTarget> targets) {
+        this.targets = List.copyOf(targets);
+    }
+
+    public static RustCrateLayout of(List<CrateTarget> targets) {
+        return targets.isEmpty() ? EMPTY : new RustCrateLayout(targets);
+    }
+
+    /**
+     * Builds the layout from {@code Cargo.toml} files without running cargo: explicit {@code [lib]}, {@code [[bin]]},
+     * {@code [[example]]}, {@code [[test]]} and {@code [[bench]]} sections plus the conventionally discovered
+     * targets ({@code src/lib.rs}, {@code src/main.rs}, {@code src/bin/}, {@code examples/}, {@code tests/},
+     * {@code benches/}).
+     */
+    public static RustCrateLayout fromManifests(Collection<Path> manifests) {
+        var targets = new ArrayList<CrateTarget>();
+        for (Path manifest : manifests) {
+            try {
+                targets.addAll(targetsOfManifest(manifest, Files.readString(manifest)));
+            } catch (IOException e) {
+                // Unreadable manifests contribute no targets.
+            }
+        }
+        return of(targets);
+    }
+
+    /** Whether {@code file} is a {@code Cargo.toml} or {@code Cargo.lock}, the inputs the layout is built from. */
+    public static boolean isCargoManifest(ProjectFile file) {
+        String name = file.getFileName();
+        return name.equals("Cargo.toml") || name.equals("Cargo.lock");
+    }
+
+    public boolean isEmpty() {
+        return targets.isEmpty();
+    }
+
+    public List<CrateTarget> targets() {
+        return targets;
+    }
+
+    /**
+     * The target owning {@code file}: the one with the deepest module root containing it, preferring the library when
+     * several targets share that root (a package's {@code src/lib.rs} and {@code src/main.rs}).
+     */
+    public Optional<CrateTarget> targetFor(Path file) {
+        Path normalized = file.normalize();
+        return targets.stream()
+                .filter(target -> target.rootFile().equals(normalized))
+                .findFirst()
+                .or(() -> targets.stream()
+                        .filter(target -> normalized.startsWith(target.moduleRoot()))
+                        .max(Comparator.<CrateTarget>comparingInt(
+                                        target -> target.moduleRoot().getNameCount())
+                                .thenComparing(target -> target.kind() == TargetKind.LIB)));
+    }
+
+    /** The dotted module path of {@code file}, prefixed with its crate name; empty when no target contains it. */
+    public Optional<String> modulePathOf(Path file) {
+        Path normalized = file.normalize();
+        return targetFor(normalized).map(target -> {
+            if (target.rootFile().equals(normalized)) {
+                return target.crateName();
+            }
+            var segments = new ArrayList<String>();
+            segments.add(target.crateName());
+            Path relative = target.moduleRoot().relativize(normalized);
+            for (int i = 0; i < relative.getNameCount() - 1; i++) {
+                segments.add(relative.getName(i).toString());
+            }
+            String stem = stemOf(normalized);
+            if (!"mod".equals(stem)) {
+                segments.add(stem);
+            }
+            return String.join(".", segments);
+        });
+    }
+
+    /**
+     * Candidate files for the module with dotted path {@code fqn}, library targets first: the crate root itself, or
+     * {@code <module root>/a/b.rs} and {@code <module root>/a/b/mod.rs}.
+     */
+    public List<Path> moduleFileCandidates(String fqn) {
+        if (fqn.isBlank()) {
+            return List.of();
+        }
+        List<String> segments = List.of(fqn.split("\\."));
+        String crateName = segments.getFirst();
+        List<String> modulePath = segments.subList(1, segments.size());
+        var candidates = new ArrayList<Path>();
+        targets.stream()
+                .filter(target -> target.crateName().equals(crateName))
+                .sorted(Comparator.comparing(target -> target.kind() != TargetKind.LIB))
+                .forEach(target -> {
+                    if (modulePath.isEmpty()) {
+                        candidates.add(target.rootFile());
+                        return;
+                    }
+                    Path dir = target.moduleRoot();
+                    for (String segment : modulePath.subList(0, modulePath.size() - 1)) {
+                        dir = dir.resolve(segment);
+                    }
+                    candidates.add(dir.resolve(modulePath.getLast() + ".rs"));
+                    candidates.add(dir.resolve(modulePath.getLast()).resolve("mod.rs"));
+                });
+        return candidates.stream().distinct().toList();
+    }
+
+    /**
+     * The out-of-line {@code mod} declarations of a parsed Rust file, including those nested in inline module blocks.
+     * Declarations carrying a {@code #[path = "..."]} attribute are reported with that path.
+     */
+    public static List<ModuleDeclaration> moduleDeclarations(TSNode root, SourceContent source) {
+        var declarations = new ArrayList<ModuleDeclaration>();
+        collectModuleDeclarations(root, List.of(), source, declarations);
+        return List.copyOf(declarations);
+    }
+
+    private static void collectModuleDeclarations(
+            TSNode node, List<String> inlineModules, SourceContent source, List<ModuleDeclaration> declarations) {
+        for (TSNode child : node.getNamedChildren()) {
+            if (!nodeType(MOD_ITEM).equals(child.getType())) {
+                continue;
+            }
+            TSNode nameNode = child.getChildByFieldName(nodeField(RustNodeField.NAME));
+            if (nameNode == null) {
+                continue;
+            }
+            String name = source.substringFrom(nameNode).strip();
+            if (name.startsWith("r#")) {
+                name = name.substring(2);
+            }
+            TSNode body = child.getChildByFieldName(nodeField(RustNodeField.BODY));
+            if (body != null) {
+                var nested = new ArrayList<>(inlineModules);
+                nested.add(name);
+                collectModuleDeclarations(body, nested, source, declarations);
+            } else {
+                declarations.add(new ModuleDeclaration(inlineModules, name, pathAttributeOf(child, source)));
+            }
+        }
+    }
+
+    /** The value of the {@code #[path = "..."]} attribute among the outer attributes preceding {@code item}. */
+    private static @Nullable String pathAttributeOf(TSNode item, SourceContent source) {
+        TSNode current = item.getPrevNamedSibling();
+        while (current != null
+                && (nodeType(ATTRIBUTE_ITEM).equals(current.getType())
+                        || COMMENT_NODE_TYPES.contains(current.getType()))) {
+            if (nodeType(ATTRIBUTE_ITEM).equals(current.getType())) {
+                for (TSNode attribute : current.getNamedChildren()) {
+                    if (!nodeType(ATTRIBUTE).equals(attribute.getType()) || attribute.getNamedChildCount() == 0) {
+                        continue;
+                    }
+                    TSNode value = attribute.getChildByFieldName(nodeField(RustNodeField.VALUE));
+                    if ("path".equals(source.substringFrom(attribute.getNamedChild(0)).strip())
+                            && value != null
+                            && nodeType(STRING_LITERAL).equals(value.getType())) {
+                        String literal = source.substringFrom(value).strip();
+                        return literal.substring(1, literal.length() - 1);
+                    }
+                }
+            }
+            current = current.getPrevNamedSibling();
+        }
+        return null;
+    }
+
+    static List<CrateTarget> targetsOfManifest(Path manifest, String manifestText) {
+        Path packageDir = manifest.toAbsolutePath().normalize().getParent();
+        if (packageDir == null) {
+            return List.of();
+        }
+        JsonNode toml = CargoToml.parse(manifestText);
+        String packageName = CargoToml.string(toml.path("package").path("name"));
+        if (packageName.isBlank()) {
+            // Virtual workspace manifests declare no targets of their own.
+            return List.of();
+        }
+
+        var targets = new LinkedHashMap<Path, CrateTarget>();
+        if (toml.path("lib").isObject()) {
+            addDeclaredTarget(packageName, packageDir, TargetKind.LIB, toml.path("lib"), targets);
+        }
+        for (TargetKind kind : List.of(TargetKind.BIN, TargetKind.EXAMPLE, TargetKind.TEST, TargetKind.BENCH)) {
+            // [[bin]], [[example]], [[test]] and [[bench]] are arrays of tables.
+            for (JsonNode section : toml.path(kind.name().toLowerCase(Locale.ROOT))) {
+                addDeclaredTarget(packageName, packageDir, kind, section, targets);
+            }
+        }
+
+        Path lib = packageDir.resolve("src").resolve("lib.rs");
+        if (Files.isRegularFile(lib) && targets.values().stream().noneMatch(t -> t.kind() == TargetKind.LIB)) {
+            targets.putIfAbsent(lib, new CrateTarget(packageName, packageName, TargetKind.LIB, lib));
+        }
+        Path main = packageDir.resolve("src").resolve("main.rs");
+        if (Files.isRegularFile(main)) {
+            targets.putIfAbsent(main, new CrateTarget(packageName, packageName, TargetKind.BIN, main));
+        }
+        discoverTargets(packageName, packageDir.resolve("src").resolve("bin"), TargetKind.BIN, targets);
+        discoverTargets(packageName, packageDir.resolve("examples"), TargetKind.EXAMPLE, targets);
+        discoverTargets(packageName, packageDir.resolve("tests"), TargetKind.TEST, targets);
+        discoverTargets(packageName, packageDir.resolve("benches"), TargetKind.BENCH, targets);
+        return List.copyOf(targets.values());
+    }
+
+    private static void addDeclaredTarget(
+            String packageName, Path packageDir, TargetKind kind, JsonNode section, Map<Path, CrateTarget> targets) {
+        String declaredName = CargoToml.string(section.path("name"));
+        String name = declaredName.isEmpty() ? packageName : declaredName;
+        String path = CargoToml.string(section.path("path"));
+        Path rootFile = path.isEmpty()
+                ? defaultTargetRoot(packageDir, kind, name)
+                : packageDir.resolve(path).normalize();
+        targets.putIfAbsent(rootFile, new CrateTarget(packageName, name, kind, rootFile));
+    }
+
+    private static Path defaultTargetRoot(Path packageDir, TargetKind kind, String name) {
+        return switch (kind) {
+            case LIB -> packageDir.resolve("src").resolve("lib.rs");
+            case BIN -> packageDir.resolve("src").resolve("bin").resolve(name + ".rs");
+            case EXAMPLE -> packageDir.resolve("examples").resolve(name + ".rs");
+            case TEST -> packageDir.resolve("tests").resolve(name + ".rs");
+            case BENCH -> packageDir.resolve("benches").resolve(name + ".rs");
+        };
+    }
+
+    /** Cargo's auto-discovery: each {@code .rs} file in {@code dir}, and each subdirectory with a {@code main.rs}. */
+    private static void discoverTargets(
+            String packageName, Path dir, TargetKind kind, Map<Path, CrateTarget> targets) {
+        if (!Files.isDirectory(dir)) {
+            return;
+        }
+        try (Stream<Path> entries = Files.list(dir)) {
+            entries.sorted().forEach(entry -> {
+                String fileName = entry.getFileName().toString();
+                if (Files.isRegularFile(entry) && fileName.endsWith(".rs")) {
+                    targets.putIfAbsent(entry, new CrateTarget(packageName, stemOf(entry), kind, entry));
+                } else if (Files.isRegularFile(entry.resolve("main.rs"))) {
+                    Path main = entry.resolve("main.rs");
+                    targets.putIfAbsent(main, new CrateTarget(packageName, fileName, kind, main));
+                }
+            });
+        } catch (IOException e) {
+            // An unreadable directory contributes no targets.
+        }
+    }
+
+    private static String crateNameOf(String targetName) {
+        return targetName.replace('-', '_');
+    }
+
+    private static String stemOf(Path file) {
+        String fileName = file.getFileName().toString();
+        return fileName.endsWith(".rs") ? fileName.substring(0, fileName.length() - 3) : fileName;
+    }
+}
@@ -1,5 +1,10 @@
 package ai.brokk.analyzer.rust;
 
+import static ai.brokk.analyzer.ASTTraversalUtils.sameRange;
+import static ai.brokk.analyzer.rust.Constants.COMMENT_NODE_TYPES;
+import static ai.brokk.analyzer.rust.Constants.DERIVED_TRAIT_ASSOCIATED_FUNCTIONS;
+import static ai.brokk.analyzer.rust.Constants.DERIVED_TRAIT_METHODS;
+import static ai.brokk.analyzer.rust.Constants.RUST_PASS_THROUGH_METHOD_NAMES;
 import static ai.brokk.analyzer.rust.Constants.RUST_PATH_KEYWORDS;
 import static ai.brokk.analyzer.rust.Constants.SIMPLE_WRAPPER_TYPES;
 import static ai.brokk.analyzer.rust.Constants.nodeField;
@@ -31,11 +36,29 @@ import java.util.List;
 import java.util.Map;
 import java.util.Optional;
 import java.util.Set;
+import java.util.regex.Pattern;
 import org.jetbrains.annotations.Nullable;
 import org.treesitter.RustNodeField;
 import org.treesitter.TSNode;
 
 public final class RustExportUsageExtractor {
+    /** Maximum number of chained {@code pub use ...::*} hops followed when expanding a glob import. */
+    static final int MAX_GLOB_REEXPORT_DEPTH = 8;
+
+    /** Glob imports whose target module exports more names than this are not expanded. */
+    static final int MAX_GLOB_IMPORT_NAMES = 512;
+
+    private static final Pattern WHITESPACE = Pattern.compile("\\s+");
+    private static final Set<String> CALL_CHAIN_TYPES = Set.of(
+            nodeType(CALL_EXPRESSION),
+            nodeType(TRY_EXPRESSION),
+            nodeType(AWAIT_EXPRESSION),
+            nodeType(FIELD_EXPRESSION));
+    private static final Set<String> DERIVE_PATH_SEGMENT_TYPES =
+            Set.of(nodeType(IDENTIFIER), nodeType(CRATE), nodeType(SELF), nodeType(SUPER));
+    private static final Set<String> STRUCT_FIELD_SITE_TYPES =
+            Set.of(nodeType(STRUCT_EXPRESSION), nodeType(STRUCT_PATTERN), nodeType(TUPLE_STRUCT_PATTERN));
+
     private RustExportUsageExtractor() {}
 
     public record InlineModuleResolution(ProjectFile file, String exportPrefix, boolean externallyVisible) {}
@@ -48,6 +71,9 @@ public final class RustExportUsageExtractor {
 
     public record RustTypeRef(List<String> segments) {}
 
+    /** A trait named in the {@code #[derive(...)]} of a struct, enum or union, as written: {@code serde::Serialize}. */
+    public record DerivedTrait(String typeName, String traitPath) {}
+
     public record RustUsageFacts(
             Set<ReferenceCandidate> referenceCandidates,
             Set<ResolvedReceiverCandidate> receiverCandidates,
@@ -61,6 +87,15 @@ public final class RustExportUsageExtractor {
 
     public record RustUsageCandidateIndex(Map<String, Set<ProjectFile>> filesByToken) {}
 
+    /**
+     * Files holding trait impls per implementing type key, the keys of all traits implemented in the crate, and the
+     * heritage index used to match usages, in which each trait impl unit stands for both its type and its trait.
+     */
+    public record TraitImplIndex(
+            Map<String, List<ProjectFile>> implFilesByType,
+            Set<String> traitKeys,
+            Map<String, Set<String>> usageHeritage) {}
+
     public static ExportIndex computeExportIndex(
             RustAnalyzer analyzer, ProjectFile file, TSNode root, SourceContent source) {
         var exports = new LinkedHashMap<String, ExportIndex.ExportEntry>();
@@ -79,15 +114,30 @@ public final class RustExportUsageExtractor {
             SourceContent source,
             Set<String> localTopLevelNames) {
         var bindings = new LinkedHashMap<String, ImportBinder.ImportBinding>();
+        // Macros reach a file textually rather than through `use`, so bind them first and let explicit imports win.
+        bindExportedMacroInvocations(analyzer, file, root, source, localTopLevelNames, bindings);
+        bindMacroUseModules(analyzer, file, root, source, List.of("self"), localTopLevelNames, bindings);
+        bindExternCrates(root, source, localTopLevelNames, bindings);
+        var globBindings = new LinkedHashMap<String, GlobBinding>();
+        var ambiguousGlobNames = new HashSet<String>();
         collectUseDeclarations(root).stream()
                 .flatMap(use -> useSpecsOf(use, source).stream())
+                .map(spec -> withExportedMacroPath(analyzer, spec))
                 .forEach(spec -> {
                     if (spec.wildcard()) {
-                        expandWildcardImport(analyzer, file, spec, localTopLevelNames, bindings);
+                        expandWildcardImport(
+                                analyzer, file, spec, localTopLevelNames, globBindings, ambiguousGlobNames);
                         return;
                     }
                     bindUseSpec(analyzer, file, spec, localTopLevelNames, bindings);
                 });
+        // As in rustc, local items and explicit imports shadow glob imports wherever they appear in the file, and a
+        // name reached through two globs with different origins is ambiguous and binds nothing.
+        globBindings.forEach((name, glob) -> {
+            if (!ambiguousGlobNames.contains(name)) {
+                bindings.putIfAbsent(name, glob.binding());
+            }
+        });
         return new ImportBinder(Map.copyOf(bindings));
     }
 
@@ -130,6 +180,10 @@ public final class RustExportUsageExtractor {
                 .orElse(CodeUnit.module(file, analyzer.packageNameOf(file), "_module_"));
         var candidates = new LinkedHashSet<ReferenceCandidate>();
         collectUsageCandidates(root, analyzer, file, source, binder, localExportNames, fallbackEnclosing, candidates);
+        var localMacroNames = new LinkedHashSet<String>();
+        collectMacroDefinitions(root, source, false, localMacroNames);
+        collectLocalMacroInvocationCandidates(
+                root, analyzer, file, source, localMacroNames, fallbackEnclosing, candidates);
         collectLocalFunctionCallCandidates(
                 root,
                 analyzer,
@@ -140,14 +194,24 @@ public final class RustExportUsageExtractor {
                 new ArrayDeque<>(),
                 candidates);
         var events = new ArrayList<LocalUsageEvent>();
-        Map<String, List<String>> typeAliases = collectTypeAliases(root, source);
-        collectLocalUsageEvents(root, analyzer, file, source, binder, typeAliases, fallbackEnclosing, null, events);
-        Set<ResolvedReceiverCandidate> receiverCandidates = LocalUsageInference.infer(events);
+        collectLocalUsageEvents(root, analyzer, file, source, binder, fallbackEnclosing, null, events);
+        Set<ResolvedReceiverCandidate> receiverCandidates = LocalUsageInference.infer(
+                events, (receiver, memberName) -> analyzer.memberValueReceivers(file, receiver, memberName));
         var candidateTokens = new LinkedHashSet<>(usageCandidateTokens(candidates, receiverCandidates));
         localTopLevelFunctionNames.forEach(token -> addToken(candidateTokens, token));
         return new RustUsageFacts(Set.copyOf(candidates), Set.copyOf(receiverCandidates), Set.copyOf(candidateTokens));
     }
 
+    /**
+     * Returns the names of {@code #[macro_export]} macros defined anywhere in the file, including inside inline
+     * modules: exported macros live at the crate root regardless of where they are written.
+     */
+    public static Set<String> computeExportedMacroNames(TSNode root, SourceContent source) {
+        var names = new LinkedHashSet<String>();
+        collectMacroDefinitions(root, source, true, names);
+        return Set.copyOf(names);
+    }
+
     public static Map<AssociatedFunctionKey, Boolean> computeSelfLikeAssociatedFunctions(
             TSNode root, SourceContent source) {
         var functions = new LinkedHashMap<AssociatedFunctionKey, Boolean>();
@@ -161,6 +225,17 @@ public final class RustExportUsageExtractor {
         return Map.copyOf(fields);
     }
 
+    /**
+     * Returns the declared return types of the file's functions, keyed by impl type for methods and associated
+     * functions and by an empty owner for free functions. Opaque returns such as {@code impl Future} are left out.
+     */
+    public static Map<AssociatedFunctionKey, RustTypeRef> computeFunctionReturnTypes(
+            TSNode root, SourceContent source) {
+        var returnTypes = new LinkedHashMap<AssociatedFunctionKey, RustTypeRef>();
+        collectFunctionReturnTypes(root, source, "", returnTypes);
+        return Map.copyOf(returnTypes);
+    }
+
     private static void collectExports(
             TSNode node,
             RustAnalyzer analyzer,
@@ -179,6 +254,17 @@ public final class RustExportUsageExtractor {
             collectReexports(node, analyzer, file, source, exports, reexportStars);
             return;
         }
+        if (nodeType(MACRO_DEFINITION).equals(type)) {
+            // macro_rules! has no visibility modifier: #[macro_export] places the macro at the crate root, otherwise
+            // it is reachable from its module through #[macro_use] or textual scope.
+            if (isItemLevelDeclaration(node)) {
+                localNameOf(node, source)
+                        .ifPresent(name -> exports.put(
+                                hasOuterAttribute(node, source, "macro_export") ? name : exportKey(modulePrefix, name),
+                                new ExportIndex.LocalExport(name)));
+            }
+            return;
+        }
         if (isExportableItem(node) && isItemLevelDeclaration(node) && isGraphVisible(node)) {
             localNameOf(node, source)
                     .ifPresent(name -> exports.put(exportKey(modulePrefix, name), new ExportIndex.LocalExport(name)));
@@ -188,10 +274,19 @@ public final class RustExportUsageExtractor {
         }
         if (nodeType(TRAIT_ITEM).equals(type)) {
             collectTraitMembers(node, source, classMembers);
+            collectSupertraits(node, source, heritageEdges);
         }
         if (nodeType(ENUM_ITEM).equals(type) && isGraphVisible(node)) {
             collectEnumVariantMembers(node, source, classMembers);
         }
+        if ((nodeType(STRUCT_ITEM).equals(type) || nodeType(UNION_ITEM).equals(type)) && isGraphVisible(node)) {
+            collectStructFieldMembers(node, source, classMembers);
+        }
+        if (nodeType(STRUCT_ITEM).equals(type)
+                || nodeType(ENUM_ITEM).equals(type)
+                || nodeType(UNION_ITEM).equals(type)) {
+            collectDerivedTraits(node, source, heritageEdges, classMembers);
+        }
         if (nodeType(MOD_ITEM).equals(type)) {
             TSNode body = node.getChildByFieldName(nodeField(RustNodeField.BODY));
             Optional<String> moduleName = localNameOf(node, source);
@@ -249,13 +344,16 @@ public final class RustExportUsageExtractor {
     private static boolean isExportableItem(TSNode node) {
         String type = node.getType();
         return nodeType(STRUCT_ITEM).equals(type)
+                || nodeType(UNION_ITEM).equals(type)
                 || nodeType(ENUM_ITEM).equals(type)
                 || nodeType(TRAIT_ITEM).equals(type)
                 || nodeType(MOD_ITEM).equals(type)
                 || nodeType(FUNCTION_ITEM).equals(type)
+                || nodeType(FUNCTION_SIGNATURE_ITEM).equals(type)
                 || nodeType(CONST_ITEM).equals(type)
                 || nodeType(STATIC_ITEM).equals(type)
-                || nodeType(TYPE_ITEM).equals(type);
+                || nodeType(TYPE_ITEM).equals(type)
+                || nodeType(MACRO_DEFINITION).equals(type);
     }
 
     private static boolean isItemLevelDeclaration(TSNode node) {
@@ -266,7 +364,10 @@ public final class RustExportUsageExtractor {
         String parentType = parent.getType();
         if (nodeType(DECLARATION_LIST).equals(parentType)) {
             TSNode owner = parent.getParent();
-            return owner != null && nodeType(MOD_ITEM).equals(owner.getType());
+            // Items of an `extern "C" { ... }` block live in the enclosing module.
+            return owner != null
+                    && (nodeType(MOD_ITEM).equals(owner.getType())
+                            || nodeType(FOREIGN_MOD_ITEM).equals(owner.getType()));
         }
         return !nodeType(DECLARATION_LIST).equals(parentType)
                 && !nodeType(FIELD_DECLARATION_LIST).equals(parentType)
@@ -274,7 +375,10 @@ public final class RustExportUsageExtractor {
     }
 
     private static boolean isGraphVisible(TSNode node) {
-        Visibility visibility = visibilityOf(node);
+        return isGraphVisible(visibilityOf(node));
+    }
+
+    private static boolean isGraphVisible(Visibility visibility) {
         return visibility == Visibility.PUBLIC || visibility == Visibility.CRATE || visibility == Visibility.SUPER;
     }
 
@@ -285,21 +389,25 @@ public final class RustExportUsageExtractor {
     private static Visibility visibilityOf(TSNode node) {
         for (TSNode child : node.getChildren()) {
             if (nodeType(VISIBILITY_MODIFIER).equals(child.getType())) {
-                if (containsNodeType(child, nodeType(CRATE))) {
-                    return Visibility.CRATE;
-                }
-                if (containsNodeType(child, nodeType(SUPER_))) {
-                    return Visibility.SUPER;
-                }
-                if (containsNodeType(child, nodeType(SELF))) {
-                    return Visibility.PRIVATE;
-                }
-                return child.getNamedChildCount() == 0 ? Visibility.PUBLIC : Visibility.PRIVATE;
+                return visibilityOfModifier(child);
             }
         }
         return Visibility.PRIVATE;
     }
 
+    private static Visibility visibilityOfModifier(TSNode modifier) {
+        if (containsNodeType(modifier, nodeType(CRATE))) {
+            return Visibility.CRATE;
+        }
+        if (containsNodeType(modifier, nodeType(SUPER_))) {
+            return Visibility.SUPER;
+        }
+        if (containsNodeType(modifier, nodeType(SELF))) {
+            return Visibility.PRIVATE;
+        }
+        return modifier.getNamedChildCount() == 0 ? Visibility.PUBLIC : Visibility.PRIVATE;
+    }
+
     private static String exportKey(List<String> modulePrefix, String name) {
         return modulePrefix.isEmpty() ? name : String.join("::", concat(modulePrefix, List.of(name)));
     }
@@ -327,7 +435,7 @@ public final class RustExportUsageExtractor {
             return;
         }
         Optional<String> ownerPath = rustPathOf(implItem.getChildByFieldName(nodeField(RustNodeField.TYPE)), source);
-        Optional<String> trait = rustPathOf(implItem.getChildByFieldName(nodeField(RustNodeField.TRAIT)), source);
+        Optional<String> trait = traitPathOf(implItem.getChildByFieldName(nodeField(RustNodeField.TRAIT)), source);
         trait.ifPresent(
                 traitName -> heritageEdges.add(new ExportIndex.HeritageEdge(ownerPath.orElseThrow(), traitName)));
 
@@ -338,10 +446,47 @@ public final class RustExportUsageExtractor {
                     classMembers.add(new ExportIndex.ClassMember(
                             owner.orElseThrow(), member.name(), member.staticMember(), member.kind()));
                 }
+                // Like the trait's own methods, trait impl methods are callable by path: `<Point as Drawable>::draw`.
+                if (trait.isPresent() && !member.staticMember()) {
+                    classMembers.add(
+                            new ExportIndex.ClassMember(owner.orElseThrow(), member.name(), true, member.kind()));
+                }
             });
         }
     }
 
+    private static void collectSupertraits(
+            TSNode traitItem, SourceContent source, Set<ExportIndex.HeritageEdge> heritageEdges) {
+        Optional<String> trait = localNameOf(traitItem, source);
+        TSNode bounds = traitItem.getChildByFieldName(nodeField(RustNodeField.BOUNDS));
+        if (trait.isEmpty() || bounds == null) {
+            return;
+        }
+        for (TSNode bound : bounds.getNamedChildren()) {
+            traitPathOf(bound, source)
+                    .ifPresent(superTrait ->
+                            heritageEdges.add(new ExportIndex.HeritageEdge(trait.orElseThrow(), superTrait)));
+        }
+    }
+
+    /**
+     * Path of a trait named in an impl header or a bound list, without its generic arguments: {@code From<Foo>}
+     * names {@code From}, not {@code Foo}. Lifetimes, {@code ?Sized} and higher-ranked bounds yield nothing.
+     */
+    private static Optional<String> traitPathOf(@Nullable TSNode node, SourceContent source) {
+        if (node == null) {
+            return Optional.empty();
+        }
+        String type = node.getType();
+        if (nodeType(GENERIC_TYPE).equals(type)) {
+            return rustPathOf(node.getChildByFieldName(nodeField(RustNodeField.TYPE)), source);
+        }
+        if (nodeType(TYPE_IDENTIFIER).equals(type) || nodeType(SCOPED_TYPE_IDENTIFIER).equals(type)) {
+            return rustPathOf(node, source);
+        }
+        return Optional.empty();
+    }
+
     private static void collectEnumVariantMembers(
             TSNode enumItem, SourceContent source, Set<ExportIndex.ClassMember> classMembers) {
         Optional<String> owner = localNameOf(enumItem, source);
@@ -356,9 +501,10 @@ public final class RustExportUsageExtractor {
     private static void collectEnumVariantMembers(
             TSNode node, SourceContent source, String ownerName, Set<ExportIndex.ClassMember> classMembers) {
         if (nodeType(ENUM_VARIANT).equals(node.getType())) {
-            localNameOf(node, source)
-                    .ifPresent(name ->
-                            classMembers.add(new ExportIndex.ClassMember(ownerName, name, true, CodeUnitType.FIELD)));
+            localNameOf(node, source).ifPresent(name -> {
+                classMembers.add(new ExportIndex.ClassMember(ownerName, name, true, CodeUnitType.FIELD));
+                collectVariantFieldMembers(node, source, ownerName + "." + name, classMembers);
+            });
             return;
         }
         for (TSNode child : node.getNamedChildren()) {
@@ -366,6 +512,78 @@ public final class RustExportUsageExtractor {
         }
     }
 
+    /** Variant fields are always public, and are owned by their variant as in {@code Shape.Named.name}. */
+    private static void collectVariantFieldMembers(
+            TSNode variant, SourceContent source, String variantOwner, Set<ExportIndex.ClassMember> classMembers) {
+        TSNode body = variant.getChildByFieldName(nodeField(RustNodeField.BODY));
+        if (body == null) {
+            return;
+        }
+        if (nodeType(FIELD_DECLARATION_LIST).equals(body.getType())) {
+            for (TSNode field : body.getNamedChildren()) {
+                if (nodeType(FIELD_DECLARATION).equals(field.getType())) {
+                    localNameOf(field, source)
+                            .ifPresent(name -> classMembers.add(
+                                    new ExportIndex.ClassMember(variantOwner, name, false, CodeUnitType.FIELD)));
+                }
+            }
+            return;
+        }
+        if (!nodeType(ORDERED_FIELD_DECLARATION_LIST).equals(body.getType())) {
+            return;
+        }
+        String typeField = nodeField(RustNodeField.TYPE);
+        int index = 0;
+        for (int i = 0; i < body.getChildCount(); i++) {
+            if (typeField.equals(body.getFieldNameForChild(i))) {
+                classMembers.add(new ExportIndex.ClassMember(
+                        variantOwner, String.valueOf(index++), false, CodeUnitType.FIELD));
+            }
+        }
+    }
+
+    /**
+     * Visible struct fields are instance members of the struct, so {@code p.x} resolves like a method call; positional
+     * fields of tuple structs are named by index, as in {@code p.0}.
+     */
+    private static void collectStructFieldMembers(
+            TSNode structItem, SourceContent source, Set<ExportIndex.ClassMember> classMembers) {
+        Optional<String> owner = localNameOf(structItem, source);
+        TSNode body = structItem.getChildByFieldName(nodeField(RustNodeField.BODY));
+        if (owner.isEmpty() || body == null) {
+            return;
+        }
+        if (nodeType(FIELD_DECLARATION_LIST).equals(body.getType())) {
+            for (TSNode field : body.getNamedChildren()) {
+                if (nodeType(FIELD_DECLARATION).equals(field.getType()) && isGraphVisible(field)) {
+                    localNameOf(field, source)
+                            .ifPresent(name -> classMembers.add(
+                                    new ExportIndex.ClassMember(owner.orElseThrow(), name, false, CodeUnitType.FIELD)));
+                }
+            }
+            return;
+        }
+        if (!nodeType(ORDERED_FIELD_DECLARATION_LIST).equals(body.getType())) {
+            return;
+        }
+        // A positional field's visibility is the modifier written just before its type.
+        String typeField = nodeField(RustNodeField.TYPE);
+        int index = 0;
+        for (int i = 0; i < body.getChildCount(); i++) {
+            if (!typeField.equals(body.getFieldNameForChild(i))) {
+                continue;
+            }
+            TSNode previous = body.getChild(i).getPrevNamedSibling();
+            if (previous != null
+                    && nodeType(VISIBILITY_MODIFIER).equals(previous.getType())
+                    && isGraphVisible(visibilityOfModifier(previous))) {
+                classMembers.add(new ExportIndex.ClassMember(
+                        owner.orElseThrow(), String.valueOf(index), false, CodeUnitType.FIELD));
+            }
+            index++;
+        }
+    }
+
     private static void collectTraitMembers(
             TSNode traitItem, SourceContent source, Set<ExportIndex.ClassMember> classMembers) {
         Optional<String> owner = localNameOf(traitItem, source);
@@ -408,9 +626,14 @@ public final class RustExportUsageExtractor {
     }
 
     private static boolean returnTypeIsSelfLike(TSNode function, SourceContent source, String ownerName) {
+        TSNode returnType = returnTypeOf(function);
+        return returnType != null && containsSelfLikeType(returnType, source, ownerName);
+    }
+
+    private static @Nullable TSNode returnTypeOf(TSNode function) {
         TSNode returnType = function.getChildByFieldName(nodeField(RustNodeField.RETURN_TYPE));
         if (returnType != null) {
-            return containsSelfLikeType(returnType, source, ownerName);
+            return returnType;
         }
         TSNode parameters = function.getChildByFieldName(nodeField(RustNodeField.PARAMETERS));
         for (TSNode child : function.getNamedChildren()) {
@@ -419,10 +642,40 @@ public final class RustExportUsageExtractor {
             }
             String type = child.getType();
             if (!nodeType(BLOCK).equals(type) && !nodeType(VISIBILITY_MODIFIER).equals(type)) {
-                return containsSelfLikeType(child, source, ownerName);
+                return child;
             }
         }
-        return false;
+        return null;
+    }
+
+    private static void collectFunctionReturnTypes(
+            TSNode node, SourceContent source, String ownerName, Map<AssociatedFunctionKey, RustTypeRef> returnTypes) {
+        String type = node.getType();
+        if (nodeType(FUNCTION_ITEM).equals(type)) {
+            TSNode returnType = returnTypeOf(node);
+            Optional<String> functionName = localNameOf(node, source);
+            if (functionName.isPresent() && returnType != null && !isNonConcreteReceiverType(returnType, source)) {
+                List<String> segments = receiverTypeSegments(returnType, source);
+                if (!segments.isEmpty()) {
+                    var key = new AssociatedFunctionKey(ownerName, functionName.orElseThrow());
+                    returnTypes.putIfAbsent(key, new RustTypeRef(segments));
+                }
+            }
+            return;
+        }
+        String nextOwner = ownerName;
+        if (nodeType(IMPL_ITEM).equals(type)) {
+            Optional<String> owner = typeNameOf(node.getChildByFieldName(nodeField(RustNodeField.TYPE)), source);
+            if (owner.isEmpty()) {
+                return;
+            }
+            nextOwner = owner.orElseThrow();
+        } else if (nodeType(TRAIT_ITEM).equals(type)) {
+            return;
+        }
+        for (TSNode child : node.getNamedChildren()) {
+            collectFunctionReturnTypes(child, source, nextOwner, returnTypes);
+        }
     }
 
     private static boolean containsSelfLikeType(TSNode node, SourceContent source, String ownerName) {
@@ -444,7 +697,7 @@ public final class RustExportUsageExtractor {
     }
 
     private static void collectStructFieldTypes(TSNode node, SourceContent source, Map<FieldKey, RustTypeRef> fields) {
-        if (nodeType(STRUCT_ITEM).equals(node.getType())) {
+        if (nodeType(STRUCT_ITEM).equals(node.getType()) || nodeType(UNION_ITEM).equals(node.getType())) {
             Optional<String> owner = localNameOf(node, source);
             if (owner.isPresent()) {
                 collectStructFieldTypes(node, source, owner.orElseThrow(), fields);
@@ -478,7 +731,8 @@ public final class RustExportUsageExtractor {
         if (!nodeType(FUNCTION_ITEM).equals(type)
                 && !nodeType(FUNCTION_SIGNATURE_ITEM).equals(type)
                 && !nodeType(CONST_ITEM).equals(type)
-                && !nodeType(TYPE_ITEM).equals(type)) {
+                && !nodeType(TYPE_ITEM).equals(type)
+                && !nodeType(ASSOCIATED_TYPE).equals(type)) {
             return Optional.empty();
         }
         return localNameOf(node, source).map(name -> new Member(name, !hasSelfParameter(node), memberKindOf(node)));
@@ -529,7 +783,7 @@ public final class RustExportUsageExtractor {
 
         if (nodeType(SCOPED_IDENTIFIER).equals(node.getType())
                 || nodeType(SCOPED_TYPE_IDENTIFIER).equals(node.getType())) {
-            List<String> chain = pathSegments(node, source);
+            List<String> chain = scopedPathSegments(node, source);
             if (chain.size() >= 2) {
                 String first = chain.getFirst();
                 candidates.add(new ReferenceCandidate(
@@ -612,6 +866,230 @@ public final class RustExportUsageExtractor {
         }
     }
 
+    private static void collectLocalMacroInvocationCandidates(
+            TSNode node,
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            SourceContent source,
+            Set<String> localMacroNames,
+            CodeUnit fallbackEnclosing,
+            Set<ReferenceCandidate> candidates) {
+        if (localMacroNames.isEmpty() || node == null) {
+            return;
+        }
+        if (nodeType(MACRO_INVOCATION).equals(node.getType())) {
+            TSNode macro = macroPathOf(node);
+            simpleIdentifierName(macro, source)
+                    .filter(localMacroNames::contains)
+                    .ifPresent(name -> candidates.add(new ReferenceCandidate(
+                            name,
+                            null,
+                            null,
+                            false,
+                            ReferenceKind.STATIC_REFERENCE,
+                            rangeOf(requireNonNull(macro)),
+                            enclosing(analyzer, file, node, fallbackEnclosing))));
+        }
+        for (TSNode child : node.getNamedChildren()) {
+            collectLocalMacroInvocationCandidates(
+                    child, analyzer, file, source, localMacroNames, fallbackEnclosing, candidates);
+        }
+    }
+
+    private static void collectMacroDefinitions(
+            TSNode node, SourceContent source, boolean exportedOnly, Set<String> names) {
+        if (nodeType(MACRO_DEFINITION).equals(node.getType())) {
+            if (!exportedOnly || hasOuterAttribute(node, source, "macro_export")) {
+                localNameOf(node, source).ifPresent(names::add);
+            }
+            return;
+        }
+        for (TSNode child : node.getNamedChildren()) {
+            collectMacroDefinitions(child, source, exportedOnly, names);
+        }
+    }
+
+    private static void collectMacroInvocationNames(TSNode node, SourceContent source, Set<String> names) {
+        if (nodeType(MACRO_INVOCATION).equals(node.getType())) {
+            simpleIdentifierName(macroPathOf(node), source).ifPresent(names::add);
+        }
+        for (TSNode child : node.getNamedChildren()) {
+            collectMacroInvocationNames(child, source, names);
+        }
+    }
+
+    private static @Nullable TSNode macroPathOf(TSNode macroInvocation) {
+        return macroInvocation.getNamedChildCount() > 0 ? macroInvocation.getNamedChild(0) : null;
+    }
+
+    private static boolean hasOuterAttribute(TSNode item, SourceContent source, String attributeName) {
+        TSNode current = item.getPrevNamedSibling();
+        while (current != null) {
+            String type = current.getType();
+            if (nodeType(ATTRIBUTE_ITEM).equals(type)) {
+                if (attributeName.equals(attributePathOf(current, source))) {
+                    return true;
+                }
+            } else if (!COMMENT_NODE_TYPES.contains(type)) {
+                return false;
+            }
+            current = current.getPrevNamedSibling();
+        }
+        return false;
+    }
+
+    /**
+     * Records each {@code #[derive(...)]} trait as a synthetic trait impl of the item: a heritage edge to the trait,
+     * and the methods of well-known derivable traits, so {@code value.clone()} resolves on a derived type. Derived
+     * traits outside the crate, such as serde's {@code Serialize}, reach the type hierarchy through
+     * {@link #derivedTraits(TSNode, SourceContent)}.
+     */
+    private static void collectDerivedTraits(
+            TSNode item,
+            SourceContent source,
+            Set<ExportIndex.HeritageEdge> heritageEdges,
+            Set<ExportIndex.ClassMember> classMembers) {
+        Optional<String> owner = localNameOf(item, source);
+        if (owner.isEmpty()) {
+            return;
+        }
+        for (String traitPath : derivedTraitPaths(item, source)) {
+            heritageEdges.add(new ExportIndex.HeritageEdge(owner.orElseThrow(), traitPath));
+            String traitName = traitPath.substring(traitPath.lastIndexOf(':') + 1);
+            DERIVED_TRAIT_METHODS.getOrDefault(traitName, List.of())
+                    .forEach(method -> classMembers.add(
+                            new ExportIndex.ClassMember(owner.orElseThrow(), method, false, CodeUnitType.FUNCTION)));
+            DERIVED_TRAIT_ASSOCIATED_FUNCTIONS.getOrDefault(traitName, List.of())
+                    .forEach(function -> classMembers.add(
+                            new ExportIndex.ClassMember(owner.orElseThrow(), function, true, CodeUnitType.FUNCTION)));
+        }
+    }
+
+    /** The derived traits of the structs, enums and unions of a file, including those of inline modules. */
+    public static List<DerivedTrait> derivedTraits(TSNode root, SourceContent source) {
+        var derived = new ArrayList<DerivedTrait>();
+        collectDerivedTraitsOf(root, source, derived);
+        return List.copyOf(derived);
+    }
+
+    private static void collectDerivedTraitsOf(TSNode node, SourceContent source, List<DerivedTrait> derived) {
+        for (TSNode child : node.getNamedChildren()) {
+            String type = child.getType();
+            if (nodeType(STRUCT_ITEM).equals(type)
+                    || nodeType(ENUM_ITEM).equals(type)
+                    || nodeType(UNION_ITEM).equals(type)) {
+                localNameOf(child, source)
+                        .ifPresent(name -> derivedTraitPaths(child, source)
+                                .forEach(traitPath -> derived.add(new DerivedTrait(name, traitPath))));
+            } else if (nodeType(MOD_ITEM).equals(type)) {
+                TSNode body = child.getChildByFieldName(nodeField(RustNodeField.BODY));
+                if (body != null) {
+                    collectDerivedTraitsOf(body, source, derived);
+                }
+            }
+        }
+    }
+
+    /**
+     * Trait paths derived by the outer attributes of an item, in source order, including derives nested in
+     * {@code #[cfg_attr(..., derive(...))]}, e.g. {@code Clone} or {@code serde::Serialize}.
+     */
+    private static List<String> derivedTraitPaths(TSNode item, SourceContent source) {
+        var traits = new ArrayList<String>();
+        TSNode current = item.getPrevNamedSibling();
+        while (current != null) {
+            String type = current.getType();
+            if (nodeType(ATTRIBUTE_ITEM).equals(type)) {
+                var attributeTraits = new ArrayList<String>();
+                for (TSNode attribute : current.getNamedChildren()) {
+                    if (nodeType(ATTRIBUTE).equals(attribute.getType()) && attribute.getNamedChildCount() > 0) {
+                        String path = source.substringFrom(attribute.getNamedChild(0)).strip();
+                        TSNode arguments = attribute.getChildByFieldName(nodeField(RustNodeField.ARGUMENTS));
+                        if (arguments != null) {
+                            collectDerives(path, arguments, source, attributeTraits);
+                        }
+                    }
+                }
+                traits.addAll(0, attributeTraits);
+            } else if (!COMMENT_NODE_TYPES.contains(type)) {
+                break;
+            }
+            current = current.getPrevNamedSibling();
+        }
+        return List.copyOf(traits);
+    }
+
+    /**
+     * Collects the traits of a {@code derive(...)} attribute, or of the {@code derive(...)} attributes that a
+     * {@code cfg_attr(predicate, ...)} applies; {@code arguments} is the attribute's token tree.
+     */
+    private static void collectDerives(
+            String attributePath, TSNode arguments, SourceContent source, List<String> traits) {
+        if ("derive".equals(attributePath)) {
+            traits.addAll(derivePathsOf(arguments, source));
+            return;
+        }
+        if (!"cfg_attr".equals(attributePath)) {
+            return;
+        }
+        // The predicate's own token trees, e.g. `all(...)`, follow identifiers other than `derive` and `cfg_attr`.
+        for (int i = 0; i + 1 < arguments.getChildCount(); i++) {
+            TSNode token = arguments.getChild(i);
+            TSNode next = arguments.getChild(i + 1);
+            if (nodeType(IDENTIFIER).equals(token.getType()) && nodeType(TOKEN_TREE).equals(next.getType())) {
+                collectDerives(source.substringFrom(token).strip(), next, source, traits);
+            }
+        }
+    }
+
+    /**
+     * The trait paths of a derive's token tree, e.g. {@code (Debug, serde::Serialize)}. Comments are skipped, and an
+     * entry that is not a plain path is dropped.
+     */
+    private static List<String> derivePathsOf(TSNode tokenTree, SourceContent source) {
+        var paths = new ArrayList<String>();
+        var segments = new ArrayList<String>();
+        boolean expectSegment = true;
+        boolean valid = true;
+        // The first and last children are the delimiters.
+        for (int i = 1; i < tokenTree.getChildCount(); i++) {
+            TSNode token = tokenTree.getChild(i);
+            String type = token.getType();
+            if (COMMENT_NODE_TYPES.contains(type)) {
+                continue;
+            }
+            String text = source.substringFrom(token).strip();
+            if (i == tokenTree.getChildCount() - 1 || ",".equals(text)) {
+                if (valid && !expectSegment) {
+                    paths.add(String.join("::", segments));
+                }
+                segments.clear();
+                expectSegment = true;
+                valid = true;
+            } else if ("::".equals(text) && (segments.isEmpty() || !expectSegment)) {
+                expectSegment = true;
+            } else if (expectSegment && DERIVE_PATH_SEGMENT_TYPES.contains(type)) {
+                segments.add(text);
+                expectSegment = false;
+            } else if (expectSegment && segments.isEmpty() && nodeType(SCOPED_IDENTIFIER).equals(type)) {
+                segments.addAll(List.of(WHITESPACE.matcher(text).replaceAll("").split("::")));
+                expectSegment = false;
+            } else {
+                valid = false;
+            }
+        }
+        return List.copyOf(paths);
+    }
+
+    private static String attributePathOf(TSNode attributeItem, SourceContent source) {
+        for (TSNode attribute : attributeItem.getNamedChildren()) {
+            if (nodeType(ATTRIBUTE).equals(attribute.getType()) && attribute.getNamedChildCount() > 0) {
+                return source.substringFrom(attribute.getNamedChild(0)).strip();
+            }
+        }
+        return "";
+    }
+
     private static void collectShadowingParameterNames(TSNode scope, SourceContent source, Deque<Set<String>> scopes) {
         if (!nodeType(FUNCTION_ITEM).equals(scope.getType())) {
             return;
@@ -668,7 +1146,6 @@ public final class RustExportUsageExtractor {
             ProjectFile file,
             SourceContent source,
             ImportBinder binder,
-            Map<String, List<String>> typeAliases,
             CodeUnit fallbackEnclosing,
             @Nullable String currentImplOwner,
             List<LocalUsageEvent> events) {
@@ -685,18 +1162,23 @@ public final class RustExportUsageExtractor {
         boolean scope = isLocalScope(node);
         if (scope) {
             events.add(new LocalUsageEvent.EnterScope());
-            collectParameterEvents(node, analyzer, file, source, binder, typeAliases, events);
+            collectParameterEvents(node, analyzer, file, source, binder, nextImplOwner, events);
         }
 
         if (nodeType(LET_DECLARATION).equals(node.getType())) {
-            collectLetDeclarationEvent(node, analyzer, file, source, binder, typeAliases, nextImplOwner, events);
+            collectLetDeclarationEvent(node, analyzer, file, source, binder, nextImplOwner, events);
         } else if (nodeType(FIELD_EXPRESSION).equals(node.getType())) {
-            collectReceiverAccessEvent(node, analyzer, file, source, fallbackEnclosing, events);
+            collectReceiverAccessEvent(
+                    node, analyzer, file, source, binder, fallbackEnclosing, nextImplOwner, events);
+        } else if (nodeType(CALL_EXPRESSION).equals(node.getType())) {
+            collectTraitPathCallEvent(node, analyzer, file, source, binder, fallbackEnclosing, events);
+        } else if (STRUCT_FIELD_SITE_TYPES.contains(node.getType())) {
+            collectStructFieldEvents(node, analyzer, file, source, binder, fallbackEnclosing, nextImplOwner, events);
         }
 
         for (TSNode child : node.getNamedChildren()) {
             collectLocalUsageEvents(
-                    child, analyzer, file, source, binder, typeAliases, fallbackEnclosing, nextImplOwner, events);
+                    child, analyzer, file, source, binder, fallbackEnclosing, nextImplOwner, events);
         }
 
         if (scope) {
@@ -715,7 +1197,7 @@ public final class RustExportUsageExtractor {
             ProjectFile file,
             SourceContent source,
             ImportBinder binder,
-            Map<String, List<String>> typeAliases,
+            @Nullable String currentImplOwner,
             List<LocalUsageEvent> events) {
         if (!nodeType(FUNCTION_ITEM).equals(scope.getType())) {
             return;
@@ -724,6 +1206,11 @@ public final class RustExportUsageExtractor {
         if (parameters == null) {
             return;
         }
+        // A method's receiver is the impl's type, so `self.other()` resolves like a call on a typed local.
+        if (currentImplOwner != null && hasSelfParameter(scope)) {
+            receiverTargetForPath(analyzer, file, List.of(currentImplOwner), binder, currentImplOwner)
+                    .ifPresent(target -> events.add(new LocalUsageEvent.SeedSymbol("self", Set.of(target))));
+        }
         for (TSNode parameter : parameters.getNamedChildren()) {
             Optional<String> name = simplePatternName(parameter, source);
             Optional<ReceiverTargetRef> target = receiverTargetForType(
@@ -732,7 +1219,7 @@ public final class RustExportUsageExtractor {
                     parameter.getChildByFieldName(nodeField(RustNodeField.TYPE)),
                     source,
                     binder,
-                    typeAliases);
+                    currentImplOwner);
             if (name.isPresent() && target.isPresent()) {
                 events.add(new LocalUsageEvent.SeedSymbol(name.orElseThrow(), Set.of(target.orElseThrow())));
             }
@@ -745,7 +1232,6 @@ public final class RustExportUsageExtractor {
             ProjectFile file,
             SourceContent source,
             ImportBinder binder,
-            Map<String, List<String>> typeAliases,
             @Nullable String currentImplOwner,
             List<LocalUsageEvent> events) {
         Optional<String> localName = simplePatternName(letDeclaration, source);
@@ -758,15 +1244,21 @@ public final class RustExportUsageExtractor {
         String name = localName.orElseThrow();
         TSNode type = letDeclaration.getChildByFieldName(nodeField(RustNodeField.TYPE));
         Optional<ReceiverTargetRef> typedTarget =
-                receiverTargetForType(analyzer, file, type, source, binder, typeAliases);
+                receiverTargetForType(analyzer, file, type, source, binder, currentImplOwner);
         if (typedTarget.isPresent()) {
             events.add(new LocalUsageEvent.SeedSymbol(name, Set.of(typedTarget.orElseThrow())));
             return;
         }
 
         TSNode value = letDeclaration.getChildByFieldName(nodeField(RustNodeField.VALUE));
+        Optional<CallChain> chain = callChainOf(value, analyzer, file, source, binder, currentImplOwner);
+        if (chain.isPresent()) {
+            bindCallChain(name, chain.orElseThrow(), events);
+            return;
+        }
+
         Optional<ReceiverTargetRef> constructedTarget =
-                receiverTargetForConstructor(analyzer, file, value, source, binder, typeAliases);
+                receiverTargetForConstructor(analyzer, file, value, source, binder, currentImplOwner);
         if (constructedTarget.isPresent()) {
             events.add(new LocalUsageEvent.SeedSymbol(name, Set.of(constructedTarget.orElseThrow())));
             return;
@@ -785,11 +1277,28 @@ public final class RustExportUsageExtractor {
             RustAnalyzer analyzer,
             ProjectFile file,
             SourceContent source,
+            ImportBinder binder,
             CodeUnit fallbackEnclosing,
+            @Nullable String currentImplOwner,
             List<LocalUsageEvent> events) {
-        Optional<String> receiver = firstIdentifierName(fieldExpressionReceiver(fieldExpression), source);
         Optional<String> field = fieldNameOf(fieldExpression, source);
-        if (receiver.isEmpty() || field.isEmpty()) {
+        if (field.isEmpty()) {
+            return;
+        }
+        TSNode receiverNode = fieldExpressionReceiver(fieldExpression);
+        Optional<String> receiver;
+        Optional<CallChain> chain = callChainOf(receiverNode, analyzer, file, source, binder, currentImplOwner);
+        if (chain.isPresent()) {
+            // `pool.get()?.query()` calls `query` on what `get` returns, bound here to a name no source local can have.
+            String chainResult = "<call@" + requireNonNull(receiverNode).getStartByte() + ">";
+            bindCallChain(chainResult, chain.orElseThrow(), events);
+            receiver = Optional.of(chainResult);
+        } else if (receiverNode != null && nodeType(SELF).equals(receiverNode.getType())) {
+            receiver = Optional.of("self");
+        } else {
+            receiver = firstIdentifierName(receiverNode, source);
+        }
+        if (receiver.isEmpty()) {
             return;
         }
         ReferenceKind kind = nodeType(CALL_EXPRESSION).equals(parentType(fieldExpression))
@@ -803,13 +1312,211 @@ public final class RustExportUsageExtractor {
                 enclosing(analyzer, file, fieldExpression, fallbackEnclosing)));
     }
 
+    /**
+     * Field references written against a struct's type rather than a receiver: initializers of a literal
+     * {@code Point { x: 1, y, ..base }} write the fields they name and read the rest from {@code base}, and a pattern
+     * {@code Point { x, .. }} or {@code Pair(a, _)} in a {@code let}, {@code match} arm or {@code if let} reads the
+     * fields it binds.
+     */
+    private static void collectStructFieldEvents(
+            TSNode node,
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            SourceContent source,
+            ImportBinder binder,
+            CodeUnit fallbackEnclosing,
+            @Nullable String currentImplOwner,
+            List<LocalUsageEvent> events) {
+        boolean literal = nodeType(STRUCT_EXPRESSION).equals(node.getType());
+        TSNode type = node.getChildByFieldName(nodeField(literal ? RustNodeField.NAME : RustNodeField.TYPE));
+        // `Shape::Named { name }` names the fields of a variant, members `Named.name` of the enum.
+        List<String> segments = selfResolvedPrefix(pathSegmentsPreservingKeywords(type, source), currentImplOwner);
+        Optional<ReceiverTargetRef> variantOwner = analyzer.enumVariantOwner(file, segments, binder);
+        String memberPrefix = variantOwner.isPresent() ? segments.getLast() + "." : "";
+        Optional<ReceiverTargetRef> target = variantOwner.or(
+                () -> receiverTargetForType(analyzer, file, type, source, binder, currentImplOwner));
+        if (type == null || target.isEmpty()) {
+            return;
+        }
+        List<FieldMention> mentions = literal
+                ? initializedFields(node, source)
+                : nodeType(STRUCT_PATTERN).equals(node.getType())
+                        ? patternFields(node, source)
+                        : positionalPatternFields(node, type, source);
+        List<FieldMention> baseReads = literal && variantOwner.isEmpty()
+                ? fieldsReadFromBase(node, target.orElseThrow(), mentions, analyzer)
+                : List.of();
+        if (mentions.isEmpty() && baseReads.isEmpty()) {
+            return;
+        }
+        String struct = "<struct@" + node.getStartByte() + ">";
+        events.add(new LocalUsageEvent.SeedSymbol(struct, Set.of(target.orElseThrow())));
+        ReferenceKind kind = literal ? ReferenceKind.FIELD_WRITE : ReferenceKind.FIELD_READ;
+        for (FieldMention mention : mentions) {
+            events.add(new LocalUsageEvent.ReceiverAccess(
+                    struct,
+                    memberPrefix + mention.name(),
+                    kind,
+                    rangeOf(mention.node()),
+                    enclosing(analyzer, file, mention.node(), fallbackEnclosing)));
+        }
+        for (FieldMention read : baseReads) {
+            events.add(new LocalUsageEvent.ReceiverAccess(
+                    struct,
+                    read.name(),
+                    ReferenceKind.FIELD_READ,
+                    rangeOf(read.node()),
+                    enclosing(analyzer, file, read.node(), fallbackEnclosing)));
+        }
+    }
+
+    private static List<FieldMention> initializedFields(TSNode structExpression, SourceContent source) {
+        TSNode body = structExpression.getChildByFieldName(nodeField(RustNodeField.BODY));
+        if (body == null) {
+            return List.of();
+        }
+        var mentions = new ArrayList<FieldMention>();
+        for (TSNode initializer : body.getNamedChildren()) {
+            TSNode field = nodeType(FIELD_INITIALIZER).equals(initializer.getType())
+                    ? initializer.getChildByFieldName(nodeField(RustNodeField.FIELD))
+                    : nodeType(SHORTHAND_FIELD_INITIALIZER).equals(initializer.getType())
+                            ? initializer.getNamedChildren().stream().findFirst().orElse(null)
+                            : null;
+            if (field != null) {
+                mentions.add(new FieldMention(source.substringFrom(field).strip(), field));
+            }
+        }
+        return mentions;
+    }
+
+    /**
+     * The fields a struct update {@code Point { x: 1, ..base }} takes from {@code base}: those of the struct the
+     * literal leaves out, each mentioned at the base expression. Only fields the graph tracks are listed.
+     */
+    private static List<FieldMention> fieldsReadFromBase(
+            TSNode structExpression, ReceiverTargetRef struct, List<FieldMention> initialized, RustAnalyzer analyzer) {
+        TSNode body = structExpression.getChildByFieldName(nodeField(RustNodeField.BODY));
+        ProjectFile structFile = struct.localFile();
+        if (body == null || structFile == null) {
+            return List.of();
+        }
+        TSNode base = body.getNamedChildren().stream()
+                .filter(child -> nodeType(BASE_FIELD_INITIALIZER).equals(child.getType()))
+                .findFirst()
+                .flatMap(initializer -> initializer.getNamedChildren().stream().findFirst())
+                .orElse(null);
+        if (base == null) {
+            return List.of();
+        }
+        var named = new HashSet<String>();
+        initialized.forEach(mention -> named.add(mention.name()));
+        return analyzer.exportIndexOf(structFile).classMembers().stream()
+                .filter(member -> member.kind() == CodeUnitType.FIELD
+                        && !member.staticMember()
+                        && member.ownerClassName().equals(struct.exportedName()))
+                .map(ExportIndex.ClassMember::memberName)
+                .filter(name -> !named.contains(name))
+                .sorted()
+                .map(name -> new FieldMention(name, base))
+                .toList();
+    }
+
+    private static List<FieldMention> patternFields(TSNode structPattern, SourceContent source) {
+        var mentions = new ArrayList<FieldMention>();
+        for (TSNode fieldPattern : structPattern.getNamedChildren()) {
+            if (!nodeType(FIELD_PATTERN).equals(fieldPattern.getType())) {
+                continue;
+            }
+            TSNode name = fieldPattern.getChildByFieldName(nodeField(RustNodeField.NAME));
+            if (name != null) {
+                mentions.add(new FieldMention(source.substringFrom(name).strip(), name));
+            }
+        }
+        return mentions;
+    }
+
+    /** Positions after a {@code ..} count from the end of the tuple, so only the leading ones are indexed. */
+    private static List<FieldMention> positionalPatternFields(
+            TSNode tupleStructPattern, TSNode type, SourceContent source) {
+        var mentions = new ArrayList<FieldMention>();
+        int index = 0;
+        for (TSNode child : tupleStructPattern.getChildren()) {
+            if (!child.isNamed() && !"_".equals(child.getType())) {
+                continue;
+            }
+            if (sameRange(child, type)) {
+                continue;
+            }
+            if (nodeType(REMAINING_FIELD_PATTERN).equals(child.getType())) {
+                break;
+            }
+            if (child.isNamed() && !"_".equals(source.substringFrom(child).strip())) {
+                mentions.add(new FieldMention(String.valueOf(index), child));
+            }
+            index++;
+        }
+        return mentions;
+    }
+
+    /**
+     * Reads a fully qualified trait call {@code Drawable::draw(&p)} as the method call {@code p.draw()}, so the call
+     * resolves through the impl for the receiver's type. Only paths naming a trait implemented in the crate qualify;
+     * {@code Point::new(&p)} says nothing about the type of {@code p}.
+     */
+    private static void collectTraitPathCallEvent(
+            TSNode callExpression,
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            SourceContent source,
+            ImportBinder binder,
+            CodeUnit fallbackEnclosing,
+            List<LocalUsageEvent> events) {
+        TSNode function = callExpression.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
+        if (function == null || !nodeType(SCOPED_IDENTIFIER).equals(function.getType())) {
+            return;
+        }
+        TSNode path = function.getChildByFieldName(nodeField(RustNodeField.PATH));
+        if (path == null || nodeType(BRACKETED_TYPE).equals(path.getType())) {
+            return;
+        }
+        List<String> traitSegments = pathSegments(path, source);
+        Optional<String> method =
+                firstIdentifierName(function.getChildByFieldName(nodeField(RustNodeField.NAME)), source);
+        Optional<String> receiver =
+                selfArgumentName(callExpression.getChildByFieldName(nodeField(RustNodeField.ARGUMENTS)), source);
+        if (method.isEmpty() || receiver.isEmpty() || !analyzer.isImplementedTrait(file, traitSegments, binder)) {
+            return;
+        }
+        events.add(new LocalUsageEvent.ReceiverAccess(
+                receiver.orElseThrow(),
+                method.orElseThrow(),
+                ReferenceKind.METHOD_CALL,
+                rangeOf(function),
+                enclosing(analyzer, file, function, fallbackEnclosing)));
+    }
+
+    /** The local passed as {@code self} to a path call: {@code x}, {@code &x} or {@code &mut x}. */
+    private static Optional<String> selfArgumentName(@Nullable TSNode arguments, SourceContent source) {
+        if (arguments == null || arguments.getNamedChildren().isEmpty()) {
+            return Optional.empty();
+        }
+        TSNode first = arguments.getNamedChildren().getFirst();
+        if (nodeType(REFERENCE_EXPRESSION).equals(first.getType())) {
+            first = first.getChildByFieldName(nodeField(RustNodeField.VALUE));
+        }
+        if (first == null || !nodeType(IDENTIFIER).equals(first.getType())) {
+            return Optional.empty();
+        }
+        return Optional.of(source.substringFrom(first).strip());
+    }
+
     private static Optional<ReceiverTargetRef> receiverTargetForConstructor(
             RustAnalyzer analyzer,
             ProjectFile file,
             @Nullable TSNode value,
             SourceContent source,
             ImportBinder binder,
-            Map<String, List<String>> typeAliases) {
+            @Nullable String currentImplOwner) {
         if (value == null) {
             return Optional.empty();
         }
@@ -820,7 +1527,7 @@ public final class RustExportUsageExtractor {
                     value.getChildByFieldName(nodeField(RustNodeField.NAME)),
                     source,
                     binder,
-                    typeAliases);
+                    currentImplOwner);
         }
         if (nodeType(CALL_EXPRESSION).equals(value.getType())) {
             TSNode function = value.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
@@ -830,28 +1537,127 @@ public final class RustExportUsageExtractor {
             Optional<TSNode> unwrapped = unwrapSimpleCallChain(function, source);
             if (unwrapped.isPresent()) {
                 return receiverTargetForConstructor(
-                        analyzer, file, unwrapped.orElseThrow(), source, binder, typeAliases);
+                        analyzer, file, unwrapped.orElseThrow(), source, binder, currentImplOwner);
+            }
+            if (nodeType(FIELD_EXPRESSION).equals(function.getType())) {
+                return Optional.empty();
             }
             if (nodeType(SCOPED_IDENTIFIER).equals(function.getType())
                     || nodeType(SCOPED_TYPE_IDENTIFIER).equals(function.getType())) {
                 List<String> segments = pathSegmentsPreservingKeywords(function, source);
                 if (segments.size() >= 2 && "new".equals(segments.getLast())) {
-                    return receiverTargetForSegments(analyzer, file, segments.subList(0, segments.size() - 1), binder);
+                    return receiverTargetForPath(
+                            analyzer, file, segments.subList(0, segments.size() - 1), binder, currentImplOwner);
                 }
                 if (segments.size() >= 2
                         && analyzer.associatedFunctionReturnsSelfLike(
-                                file, segments.subList(0, segments.size() - 1), segments.getLast(), binder)) {
-                    return receiverTargetForSegments(analyzer, file, segments.subList(0, segments.size() - 1), binder);
+                                file,
+                                selfResolvedSegments(segments.subList(0, segments.size() - 1), currentImplOwner),
+                                segments.getLast(),
+                                binder)) {
+                    return receiverTargetForPath(
+                            analyzer, file, segments.subList(0, segments.size() - 1), binder, currentImplOwner);
                 }
                 if (segments.size() >= 2) {
                     return Optional.empty();
                 }
             }
-            return receiverTargetForType(analyzer, file, function, source, binder, typeAliases);
+            return receiverTargetForType(analyzer, file, function, source, binder, currentImplOwner);
+        }
+        return Optional.empty();
+    }
+
+    /**
+     * Reads a call, field, {@code ?} or {@code .await} expression as a chain of method calls and field reads on a base:
+     * a local or {@code self}, or a path or function call seeded from its declared return type. {@code ?},
+     * {@code .await} and pass-through calls such as {@code unwrap} add no link, since return and field types are
+     * followed with {@code Option}/{@code Result} already unwrapped.
+     */
+    private static Optional<CallChain> callChainOf(
+            @Nullable TSNode value,
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            SourceContent source,
+            ImportBinder binder,
+            @Nullable String currentImplOwner) {
+        if (value == null || !CALL_CHAIN_TYPES.contains(value.getType())) {
+            return Optional.empty();
+        }
+        var members = new ArrayDeque<String>();
+        TSNode node = value;
+        while (node != null) {
+            String type = node.getType();
+            if (nodeType(TRY_EXPRESSION).equals(type) || nodeType(AWAIT_EXPRESSION).equals(type)) {
+                var children = node.getNamedChildren();
+                node = children.isEmpty() ? null : children.getFirst();
+                continue;
+            }
+            if (nodeType(IDENTIFIER).equals(type) || nodeType(SELF).equals(type)) {
+                String local = source.substringFrom(node).strip();
+                return members.isEmpty()
+                        ? Optional.empty()
+                        : Optional.of(new CallChain(local, Set.of(), List.copyOf(members)));
+            }
+            if (nodeType(FIELD_EXPRESSION).equals(type)) {
+                Optional<String> field = fieldNameOf(node, source);
+                if (field.isEmpty()) {
+                    return Optional.empty();
+                }
+                members.addFirst(field.orElseThrow());
+                node = fieldExpressionReceiver(node);
+                continue;
+            }
+            if (!nodeType(CALL_EXPRESSION).equals(type)) {
+                return Optional.empty();
+            }
+            TSNode function = node.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
+            if (function != null && nodeType(GENERIC_FUNCTION).equals(function.getType())) {
+                function = function.getChildByFieldName(nodeField(RustNodeField.FUNCTION));
+            }
+            if (function == null) {
+                return Optional.empty();
+            }
+            if (nodeType(FIELD_EXPRESSION).equals(function.getType())) {
+                Optional<String> method = fieldNameOf(function, source);
+                if (method.isEmpty()) {
+                    return Optional.empty();
+                }
+                if (!RUST_PASS_THROUGH_METHOD_NAMES.contains(method.orElseThrow())) {
+                    members.addFirst(method.orElseThrow());
+                }
+                node = fieldExpressionReceiver(function);
+                continue;
+            }
+            List<String> segments = pathSegmentsPreservingKeywords(function, source);
+            if (currentImplOwner != null && segments.size() >= 2 && "Self".equals(segments.getFirst())) {
+                segments = concat(List.of(currentImplOwner), segments.subList(1, segments.size()));
+            }
+            Set<ReceiverTargetRef> returned = analyzer.callReturnReceivers(file, segments, binder);
+            if (returned.isEmpty()) {
+                returned = receiverTargetForConstructor(analyzer, file, node, source, binder, currentImplOwner)
+                        .map(Set::of)
+                        .orElse(Set.of());
+            }
+            return returned.isEmpty()
+                    ? Optional.empty()
+                    : Optional.of(new CallChain(null, returned, List.copyOf(members)));
         }
         return Optional.empty();
     }
 
+    private static void bindCallChain(String name, CallChain chain, List<LocalUsageEvent> events) {
+        String base = chain.baseLocal();
+        if (base == null && chain.members().isEmpty()) {
+            events.add(new LocalUsageEvent.SeedSymbol(name, chain.baseTargets()));
+            return;
+        }
+        if (base == null) {
+            base = name + "#base";
+            events.add(new LocalUsageEvent.SeedSymbol(base, chain.baseTargets()));
+        }
+        events.add(new LocalUsageEvent.CallResultSymbol(name, base, chain.members()));
+    }
+
     private static Optional<TSNode> unwrapSimpleCallChain(TSNode function, SourceContent source) {
         if (!nodeType(FIELD_EXPRESSION).equals(function.getType())) {
             return Optional.empty();
@@ -889,7 +1695,7 @@ public final class RustExportUsageExtractor {
                 .ifPresent(fieldType -> {
                     List<String> concreteSegments = unwrapSimpleWrapperType(fieldType.segments());
                     Optional<ReceiverTargetRef> target =
-                            receiverTargetForSegments(analyzer, file, concreteSegments, binder);
+                            receiverTargetForPath(analyzer, file, concreteSegments, binder, currentImplOwner);
                     target.ifPresent(receiverTarget -> events.add(
                             new LocalUsageEvent.SeedSymbol(localName.orElseThrow(), Set.of(receiverTarget))));
                 });
@@ -927,7 +1733,7 @@ public final class RustExportUsageExtractor {
         return children.isEmpty() ? null : children.getFirst();
     }
 
-    private static List<String> unwrapSimpleWrapperType(List<String> segments) {
+    public static List<String> unwrapSimpleWrapperType(List<String> segments) {
         if (segments.size() > 1 && SIMPLE_WRAPPER_TYPES.contains(segments.getFirst())) {
             return segments.subList(1, segments.size());
         }
@@ -940,18 +1746,40 @@ public final class RustExportUsageExtractor {
             @Nullable TSNode type,
             SourceContent source,
             ImportBinder binder,
-            Map<String, List<String>> typeAliases) {
+            @Nullable String currentImplOwner) {
         if (isNonConcreteReceiverType(type, source)) {
             return Optional.empty();
         }
-        List<String> segments = receiverTypeSegments(type, source);
-        if (segments.size() == 1) {
-            List<String> aliased = typeAliases.getOrDefault(segments.getFirst(), List.of());
-            if (!aliased.isEmpty()) {
-                segments = aliased;
-            }
+        return receiverTargetForPath(analyzer, file, receiverTypeSegments(type, source), binder, currentImplOwner);
+    }
+
+    /**
+     * The receiver named by a type path as written in {@code file}: {@code Self} stands for the enclosing impl's type,
+     * and {@code type} aliases, local or imported and possibly chained, are followed to the type they stand for.
+     */
+    private static Optional<ReceiverTargetRef> receiverTargetForPath(
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            List<String> segments,
+            ImportBinder binder,
+            @Nullable String currentImplOwner) {
+        List<String> resolved = selfResolvedSegments(segments, currentImplOwner);
+        return analyzer.receiverTargetThroughTypeAliases(file, resolved, binder)
+                .or(() -> receiverTargetForSegments(analyzer, file, resolved, binder));
+    }
+
+    private static List<String> selfResolvedSegments(List<String> segments, @Nullable String currentImplOwner) {
+        if (currentImplOwner != null && segments.size() == 1 && "Self".equals(segments.getFirst())) {
+            return List.of(currentImplOwner);
+        }
+        return segments;
+    }
+
+    private static List<String> selfResolvedPrefix(List<String> segments, @Nullable String currentImplOwner) {
+        if (currentImplOwner != null && segments.size() > 1 && "Self".equals(segments.getFirst())) {
+            return concat(List.of(currentImplOwner), segments.subList(1, segments.size()));
         }
-        return receiverTargetForSegments(analyzer, file, segments, binder);
+        return segments;
     }
 
     private static List<String> receiverTypeSegments(@Nullable TSNode type, SourceContent source) {
@@ -1132,6 +1960,11 @@ public final class RustExportUsageExtractor {
     }
 
     private static Optional<String> fieldNameOf(TSNode fieldExpression, SourceContent source) {
+        // Positional fields of tuple structs are read as `p.0`.
+        TSNode field = fieldExpression.getChildByFieldName(nodeField(RustNodeField.FIELD));
+        if (field != null && nodeType(INTEGER_LITERAL).equals(field.getType())) {
+            return Optional.of(source.substringFrom(field).strip());
+        }
         for (TSNode child : fieldExpression.getNamedChildren()) {
             if (nodeType(FIELD_IDENTIFIER).equals(child.getType())) {
                 return Optional.of(source.substringFrom(child).strip()).filter(s -> !s.isBlank());
@@ -1172,6 +2005,29 @@ public final class RustExportUsageExtractor {
                 && source.substringFrom(name).equals(source.substringFrom(node));
     }
 
+    /**
+     * Segments of a scoped path, reading the qualified self type {@code <Point as Drawable>::draw} as
+     * {@code Point::draw}: the call dispatches to the implementing type's impl of the trait.
+     */
+    private static List<String> scopedPathSegments(TSNode node, SourceContent source) {
+        TSNode path = node.getChildByFieldName(nodeField(RustNodeField.PATH));
+        if (path == null || !nodeType(BRACKETED_TYPE).equals(path.getType())) {
+            return pathSegments(node, source);
+        }
+        TSNode qualified = path.getNamedChildren().stream()
+                .filter(child -> nodeType(QUALIFIED_TYPE).equals(child.getType()))
+                .findFirst()
+                .orElse(null);
+        if (qualified == null) {
+            return pathSegments(node, source);
+        }
+        var segments = new ArrayList<String>();
+        collectPathSegments(qualified.getChildByFieldName(nodeField(RustNodeField.TYPE)), source, segments);
+        collectPathSegments(node.getChildByFieldName(nodeField(RustNodeField.NAME)), source, segments);
+        segments.removeIf(RustExportUsageExtractor::isRustPathKeyword);
+        return List.copyOf(segments);
+    }
+
     private static List<String> pathSegments(TSNode node, SourceContent source) {
         var segments = new ArrayList<String>();
         collectPathSegments(node, source, segments);
@@ -1340,7 +2196,12 @@ public final class RustExportUsageExtractor {
         return Optional.of(new PathParts(module, importedName));
     }
 
-    private static Map<String, List<String>> collectTypeAliases(TSNode root, SourceContent source) {
+    /**
+     * Returns the {@code type} aliases declared in the file, mapped to the receiver type each stands for with simple
+     * wrappers unwrapped: {@code type ConnRef = Arc<Connection>} maps {@code ConnRef} to {@code [Connection]}. Aliases
+     * of trait objects and {@code impl} types map to an empty list, since no receiver type can be read from them.
+     */
+    public static Map<String, List<String>> computeTypeAliases(TSNode root, SourceContent source) {
         var aliases = new LinkedHashMap<String, List<String>>();
         collectTypeAliases(root, source, aliases);
         return Map.copyOf(aliases);
@@ -1349,35 +2210,41 @@ public final class RustExportUsageExtractor {
     private static void collectTypeAliases(TSNode node, SourceContent source, Map<String, List<String>> aliases) {
         if (nodeType(TYPE_ITEM).equals(node.getType())) {
             Optional<String> name = localNameOf(node, source);
-            List<String> target = typeAliasTargetSegments(node, source);
-            if (name.isPresent() && !target.isEmpty()) {
-                aliases.put(name.orElseThrow(), target);
+            TSNode target = typeAliasTarget(node);
+            if (name.isPresent() && target != null) {
+                aliases.put(
+                        name.orElseThrow(),
+                        isNonConcreteReceiverType(target, source)
+                                ? List.of()
+                                : receiverTypeSegments(target, source));
             }
             return;
         }
+        if (nodeType(IMPL_ITEM).equals(node.getType()) || nodeType(TRAIT_ITEM).equals(node.getType())) {
+            // Associated types are reached through their owner, not by bare name.
+            return;
+        }
         for (TSNode child : node.getNamedChildren()) {
             collectTypeAliases(child, source, aliases);
         }
     }
 
-    private static List<String> typeAliasTargetSegments(TSNode typeItem, SourceContent source) {
+    private static @Nullable TSNode typeAliasTarget(TSNode typeItem) {
         TSNode name = typeItem.getChildByFieldName(nodeField(RustNodeField.NAME));
         TSNode target = typeItem.getChildByFieldName(nodeField(RustNodeField.TYPE));
         if (target != null && (name == null || target.getStartByte() != name.getStartByte())) {
-            return pathSegmentsPreservingKeywords(target, source);
+            return target;
         }
         for (TSNode child : typeItem.getNamedChildren()) {
             if (name != null && child.getStartByte() <= name.getStartByte()) {
                 continue;
             }
-            if (!nodeType(VISIBILITY_MODIFIER).equals(child.getType())) {
-                List<String> segments = pathSegmentsPreservingKeywords(child, source);
-                if (!segments.isEmpty()) {
-                    return segments;
-                }
+            String type = child.getType();
+            if (!nodeType(VISIBILITY_MODIFIER).equals(type) && !nodeType(TYPE_PARAMETERS).equals(type)) {
+                return child;
             }
         }
-        return List.of();
+        return null;
     }
 
     private static void bindUseSpec(
@@ -1406,18 +2273,133 @@ public final class RustExportUsageExtractor {
                                         .orElse(parts.importedName()))));
     }
 
+    /**
+     * Binds {@code extern crate foo;} and {@code extern crate foo as bar;} as namespace imports of the crate, and
+     * {@code extern crate self as bar;} as an alias of the current crate root.
+     */
+    private static void bindExternCrates(
+            TSNode node,
+            SourceContent source,
+            Set<String> localTopLevelNames,
+            Map<String, ImportBinder.ImportBinding> bindings) {
+        if (nodeType(EXTERN_CRATE_DECLARATION).equals(node.getType())) {
+            TSNode nameNode = node.getChildByFieldName(nodeField(RustNodeField.NAME));
+            if (nameNode == null) {
+                return;
+            }
+            String crateName = source.substringFrom(nameNode).strip();
+            TSNode aliasNode = node.getChildByFieldName(nodeField(RustNodeField.ALIAS));
+            String localName = aliasNode != null ? source.substringFrom(aliasNode).strip() : crateName;
+            if (localName.equals("_") || localTopLevelNames.contains(localName)) {
+                return;
+            }
+            String cratePath = crateName.equals("self") ? "crate" : crateName;
+            bindings.put(localName, new ImportBinder.ImportBinding(cratePath, ImportBinder.ImportKind.NAMESPACE, null));
+            return;
+        }
+        for (TSNode child : node.getNamedChildren()) {
+            bindExternCrates(child, source, localTopLevelNames, bindings);
+        }
+    }
+
+    private static void bindExportedMacroInvocations(
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            TSNode root,
+            SourceContent source,
+            Set<String> localTopLevelNames,
+            Map<String, ImportBinder.ImportBinding> bindings) {
+        Map<String, String> exportedMacros = analyzer.exportedMacroModules();
+        if (exportedMacros.isEmpty()) {
+            return;
+        }
+        var invokedNames = new LinkedHashSet<String>();
+        collectMacroInvocationNames(root, source, invokedNames);
+        for (String name : invokedNames) {
+            String moduleSpecifier = exportedMacros.get(name);
+            if (moduleSpecifier != null) {
+                bindUseSpec(
+                        analyzer,
+                        file,
+                        new UseSpec(moduleSpecifier + "::" + name, null, name, false),
+                        localTopLevelNames,
+                        bindings);
+            }
+        }
+    }
+
+    private static void bindMacroUseModules(
+            RustAnalyzer analyzer,
+            ProjectFile file,
+            TSNode node,
+            SourceContent source,
+            List<String> modulePath,
+            Set<String> localTopLevelNames,
+            Map<String, ImportBinder.ImportBinding> bindings) {
+        for (TSNode child : node.getNamedChildren()) {
+            if (!nodeType(MOD_ITEM).equals(child.getType()) || !hasOuterAttribute(child, source, "macro_use")) {
+                continue;
+            }
+            Optional<String> moduleName = localNameOf(child, source);
+            if (moduleName.isEmpty()) {
+                continue;
+            }
+            // Macros defined in an inline module stay in this file and resolve as same-file candidates.
+            if (child.getChildByFieldName(nodeField(RustNodeField.BODY)) != null) {
+                continue;
+            }
+            String moduleSpecifier = String.join("::", concat(modulePath, List.of(moduleName.orElseThrow())));
+            analyzer.resolveRustModuleOutcome(file, moduleSpecifier)
+                    .resolved()
+                    .ifPresent(moduleFile -> analyzer.macroNamesOf(moduleFile)
+                            .forEach(name -> bindUseSpec(
+                                    analyzer,
+                                    file,
+                                    new UseSpec(moduleSpecifier + "::" + name, null, name, false),
+                                    localTopLevelNames,
+                                    bindings)));
+        }
+    }
+
+    /**
+     * {@code use crate::my_macro;} names a {@code #[macro_export]} macro through the crate root even when the macro is
+     * written in another module; point the spec at the defining module so the binding resolves to the definition.
+     */
+    private static UseSpec withExportedMacroPath(RustAnalyzer analyzer, UseSpec spec) {
+        if (spec.wildcard() || !spec.path().startsWith("crate::")) {
+            return spec;
+        }
+        String name = spec.path().substring("crate::".length());
+        if (name.contains("::")) {
+            return spec;
+        }
+        String moduleSpecifier = analyzer.exportedMacroModules().get(name);
+        if (moduleSpecifier == null || "crate".equals(moduleSpecifier)) {
+            return spec;
+        }
+        return new UseSpec(moduleSpecifier + "::" + name, spec.alias(), spec.localName(), false);
+    }
+
+    /**
+     * Expands {@code use path::*;} into one named binding per name the target module exports, including names reached
+     * through its own {@code pub use ...::*} re-exports. Star chains are followed breadth-first for at most
+     * {@link #MAX_GLOB_REEXPORT_DEPTH} hops; a direct export shadows a name of the same spelling from a star further
+     * down the chain. Modules exporting more than {@link #MAX_GLOB_IMPORT_NAMES} names are left unexpanded.
+     */
     private static void expandWildcardImport(
             RustAnalyzer analyzer,
             ProjectFile file,
             UseSpec spec,
             Set<String> localTopLevelNames,
-            Map<String, ImportBinder.ImportBinding> bindings) {
+            Map<String, GlobBinding> globBindings,
+            Set<String> ambiguousGlobNames) {
         String moduleSpecifier = stripWildcardSegment(spec.path());
         if (moduleSpecifier.isBlank()) {
             return;
         }
         analyzer.resolveRustModuleOutcome(file, moduleSpecifier).resolved().ifPresent(moduleFile -> {
             var prefix = analyzer.inlineRustModuleExportPrefix(file, moduleSpecifier, false);
+            var names = new LinkedHashMap<String, GlobBinding>();
             for (String exportName :
                     analyzer.exportIndexOf(moduleFile).exportsByName().keySet()) {
                 if (prefix.isPresent()
@@ -1428,23 +2410,105 @@ public final class RustExportUsageExtractor {
                 String localName = prefix.isPresent()
                         ? exportName.substring(prefix.orElseThrow().length()).replaceFirst("^::", "")
                         : exportName;
-                if (!localName.contains("::") && !localTopLevelNames.contains(localName)) {
-                    bindings.putIfAbsent(
+                if (!localName.isEmpty() && !localName.contains("::")) {
+                    names.putIfAbsent(
                             localName,
-                            new ImportBinder.ImportBinding(moduleSpecifier, ImportBinder.ImportKind.NAMED, exportName));
+                            new GlobBinding(
+                                    new ImportBinder.ImportBinding(
+                                            moduleSpecifier, ImportBinder.ImportKind.NAMED, exportName),
+                                    globOrigin(analyzer, moduleFile, exportName)));
                 }
             }
+            if (prefix.isEmpty()) {
+                collectStarReexportedNames(analyzer, moduleFile, moduleSpecifier, names);
+            }
+            if (names.size() > MAX_GLOB_IMPORT_NAMES) {
+                return;
+            }
+            names.forEach((localName, glob) -> {
+                if (localTopLevelNames.contains(localName)) {
+                    return;
+                }
+                GlobBinding existing = globBindings.putIfAbsent(localName, glob);
+                if (existing != null && !existing.origin().equals(glob.origin())) {
+                    ambiguousGlobNames.add(localName);
+                }
+            });
         });
     }
 
+    private static void collectStarReexportedNames(
+            RustAnalyzer analyzer, ProjectFile moduleFile, String moduleSpecifier, Map<String, GlobBinding> names) {
+        var visited = new HashSet<ProjectFile>();
+        visited.add(moduleFile);
+        List<ProjectFile> layer = List.of(moduleFile);
+        for (int depth = 0; depth < MAX_GLOB_REEXPORT_DEPTH && !layer.isEmpty(); depth++) {
+            var next = new ArrayList<ProjectFile>();
+            for (ProjectFile current : layer) {
+                for (ExportIndex.ReexportStar star : analyzer.exportIndexOf(current).reexportStars()) {
+                    Optional<ProjectFile> starFile = analyzer.resolveRustModuleOutcome(current, star.moduleSpecifier())
+                            .resolved();
+                    if (starFile.isEmpty() || !visited.add(starFile.orElseThrow())) {
+                        continue;
+                    }
+                    ProjectFile reexported = starFile.orElseThrow();
+                    for (String exportName :
+                            analyzer.exportIndexOf(reexported).exportsByName().keySet()) {
+                        if (!exportName.contains("::")) {
+                            names.putIfAbsent(
+                                    exportName,
+                                    new GlobBinding(
+                                            new ImportBinder.ImportBinding(
+                                                    moduleSpecifier, ImportBinder.ImportKind.NAMED, exportName),
+                                            globOrigin(analyzer, reexported, exportName)));
+                        }
+                    }
+                    next.add(reexported);
+                }
+                if (names.size() > MAX_GLOB_IMPORT_NAMES) {
+                    return;
+                }
+            }
+            layer = next;
+        }
+    }
+
+    /** Follows named re-exports of {@code exportName} to the module that declares it, so re-exports match originals. */
+    private static String globOrigin(RustAnalyzer analyzer, ProjectFile file, String exportName) {
+        ProjectFile current = file;
+        String name = exportName;
+        for (int depth = 0; depth < MAX_GLOB_REEXPORT_DEPTH; depth++) {
+            if (!(analyzer.exportIndexOf(current).exportsByName().get(name)
+                    instanceof ExportIndex.ReexportedNamed reexport)) {
+                break;
+            }
+            Optional<ProjectFile> next = analyzer.resolveRustModuleOutcome(current, reexport.moduleSpecifier())
+                    .resolved();
+            if (next.isEmpty()) {
+                return reexport.moduleSpecifier() + "::" + reexport.importedName();
+            }
+            current = next.orElseThrow();
+            name = reexport.importedName();
+        }
+        return current + "::" + name;
+    }
+
     private static String stripWildcardSegment(String rustPath) {
         return rustPath.endsWith("::*") ? rustPath.substring(0, rustPath.length() - 3) : rustPath;
     }
 
     public record UseSpec(String path, @Nullable String alias, @Nullable String localName, boolean wildcard) {}
 
+    /** A name bound by a glob import, with the module file and export it originates from. */
+    private record GlobBinding(ImportBinder.ImportBinding binding, String origin) {}
+
     private record Member(String name, boolean staticMember, CodeUnitType kind) {}
 
+    private record FieldMention(String name, TSNode node) {}
+
+    /** A chain read by {@link #callChainOf}: members called or read in turn on a local, or on a call's return value. */
+    private record CallChain(@Nullable String baseLocal, Set<ReceiverTargetRef> baseTargets, List<String> members) {}
+
     private record PathParts(String moduleSpecifier, String importedName) {}
 
     private enum Visibility {
@@ -0,0 +1,362 @@
+package ai.brokk.analyzer.rust;
+
+import static ai.brokk.analyzer.ASTTraversalUtils.children;
+import static ai.brokk.analyzer.ASTTraversalUtils.directNamedChildOfAnyType;
+import static ai.brokk.analyzer.ASTTraversalUtils.namedChildren;
+import static ai.brokk.analyzer.ASTTraversalUtils.sameRange;
+import static ai.brokk.analyzer.ASTTraversalUtils.typeOf;
+import static ai.brokk.analyzer.rust.Constants.nodeField;
+import static ai.brokk.analyzer.rust.Constants.nodeType;
+import static org.treesitter.RustNodeType.*;
+
+import ai.brokk.analyzer.SourceContent;
+import ai.brokk.analyzer.usages.UsageKind;
+import java.util.Optional;
+import java.util.Set;
+import java.util.function.Predicate;
+import org.jetbrains.annotations.Nullable;
+import org.treesitter.RustNodeField;
+import org.treesitter.TSNode;
+
+/**
+ * Reads how a Rust reference uses what it names from the syntax around it: the left side of an assignment writes,
+ * {@code &mut} borrows mutably, a by-value use of a non-{@code Copy} value moves it, and a method call uses its
+ * receiver the way the method's {@code self} parameter says.
+ */
+public final class RustUsageKinds {
+
+    /** Expressions that still name (part of) the same storage as their operand: {@code p.x.y}, {@code p.x[0]}. */
+    private static final Set<String> PLACE_PROJECTION_TYPES = Set.of(
+            nodeType(FIELD_EXPRESSION), nodeType(INDEX_EXPRESSION), nodeType(PARENTHESIZED_EXPRESSION));
+
+    private static final Set<String> ASSIGNMENT_TYPES =
+            Set.of(nodeType(ASSIGNMENT_EXPRESSION), nodeType(COMPOUND_ASSIGNMENT_EXPR));
+
+    /** Parents that take any operand by value, including the base of a struct update {@code Point { x, ..base }}. */
+    private static final Set<String> BY_VALUE_OPERAND_TYPES = Set.of(
+            nodeType(ARGUMENTS),
+            nodeType(RETURN_EXPRESSION),
+            nodeType(TUPLE_EXPRESSION),
+            nodeType(ARRAY_EXPRESSION),
+            nodeType(BASE_FIELD_INITIALIZER));
+
+    /** Parents whose {@code value} field is taken by value: {@code let a = v;}, {@code Point { x: v }}, {@code for}. */
+    private static final Set<String> BY_VALUE_FIELD_TYPES =
+            Set.of(nodeType(LET_DECLARATION), nodeType(FIELD_INITIALIZER), nodeType(FOR_EXPRESSION));
+
+    private static final Set<String> MUTABLE_SPECIFIER_TYPES = Set.of(nodeType(MUTABLE_SPECIFIER));
+
+    private static final Set<String> COPY_TYPES = Set.of(
+            nodeType(PRIMITIVE_TYPE), nodeType(UNIT_TYPE), nodeType(POINTER_TYPE), nodeType(FUNCTION_TYPE));
+
+    /** Standard generic types that are {@code Copy} whenever their type arguments are. */
+    private static final Set<String> COPY_GENERIC_TYPES =
+            Set.of("Option", "std::option::Option", "core::option::Option");
+
+    /**
+     * Standard collection, {@code String} and {@code Option} methods that take {@code &mut self}, for calls whose
+     * receiver type lies outside the project.
+     */
+    private static final Set<String> STD_MUTATING_METHODS = Set.of(
+            "append",
+            "clear",
+            "dedup",
+            "drain",
+            "entry",
+            "extend",
+            "get_mut",
+            "get_or_insert_with",
+            "insert",
+            "iter_mut",
+            "pop",
+            "pop_back",
+            "pop_front",
+            "push",
+            "push_back",
+            "push_front",
+            "push_str",
+            "remove",
+            "replace",
+            "resize",
+            "retain",
+            "reverse",
+            "sort",
+            "sort_by",
+            "sort_by_key",
+            "sort_unstable",
+            "swap",
+            "take",
+            "truncate");
+
+    /** Standard methods that only read their {@code &self} receiver, for calls outside the project. */
+    private static final Set<String> STD_READING_METHODS = Set.of(
+            "as_ref",
+            "as_str",
+            "clone",
+            "contains",
+            "contains_key",
+            "first",
+            "get",
+            "is_empty",
+            "is_none",
+            "is_some",
+            "iter",
+            "keys",
+            "last",
+            "len",
+            "to_owned",
+            "to_string",
+            "values");
+
+    /** Subpatterns of a field pattern that bind the whole field: {@code label: l}, {@code label: mut l}. */
+    private static final Set<String> BINDING_PATTERN_TYPES = Set.of(nodeType(IDENTIFIER), nodeType(MUT_PATTERN));
+
+    private RustUsageKinds() {}
+
+    /**
+     * Classifies a reference to a field, static or constant by the place expression it starts: written when the place
+     * is assigned to, including through a projection such as {@code p.x.y += 1}; borrowed mutably under {@code &mut};
+     * moved when {@code movesOnUse} and the place itself is taken by value; read otherwise. A field named in a pattern
+     * such as {@code let Point { label, .. } = p} or {@code let Pair(first, _) = p} moves when {@code movesOnUse} and
+     * the pattern binds it by value.
+     */
+    public static UsageKind placeUse(TSNode reference, boolean movesOnUse) {
+        TSNode pattern = reference.getParent();
+        String patternType = typeOf(pattern);
+        if (pattern != null
+                && nodeType(FIELD_PATTERN).equals(patternType)
+                && isField(pattern, RustNodeField.NAME, reference)) {
+            TSNode binding = pattern.getChildByFieldName(nodeField(RustNodeField.PATTERN));
+            return movesOnUse && bindsByValue(pattern, binding) ? UsageKind.MOVE : UsageKind.READ;
+        }
+        if (pattern != null && nodeType(TUPLE_STRUCT_PATTERN).equals(patternType)) {
+            return movesOnUse && bindsByValue(pattern, reference) ? UsageKind.MOVE : UsageKind.READ;
+        }
+        TSNode place = reference;
+        boolean projected = false;
+        TSNode parent = place.getParent();
+        while (parent != null && isProjectionOf(parent, place)) {
+            place = parent;
+            projected = true;
+            parent = place.getParent();
+        }
+        String parentType = typeOf(parent);
+        if (parent == null || parentType == null) {
+            return UsageKind.READ;
+        }
+        if (ASSIGNMENT_TYPES.contains(parentType) && isField(parent, RustNodeField.LEFT, place)) {
+            return UsageKind.WRITE;
+        }
+        if (nodeType(REFERENCE_EXPRESSION).equals(parentType)) {
+            return directNamedChildOfAnyType(parent, MUTABLE_SPECIFIER_TYPES) != null
+                    ? UsageKind.MUTABLE_BORROW
+                    : UsageKind.READ;
+        }
+        return movesOnUse && !projected && takesByValue(parent, parentType, place) ? UsageKind.MOVE : UsageKind.READ;
+    }
+
+    /**
+     * The name of the method called on the place expression {@code reference} starts, as {@code push} in
+     * {@code self.items.push(x)}, or null when the place is not the receiver of a method call. Such a use is
+     * classified by the called method's {@link #receiverUse receiver} rather than by {@link #placeUse}.
+     */
+    public static @Nullable TSNode methodCalledOn(TSNode reference) {
+        TSNode place = reference;
+        TSNode parent = place.getParent();
+        while (parent != null && isProjectionOf(parent, place)) {
+            place = parent;
+            parent = place.getParent();
+        }
+        if (parent == null
+                || !nodeType(FIELD_EXPRESSION).equals(typeOf(parent))
+                || !isField(parent, RustNodeField.VALUE, place)
+                || !isCallee(parent)) {
+            return null;
+        }
+        return parent.getChildByFieldName(nodeField(RustNodeField.FIELD));
+    }
+
+    /**
+     * How a standard library method named {@code methodName} uses its receiver, for calls the project cannot resolve:
+     * common collection mutators borrow mutably, {@code into_*} conversions move and common accessors read. Empty for
+     * any other method.
+     */
+    public static Optional<UsageKind> standardReceiverUse(String methodName) {
+        if (STD_MUTATING_METHODS.contains(methodName)) {
+            return Optional.of(UsageKind.MUTABLE_BORROW);
+        }
+        if (methodName.startsWith("into_")) {
+            return Optional.of(UsageKind.MOVE);
+        }
+        return STD_READING_METHODS.contains(methodName) ? Optional.of(UsageKind.READ) : Optional.empty();
+    }
+
+    /** True when {@code reference} is the function a call expression calls, as in {@code p.area()} or {@code f()}. */
+    public static boolean isCallee(TSNode reference) {
+        TSNode callee = reference;
+        TSNode parent = callee.getParent();
+        if (parent != null
+                && nodeType(GENERIC_FUNCTION).equals(typeOf(parent))
+                && isField(parent, RustNodeField.FUNCTION, callee)) {
+            callee = parent;
+            parent = callee.getParent();
+        }
+        return parent != null
+                && nodeType(CALL_EXPRESSION).equals(typeOf(parent))
+                && isField(parent, RustNodeField.FUNCTION, callee);
+    }
+
+    /**
+     * How calling {@code function} uses its receiver: {@code &self} reads it, {@code &mut self} borrows it mutably and
+     * {@code self} moves it, also when spelled with an explicit type such as {@code self: &mut Self}. Empty for a
+     * function without a {@code self} parameter.
+     */
+    public static Optional<UsageKind> receiverUse(TSNode function) {
+        TSNode parameters = function.getChildByFieldName(nodeField(RustNodeField.PARAMETERS));
+        if (parameters == null) {
+            return Optional.empty();
+        }
+        for (TSNode parameter : namedChildren(parameters)) {
+            String type = typeOf(parameter);
+            if (nodeType(SELF_PARAMETER).equals(type)) {
+                boolean reference = children(parameter).stream().anyMatch(child -> "&".equals(child.getType()));
+                return Optional.of(reference ? referenceUse(parameter) : UsageKind.MOVE);
+            }
+            if (nodeType(PARAMETER).equals(type)
+                    && nodeType(SELF).equals(typeOf(parameter.getChildByFieldName(nodeField(RustNodeField.PATTERN))))) {
+                TSNode selfType = parameter.getChildByFieldName(nodeField(RustNodeField.TYPE));
+                return Optional.of(
+                        nodeType(REFERENCE_TYPE).equals(typeOf(selfType)) ? referenceUse(selfType) : UsageKind.MOVE);
+            }
+        }
+        return Optional.empty();
+    }
+
+    /**
+     * Whether values of the declared {@code type} are {@code Copy}: primitives, shared references, raw and function
+     * pointers, and tuples, arrays and {@code Option}s of those, with named types left to {@code isCopyNamedType}. A
+     * generic named type is only {@code Copy} when its type arguments are, as {@code #[derive(Copy)]} requires.
+     */
+    public static boolean isCopyType(@Nullable TSNode type, SourceContent source, Predicate<String> isCopyNamedType) {
+        String typeName = typeOf(type);
+        if (type == null || typeName == null) {
+            return false;
+        }
+        if (COPY_TYPES.contains(typeName)) {
+            return true;
+        }
+        if (nodeType(REFERENCE_TYPE).equals(typeName)) {
+            return directNamedChildOfAnyType(type, MUTABLE_SPECIFIER_TYPES) == null;
+        }
+        if (nodeType(TUPLE_TYPE).equals(typeName)) {
+            return namedChildren(type).stream().allMatch(element -> isCopyType(element, source, isCopyNamedType));
+        }
+        if (nodeType(ARRAY_TYPE).equals(typeName)) {
+            return isCopyType(type.getChildByFieldName(nodeField(RustNodeField.ELEMENT)), source, isCopyNamedType);
+        }
+        if (nodeType(GENERIC_TYPE).equals(typeName)) {
+            TSNode name = type.getChildByFieldName(nodeField(RustNodeField.TYPE));
+            if (name == null) {
+                return false;
+            }
+            String baseName = source.substringFrom(name).strip();
+            if (!COPY_GENERIC_TYPES.contains(baseName) && !isCopyNamedType.test(baseName)) {
+                return false;
+            }
+            TSNode arguments = type.getChildByFieldName(nodeField(RustNodeField.TYPE_ARGUMENTS));
+            return arguments == null
+                    || namedChildren(arguments).stream()
+                            .filter(argument -> !nodeType(LIFETIME).equals(typeOf(argument)))
+                            .allMatch(argument -> isCopyType(argument, source, isCopyNamedType));
+        }
+        if (nodeType(TYPE_IDENTIFIER).equals(typeName) || nodeType(SCOPED_TYPE_IDENTIFIER).equals(typeName)) {
+            return isCopyNamedType.test(source.substringFrom(type).strip());
+        }
+        return false;
+    }
+
+    /**
+     * Whether {@code pattern} takes a field by value out of what it destructures, binding it to {@code binding}, which
+     * is null for a shorthand such as {@code Point { label, .. }}. A {@code ref} binding, a wildcard or a nested
+     * pattern does not, and neither does destructuring a visible reference such as
+     * {@code let Point { label, .. } = &p} or a {@code &Point} parameter, whose fields bind by reference.
+     */
+    private static boolean bindsByValue(TSNode pattern, @Nullable TSNode binding) {
+        if (children(pattern).stream().anyMatch(child -> "ref".equals(child.getType()))) {
+            return false;
+        }
+        String bindingType = typeOf(binding);
+        if (binding != null && (bindingType == null || !BINDING_PATTERN_TYPES.contains(bindingType))) {
+            return false;
+        }
+        TSNode owner = pattern.getParent();
+        String ownerType = typeOf(owner);
+        while (owner != null && ownerType != null && ownerType.endsWith("_pattern")) {
+            owner = owner.getParent();
+            ownerType = typeOf(owner);
+        }
+        if (owner == null || ownerType == null) {
+            return true;
+        }
+        if (nodeType(PARAMETER).equals(ownerType)) {
+            TSNode parameterType = owner.getChildByFieldName(nodeField(RustNodeField.TYPE));
+            return !nodeType(REFERENCE_TYPE).equals(typeOf(parameterType));
+        }
+        // A match arm destructures the value of its match expression: arm -> match block -> match expression.
+        TSNode valueOwner = owner;
+        if (nodeType(MATCH_ARM).equals(ownerType)) {
+            TSNode matchBlock = owner.getParent();
+            valueOwner = matchBlock == null ? null : matchBlock.getParent();
+        }
+        TSNode destructured =
+                valueOwner == null ? null : valueOwner.getChildByFieldName(nodeField(RustNodeField.VALUE));
+        return !nodeType(REFERENCE_EXPRESSION).equals(typeOf(destructured));
+    }
+
+    private static UsageKind referenceUse(TSNode reference) {
+        return directNamedChildOfAnyType(reference, MUTABLE_SPECIFIER_TYPES) != null
+                ? UsageKind.MUTABLE_BORROW
+                : UsageKind.READ;
+    }
+
+    /**
+     * True when {@code parent} projects from {@code place} rather than using it: a field or index access on it, or
+     * parentheses around it. A method call on the place ({@code p.items.push(x)}) is a use, not a projection.
+     */
+    private static boolean isProjectionOf(TSNode parent, TSNode place) {
+        String parentType = typeOf(parent);
+        if (parentType == null || !PLACE_PROJECTION_TYPES.contains(parentType)) {
+            return false;
+        }
+        if (nodeType(FIELD_EXPRESSION).equals(parentType)) {
+            return isField(parent, RustNodeField.VALUE, place) && !isCallee(parent);
+        }
+        if (nodeType(INDEX_EXPRESSION).equals(parentType)) {
+            return parent.getNamedChildCount() > 0 && sameRange(parent.getNamedChild(0), place);
+        }
+        return true;
+    }
+
+    private static boolean takesByValue(TSNode parent, String parentType, TSNode place) {
+        if (BY_VALUE_OPERAND_TYPES.contains(parentType)) {
+            return true;
+        }
+        if (BY_VALUE_FIELD_TYPES.contains(parentType)) {
+            return isField(parent, RustNodeField.VALUE, place);
+        }
+        if (ASSIGNMENT_TYPES.contains(parentType)) {
+            return isField(parent, RustNodeField.RIGHT, place);
+        }
+        if (nodeType(BLOCK).equals(parentType)) {
+            // The block's tail expression is its value.
+            var statements = namedChildren(parent);
+            return !statements.isEmpty() && sameRange(statements.getLast(), place);
+        }
+        return false;
+    }
+
+    private static boolean isField(TSNode parent, RustNodeField field, TSNode child) {
+        TSNode value = parent.getChildByFieldName(nodeField(field));
+        return typeOf(value) != null && sameRange(value, child);
+    }
+}
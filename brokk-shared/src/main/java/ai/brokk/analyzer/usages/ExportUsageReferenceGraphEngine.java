@@ -3,6 +3,7 @@ package ai.brokk.analyzer.usages;
 import static java.util.Objects.requireNonNull;
 
 import ai.brokk.analyzer.CodeUnit;
+import ai.brokk.analyzer.IAnalyzer;
 import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.util.PathNormalizer;
 import java.util.ArrayDeque;
@@ -93,6 +94,7 @@ public final class ExportUsageReferenceGraphEngine {
                     shouldResolveReceiverCandidates ? adapter.resolvedReceiverCandidatesOf(file, binder) : Set.of();
             if (candidates.isEmpty() && receiverCandidates.isEmpty()) continue;
 
+            Set<IAnalyzer.Range> hitRanges = new HashSet<>();
             for (ReferenceCandidate cand : candidates) {
                 if (hits.size() >= limits.maxHits()) break;
 
@@ -107,6 +109,7 @@ public final class ExportUsageReferenceGraphEngine {
                     if (matchesTarget(target, resolvedTarget, heritageEdges)) {
                         hits.add(new ReferenceHit(
                                 file, cand.range(), cand.enclosingUnit(), cand.kind(), target, confidence));
+                        hitRanges.add(cand.range());
                         break;
                     }
                 }
@@ -114,6 +117,8 @@ public final class ExportUsageReferenceGraphEngine {
 
             for (ResolvedReceiverCandidate cand : receiverCandidates) {
                 if (hits.size() >= limits.maxHits()) break;
+                // A path call like `Trait::method(&x)` can be both a reference and a receiver fact; count it once.
+                if (hitRanges.contains(cand.range())) continue;
 
                 Optional<ResolvedExport> resolved =
                         resolveReceiverCandidate(cand, file, adapter, limits, frontier, externalFrontier);
@@ -136,6 +141,44 @@ public final class ExportUsageReferenceGraphEngine {
         return new ReferenceGraphResult(Set.copyOf(hits), Set.copyOf(frontier), Set.copyOf(externalFrontier));
     }
 
+    /**
+     * Resolves every reference and receiver candidate in {@code file} to the code unit it refers to: the forward
+     * direction of {@link #findExportUsages}, used to list what a function calls.
+     */
+    public static Set<ReferenceHit> resolveReferencesIn(
+            ProjectFile file, ExportUsageGraphLanguageAdapter adapter, Limits limits) {
+        Set<ProjectFile> frontier = new LinkedHashSet<>();
+        Set<String> externalFrontier = new LinkedHashSet<>();
+        ImportBinder binder = adapter.importBinderOf(file);
+        Set<ReferenceHit> hits = new LinkedHashSet<>();
+        Set<IAnalyzer.Range> hitRanges = new HashSet<>();
+        for (ReferenceCandidate cand : adapter.usageCandidatesOf(file, binder)) {
+            resolveCandidate(cand, file, binder, adapter, limits, frontier, externalFrontier)
+                    .ifPresent(resolved -> {
+                        hits.add(new ReferenceHit(
+                                file,
+                                cand.range(),
+                                cand.enclosingUnit(),
+                                cand.kind(),
+                                resolved.target(),
+                                resolved.confidence()));
+                        hitRanges.add(cand.range());
+                    });
+        }
+        for (ResolvedReceiverCandidate cand : adapter.resolvedReceiverCandidatesOf(file, binder)) {
+            if (hitRanges.contains(cand.range())) continue;
+            resolveReceiverCandidate(cand, file, adapter, limits, frontier, externalFrontier)
+                    .ifPresent(resolved -> hits.add(new ReferenceHit(
+                            file,
+                            cand.range(),
+                            cand.enclosingUnit(),
+                            cand.kind(),
+                            resolved.target(),
+                            resolved.confidence())));
+        }
+        return Set.copyOf(hits);
+    }
+
     private static boolean matchesTarget(
             CodeUnit queryTarget, CodeUnit resolvedTarget, Map<String, Set<String>> heritageEdges) {
         if (queryTarget.source().equals(resolvedTarget.source())
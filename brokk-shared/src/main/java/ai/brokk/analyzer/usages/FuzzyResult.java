@@ -69,6 +69,34 @@ public sealed interface FuzzyResult
         return EitherUsagesOrError.from(uses);
     }
 
+    /**
+     * Keeps only the hits whose {@link UsageHit#kind()} is one of {@code kinds}, dropping unclassified hits. An empty
+     * {@code kinds} keeps every hit.
+     */
+    default FuzzyResult withKinds(Set<UsageKind> kinds) {
+        if (kinds.isEmpty()) {
+            return this;
+        }
+        return switch (this) {
+            case Success success -> new Success(filterByKind(success.hitsByOverload(), kinds));
+            case Ambiguous ambiguous ->
+                new Ambiguous(
+                        ambiguous.shortName(),
+                        ambiguous.candidateTargets(),
+                        filterByKind(ambiguous.hitsByOverload(), kinds));
+            case Failure failure -> failure;
+            case TooManyCallsites tooMany -> tooMany;
+        };
+    }
+
+    private static Map<CodeUnit, Set<UsageHit>> filterByKind(
+            Map<CodeUnit, Set<UsageHit>> hitsByOverload, Set<UsageKind> kinds) {
+        return hitsByOverload.entrySet().stream()
+                .collect(Collectors.toMap(Map.Entry::getKey, e -> e.getValue().stream()
+                        .filter(hit -> hit.kind() != null && kinds.contains(hit.kind()))
+                        .collect(Collectors.toSet())));
+    }
+
     /** Successful resolution of usages (possibly empty). */
     record Success(Map<CodeUnit, Set<UsageHit>> hitsByOverload) implements FuzzyResult {
         public Success(Map<CodeUnit, Set<UsageHit>> hitsByOverload) {
@@ -2,6 +2,7 @@ package ai.brokk.analyzer.usages;
 
 import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.IAnalyzer.Range;
+import java.util.List;
 import java.util.Set;
 
 public sealed interface LocalUsageEvent
@@ -10,6 +11,7 @@ public sealed interface LocalUsageEvent
                 LocalUsageEvent.DeclareSymbol,
                 LocalUsageEvent.SeedSymbol,
                 LocalUsageEvent.AliasSymbol,
+                LocalUsageEvent.CallResultSymbol,
                 LocalUsageEvent.ReceiverAccess {
 
     record EnterScope() implements LocalUsageEvent {}
@@ -22,6 +24,9 @@ public sealed interface LocalUsageEvent
 
     record AliasSymbol(String name, String sourceName) implements LocalUsageEvent {}
 
+    /** Binds {@code name} to the value reached by calling or reading {@code members} in turn on a receiver. */
+    record CallResultSymbol(String name, String receiverName, List<String> members) implements LocalUsageEvent {}
+
     record ReceiverAccess(
             String receiverName, String identifier, ReferenceKind kind, Range range, CodeUnit enclosingUnit)
             implements LocalUsageEvent {}
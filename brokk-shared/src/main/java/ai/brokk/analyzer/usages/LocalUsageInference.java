@@ -18,6 +18,17 @@ public final class LocalUsageInference {
         }
     }
 
+    /**
+     * Looks up the receivers for the value a method returns or a field holds, so
+     * {@link LocalUsageEvent.CallResultSymbol} can follow chains such as {@code pool.get().connection()}.
+     */
+    @FunctionalInterface
+    public interface ReturnTypeResolver {
+        ReturnTypeResolver NONE = (receiver, memberName) -> Set.of();
+
+        Set<ReceiverTargetRef> returnTargets(ReceiverTargetRef receiver, String memberName);
+    }
+
     private record LocalSymbolState(Set<ReceiverTargetRef> targets, int aliasDepth, boolean blocked) {}
 
     public static Set<ResolvedReceiverCandidate> infer(List<LocalUsageEvent> events) {
@@ -25,6 +36,15 @@ public final class LocalUsageInference {
     }
 
     public static Set<ResolvedReceiverCandidate> infer(List<LocalUsageEvent> events, Limits limits) {
+        return infer(events, limits, ReturnTypeResolver.NONE);
+    }
+
+    public static Set<ResolvedReceiverCandidate> infer(List<LocalUsageEvent> events, ReturnTypeResolver resolver) {
+        return infer(events, Limits.defaults(), resolver);
+    }
+
+    public static Set<ResolvedReceiverCandidate> infer(
+            List<LocalUsageEvent> events, Limits limits, ReturnTypeResolver resolver) {
         var scopes = new ArrayDeque<Map<String, LocalSymbolState>>();
         scopes.addLast(new HashMap<>());
         var resolved = new LinkedHashSet<ResolvedReceiverCandidate>();
@@ -43,6 +63,8 @@ public final class LocalUsageInference {
                     currentScope(scopes).put(seed.name(), stateFor(seed.targets(), 0, limits));
                 case LocalUsageEvent.AliasSymbol alias ->
                     currentScope(scopes).put(alias.name(), aliasState(alias.sourceName(), scopes, limits));
+                case LocalUsageEvent.CallResultSymbol call ->
+                    currentScope(scopes).put(call.name(), callResultState(call, scopes, limits, resolver));
                 case LocalUsageEvent.ReceiverAccess access ->
                     lookupVisible(access.receiverName(), scopes)
                             .filter(state ->
@@ -81,6 +103,30 @@ public final class LocalUsageInference {
         return stateFor(source.targets(), source.aliasDepth() + 1, limits);
     }
 
+    /** Each link in the chain counts as one alias hop, and a chain whose targets fan out past the cap is dropped. */
+    private static LocalSymbolState callResultState(
+            LocalUsageEvent.CallResultSymbol call,
+            ArrayDeque<Map<String, LocalSymbolState>> scopes,
+            Limits limits,
+            ReturnTypeResolver resolver) {
+        LocalSymbolState source = lookupVisible(call.receiverName(), scopes).orElse(null);
+        if (source == null || source.blocked() || source.targets().isEmpty()) {
+            return blockedState();
+        }
+        Set<ReceiverTargetRef> targets = source.targets();
+        for (String member : call.members()) {
+            var returned = new LinkedHashSet<ReceiverTargetRef>();
+            for (ReceiverTargetRef target : targets) {
+                returned.addAll(resolver.returnTargets(target, member));
+            }
+            if (returned.isEmpty() || returned.size() > limits.maxTargetsPerSymbol()) {
+                return blockedState();
+            }
+            targets = returned;
+        }
+        return stateFor(targets, source.aliasDepth() + call.members().size(), limits);
+    }
+
     private static LocalSymbolState stateFor(Set<ReceiverTargetRef> targets, int aliasDepth, Limits limits) {
         if (targets.isEmpty() || targets.size() > limits.maxTargetsPerSymbol()) {
             return blockedState();
@@ -3,6 +3,7 @@ package ai.brokk.analyzer.usages;
 import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.analyzer.RustAnalyzer;
+import java.util.List;
 import java.util.Map;
 import java.util.Set;
 import org.jetbrains.annotations.Nullable;
@@ -51,7 +52,12 @@ public final class RustExportUsageGraphAdapter implements ExportUsageGraphLangua
 
     @Override
     public Map<String, Set<String>> heritageIndex() {
-        return analyzer.heritageIndex();
+        return analyzer.usageHeritageIndex();
+    }
+
+    @Override
+    public List<CodeUnit> ancestorsOf(CodeUnit ownerClass) {
+        return analyzer.getAncestors(ownerClass);
     }
 
     @Override
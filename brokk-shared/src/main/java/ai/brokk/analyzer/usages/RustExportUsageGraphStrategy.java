@@ -44,7 +44,7 @@ public final class RustExportUsageGraphStrategy implements UsageAnalyzer {
         if (analyzer.isEmpty()) {
             return false;
         }
-        return !inferExportNames(target).isEmpty();
+        return !inferExportRoots(target).isEmpty();
     }
 
     @Override
@@ -58,20 +58,24 @@ public final class RustExportUsageGraphStrategy implements UsageAnalyzer {
         if (analyzer.isEmpty()) {
             return new FuzzyResult.Success(Map.of(target, Set.of()));
         }
-        Set<String> exportNames = inferExportNames(target);
-        if (exportNames.isEmpty()) {
+        Set<ExportRoot> exportRoots = inferExportRoots(target);
+        if (exportRoots.isEmpty()) {
             return new FuzzyResult.Success(Map.of(target, Set.of()));
         }
+        Set<String> exportNames =
+                exportRoots.stream().map(ExportRoot::exportName).collect(Collectors.toUnmodifiableSet());
 
         int graphHitLimit = maxUsages == Integer.MAX_VALUE ? maxUsages : maxUsages + 1;
-        var adapter = new RustExportUsageGraphAdapter(analyzer.orElseThrow());
+        RustAnalyzer rustAnalyzer = analyzer.orElseThrow();
+        var adapter = new RustExportUsageGraphAdapter(rustAnalyzer);
         var effectiveLimits = new ExportUsageReferenceGraphEngine.Limits(
                 limits.maxFiles(), Math.max(1, Math.min(limits.maxHits(), graphHitLimit)), limits.maxReexportDepth());
         Set<UsageHit> hits = new LinkedHashSet<>();
-        for (String exportName : exportNames) {
-            Set<ProjectFile> effectiveCandidateFiles = effectiveCandidateFiles(candidateFiles, exportNames, target);
+        Set<ProjectFile> effectiveCandidateFiles = effectiveCandidateFiles(candidateFiles, exportNames, target);
+        for (ExportRoot root : exportRoots) {
             ReferenceGraphResult graphResult = ExportUsageReferenceGraphEngine.findExportUsages(
-                    target.source(), exportName, target, adapter, effectiveLimits, effectiveCandidateFiles);
+                    root.definingFile(), root.exportName(), target, adapter, effectiveLimits, effectiveCandidateFiles);
+            Map<ReferenceHit, UsageKind> kinds = rustAnalyzer.usageKindsOf(graphResult.hits());
             hits.addAll(graphResult.hits().stream()
                     .map(hit -> new UsageHit(
                             hit.file(),
@@ -80,7 +84,8 @@ public final class RustExportUsageGraphStrategy implements UsageAnalyzer {
                             hit.range().endByte(),
                             hit.enclosingUnit(),
                             hit.confidence(),
-                            ""))
+                            "",
+                            kinds.get(hit)))
                     .collect(Collectors.toCollection(LinkedHashSet::new)));
             if (hits.size() > maxUsages) {
                 break;
@@ -111,17 +116,40 @@ public final class RustExportUsageGraphStrategy implements UsageAnalyzer {
         return filtered.isEmpty() ? Set.of(target.source()) : filtered;
     }
 
-    private Set<String> inferExportNames(CodeUnit target) {
-        var exportNames = new LinkedHashSet<>(inferExportNames(target.source(), target.identifier()));
-        analyzer.orElseThrow()
-                .parentOf(target)
-                .ifPresent(owner -> exportNames.addAll(inferExportNames(owner.source(), owner.identifier())));
-        if (exportNames.isEmpty()
-                && target.isFunction()
-                && analyzer.orElseThrow().parentOf(target).isEmpty()) {
-            exportNames.add(target.identifier());
+    /**
+     * Where the target is exported from: its own name, and for members the owning type. A trait impl may live apart
+     * from both its self type and its trait, so members of {@code impl Drawable for Point} are searched from where
+     * {@code Point} and {@code Drawable} are declared; calls through either resolve to the impl. The fields of an enum
+     * variant, as in {@code Shape.Named.name}, are searched from where the enum is declared.
+     */
+    private Set<ExportRoot> inferExportRoots(CodeUnit target) {
+        RustAnalyzer rustAnalyzer = analyzer.orElseThrow();
+        var roots = new LinkedHashSet<ExportRoot>();
+        addExportRoots(roots, target.source(), target.identifier());
+        Optional<CodeUnit> parent = rustAnalyzer.parentOf(target);
+        if (parent.isPresent() && parent.orElseThrow().isField()) {
+            parent = rustAnalyzer.parentOf(parent.orElseThrow());
         }
-        return Set.copyOf(exportNames);
+        parent.ifPresent(owner -> {
+            String selfTypeName = RustAnalyzer.implSelfTypeName(owner.identifier());
+            rustAnalyzer
+                    .resolveRustTypeName(owner.source(), selfTypeName)
+                    .ifPresentOrElse(
+                            selfType -> addExportRoots(roots, selfType.source(), selfType.identifier()),
+                            () -> addExportRoots(roots, owner.source(), selfTypeName));
+            RustAnalyzer.implTraitName(owner.identifier())
+                    .flatMap(traitName -> rustAnalyzer.resolveRustTypeName(owner.source(), traitName))
+                    .ifPresent(trait -> addExportRoots(roots, trait.source(), trait.identifier()));
+        });
+        if (roots.isEmpty() && target.isFunction() && rustAnalyzer.parentOf(target).isEmpty()) {
+            roots.add(new ExportRoot(target.source(), target.identifier()));
+        }
+        return Set.copyOf(roots);
+    }
+
+    private void addExportRoots(Set<ExportRoot> roots, ProjectFile definingFile, String localName) {
+        inferExportNames(definingFile, localName)
+                .forEach(exportName -> roots.add(new ExportRoot(definingFile, exportName)));
     }
 
     private Set<String> inferExportNames(ProjectFile definingFile, String localName) {
@@ -138,4 +166,6 @@ public final class RustExportUsageGraphStrategy implements UsageAnalyzer {
         }
         return Set.copyOf(exportNames);
     }
+
+    private record ExportRoot(ProjectFile definingFile, String exportName) {}
 }
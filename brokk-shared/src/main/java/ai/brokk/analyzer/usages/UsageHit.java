@@ -3,6 +3,7 @@ package ai.brokk.analyzer.usages;
 import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.ProjectFile;
 import java.util.Objects;
+import org.jetbrains.annotations.Nullable;
 
 /**
  * Immutable metadata describing a usage occurrence.
@@ -14,6 +15,7 @@ import java.util.Objects;
  * @param enclosing best-effort enclosing CodeUnit for the usage
  * @param confidence [0.0, 1.0], 1.0 for exact/unique matches; may be lower when disambiguated
  * @param snippet short text snippet around the usage location
+ * @param kind how the usage touches the target, or null when the analyzer does not classify usages
  */
 public record UsageHit(
         ProjectFile file,
@@ -22,9 +24,21 @@ public record UsageHit(
         int endOffset,
         CodeUnit enclosing,
         double confidence,
-        String snippet) {
+        String snippet,
+        @Nullable UsageKind kind) {
+    public UsageHit(
+            ProjectFile file,
+            int line,
+            int startOffset,
+            int endOffset,
+            CodeUnit enclosing,
+            double confidence,
+            String snippet) {
+        this(file, line, startOffset, endOffset, enclosing, confidence, snippet, null);
+    }
+
     public UsageHit withConfidence(double confidence) {
-        return new UsageHit(file, line, startOffset, endOffset, enclosing, confidence, snippet);
+        return new UsageHit(file, line, startOffset, endOffset, enclosing, confidence, snippet, kind);
     }
 
     @Override
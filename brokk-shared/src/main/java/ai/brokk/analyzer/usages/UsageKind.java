@@ -0,0 +1,56 @@
+package ai.brokk.analyzer.usages;
+
+import java.util.Arrays;
+import java.util.EnumSet;
+import java.util.List;
+import java.util.Locale;
+import java.util.Optional;
+import java.util.Set;
+import java.util.stream.Collectors;
+
+/** How a usage touches its target, for analyzers that can tell a mutation from a mention. */
+public enum UsageKind {
+    READ("read"),
+    WRITE("write"),
+    MUTABLE_BORROW("mutable-borrow"),
+    MOVE("move"),
+    TYPE("type");
+
+    private final String label;
+
+    UsageKind(String label) {
+        this.label = label;
+    }
+
+    public String label() {
+        return label;
+    }
+
+    /** Parses a label such as {@code mutable-borrow} or a constant name such as {@code MUTABLE_BORROW}. */
+    public static Optional<UsageKind> parse(String text) {
+        String normalized = text.strip().toLowerCase(Locale.ROOT).replace('_', '-');
+        return Arrays.stream(values())
+                .filter(kind -> kind.label.equals(normalized))
+                .findFirst();
+    }
+
+    /**
+     * Parses the kinds a usage tool was asked to keep, skipping blank entries.
+     *
+     * @throws IllegalArgumentException naming the accepted labels when a kind is unknown
+     */
+    public static Set<UsageKind> parseAll(List<String> texts) {
+        var parsed = EnumSet.noneOf(UsageKind.class);
+        for (String text : texts) {
+            if (text.isBlank()) continue;
+            var kind = parse(text);
+            if (kind.isEmpty()) {
+                String expected = Arrays.stream(values()).map(UsageKind::label).collect(Collectors.joining(", "));
+                throw new IllegalArgumentException(
+                        "Unknown usage kind: " + text + " (expected one of " + expected + ")");
+            }
+            parsed.add(kind.get());
+        }
+        return parsed;
+    }
+}
@@ -41,7 +41,8 @@ public final class UsageRenderer {
                 .toList();
 
         var callSites = hits.stream()
-                .map(hit -> "- `%s` (%s:%d)".formatted(hit.enclosing().fqName(), displayPath(hit.file()), hit.line()))
+                .map(hit -> "- `%s` (%s:%d%s)"
+                        .formatted(hit.enclosing().fqName(), displayPath(hit.file()), hit.line(), kindSuffix(hit)))
                 .collect(Collectors.joining("\n"));
 
         List<AnalyzerUtil.CodeWithSource> sources =
@@ -78,6 +79,11 @@ public final class UsageRenderer {
         return !hit.enclosing().equals(target);
     }
 
+    private static String kindSuffix(UsageHit hit) {
+        UsageKind kind = hit.kind();
+        return kind == null ? "" : ", " + kind.label();
+    }
+
     private static String displayPath(ProjectFile file) {
         return PathNormalizer.canonicalizeForProject(file.getRelPath().toString(), file.getRoot());
     }
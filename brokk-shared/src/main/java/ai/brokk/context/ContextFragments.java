@@ -7,6 +7,7 @@ import ai.brokk.IContextManager;
 import ai.brokk.analyzer.BrokkFile;
 import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.CodeUnitType;
+import ai.brokk.analyzer.ConditionalCompilationProvider;
 import ai.brokk.analyzer.ExternalFile;
 import ai.brokk.analyzer.IAnalyzer;
 import ai.brokk.analyzer.ImportAnalysisProvider;
@@ -111,7 +112,7 @@ public class ContextFragments {
                                 .map(p -> p.getDirectAncestors(cu))
                                 .orElse(List.of())
                                 .stream())
-                .filter(anc -> !anc.isAnonymous())
+                .filter(anc -> !anc.isAnonymous() && !anc.isSynthetic())
                 .distinct()
                 .map(anc -> new SummaryFragment(
                         contextManager, anc.fqName(), ContextFragment.SummaryType.CODEUNIT_SKELETON))
@@ -1480,7 +1481,8 @@ public class ContextFragments {
                     .filter(pf -> includeTestFiles || !ai.brokk.analyzer.TestFileHeuristics.isTestFile(pf, analyzer))
                     .collect(Collectors.toCollection(LinkedHashSet::new));
 
-            List<CodeUnit> overloads = List.copyOf(analyzer.getDefinitions(targetIdentifier));
+            List<CodeUnit> overloads =
+                    ConditionalCompilationProvider.preferActive(analyzer, analyzer.getDefinitions(targetIdentifier));
             if (overloads.isEmpty()) {
                 overloads = analyzer.searchDefinitions(targetIdentifier).stream()
                         .limit(5)
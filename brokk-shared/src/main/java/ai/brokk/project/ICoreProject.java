@@ -58,6 +58,14 @@ public interface ICoreProject extends AutoCloseable {
     /** Set language-specific source roots. */
     void setSourceRoots(Language language, List<String> roots);
 
+    /** Language-specific analyzer settings as JSON, in a format the language defines; empty if none are set. */
+    default String getLanguageSettings(Language language) {
+        return "";
+    }
+
+    /** Set language-specific analyzer settings. */
+    default void setLanguageSettings(Language language, String json) {}
+
     /** Check if a path is gitignored. */
     boolean isGitignored(Path relPath);
 
@@ -7,6 +7,12 @@
   ; where_clause: (_) @class.where_clause ; optional
   ) @class.definition
 
+;; Union definitions
+(union_item
+  (visibility_modifier)? @keyword.modifier
+  name: (type_identifier) @class.name
+  ) @class.definition
+
 ;; Enum definitions
 (enum_item
   (visibility_modifier)? @keyword.modifier
@@ -33,13 +39,12 @@
 ;; Impl blocks
 (impl_item
   ; For `impl MyType` or `impl Trait for MyType`
-  ; We capture MyType as the "class name" for associating methods.
-  ; If it's `impl Trait for MyType`, type arguments can make it complex.
-  ; This captures the direct type identifier after `impl` or after `for`.
+  ; No @impl.name capture: RustAnalyzer.extractSimpleName names the block `MyType` for inherent impls
+  ; and `MyType as Trait` for trait impls, so each trait impl stays a distinct unit.
   type: [
-          (type_identifier) @impl.name               ; e.g., impl MyType
-          (generic_type type: (type_identifier) @impl.name) ; e.g., impl<T> MyType<T>
-          (scoped_type_identifier name: (type_identifier) @impl.name) ; e.g., impl foo::MyType
+          (type_identifier)               ; e.g., impl MyType
+          (generic_type type: (type_identifier)) ; e.g., impl<T> MyType<T>
+          (scoped_type_identifier name: (type_identifier)) ; e.g., impl foo::MyType
           ; This list covers the type being implemented in `impl MyType`
           ; or the concrete type in `impl Trait for MyType`.
           ]
@@ -55,7 +60,7 @@
   ; body: (block)
   ) @function.definition
 
-;; Method signatures within trait definitions (no body)
+;; Method signatures within trait definitions and foreign functions in `extern "C" { ... }` blocks (no body)
 (function_signature_item
   (visibility_modifier)? @keyword.modifier
   name: (identifier) @function.name
@@ -74,6 +79,23 @@
           )
   )
 
+;; Positional fields of a tuple struct, named `0`, `1`, ... by RustAnalyzer.extractSimpleName
+(struct_item
+  body: (ordered_field_declaration_list
+          type: (_) @field.definition
+          )
+  )
+
+;; Fields within a union_item's field_declaration_list
+(union_item
+  body: (field_declaration_list
+          (field_declaration
+            (visibility_modifier)? @keyword.modifier
+            name: (field_identifier) @field.name
+            ) @field.definition
+          )
+  )
+
 ;; Top-level constants
 (const_item
   (visibility_modifier)? @keyword.modifier
@@ -82,7 +104,7 @@
   ; value: (_) @field.value
   ) @field.definition
 
-;; Top-level static items
+;; Top-level static items, including foreign statics in `extern "C" { ... }` blocks
 (static_item
   (visibility_modifier)? @keyword.modifier
   name: (identifier) @field.name
@@ -101,6 +123,22 @@
           )
   )
 
+;; Fields of struct-like and tuple-like enum variants, nested under their variant (e.g. `Color.Named.name`)
+(enum_variant
+  body: (field_declaration_list
+          (field_declaration
+            (visibility_modifier)? @keyword.modifier
+            name: (field_identifier) @field.name
+            ) @field.definition
+          )
+  )
+
+(enum_variant
+  body: (ordered_field_declaration_list
+          type: (_) @field.definition
+          )
+  )
+
 ;; Associated constants within impl blocks
 (impl_item
   body: (declaration_list
@@ -125,12 +163,22 @@
           )
   )
 
-;; Type aliases
+;; Declarative macros (macro_rules!)
+(macro_definition
+  name: (identifier) @macro.name
+  ) @macro.definition
+
+;; Type aliases, including associated types in impl blocks (`type Output = Meters;`)
 (type_item
   (visibility_modifier)? @keyword.modifier
   name: (type_identifier) @typealias.name
 ) @typealias.definition
 
+;; Associated types declared in trait definitions (`type Item;`)
+(associated_type
+  name: (type_identifier) @typealias.name
+) @typealias.definition
+
 ;; Test markers - capture attribute_item nodes directly for validation in Java
 (attribute_item
   (attribute
@@ -140,3 +188,7 @@
   (attribute
     (identifier)
     (token_tree (identifier)))) @test_marker
+
+(attribute_item
+  (attribute
+    (scoped_identifier))) @test_marker
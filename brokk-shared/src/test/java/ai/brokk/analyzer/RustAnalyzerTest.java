@@ -2,11 +2,20 @@ package ai.brokk.analyzer;
 
 import static ai.brokk.testutil.AssertionHelperUtil.assertCodeContains;
 import static ai.brokk.testutil.AssertionHelperUtil.assertCodeUnitType;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotEquals;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
 import ai.brokk.AnalyzerUtil;
+import ai.brokk.analyzer.rust.RustCfgSettings;
 import ai.brokk.project.ICoreProject;
 import ai.brokk.testutil.InlineCoreProject;
+import java.nio.file.Path;
+import java.util.List;
+import java.util.Optional;
+import java.util.Set;
+import java.util.stream.Collectors;
 import org.junit.jupiter.api.Test;
 
 public class RustAnalyzerTest {
@@ -249,6 +258,84 @@ public class RustAnalyzerTest {
         }
     }
 
+    @Test
+    void testTraitImplMembersAreQualifiedByTheirImpl() throws Exception {
+        String rustCode =
+                """
+            use std::fmt;
+
+            pub struct Point {
+                x: i32,
+            }
+
+            impl fmt::Display for Point {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.x)
+                }
+            }
+
+            impl fmt::Debug for Point {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "Point({})", self.x)
+                }
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(rustCode, "lib.rs").build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            analyzer.update();
+
+            CodeUnit displayFmt = analyzer.getDefinitions("Point as Display.fmt").stream()
+                    .findFirst()
+                    .orElseThrow();
+            CodeUnit debugFmt = analyzer.getDefinitions("Point as Debug.fmt").stream()
+                    .findFirst()
+                    .orElseThrow();
+            assertNotEquals(displayFmt, debugFmt);
+            assertEquals("Point as Display", analyzer.parentOf(displayFmt).orElseThrow().shortName());
+            assertEquals("Point as Debug", analyzer.parentOf(debugFmt).orElseThrow().shortName());
+
+            // Looked up as a member of the type, `Point.fmt` names both.
+            assertEquals(Set.of(displayFmt, debugFmt), Set.copyOf(analyzer.getDefinitions("Point.fmt")));
+        }
+    }
+
+    @Test
+    void testGenericTraitImplsAreSeparatedByTheirTypeArguments() throws Exception {
+        String rustCode =
+                """
+            pub struct Celsius(f64);
+
+            impl From<f64> for Celsius {
+                fn from(value: f64) -> Self {
+                    Celsius(value)
+                }
+            }
+
+            impl From<(i32, u32)> for Celsius {
+                fn from(value: (i32, u32)) -> Self {
+                    Celsius(value.0 as f64 + value.1 as f64 / 100.0)
+                }
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(rustCode, "lib.rs").build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            analyzer.update();
+
+            CodeUnit fromFloat = analyzer.getDefinitions("Celsius as From<f64>.from").stream()
+                    .findFirst()
+                    .orElseThrow();
+            CodeUnit fromPair = analyzer.getDefinitions("Celsius as From<(i32, u32)>.from").stream()
+                    .findFirst()
+                    .orElseThrow();
+            assertNotEquals(fromFloat, fromPair);
+            assertEquals("Celsius as From<f64>", analyzer.parentOf(fromFloat).orElseThrow().shortName());
+            assertEquals(Set.of(fromFloat, fromPair), Set.copyOf(analyzer.getDefinitions("Celsius.from")));
+            assertEquals(Optional.of("From"), RustAnalyzer.implTraitName("Celsius as From<(i32, u32)>"));
+        }
+    }
+
     @Test
     void testNestedModulesWithTestFunction() throws Exception {
         String rustCode =
@@ -354,4 +441,457 @@ public class RustAnalyzerTest {
             assertCodeContains(associatedConstSkeleton, "pub const ID: i32 = 1;");
         }
     }
+
+    @Test
+    void testMacroRulesDefinitions() throws Exception {
+        String rustCode =
+                """
+            #[macro_export]
+            macro_rules! square {
+                ($x:expr) => {
+                    $x * $x
+                };
+                ($x:expr,   $y:expr) => {
+                    $x * $y
+                };
+            }
+
+            mod helpers {
+                macro_rules! noop {
+                    () => {};
+                }
+            }
+
+            pub fn area(side: i32) -> i32 {
+                square!(side)
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(rustCode, "lib.rs").build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            analyzer.update();
+
+            assertCodeUnitType(analyzer, "square", CodeUnitType.MACRO);
+            assertCodeUnitType(analyzer, "helpers.noop", CodeUnitType.MACRO);
+
+            String squareSkeleton = AnalyzerUtil.getSkeleton(analyzer, "square").orElseThrow();
+            assertCodeContains(squareSkeleton, "#[macro_export]");
+            assertCodeContains(squareSkeleton, "macro_rules! square {");
+            assertCodeContains(squareSkeleton, "($x:expr) => { ... };");
+            assertCodeContains(squareSkeleton, "($x:expr, $y:expr) => { ... };");
+            assertFalse(squareSkeleton.contains("$x * $x"), "Macro skeleton should elide transcribers");
+
+            String squareSource = AnalyzerUtil.getSource(analyzer, "square", false).orElseThrow();
+            assertCodeContains(squareSource, "$x * $x");
+        }
+    }
+
+    @Test
+    void testGenericsLifetimesAndWhereClausesInSignatures() throws Exception {
+        String rustCode =
+                """
+            pub struct Cache<'a, T: Clone + Send>
+            where
+                T: std::hash::Hash,
+            {
+                entries: &'a [T],
+            }
+
+            pub struct Wrapper<T>(T)
+            where
+                T: Copy;
+
+            pub trait Store<K>: Clone + Send
+            where
+                K: Eq,
+            {
+                fn get(&self, key: &K) -> Option<&K>;
+            }
+
+            impl<'a, T> Cache<'a, T>
+            where
+                T: Clone + Send + std::hash::Hash,
+            {
+                pub fn find<Q: ?Sized>(
+                    &self,
+                    key: &Q,
+                ) -> Option<&'a T>
+                where
+                    T: std::borrow::Borrow<Q>,
+                    Q: Eq,
+                {
+                    None
+                }
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(rustCode, "lib.rs").build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            analyzer.update();
+
+            String cacheSkeleton = AnalyzerUtil.getSkeleton(analyzer, "Cache").orElseThrow();
+            assertCodeContains(cacheSkeleton, "pub struct Cache<'a, T: Clone + Send> where T: std::hash::Hash {");
+            assertCodeContains(cacheSkeleton, "impl<'a, T> Cache<'a, T> where T: Clone + Send + std::hash::Hash {");
+            assertCodeContains(
+                    cacheSkeleton,
+                    "pub fn find<Q: ?Sized>(&self, key: &Q) -> Option<&'a T> where T: std::borrow::Borrow<Q>, Q: Eq"
+                            + " { ... }");
+
+            String wrapperSkeleton = AnalyzerUtil.getSkeleton(analyzer, "Wrapper").orElseThrow();
+            assertCodeContains(wrapperSkeleton, "pub struct Wrapper<T> where T: Copy {");
+
+            String storeSkeleton = AnalyzerUtil.getSkeleton(analyzer, "Store").orElseThrow();
+            assertCodeContains(storeSkeleton, "pub trait Store<K>: Clone + Send where K: Eq {");
+            assertCodeContains(storeSkeleton, "fn get(&self, key: &K) -> Option<&K>;");
+
+            CodeUnit cache = analyzer.getDefinitions("Cache").stream()
+                    .filter(CodeUnit::isClass)
+                    .findFirst()
+                    .orElseThrow();
+            assertTrue(analyzer.getDisplaySignatures(cache)
+                    .contains("pub struct Cache<'a, T: Clone + Send> where T: std::hash::Hash"));
+
+            CodeUnit find = analyzer.getDefinitions("Cache.find").stream()
+                    .findFirst()
+                    .orElseThrow();
+            assertEquals(
+                    "pub fn find<Q: ?Sized>(&self, key: &Q) -> Option<&'a T> where T: std::borrow::Borrow<Q>, Q: Eq"
+                            + " { ... }",
+                    analyzer.getDisplaySignatures(find).getFirst());
+        }
+    }
+
+    @Test
+    void testUnionsAndForeignItems() throws Exception {
+        String rustCode =
+                """
+            #[repr(C)]
+            pub union IntOrFloat {
+                pub i: u32,
+                f: f32,
+            }
+
+            extern "C" {
+                pub fn abs(input: i32) -> i32;
+                pub static mut errno: i32;
+            }
+
+            pub extern "C" fn callback(code: i32) {}
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(rustCode, "lib.rs").build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            analyzer.update();
+
+            assertCodeUnitType(analyzer, "IntOrFloat", CodeUnitType.CLASS);
+            assertCodeUnitType(analyzer, "IntOrFloat.i", CodeUnitType.FIELD);
+            String unionSkeleton = AnalyzerUtil.getSkeleton(analyzer, "IntOrFloat").orElseThrow();
+            assertCodeContains(unionSkeleton, "pub union IntOrFloat {");
+            assertCodeContains(unionSkeleton, "pub i: u32");
+            assertCodeContains(unionSkeleton, "f: f32");
+
+            assertCodeUnitType(analyzer, "abs", CodeUnitType.FUNCTION);
+            String absSkeleton = AnalyzerUtil.getSkeleton(analyzer, "abs").orElseThrow();
+            assertCodeContains(absSkeleton, "pub extern \"C\" fn abs(input: i32) -> i32;");
+
+            String errnoSkeleton =
+                    AnalyzerUtil.getSkeleton(analyzer, "_module_.errno").orElseThrow();
+            assertCodeContains(errnoSkeleton, "pub extern \"C\" static mut errno: i32;");
+
+            String callbackSkeleton = AnalyzerUtil.getSkeleton(analyzer, "callback").orElseThrow();
+            assertCodeContains(callbackSkeleton, "pub extern \"C\" fn callback(code: i32) { ... }");
+        }
+    }
+
+    @Test
+    void testPositionalFieldsVariantFieldsAndAssociatedTypes() throws Exception {
+        String rustCode =
+                """
+            pub struct Meters(pub f64, u8);
+
+            pub enum Shape {
+                Circle(f64),
+                Named { name: String },
+            }
+
+            pub trait Source {
+                type Item: Clone;
+                fn next(&mut self) -> Option<Self::Item>;
+            }
+
+            pub struct Counter;
+
+            impl Source for Counter {
+                type Item = u32;
+                fn next(&mut self) -> Option<u32> { None }
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(rustCode, "lib.rs").build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            analyzer.update();
+
+            assertCodeUnitType(analyzer, "Meters.0", CodeUnitType.FIELD);
+            assertCodeUnitType(analyzer, "Meters.1", CodeUnitType.FIELD);
+            CodeUnit meters = analyzer.getDefinitions("Meters").stream().findFirst().orElseThrow();
+            assertEquals(Set.of("Meters"), analyzer.getSymbols(Set.of(meters)), "Positional indices are no symbols");
+            String metersSkeleton = AnalyzerUtil.getSkeleton(analyzer, "Meters").orElseThrow();
+            assertCodeContains(metersSkeleton, "pub 0: f64");
+            assertCodeContains(metersSkeleton, "1: u8");
+
+            assertCodeUnitType(analyzer, "Shape.Circle.0", CodeUnitType.FIELD);
+            assertCodeUnitType(analyzer, "Shape.Named.name", CodeUnitType.FIELD);
+            CodeUnit named = analyzer.getDefinitions("Shape.Named").stream()
+                    .findFirst()
+                    .orElseThrow();
+            CodeUnit name = analyzer.getDefinitions("Shape.Named.name").stream()
+                    .findFirst()
+                    .orElseThrow();
+            assertEquals(List.of(name), analyzer.getDirectChildren(named));
+            assertCodeContains(
+                    AnalyzerUtil.getSource(analyzer, "Shape.Named.name", false).orElseThrow(), "name: String");
+            String shapeSkeleton = AnalyzerUtil.getSkeleton(analyzer, "Shape").orElseThrow();
+            assertEquals(1, shapeSkeleton.split("name: String", -1).length - 1, "Variant fields render once");
+
+            assertTrue(analyzer.isTypeAlias(analyzer.getDefinitions("Source.Item").stream()
+                    .findFirst()
+                    .orElseThrow()));
+            assertCodeContains(AnalyzerUtil.getSkeleton(analyzer, "Source").orElseThrow(), "type Item: Clone;");
+            CodeUnit counterItem = analyzer.getDefinitions("Counter.Item").stream()
+                    .findFirst()
+                    .orElseThrow();
+            assertEquals(
+                    "Counter as Source",
+                    analyzer.parentOf(counterItem).orElseThrow().shortName());
+            assertCodeContains(
+                    AnalyzerUtil.getSkeleton(analyzer, "Counter as Source").orElseThrow(), "type Item = u32;");
+        }
+    }
+
+    @Test
+    void testModulePathsFollowCargoTargetsAndPathAttributes() throws Exception {
+        String workspaceManifest =
+                """
+            [workspace]
+            members = ["crates/core-utils", "crates/app"]
+            """;
+        String coreManifest =
+                """
+            [package]
+            name = "core-utils"
+            version = "0.1.0"
+
+            [lib]
+            path = "src/core.rs"
+            """;
+        String coreRoot =
+                """
+            pub mod shapes;
+
+            #[path = "generated/ids.rs"]
+            pub mod ids;
+
+            pub struct Engine;
+            """;
+        String appManifest =
+                """
+            [package]
+            name = "app"
+            version = "0.1.0"
+            """;
+        String appMain =
+                """
+            mod cli;
+
+            use core_utils::shapes::Circle;
+            use crate::cli::Args;
+
+            fn main() {}
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(workspaceManifest, "Cargo.toml")
+                .addFileContents(coreManifest, "crates/core-utils/Cargo.toml")
+                .addFileContents(coreRoot, "crates/core-utils/src/core.rs")
+                .addFileContents("pub struct Circle;\n", "crates/core-utils/src/shapes.rs")
+                .addFileContents("pub mod raw;\npub struct Id;\n", "crates/core-utils/src/generated/ids.rs")
+                .addFileContents("pub struct RawId;\n", "crates/core-utils/src/generated/raw.rs")
+                .addFileContents(appManifest, "crates/app/Cargo.toml")
+                .addFileContents(appMain, "crates/app/src/main.rs")
+                .addFileContents("pub struct Args;\n", "crates/app/src/cli.rs")
+                .addFileContents("pub struct Tool;\n", "crates/app/src/bin/tool.rs")
+                .addFileContents("struct Demo;\n", "crates/app/examples/demo.rs")
+                .build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+
+            for (String fqn : List.of(
+                    "core_utils.Engine",
+                    "core_utils.shapes.Circle",
+                    "core_utils.ids.Id",
+                    "core_utils.ids.raw.RawId",
+                    "app.cli.Args",
+                    "tool.Tool",
+                    "demo.Demo")) {
+                assertFalse(analyzer.getDefinitions(fqn).isEmpty(), fqn + " should be defined");
+            }
+            assertTrue(analyzer.getDefinitions("crates.core-utils.src.shapes.Circle")
+                    .isEmpty());
+
+            ProjectFile main =
+                    project.getFileByRelPath(Path.of("crates/app/src/main.rs")).orElseThrow();
+            assertEquals("app", analyzer.packageNameOf(main));
+            assertEquals(
+                    Set.of("core_utils.shapes.Circle", "app.cli.Args"),
+                    analyzer.importedCodeUnitsOf(main).stream()
+                            .map(CodeUnit::fqName)
+                            .collect(Collectors.toSet()));
+        }
+    }
+
+    @Test
+    void testPathAttributesInsideInlineModulesAndCommentsFollowTheSyntaxTree() throws Exception {
+        String manifest =
+                """
+            [package]
+            name = "alpha"
+            version = "0.1.0"
+            """;
+        String lib =
+                """
+            // #[path = "gen/ids.rs"] mod ids;
+            const DOC: &str = "#[path = \\"gen/ids.rs\\"] mod quoted;";
+
+            pub mod outer {
+                #[path = "gen/inner_impl.rs"]
+                pub mod inner;
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(manifest, "Cargo.toml")
+                .addFileContents(lib, "src/lib.rs")
+                .addFileContents("pub struct Id;\n", "src/gen/ids.rs")
+                .addFileContents("pub struct Inner;\n", "src/outer/gen/inner_impl.rs")
+                .build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+
+            assertFalse(analyzer.getDefinitions("alpha.outer.inner.Inner").isEmpty());
+            assertFalse(analyzer.getDefinitions("alpha.gen.ids.Id").isEmpty());
+            assertTrue(analyzer.getDefinitions("alpha.ids.Id").isEmpty());
+            assertTrue(analyzer.getDefinitions("alpha.quoted.Id").isEmpty());
+        }
+    }
+
+    @Test
+    void testCrateLayoutFollowsManifestEdits() throws Exception {
+        String manifest =
+                """
+            [package]
+            name = "alpha"
+            version = "0.1.0"
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(manifest, "Cargo.toml")
+                .addFileContents("pub mod shapes;\n", "src/lib.rs")
+                .addFileContents("pub struct Circle;\n", "src/shapes.rs")
+                .build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            assertFalse(analyzer.getDefinitions("alpha.shapes.Circle").isEmpty());
+
+            ProjectFile shapes = project.getFileByRelPath(Path.of("src/shapes.rs")).orElseThrow();
+            shapes.write("pub struct Circle;\npub struct Square;\n");
+            IAnalyzer edited = analyzer.update(Set.of(shapes));
+            assertFalse(edited.getDefinitions("alpha.shapes.Square").isEmpty());
+
+            ProjectFile cargoToml = project.getFileByRelPath(Path.of("Cargo.toml")).orElseThrow();
+            cargoToml.write(manifest.replace("alpha", "beta"));
+            IAnalyzer renamed = edited.update(Set.of(cargoToml));
+            assertFalse(renamed.getDefinitions("beta.shapes.Circle").isEmpty());
+            assertTrue(renamed.getDefinitions("alpha.shapes.Circle").isEmpty());
+        }
+    }
+
+    @Test
+    void testCfgConditionsFollowItemsModulesAndSettings() throws Exception {
+        String manifest =
+                """
+            [package]
+            name = "plat"
+            version = "0.1.0"
+
+            [features]
+            default = ["std"]
+            std = ["fast"]
+            fast = []
+            serde = []
+            """;
+        String lib =
+                """
+            #[cfg(windows)]
+            mod win;
+            mod extras;
+
+            #[cfg(target_os = "windows")]
+            pub fn home_dir() -> String { String::new() }
+
+            #[cfg(not(target_os = "windows"))]
+            pub fn home_dir() -> &'static str { "/home" }
+
+            #[cfg(feature = "fast")]
+            pub fn fast_path() {}
+
+            #[cfg(test)]
+            mod tests {
+                #[test]
+                fn it_works() {}
+            }
+            """;
+
+        try (ICoreProject project = InlineCoreProject.code(manifest, "Cargo.toml")
+                .addFileContents(lib, "src/lib.rs")
+                .addFileContents("pub fn open() {}\n", "src/win.rs")
+                .addFileContents("#![cfg(feature = \"serde\")]\npub fn to_json() {}\n", "src/extras.rs")
+                .build()) {
+            RustCfgSettings.store(project, new RustCfgSettings(true, List.of(), "linux", false, List.of()));
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            CodeUnit open = definition(analyzer, "plat.win.open");
+            CodeUnit toJson = definition(analyzer, "plat.extras.to_json");
+            CodeUnit fastPath = definition(analyzer, "plat.fast_path");
+            CodeUnit itWorks = definition(analyzer, "plat.tests.it_works");
+            CodeUnit homeDir = definition(analyzer, "plat.home_dir");
+
+            assertEquals(Optional.of("windows"), analyzer.compilationCondition(open));
+            assertEquals(Optional.of("feature = \"serde\""), analyzer.compilationCondition(toJson));
+            assertEquals(Optional.of("feature = \"fast\""), analyzer.compilationCondition(fastPath));
+            assertEquals(Optional.of("test"), analyzer.compilationCondition(itWorks));
+            assertEquals(
+                    Optional.of("any(target_os = \"windows\", not(target_os = \"windows\"))"),
+                    analyzer.compilationCondition(homeDir));
+
+            // Default features are on (default -> std -> fast); the target is Linux and cfg(test) is off.
+            assertFalse(analyzer.isActive(open));
+            assertFalse(analyzer.isActive(toJson));
+            assertTrue(analyzer.isActive(fastPath));
+            assertFalse(analyzer.isActive(itWorks));
+            assertTrue(analyzer.isActive(homeDir));
+            assertEquals(" [cfg(windows), inactive]", ConditionalCompilationProvider.describe(analyzer, open));
+            assertEquals(List.of(fastPath, open), List.copyOf(analyzer.sortDefinitions(Set.of(open, fastPath))));
+
+            // The skeleton of a per-platform item shows only the variant that is built.
+            String skeleton = analyzer.getSkeleton(homeDir).orElseThrow();
+            assertTrue(skeleton.contains("&'static str"), skeleton);
+            assertFalse(skeleton.contains("String"), skeleton);
+
+            RustCfgSettings.store(project, new RustCfgSettings(false, List.of("serde"), "windows", true, List.of()));
+            assertTrue(analyzer.isActive(open));
+            assertTrue(analyzer.isActive(toJson));
+            assertFalse(analyzer.isActive(fastPath));
+            assertTrue(analyzer.isActive(itWorks));
+            assertEquals(List.of(open, fastPath), List.copyOf(analyzer.sortDefinitions(Set.of(open, fastPath))));
+        }
+    }
+
+    private static CodeUnit definition(RustAnalyzer analyzer, String fqName) {
+        return analyzer.getDefinitions(fqName).stream()
+                .findFirst()
+                .orElseThrow(() -> new AssertionError(fqName + " should be defined"));
+    }
 }
@@ -0,0 +1,132 @@
+package ai.brokk.analyzer;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import ai.brokk.AnalyzerUtil;
+import ai.brokk.testutil.InlineTestProjectCreator;
+import java.util.List;
+import java.util.Set;
+import java.util.stream.Collectors;
+import org.junit.jupiter.api.Test;
+
+class RustCallGraphTest {
+
+    private static final String SERVICE =
+            """
+            pub struct Store;
+
+            impl Store {
+                pub fn open() -> Store {
+                    Store
+                }
+
+                pub fn load(&self) -> u32 {
+                    self.read()
+                }
+
+                pub fn read(&self) -> u32 {
+                    parse()
+                }
+            }
+
+            pub fn parse() -> u32 {
+                1
+            }
+            """;
+
+    private static final String MAIN =
+            """
+            use crate::service::Store;
+
+            fn run() -> u32 {
+                let store: Store = Store::open();
+                let total = store.load();
+                total + offset()
+            }
+
+            fn offset() -> u32 {
+                1
+            }
+            """;
+
+    @Test
+    void calleesResolveAssociatedSelfTypedLocalAndFreeFunctionCalls() throws Exception {
+        try (var project = InlineTestProjectCreator.code(SERVICE, "src/service.rs")
+                .addFileContents(MAIN, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+
+            var runCallees = analyzer.getCallees(function(analyzer, "run"));
+            assertEquals(Set.of("open", "load", "offset"), identifiers(runCallees), runCallees.toString());
+            assertTrue(runCallees.stream()
+                    .anyMatch(site -> site.target().identifier().equals("open")
+                            && site.sourceLine().equals("let store: Store = Store::open();")));
+
+            assertEquals(Set.of("read"), identifiers(analyzer.getCallees(function(analyzer, "load"))));
+            assertEquals(Set.of("parse"), identifiers(analyzer.getCallees(function(analyzer, "read"))));
+            assertTrue(analyzer.getCallees(function(analyzer, "offset")).isEmpty());
+        }
+    }
+
+    @Test
+    void callersFollowCallChainToRequestedDepth() throws Exception {
+        try (var project = InlineTestProjectCreator.code(SERVICE, "src/service.rs")
+                .addFileContents(MAIN, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            CodeUnit parse = function(analyzer, "parse");
+
+            var shallow = analyzer.getCallGraphTo(parse, 2);
+            assertEquals(
+                    List.of(parse.fqName(), function(analyzer, "read").fqName()), List.copyOf(shallow.keySet()));
+
+            var deep = analyzer.getCallGraphTo(parse, 5);
+            assertEquals(Set.of("run"), identifiers(deep.get(function(analyzer, "load").fqName())));
+            assertEquals(3, deep.size(), deep.toString());
+
+            String rendered = AnalyzerUtil.formatCallGraph(deep, parse.fqName(), true);
+            assertTrue(rendered.startsWith(parse.fqName()), rendered);
+            assertTrue(rendered.contains("<- " + function(analyzer, "run").fqName()), rendered);
+            assertTrue(rendered.contains("let total = store.load();"), rendered);
+        }
+    }
+
+    @Test
+    void functionsPassedAsValuesAreNotCalls() throws Exception {
+        String code =
+                """
+                fn double(x: u32) -> u32 {
+                    x * 2
+                }
+
+                fn apply(values: Vec<u32>) -> Vec<u32> {
+                    values.into_iter().map(double).collect()
+                }
+
+                fn twice(x: u32) -> u32 {
+                    double(double(x))
+                }
+                """;
+        try (var project = InlineTestProjectCreator.code(code, "src/lib.rs").build()) {
+            var analyzer = new RustAnalyzer(project);
+
+            assertTrue(analyzer.getCallees(function(analyzer, "apply")).isEmpty());
+            var callers = analyzer.getCallers(function(analyzer, "double"));
+            assertEquals(Set.of("twice"), identifiers(callers), callers.toString());
+            assertEquals(2, callers.size(), callers.toString());
+        }
+    }
+
+    private static CodeUnit function(RustAnalyzer analyzer, String name) {
+        return analyzer.getAllDeclarations().stream()
+                .filter(CodeUnit::isFunction)
+                .filter(cu -> cu.identifier().equals(name))
+                .findFirst()
+                .orElseThrow();
+    }
+
+    private static Set<String> identifiers(List<CallSite> sites) {
+        return sites.stream().map(site -> site.target().identifier()).collect(Collectors.toSet());
+    }
+}
@@ -68,18 +68,10 @@ public class TreeSitterAnalyzerRustTest {
         assertFalse(skeletonsInPointRs.isEmpty(), "Skeletons map for Point.rs should not be empty.");
 
         CodeUnit pointCU = CodeUnit.cls(pointRsFile, "", "Point"); // From `pub struct Point`
-        CodeUnit pointImplCU = CodeUnit.cls(pointRsFile, "", "Point"); // From `impl Point`
-        // Note: `impl Point` and `struct Point` will both map to CU "Point". Signatures will be aggregated.
-        // The SCM for impl_item captures the type being implemented as @class.name.
-        // So for `impl Point`, simpleName is "Point". For `impl Drawable for Point`, simpleName is "Drawable".
-        // The createCodeUnit logic will make `CodeUnit.cls(..., "Point")` for `impl Point`.
-        // If the SCM for `impl_item` has `@class.name` point to `Point` in `impl Drawable for Point`, then `simpleName`
-        // for that block would be `Point`.
-        // The provided SCM for `impl_item` uses `@impl.type.target` for `impl Trait for ActualType` -> `ActualType`.
-        // Let's adjust the test expectation slightly if `impl Drawable for Point` creates a CU for `Point` rather than
-        // `Drawable`.
-        // The current SCM has `type: [ ... (type_identifier) @impl.type.target ... ] @class.name`. This means
-        // @class.name will be `Point` for `impl Drawable for Point`.
+        // Note: `impl Point` and `struct Point` both map to CU "Point" and their signatures are aggregated.
+        // Each trait impl is its own CU named `Type as Trait`, e.g. `impl Drawable for Point` -> "Point as Drawable".
+        CodeUnit pointAsDrawableCU = CodeUnit.cls(pointRsFile, "", "Point as Drawable");
+        CodeUnit pointAsShapeCU = CodeUnit.cls(pointRsFile, "", "Point as Shape");
 
         CodeUnit drawableTraitCU = CodeUnit.cls(pointRsFile, "", "Drawable"); // From `pub trait Drawable`
 
@@ -93,25 +85,18 @@ public class TreeSitterAnalyzerRustTest {
         String pointSkeleton = skeletonsInPointRs.get(pointCU);
         assertNotNull(pointSkeleton);
 
-        // Expected skeleton for Point (combining struct and its impl block, and impl Drawable for Point)
+        // Expected skeleton for Point (combining struct and its inherent impl block)
         // Fields in Point.rs do not have semicolons.
         // The TreeSitterAnalyzer's reconstructSkeletonRecursive method will list all signatures for "Point"
-        // (struct, impl Point, impl Drawable for Point) first, then all children (fields and methods),
-        // then a single closer.
+        // (struct, impl Point) first, then all children (fields and methods), then a single closer.
         String expectedPointSkeleton =
                 """
                                        pub struct Point {
                                        impl Point {
-                                       impl Drawable for Point {
-                                       impl Shape for Point {
-                                       impl DefaultPosition for Point {
                                          pub x: i32
                                          pub y: i32
                                          pub fn new(x: i32, y: i32) -> Self { ... }
                                          pub fn translate(&mut self, dx: i32, dy: i32) { ... }
-                                         fn draw(&self) { ... }
-                                         const ID: u32 = 1;
-                                         fn area(&self) -> f64 { ... }
                                        }
                                        """;
         assertEquals(
@@ -119,6 +104,36 @@ public class TreeSitterAnalyzerRustTest {
                 normalizeSource.apply(pointSkeleton),
                 "Point struct/impl skeleton mismatch.");
 
+        // Trait impls keep their own `impl Trait for Type` header and members.
+        assertTrue(
+                skeletonsInPointRs.containsKey(pointAsDrawableCU),
+                "Skeletons map should contain `Point as Drawable`. Found: " + skeletonsInPointRs.keySet());
+        String expectedPointAsDrawableSkeleton =
+                """
+                                       impl Drawable for Point {
+                                         fn draw(&self) { ... }
+                                       }
+                                       """;
+        assertEquals(
+                normalizeSource.apply(expectedPointAsDrawableSkeleton),
+                normalizeSource.apply(skeletonsInPointRs.get(pointAsDrawableCU)),
+                "Point as Drawable skeleton mismatch.");
+
+        assertTrue(
+                skeletonsInPointRs.containsKey(pointAsShapeCU),
+                "Skeletons map should contain `Point as Shape`. Found: " + skeletonsInPointRs.keySet());
+        String expectedPointAsShapeSkeleton =
+                """
+                                       impl Shape for Point {
+                                         const ID: u32 = 1;
+                                         fn area(&self) -> f64 { ... }
+                                       }
+                                       """;
+        assertEquals(
+                normalizeSource.apply(expectedPointAsShapeSkeleton),
+                normalizeSource.apply(skeletonsInPointRs.get(pointAsShapeCU)),
+                "Point as Shape skeleton mismatch.");
+
         String drawableSkeleton = skeletonsInPointRs.get(drawableTraitCU);
         String expectedDrawableSkeleton =
                 """
@@ -197,12 +212,8 @@ public class TreeSitterAnalyzerRustTest {
                 """
                 pub struct Point {
                 impl Point {
-                impl Drawable for Point {
-                impl Shape for Point {
-                impl DefaultPosition for Point {
                   pub x: i32
                   pub y: i32
-                  const ID: u32 = 1;
                   [...]
                 }
                 """
@@ -257,73 +268,39 @@ public class TreeSitterAnalyzerRustTest {
 
     @Test
     void testGetMembersInClass_Rust() {
-        // Members of struct Point (fields, methods from impl Point, impl Drawable for Point, impl Shape for Point, impl
-        // DefaultPosition for Point)
+        // Members of struct Point: fields from the struct and methods from the inherent `impl Point`.
+        // Trait impl members belong to, and are named after, their `Point as Trait` unit.
         List<CodeUnit> pointMembers = AnalyzerUtil.getMembersInClass(rsAnalyzer, "Point");
-        Set<String> expectedPointMemberFqNames = Set.of(
-                "Point.x",
-                "Point.y", // fields from struct Point
-                "Point.new",
-                "Point.translate", // methods from impl Point
-                "Point.draw", // method from impl Drawable for Point
-                "Point.ID", // associated const from impl Shape for Point
-                "Point.area", // method from impl Shape for Point
-                "Point.DEFAULT_X",
-                "Point.DEFAULT_Y" // associated consts from DefaultPosition (via impl DefaultPosition for Point)
-                // default_pos is also a member via DefaultPosition trait
-                );
         Set<String> actualPointMemberFqNames =
                 pointMembers.stream().map(CodeUnit::fqName).collect(Collectors.toSet());
-
-        // Expected members: Point.x, Point.y, Point.new, Point.translate, Point.draw, Point.ID (from impl Shape),
-        // Point.area, Point.DEFAULT_X, Point.DEFAULT_Y, Point.default_pos
-        // Total: 10
-        // Note: The 'default_pos' method from DefaultPosition should also be a member of Point through its impl.
-        // We need to ensure methods from traits (even with default implementations) are captured if the trait is
-        // implemented.
-        // The current SCM for `impl_item` captures the type `Point` as the class for `impl DefaultPosition for Point`.
-        // Children of `impl DefaultPosition for Point {}` (if any were explicitly defined there) would be members of
-        // `Point`.
-        // To get members from the trait `DefaultPosition` itself (like default_pos), we need to handle trait
-        // inheritance/composition,
-        // which is more complex. For now, getMembersInClass will primarily show direct declarations under the impl.
-        // Let's adjust expectation: only `Point.ID` from `impl Shape` is directly in an impl for Point.
-        // `DEFAULT_X` and `DEFAULT_Y` would appear if `impl DefaultPosition for Point` explicitly defined them or if
-        // we had logic to pull in trait defaults. For now, `getMembersInClass("Point")` will find fields from struct
-        // Point,
-        // methods from `impl Point`, `impl Drawable for Point`, `impl Shape for Point`.
-        // It will NOT automatically find `Point.DEFAULT_X` unless `impl DefaultPosition for Point` overrode it or
-        // re-declared it.
-        // The current `RustAnalyzer` and query don't explicitly resolve trait items into implementing types' member
-        // lists
-        // unless they are re-declared in the `impl` block.
-
-        // Re-evaluating expected members for "Point":
-        // Fields: Point.x, Point.y
-        // Methods in `impl Point`: Point.new, Point.translate
-        // Methods in `impl Drawable for Point`: Point.draw
-        // Const in `impl Shape for Point`: Point.ID
-        // Method in `impl Shape for Point`: Point.area
-        // Total 7 directly defined/associated members under 'Point' through its struct and impls.
         Set<String> expectedPointMembersStrict = Set.of(
                 "Point.x",
-                "Point.y",
+                "Point.y", // fields from struct Point
                 "Point.new",
-                "Point.translate",
-                "Point.draw",
-                "Point.ID", // from `impl Shape for Point`
-                "Point.area" // from `impl Shape for Point`
+                "Point.translate" // methods from impl Point
                 );
         assertEquals(
-                expectedPointMembersStrict.size(),
-                actualPointMemberFqNames.size(),
-                "Point member count mismatch. Expected: " + expectedPointMembersStrict + ", Got: "
-                        + actualPointMemberFqNames);
-        assertTrue(
-                actualPointMemberFqNames.containsAll(expectedPointMembersStrict),
+                expectedPointMembersStrict,
+                actualPointMemberFqNames,
                 "Point members mismatch. Expected: " + expectedPointMembersStrict + ", Got: "
                         + actualPointMemberFqNames);
 
+        List<CodeUnit> pointAsShapeMembers = AnalyzerUtil.getMembersInClass(rsAnalyzer, "Point as Shape");
+        assertEquals(
+                Set.of("Point as Shape.ID", "Point as Shape.area"),
+                pointAsShapeMembers.stream().map(CodeUnit::fqName).collect(Collectors.toSet()),
+                "Point as Shape members mismatch.");
+        assertEquals(
+                Optional.of(CodeUnit.cls(pointRsFile, "", "Point as Shape")),
+                rsAnalyzer.parentOf(CodeUnit.fn(pointRsFile, "", "Point as Shape.area")),
+                "Trait impl members should record their originating impl.");
+
+        List<CodeUnit> pointAsDrawableMembers = AnalyzerUtil.getMembersInClass(rsAnalyzer, "Point as Drawable");
+        assertEquals(
+                Set.of("Point as Drawable.draw"),
+                pointAsDrawableMembers.stream().map(CodeUnit::fqName).collect(Collectors.toSet()),
+                "Point as Drawable members mismatch.");
+
         // Members of trait Drawable (methods)
         List<CodeUnit> drawableMembers = AnalyzerUtil.getMembersInClass(rsAnalyzer, "Drawable");
         CodeUnit drawMethodInTrait = CodeUnit.fn(pointRsFile, "", "Drawable.draw");
@@ -430,11 +407,13 @@ public class TreeSitterAnalyzerRustTest {
         assertTrue(shapeIdDef.isPresent());
         assertEquals(CodeUnit.field(pointRsFile, "", "Shape.ID"), shapeIdDef.get());
 
-        // Associated const in impl
+        // Associated const in a trait impl, found by its type's name too
+        CodeUnit pointAsShapeId = CodeUnit.field(pointRsFile, "", "Point as Shape.ID");
+        assertEquals(List.of(pointAsShapeId), List.copyOf(rsAnalyzer.getDefinitions("Point as Shape.ID")));
         Optional<CodeUnit> pointIdDef =
                 rsAnalyzer.getDefinitions("Point.ID").stream().findFirst();
         assertTrue(pointIdDef.isPresent());
-        assertEquals(CodeUnit.field(pointRsFile, "", "Point.ID"), pointIdDef.get());
+        assertEquals(pointAsShapeId, pointIdDef.get());
 
         Optional<CodeUnit> nonExistentDef =
                 rsAnalyzer.getDefinitions("NonExistent").stream().findFirst();
@@ -445,12 +424,19 @@ public class TreeSitterAnalyzerRustTest {
     void testSearchDefinitions_Rust() {
         var pointResults = rsAnalyzer.searchDefinitions("Point");
         Set<String> pointFqNames = pointResults.stream().map(CodeUnit::fqName).collect(Collectors.toSet());
-        // Expected: Point (struct), Point.x, Point.y (fields), Point.new, Point.translate, Point.draw (methods)
-        // Point.ID, Point.area (from impl Shape for Point)
+        // Expected: Point (struct), Point.x, Point.y (fields), Point.new, Point.translate (methods),
+        // Point as Drawable.draw, and Point as Shape.ID and Point as Shape.area (from the trait impls)
         // _module_.ORIGIN (type is Point), distance (param type is Point)
         // The search is on FQ name.
         Set<String> expectedPointRelatedFqNs = Set.of(
-                "Point", "Point.x", "Point.y", "Point.new", "Point.translate", "Point.draw", "Point.ID", "Point.area"
+                "Point",
+                "Point.x",
+                "Point.y",
+                "Point.new",
+                "Point.translate",
+                "Point as Drawable.draw",
+                "Point as Shape.ID",
+                "Point as Shape.area"
                 // Point.DEFAULT_X, Point.DEFAULT_Y, Point.default_pos are not directly in `impl Point`
                 );
         assertTrue(
@@ -464,7 +450,7 @@ public class TreeSitterAnalyzerRustTest {
         var drawResults = rsAnalyzer.searchDefinitions("draw");
         var drawFqNames = drawResults.stream().map(CodeUnit::fqName).collect(Collectors.toSet());
         assertTrue(drawFqNames.contains("Drawable.draw"));
-        assertTrue(drawFqNames.contains("Point.draw"));
+        assertTrue(drawFqNames.contains("Point as Drawable.draw"));
         assertTrue(
                 drawFqNames.size() >= 2,
                 "Should find at least 2 symbols containing 'draw' (case-insensitive). Found: " + drawFqNames.size()
@@ -482,8 +468,8 @@ public class TreeSitterAnalyzerRustTest {
         var idResults = rsAnalyzer.searchDefinitions("ID"); // Will find Shape.ID, Point.ID, Circle.ID
         Set<String> idFqNames = idResults.stream().map(CodeUnit::fqName).collect(Collectors.toSet());
         assertTrue(idFqNames.contains("Shape.ID"));
-        assertTrue(idFqNames.contains("Point.ID"));
-        assertTrue(idFqNames.contains("Circle.ID"));
+        assertTrue(idFqNames.contains("Point as Shape.ID"));
+        assertTrue(idFqNames.contains("Circle as Shape.ID"));
         assertEquals(3, idFqNames.size());
 
         // Search for constructor-like patterns (Rust uses 'new' convention)
@@ -546,6 +532,21 @@ public class TreeSitterAnalyzerRustTest {
                                      }
                                      """;
         assertEquals(normalizeSource.apply(expectedShapeSource), normalizeSource.apply(shapeSource));
+
+        // Source for a trait impl is the impl block itself
+        String pointAsShapeSource =
+                AnalyzerUtil.getSource(rsAnalyzer, "Point as Shape", true).get();
+        String expectedPointAsShapeSource =
+                """
+                                     impl Shape for Point {
+                                         const ID: u32 = 1; // Associated constant in impl
+
+                                         fn area(&self) -> f64 {
+                                             0.0 // Points have no area
+                                         }
+                                     }
+                                     """;
+        assertEquals(normalizeSource.apply(expectedPointAsShapeSource), normalizeSource.apply(pointAsShapeSource));
     }
 
     @Test
@@ -619,10 +620,10 @@ public class TreeSitterAnalyzerRustTest {
         CodeUnit circleCU =
                 rsAnalyzer.getDefinitions("Circle").stream().findFirst().orElseThrow();
 
-        // Test with Point struct (includes its fields and methods from impls)
+        // Test with Point struct (includes its fields and methods from its inherent impl)
         Set<String> pointSymbols = rsAnalyzer.getSymbols(Set.of(pointCU));
-        // Expected: Point, x, y, new, translate, draw (from Drawable), ID (from Shape), area (from Shape)
-        Set<String> expectedPointSymbols = Set.of("Point", "x", "y", "new", "translate", "draw", "ID", "area");
+        // Expected: Point, x, y, new, translate; trait impl members live under `Point as Drawable` etc.
+        Set<String> expectedPointSymbols = Set.of("Point", "x", "y", "new", "translate");
         assertEquals(expectedPointSymbols, pointSymbols, "Symbols for Point CU mismatch.");
 
         // Test with Drawable trait (includes its method signatures)
@@ -638,9 +639,9 @@ public class TreeSitterAnalyzerRustTest {
         Set<String> distanceSymbols = rsAnalyzer.getSymbols(Set.of(distanceCU));
         assertEquals(Set.of("distance"), distanceSymbols);
 
-        // Test with Color enum (includes its variants)
+        // Test with Color enum (includes its variants and their named fields; positional fields are no symbols)
         Set<String> colorSymbols = rsAnalyzer.getSymbols(Set.of(colorCU));
-        Set<String> expectedColorSymbols = Set.of("Color", "Red", "Green", "Blue", "Rgb", "Named");
+        Set<String> expectedColorSymbols = Set.of("Color", "Red", "Green", "Blue", "Rgb", "Named", "name");
         assertEquals(expectedColorSymbols, colorSymbols);
 
         // Test with Shape trait (includes its associated const and method)
@@ -648,10 +649,9 @@ public class TreeSitterAnalyzerRustTest {
         Set<String> expectedShapeSymbols = Set.of("Shape", "ID", "area");
         assertEquals(expectedShapeSymbols, shapeSymbols);
 
-        // Test with Circle struct (includes its fields and methods/consts from impl Shape for Circle)
+        // Test with Circle struct (includes its fields; ID and area belong to `Circle as Shape`)
         Set<String> circleSymbols = rsAnalyzer.getSymbols(Set.of(circleCU));
-        Set<String> expectedCircleSymbols =
-                Set.of("Circle", "center", "radius", "ID", "area"); // ID and area from impl Shape for Circle
+        Set<String> expectedCircleSymbols = Set.of("Circle", "center", "radius");
         assertEquals(expectedCircleSymbols, circleSymbols, "Symbols for Circle CU mismatch");
 
         // Test with multiple sources
@@ -0,0 +1,193 @@
+package ai.brokk.analyzer.code_quality;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.RustRiskSmellProvider;
+import ai.brokk.testutil.InlineCoreProject;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+public class RustRiskSmellTest {
+
+    @Test
+    void scoresUnwrapAndExpectPerFunction() {
+        String code =
+                """
+                pub fn load(path: &str) -> String {
+                    let text = std::fs::read_to_string(path).unwrap();
+                    let first = text.lines().next().expect("file is not empty");
+                    let n: u32 = first.parse().unwrap();
+                    let _ = Some(1).unwrap_or(0);
+                    format!("{n}")
+                }
+
+                pub fn quiet() -> Option<u32> {
+                    Some(1)
+                }
+                """;
+        var findings = analyze(code);
+        assertEquals(1, findings.size(), "Only the function with risk sites is reported: " + findings);
+        var load = findings.getFirst();
+        assertTrue(load.enclosingFqName().endsWith("load"), load.enclosingFqName());
+        assertEquals(List.of("unwrap:2", "expect:1"), load.reasons());
+        assertEquals(3, load.siteCount());
+        assertEquals(5, load.score());
+    }
+
+    @Test
+    void flagsUnsafeBlocksWithoutSafetyComment() {
+        String code =
+                """
+                pub fn undocumented(p: *const u8) -> u8 {
+                    unsafe { *p }
+                }
+
+                pub fn documented(p: *const u8) -> u8 {
+                    // SAFETY: callers pass a pointer to a live byte.
+                    let value = unsafe { *p };
+                    value
+                }
+
+                pub fn documented_inside(p: *const u8) -> u8 {
+                    unsafe {
+                        // SAFETY: p comes from a live reference.
+                        *p
+                    }
+                }
+
+                pub fn lowercase(p: *const u8) -> u8 {
+                    // safety: clippy only accepts the uppercase marker.
+                    unsafe { *p }
+                }
+
+                pub fn documented_block(p: *const u8) -> u8 {
+                    /*
+                     * SAFETY: p points to a live byte.
+                     */
+                    unsafe { *p }
+                }
+
+                pub unsafe fn reset(ptr: *mut u8, q: *const u8) -> u8 {
+                    // SAFETY: the caller guarantees ptr is writable.
+                    *ptr = 0;
+                    unsafe { *q }
+                }
+                """;
+        var findings = analyze(code);
+        assertEquals(3, findings.size(), "Only unsafe blocks without an uppercase SAFETY: comment count: " + findings);
+        assertTrue(findings.stream().anyMatch(f -> f.enclosingFqName().endsWith("undocumented")));
+        assertTrue(findings.stream().anyMatch(f -> f.enclosingFqName().endsWith("lowercase")));
+        assertTrue(
+                findings.stream().anyMatch(f -> f.enclosingFqName().endsWith("reset")),
+                "A deref statement between the comment and the block must not read as part of the comment");
+        assertTrue(findings.stream().allMatch(f -> f.reasons().equals(List.of("unsafe-without-safety-comment:1"))));
+    }
+
+    @Test
+    void flagsPanicsInLibraryCodeAndClonesInLoops() {
+        String code =
+                """
+                pub fn unfinished() -> u32 {
+                    todo!()
+                }
+
+                pub fn fail(reason: &str) {
+                    panic!("failed: {reason}");
+                }
+
+                pub fn copies(names: &[String], template: &String) -> Vec<String> {
+                    let mut out = Vec::new();
+                    for name in names.to_vec().clone() {
+                        out.push(template.clone());
+                        out.push(name);
+                    }
+                    out
+                }
+                """;
+        var findings = analyze(code);
+        assertTrue(findings.stream()
+                .anyMatch(f -> f.enclosingFqName().endsWith("unfinished")
+                        && f.reasons().equals(List.of("todo-or-unimplemented:1"))));
+        assertTrue(findings.stream()
+                .anyMatch(f -> f.enclosingFqName().endsWith("fail") && f.reasons().equals(List.of("panic:1"))));
+        assertTrue(
+                findings.stream()
+                        .anyMatch(f -> f.enclosingFqName().endsWith("copies")
+                                && f.reasons().equals(List.of("clone-in-loop:1"))),
+                "The clone of the iterated collection runs once and is not flagged: " + findings);
+    }
+
+    @Test
+    void skipsTestCode() {
+        String code =
+                """
+                pub fn add(a: u32, b: u32) -> u32 {
+                    a + b
+                }
+
+                #[test]
+                fn standalone_test() {
+                    assert_eq!(Some(add(1, 2)).unwrap(), 3);
+                }
+
+                #[cfg(test)]
+                mod tests {
+                    use super::*;
+
+                    fn helper() -> u32 {
+                        "3".parse().unwrap()
+                    }
+
+                    #[tokio::test]
+                    async fn async_test() {
+                        let value = Some(add(1, 2)).expect("sum");
+                        assert_eq!(value, helper());
+                        unsafe { std::hint::unreachable_unchecked() };
+                        panic!("unreachable");
+                    }
+                }
+                """;
+        assertTrue(analyze(code).isEmpty(), "Test functions and cfg(test) modules are skipped");
+    }
+
+    @Test
+    void doesNotFlagPanicsInBinaries() {
+        String manifest =
+                """
+                [package]
+                name = "tool"
+                version = "0.1.0"
+                edition = "2021"
+                """;
+        String main =
+                """
+                fn main() {
+                    let args: Vec<String> = std::env::args().collect();
+                    if args.len() < 2 {
+                        panic!("usage: tool <file>");
+                    }
+                    let text = std::fs::read_to_string(&args[1]).unwrap();
+                    println!("{text}");
+                }
+                """;
+        try (var testProject = InlineCoreProject.code(manifest, "Cargo.toml")
+                .addFile(main, "src/main.rs")
+                .build()) {
+            var provider = testProject.getAnalyzer().as(RustRiskSmellProvider.class).orElseThrow();
+            ProjectFile file = new ProjectFile(testProject.getRoot(), "src/main.rs");
+            var findings = provider.findRustRiskSmells(file, RustRiskSmellProvider.RustRiskSmellWeights.defaults());
+            assertEquals(1, findings.size());
+            assertEquals(List.of("unwrap:1"), findings.getFirst().reasons());
+        }
+    }
+
+    private List<RustRiskSmellProvider.RustRiskSmell> analyze(String source) {
+        try (var testProject = InlineCoreProject.code(source, "src/lib.rs").build()) {
+            var provider = testProject.getAnalyzer().as(RustRiskSmellProvider.class).orElseThrow();
+            ProjectFile file = new ProjectFile(testProject.getRoot(), "src/lib.rs");
+            return provider.findRustRiskSmells(file, RustRiskSmellProvider.RustRiskSmellWeights.defaults());
+        }
+    }
+}
@@ -104,6 +104,26 @@ public class RustTestAssertionSmellTest extends AbstractBrittleTestSuite {
         assertTrue(hasReason(findings, "overspecified-literal"));
     }
 
+    @Test
+    void asyncRuntimeTestsAreCheckedButBenchesAreNot() {
+        String code =
+                """
+                #[tokio::test(flavor = "multi_thread")]
+                async fn fetches() {
+                    let _ = 1 + 1;
+                }
+
+                #[bench]
+                fn bench_add(b: &mut Bencher) {
+                    b.iter(|| 1 + 1);
+                }
+                """;
+        var findings = analyze(code);
+        assertEquals(1, findings.size(), findings.toString());
+        assertTrue(findings.getFirst().enclosingFqName().endsWith("fetches"), findings.toString());
+        assertTrue(hasReason(findings, "no-assertions"));
+    }
+
     @Override
     protected String defaultTestPath() {
         return "src/lib.rs";
@@ -4,7 +4,10 @@ import static org.junit.jupiter.api.Assertions.assertEquals;
 
 import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.IAnalyzer;
+import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.testutil.InlineCoreProject;
+import java.util.List;
+import java.util.Map;
 import org.junit.jupiter.api.Test;
 
 public class RustCognitiveComplexityTest {
@@ -74,6 +77,138 @@ public class RustCognitiveComplexityTest {
         assertComplexity(source, "method", 7_260);
     }
 
+    @Test
+    void testTryOperatorAndLetElseComplexity() {
+        assertComplexity(
+                """
+                fn method(path: &str) -> Result<u32, Error> {
+                    let Some(name) = path.strip_prefix("/") else {
+                        return Err(Error::Empty);
+                    };
+                    let text = std::fs::read_to_string(name)?;
+                    let n = text.trim().parse::<u32>()?;
+                    Ok(n)
+                }
+                """,
+                "method",
+                3);
+    }
+
+    @Test
+    void testIfLetWhileLetAndLetChainComplexity() {
+        assertComplexity(
+                """
+                fn method(items: &mut Vec<Option<i32>>, limit: Option<i32>) -> i32 {
+                    let mut total = 0;
+                    while let Some(item) = items.pop() {
+                        if let Some(value) = item {
+                            total += value;
+                        }
+                    }
+                    if let Some(max) = limit && total > max {
+                        return max;
+                    }
+                    total
+                }
+                """,
+                "method",
+                5);
+    }
+
+    @Test
+    void testLabeledJumpsAndValueBreaksComplexity() {
+        assertComplexity(
+                """
+                fn method(grid: &[Vec<i32>]) -> i32 {
+                    let found = 'rows: loop {
+                        for row in grid {
+                            for &cell in row {
+                                if cell < 0 { continue 'rows; }
+                                if cell > 9 { break 'rows cell; }
+                            }
+                        }
+                        break 0;
+                    };
+                    found
+                }
+                """,
+                "method",
+                16);
+    }
+
+    @Test
+    void testIteratorAdaptorClosuresNest() {
+        assertComplexity(
+                """
+                fn method(orders: &[Order]) -> u32 {
+                    let describe = |o: &Order| o.id.to_string();
+                    orders
+                        .iter()
+                        .filter(|o| o.active)
+                        .flat_map(|o| o.lines.iter().map(|l| l.qty))
+                        .sum()
+                }
+                """,
+                "method",
+                4);
+    }
+
+    @Test
+    void testAsyncBlocksNest() {
+        assertComplexity(
+                """
+                async fn method(urls: Vec<String>) -> usize {
+                    let tasks = urls.into_iter().map(|url| async move {
+                        if url.is_empty() { 0 } else { fetch(&url).await }
+                    });
+                    futures::future::join_all(tasks).await.len()
+                }
+                """,
+                "method",
+                6);
+    }
+
+    @Test
+    void testBreakdownExplainsScore() {
+        String source =
+                """
+                fn method(lines: &[&str]) -> Result<Vec<u32>, String> {
+                    let mut out = Vec::new();
+                    for line in lines {
+                        let Some((key, value)) = line.split_once('=') else {
+                            continue;
+                        };
+                        let parsed: u32 = value.parse().map_err(|e| format!("{key}: {e}"))?;
+                        match parsed {
+                            0 => return Err("zero".into()),
+                            _ => out.push(parsed),
+                        }
+                    }
+                    Ok(out.into_iter().filter(|v| *v > 1 || *v == 0).collect())
+                }
+                """;
+        try (var project = InlineCoreProject.code(source, "src/lib.rs").build()) {
+            IAnalyzer analyzer = project.getAnalyzer();
+            CodeUnit cu = function(analyzer, "method");
+            ProjectFile file = new ProjectFile(project.getRoot(), "src/lib.rs");
+            var breakdown = analyzer.computeCognitiveComplexityBreakdowns(file).get(cu);
+
+            assertEquals(
+                    List.of("loop", "match-arm", "let-else", "logical-operators", "try-operator", "iterator-closure"),
+                    List.copyOf(breakdown.keySet()));
+            assertEquals(
+                    Map.of(
+                            "loop", 1,
+                            "match-arm", 2,
+                            "let-else", 2,
+                            "logical-operators", 1,
+                            "try-operator", 1,
+                            "iterator-closure", 1),
+                    breakdown);
+            assertEquals(8, analyzer.computeCognitiveComplexity(cu));
+        }
+    }
+
     private void assertComplexity(String source, String functionName, int expected) {
         try (var project = InlineCoreProject.code(source, "src/lib.rs").build()) {
             assertEquals(expected, complexity(project.getAnalyzer(), functionName), "Complexity for " + functionName);
@@ -81,10 +216,13 @@ public class RustCognitiveComplexityTest {
     }
 
     private int complexity(IAnalyzer analyzer, String functionName) {
-        CodeUnit cu = analyzer.getAllDeclarations().stream()
+        return analyzer.computeCognitiveComplexity(function(analyzer, functionName));
+    }
+
+    private CodeUnit function(IAnalyzer analyzer, String functionName) {
+        return analyzer.getAllDeclarations().stream()
                 .filter(u -> u.isFunction() && u.identifier().endsWith(functionName))
                 .findFirst()
                 .orElseThrow(() -> new AssertionError("Function not found: " + functionName));
-        return analyzer.computeCognitiveComplexity(cu);
     }
 }
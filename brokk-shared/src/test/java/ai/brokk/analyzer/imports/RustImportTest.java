@@ -95,6 +95,30 @@ class RustImportTest {
         assertTrue(isAlias, "CodeUnit should be identified as a type alias");
     }
 
+    @Test
+    void testResolveTypeAliasChain() throws IOException {
+        String code =
+                """
+                use std::sync::Arc;
+
+                pub struct Connection;
+                type Shared = Arc<Connection>;
+                pub type ConnRef = Shared;
+                """;
+        ICoreProject project = InlineCoreProject.code(code, "src/main.rs").build();
+        RustAnalyzer analyzer = new RustAnalyzer(project);
+        ProjectFile file = new ProjectFile(project.getRoot(), "src/main.rs");
+
+        CodeUnit aliasCu = analyzer.getDeclarations(file).stream()
+                .filter(cu -> cu.identifier().equals("ConnRef"))
+                .findFirst()
+                .orElseThrow();
+
+        var resolved = analyzer.as(TypeAliasProvider.class).flatMap(p -> p.resolveTypeAlias(aliasCu));
+
+        assertEquals("Connection", resolved.map(CodeUnit::identifier).orElse(null));
+    }
+
     @Test
     void testResolveImports_Semantic() throws IOException {
         ICoreProject project = InlineCoreProject.code("pub struct MyStruct;", "src/my_module.rs")
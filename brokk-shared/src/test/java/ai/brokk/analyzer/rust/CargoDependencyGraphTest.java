@@ -0,0 +1,277 @@
+package ai.brokk.analyzer.rust;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import ai.brokk.analyzer.Languages;
+import ai.brokk.analyzer.RustLanguage;
+import ai.brokk.analyzer.RustLanguage.CargoMetadata;
+import ai.brokk.analyzer.rust.CargoDependencyGraph.Crate;
+import ai.brokk.analyzer.rust.CargoDependencyGraph.Dependency;
+import ai.brokk.testutil.InlineCoreProject;
+import com.fasterxml.jackson.databind.DeserializationFeature;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.attribute.FileTime;
+import java.util.List;
+import java.util.stream.Collectors;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+class CargoDependencyGraphTest {
+    private static final String REGISTRY = "registry+https://github.com/rust-lang/crates.io-index";
+
+    /** A trimmed {@code cargo metadata} output for a workspace of {@code app} and {@code cli}. */
+    private static final String METADATA =
+            """
+            {
+              "packages": [
+                {
+                  "id": "path+file:///ws/app#0.1.0", "name": "app", "version": "0.1.0", "source": null,
+                  "dependencies": [
+                    {"name": "serde_json", "kind": null, "rename": "json", "target": null, "optional": false,
+                     "uses_default_features": true, "features": []},
+                    {"name": "libc", "kind": null, "rename": null, "target": "cfg(unix)", "optional": true,
+                     "uses_default_features": false, "features": ["extra_traits"]},
+                    {"name": "syn", "kind": "dev", "rename": null, "target": null, "optional": false,
+                     "uses_default_features": true, "features": ["full"]}
+                  ]
+                },
+                {
+                  "id": "path+file:///ws/cli#0.1.0", "name": "cli", "version": "0.1.0", "source": null,
+                  "dependencies": [
+                    {"name": "app", "kind": null, "rename": null, "target": null, "optional": false,
+                     "uses_default_features": true, "features": []}
+                  ]
+                },
+                {
+                  "id": "registry+https://github.com/rust-lang/crates.io-index#serde_json@1.0.1",
+                  "name": "serde_json", "version": "1.0.1", "source": "%1$s",
+                  "dependencies": [
+                    {"name": "serde", "kind": null, "rename": null, "target": null, "optional": false,
+                     "uses_default_features": true, "features": []},
+                    {"name": "syn", "kind": "build", "rename": null, "target": null, "optional": false,
+                     "uses_default_features": true, "features": []}
+                  ]
+                },
+                {"id": "registry+https://github.com/rust-lang/crates.io-index#serde@1.0.200",
+                 "name": "serde", "version": "1.0.200", "source": "%1$s", "dependencies": []},
+                {"id": "registry+https://github.com/rust-lang/crates.io-index#libc@0.2.150",
+                 "name": "libc", "version": "0.2.150", "source": "%1$s", "dependencies": []},
+                {"id": "registry+https://github.com/rust-lang/crates.io-index#syn@1.0.109",
+                 "name": "syn", "version": "1.0.109", "source": "%1$s", "dependencies": []},
+                {"id": "registry+https://github.com/rust-lang/crates.io-index#syn@2.0.48",
+                 "name": "syn", "version": "2.0.48", "source": "%1$s", "dependencies": []}
+              ],
+              "workspace_members": ["path+file:///ws/app#0.1.0", "path+file:///ws/cli#0.1.0"],
+              "resolve": {
+                "nodes": [
+                  {
+                    "id": "path+file:///ws/app#0.1.0", "features": [],
+                    "deps": [
+                      {"name": "json", "pkg": "registry+https://github.com/rust-lang/crates.io-index#serde_json@1.0.1",
+                       "dep_kinds": [{"kind": null, "target": null}]},
+                      {"name": "libc", "pkg": "registry+https://github.com/rust-lang/crates.io-index#libc@0.2.150",
+                       "dep_kinds": [{"kind": null, "target": "cfg(unix)"}]},
+                      {"name": "syn", "pkg": "registry+https://github.com/rust-lang/crates.io-index#syn@2.0.48",
+                       "dep_kinds": [{"kind": "dev", "target": null}]}
+                    ]
+                  },
+                  {
+                    "id": "path+file:///ws/cli#0.1.0", "features": [],
+                    "deps": [
+                      {"name": "app", "pkg": "path+file:///ws/app#0.1.0", "dep_kinds": [{"kind": null, "target": null}]}
+                    ]
+                  },
+                  {
+                    "id": "registry+https://github.com/rust-lang/crates.io-index#serde_json@1.0.1",
+                    "features": ["default", "std"],
+                    "deps": [
+                      {"name": "serde", "pkg": "registry+https://github.com/rust-lang/crates.io-index#serde@1.0.200",
+                       "dep_kinds": [{"kind": null, "target": null}]},
+                      {"name": "syn", "pkg": "registry+https://github.com/rust-lang/crates.io-index#syn@1.0.109",
+                       "dep_kinds": [{"kind": "build", "target": null}]}
+                    ]
+                  }
+                ]
+              }
+            }
+            """
+                    .formatted(REGISTRY);
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void explainsWhyACrateIsInTheTree() throws IOException {
+        var graph = CargoDependencyGraph.fromCargoMetadata(parse(METADATA));
+        Crate serde = graph.cratesNamed("serde").getFirst();
+
+        var why = graph.why(serde);
+
+        assertEquals(List.of("app", "cli"), why.stream().map(d -> d.member().name()).toList());
+        assertEquals(
+                List.of("serde_json", "serde"),
+                why.getFirst().path().stream()
+                        .map(edge -> graph.crate(edge.to()).name())
+                        .toList());
+        assertEquals(3, why.get(1).path().size(), "cli reaches serde through app");
+        assertEquals(List.of("default", "std"), graph.cratesNamed("serde-json").getFirst().features());
+    }
+
+    @Test
+    void edgesCarryTheDeclarationDetails() throws IOException {
+        var graph = CargoDependencyGraph.fromCargoMetadata(parse(METADATA));
+        Crate app = graph.members().getFirst();
+
+        var byName = graph.dependenciesOf(app.id()).stream()
+                .collect(Collectors.toMap(
+                        (Dependency edge) -> graph.crate(edge.to()).name(), edge -> edge));
+
+        assertEquals("json", byName.get("serde_json").rename());
+        Dependency libc = byName.get("libc");
+        assertEquals("cfg(unix)", libc.target());
+        assertTrue(libc.optional());
+        assertFalse(libc.defaultFeatures());
+        assertEquals(List.of("extra_traits"), libc.features());
+        assertEquals("dev", byName.get("syn").kind());
+    }
+
+    @Test
+    void findsDependentMembersAndDuplicateVersions() throws IOException {
+        var graph = CargoDependencyGraph.fromCargoMetadata(parse(METADATA));
+
+        var dependents = graph.dependentMembers("syn");
+        assertEquals(List.of("app", "cli"), dependents.stream().map(d -> d.member().name()).toList());
+        assertTrue(dependents.getFirst().isDirect());
+        assertFalse(dependents.get(1).isDirect());
+
+        var duplicates = graph.duplicates();
+        assertEquals(List.of("syn"), List.copyOf(duplicates.keySet()));
+        assertEquals(
+                List.of("1.0.109", "2.0.48"),
+                duplicates.get("syn").stream().map(Crate::version).toList());
+        assertTrue(graph.dependentMembers("tokio").isEmpty());
+    }
+
+    @Test
+    void buildsTheGraphFromLockfilesWithoutCargo() throws IOException {
+        Path app = writeManifest("app", "[dependencies]\nserde = \"1\"\n\n[dev-dependencies]\nsyn = \"1\"\n");
+        Path lockfile = tempDir.resolve("Cargo.lock");
+        Files.writeString(
+                lockfile,
+                """
+                [[package]]
+                name = "app"
+                version = "0.1.0"
+                dependencies = [
+                 "serde",
+                 "syn 1.0.109",
+                ]
+
+                [[package]]
+                name = "serde"
+                version = "1.0.200"
+                source = "%1$s"
+                dependencies = [
+                 "syn 2.0.48",
+                ]
+
+                [[package]]
+                name = "syn"
+                version = "1.0.109"
+                source = "%1$s"
+
+                [[package]]
+                name = "syn"
+                version = "2.0.48"
+                source = "%1$s"
+                """
+                        .formatted(REGISTRY));
+
+        var metadata = new CargoOfflineSources(tempDir.resolve("no-cargo-home"), List.of())
+                .metadata(List.of(app), List.of(lockfile));
+        var graph = CargoDependencyGraph.fromCargoMetadata(metadata);
+
+        var syn = graph.cratesNamed("syn");
+        assertEquals(List.of("1.0.109", "2.0.48"), syn.stream().map(Crate::version).toList());
+        var direct = graph.why(syn.getFirst()).getFirst();
+        assertTrue(direct.isDirect());
+        assertEquals("dev", direct.path().getFirst().kind());
+        assertEquals(2, graph.why(syn.get(1)).getFirst().path().size(), "syn 2 comes in through serde");
+    }
+
+    @Test
+    void keepsTheGraphUntilALockfileChanges() throws IOException {
+        String manifest =
+                """
+                [package]
+                name = "app"
+                version = "0.1.0"
+
+                [dependencies]
+                log = "0.4"
+                """;
+        String lockfile =
+                """
+                [[package]]
+                name = "app"
+                version = "0.1.0"
+                dependencies = [
+                 "log",
+                ]
+
+                [[package]]
+                name = "log"
+                version = "%s"
+                source = "%s"
+                """;
+        try (var project = InlineCoreProject.code(manifest, "Cargo.toml")
+                .addFile(lockfile.formatted("0.4.20", REGISTRY), "Cargo.lock")
+                .addFile("", "src/lib.rs")
+                .build()) {
+            var rust = (RustLanguage) Languages.RUST;
+            var graph = rust.getDependencyGraph(project);
+            assertSame(graph, rust.getDependencyGraph(project));
+
+            Path lock = project.getRoot().resolve("Cargo.lock");
+            Files.writeString(lock, lockfile.formatted("0.4.21", REGISTRY));
+            Files.setLastModifiedTime(lock, FileTime.fromMillis(Files.getLastModifiedTime(lock).toMillis() + 2_000));
+
+            var updated = rust.getDependencyGraph(project);
+            assertNotSame(graph, updated);
+            assertEquals(List.of("0.4.21"), updated.cratesNamed("log").stream().map(Crate::version).toList());
+        }
+    }
+
+    @Test
+    void comparesVersionsNumerically() {
+        assertTrue(CargoDependencyGraph.compareVersions("1.10.0", "1.9.3") > 0);
+        assertTrue(CargoDependencyGraph.compareVersions("0.2.0", "0.2.0-alpha.1") > 0);
+        assertTrue(CargoDependencyGraph.compareVersions("0.2.0-alpha.2", "0.2.0-alpha.10") < 0);
+        assertEquals(0, CargoDependencyGraph.compareVersions("2.0.48", "2.0.48"));
+    }
+
+    private Path writeManifest(String name, String dependencies) throws IOException {
+        Path directory = tempDir.resolve(name);
+        Files.createDirectories(directory);
+        Path manifest = directory.resolve("Cargo.toml");
+        Files.writeString(
+                manifest,
+                """
+                [package]
+                name = "%s"
+                version = "0.1.0"
+
+                %s"""
+                        .formatted(name, dependencies));
+        return manifest;
+    }
+
+    private static CargoMetadata parse(String json) throws IOException {
+        return new ObjectMapper()
+                .configure(DeserializationFeature.FAIL_ON_UNKNOWN_PROPERTIES, false)
+                .readValue(json, CargoMetadata.class);
+    }
+}
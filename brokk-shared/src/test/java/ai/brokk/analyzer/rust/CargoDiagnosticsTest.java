@@ -0,0 +1,137 @@
+package ai.brokk.analyzer.rust;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import ai.brokk.analyzer.LintResult.LintDiagnostic;
+import ai.brokk.analyzer.LintResult.LintDiagnostic.Severity;
+import ai.brokk.analyzer.LintResult.Suggestion;
+import ai.brokk.analyzer.LintResult.Suggestion.Applicability;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Path;
+import java.util.List;
+import java.util.Objects;
+import org.junit.jupiter.api.Test;
+
+class CargoDiagnosticsTest {
+    private static final Path PROJECT_ROOT = Path.of("/work/shapes");
+
+    @Test
+    void parsesCargoCheckErrorsAndWarnings() throws Exception {
+        var result = CargoDiagnostics.parse(fixture("check.jsonl"), PROJECT_ROOT);
+
+        // "aborting due to ..." and "1 warning emitted" carry no spans and are dropped.
+        assertEquals(3, result.diagnostics().size(), result.diagnostics().toString());
+        assertTrue(result.hasErrors());
+        assertEquals(2, result.getErrors().size());
+
+        LintDiagnostic mismatch = result.diagnostics().get(0);
+        assertEquals("src/area.rs", mismatch.file());
+        assertEquals(7, mismatch.line());
+        assertEquals(19, mismatch.column());
+        assertEquals(Severity.ERROR, mismatch.severity());
+        assertEquals("E0308", mismatch.code());
+        assertEquals("mismatched types: expected `&str`, found `String`", mismatch.message());
+        assertEquals(
+                List.of(new Suggestion(
+                        "src/area.rs",
+                        7,
+                        19,
+                        7,
+                        19,
+                        141,
+                        141,
+                        "&",
+                        Applicability.MACHINE_APPLICABLE,
+                        "consider borrowing here",
+                        List.of("    print_name(name);"))),
+                mismatch.suggestions());
+
+        LintDiagnostic unused = result.diagnostics().get(1);
+        assertEquals(Severity.WARNING, unused.severity());
+        assertEquals("unused_variables", unused.code());
+        assertEquals(1, unused.suggestions().size());
+        assertEquals("_scale", unused.suggestions().getFirst().replacement());
+        assertEquals(Applicability.MAYBE_INCORRECT, unused.suggestions().getFirst().applicability());
+
+        LintDiagnostic missingMacro = result.diagnostics().get(2);
+        assertEquals("", missingMacro.code());
+        assertTrue(missingMacro.suggestions().isEmpty());
+    }
+
+    @Test
+    void parsesClippyLintsWithAbsolutePathsAndMacroExpansions() throws Exception {
+        var result = CargoDiagnostics.parse(fixture("clippy.jsonl"), PROJECT_ROOT);
+
+        // The repeated needless_return message is reported once; the plain-text progress line is ignored.
+        assertEquals(2, result.diagnostics().size(), result.diagnostics().toString());
+        assertFalse(result.hasErrors());
+
+        LintDiagnostic needlessReturn = result.diagnostics().get(0);
+        assertEquals("src/lib.rs", needlessReturn.file());
+        assertEquals(4, needlessReturn.line());
+        assertEquals("clippy::needless_return", needlessReturn.code());
+        Suggestion fix = needlessReturn.suggestions().getFirst();
+        assertEquals("src/lib.rs", fix.file());
+        assertEquals(60, fix.byteStart());
+        assertEquals(74, fix.byteEnd());
+        assertEquals("a * b", fix.replacement());
+        assertEquals(Applicability.MACHINE_APPLICABLE, fix.applicability());
+        assertEquals("remove `return`", fix.message());
+
+        // A lint raised inside a std macro points at the invocation in the project.
+        LintDiagnostic macro = result.diagnostics().get(1);
+        assertEquals("clippy::if_same_then_else", macro.code());
+        assertEquals("src/lib.rs", macro.file());
+        assertEquals(9, macro.line());
+        assertEquals(5, macro.column());
+    }
+
+    @Test
+    void formatsReportWithSuggestions() throws Exception {
+        var result = CargoDiagnostics.parse(fixture("check.jsonl"), PROJECT_ROOT);
+
+        String report = CargoDiagnostics.formatReport(result.getErrors());
+        assertEquals(
+                """
+                src/area.rs:7:19: error[E0308]: mismatched types: expected `&str`, found `String`
+                    consider borrowing here: replace src/area.rs:7:19-7:19 with `&` (machine_applicable)
+                src/area.rs:15:5: error: cannot find macro `sqaure` in this scope""",
+                report);
+    }
+
+    @Test
+    void addsJsonMessageFormatToCargoCommands() {
+        assertEquals("cargo check --message-format=json", CargoDiagnostics.withJsonMessageFormat("cargo check"));
+        assertEquals(
+                "cargo clippy --all-targets --message-format=json -- -D warnings",
+                CargoDiagnostics.withJsonMessageFormat("cargo clippy --all-targets -- -D warnings"));
+        assertEquals(
+                "cargo check --message-format=json-diagnostic-short",
+                CargoDiagnostics.withJsonMessageFormat("cargo check --message-format=json-diagnostic-short"));
+    }
+
+    @Test
+    void recognizesSingleCargoLintCommands() {
+        assertTrue(CargoDiagnostics.isLintCommand("cargo check"));
+        assertTrue(CargoDiagnostics.isLintCommand("  cargo clippy --all-targets -- -D warnings "));
+        assertTrue(CargoDiagnostics.isLintCommand("cargo +nightly check --workspace"));
+        assertFalse(CargoDiagnostics.isLintCommand("cargo test"));
+        assertFalse(CargoDiagnostics.isLintCommand("cargo checkout"));
+        assertFalse(CargoDiagnostics.isLintCommand("cargo check && cargo test"));
+        assertFalse(CargoDiagnostics.isLintCommand("cargo clippy 2>&1 | tee lint.log"));
+        assertFalse(CargoDiagnostics.isLintCommand("./gradlew check"));
+    }
+
+    @Test
+    void ignoresNonJsonOutput() {
+        var result = CargoDiagnostics.parse("error: could not find `Cargo.toml`\n{not json}\n", PROJECT_ROOT);
+        assertTrue(result.diagnostics().isEmpty());
+    }
+
+    private static String fixture(String name) throws Exception {
+        try (var in = Objects.requireNonNull(
+                CargoDiagnosticsTest.class.getResourceAsStream("/cargo-diagnostics/" + name), name)) {
+            return new String(in.readAllBytes(), StandardCharsets.UTF_8);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+package ai.brokk.analyzer.rust;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import ai.brokk.analyzer.RustLanguage.CargoPackage;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+import java.util.stream.Collectors;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+class CargoOfflineSourcesTest {
+    private static final String LOCKFILE =
+            """
+            # This file is automatically @generated by Cargo.
+            version = 3
+
+            [[package]]
+            name = "app"
+            version = "0.1.0"
+            dependencies = [
+             "log",
+             "serde 1.0.200",
+             "tiny-git",
+            ]
+
+            [[package]]
+            name = "log"
+            version = "0.4.21"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+
+            [[package]]
+            name = "serde"
+            version = "1.0.200"
+            source = "registry+https://github.com/rust-lang/crates.io-index"
+            checksum = "ddc6f9cc94d67c0e21aaf7eda3a010fd3af78ebf6e096aa6e2e13c79749cce4f"
+
+            [[package]]
+            name = "serde"
+            version = "0.9.15"
+            source = "sparse+https://index.crates.io/"
+
+            [[package]]
+            name = "tiny-git"
+            version = "0.2.0"
+            source = "git+https://github.com/acme/tiny.git?branch=main#0123456789abcdef0123456789abcdef01234567"
+            """;
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void parsesLockedPackagesAndSources() {
+        var lockfile = CargoLockfile.parse(LOCKFILE);
+
+        assertEquals(
+                List.of("app", "log", "serde", "serde", "tiny-git"),
+                lockfile.packages().stream().map(CargoLockfile.LockedPackage::name).toList());
+        var app = lockfile.packages().getFirst();
+        assertTrue(app.isLocal());
+        assertEquals(List.of("log", "serde 1.0.200", "tiny-git"), app.dependencies());
+
+        var serde = lockfile.packages().get(2);
+        assertTrue(serde.isRegistry());
+        assertTrue(lockfile.packages().get(3).isRegistry(), "Sparse registries are registries too");
+
+        var git = lockfile.packages().get(4);
+        assertTrue(git.isGit());
+        assertEquals("tiny", git.gitRepositoryName());
+        assertEquals("0123456789abcdef0123456789abcdef01234567", git.gitCommit());
+    }
+
+    @Test
+    void locatesVendoredRegistryAndGitSources() throws IOException {
+        Path cargoHome = tempDir.resolve("cargo-home");
+        Path vendor = tempDir.resolve("project/vendor");
+        Path vendoredLog = writeManifest(vendor.resolve("log"), "log", "0.4.21");
+        Path cachedSerde = writeManifest(
+                cargoHome.resolve("registry/src/index.crates.io-6f17d22bba15001f/serde-1.0.200"), "serde", "1.0.200");
+        writeManifest(vendor.resolve("serde"), "serde", "1.0.150");
+        Path gitCheckout = cargoHome.resolve("git/checkouts/tiny-3f2a9c1d0e4b5a67/0123456");
+        Files.createDirectories(gitCheckout);
+        Files.writeString(gitCheckout.resolve("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n");
+        Path gitCrate = writeManifest(gitCheckout.resolve("crates/tiny-git"), "tiny-git", "0.2.0");
+
+        var sources = new CargoOfflineSources(cargoHome, List.of(vendor));
+        var packages = CargoLockfile.parse(LOCKFILE).packages();
+
+        assertTrue(sources.locateManifest(packages.get(0)).isEmpty(), "Path packages have no external sources");
+        assertEquals(vendoredLog, sources.locateManifest(packages.get(1)).orElseThrow());
+        assertEquals(
+                cachedSerde,
+                sources.locateManifest(packages.get(2)).orElseThrow(),
+                "A vendored crate of another version must not shadow the cached one");
+        assertTrue(sources.locateManifest(packages.get(3)).isEmpty(), "serde 0.9.15 is not available offline");
+        assertEquals(gitCrate, sources.locateManifest(packages.get(4)).orElseThrow());
+    }
+
+    @Test
+    void readsDirectDependencyKindsFromManifests() {
+        String manifest =
+                """
+                [package]
+                name = "app"
+                version = "0.1.0"
+
+                [dependencies]
+                serde = { version = "1.0", features = ["derive"] }
+                json = { package = "serde_json", version = "1" }
+                tiny-git = { git = "https://github.com/acme/tiny.git" }
+
+                [dependencies.log]
+                version = "0.4"
+                features = [
+                    "std",
+                ]
+
+                [target.'cfg(unix)'.dependencies]
+                libc = "0.2"
+
+                [dev-dependencies]
+                serde = "1.0"
+
+                [build-dependencies.generator]
+                package = "codegen"
+                version = "0.1"
+                """;
+
+        assertEquals(
+                Map.of(
+                        "serde", Set.of("normal", "dev"),
+                        "serde_json", Set.of("normal"),
+                        "tiny-git", Set.of("normal"),
+                        "log", Set.of("normal"),
+                        "libc", Set.of("normal"),
+                        "codegen", Set.of("build")),
+                CargoOfflineSources.directDependencyKinds(manifest));
+    }
+
+    @Test
+    void buildsMetadataFromLockfileAndMembers() throws IOException {
+        Path project = tempDir.resolve("project");
+        Path member = writeManifest(project, "app", "0.1.0");
+        Files.writeString(member, Files.readString(member) + "\n[dependencies]\nlog = \"0.4\"\n");
+        Path lockfile = project.resolve("Cargo.lock");
+        Files.writeString(lockfile, LOCKFILE);
+        Path vendor = project.resolve("vendor");
+        writeManifest(vendor.resolve("log"), "log", "0.4.21");
+
+        var metadata = new CargoOfflineSources(tempDir.resolve("no-cargo-home"), List.of(vendor))
+                .metadata(List.of(member), List.of(lockfile));
+
+        assertEquals(1, metadata.workspace_members.size());
+        CargoPackage app = metadata.packages.getFirst();
+        assertEquals(metadata.workspace_members.getFirst(), app.id);
+        assertEquals(List.of("log"), app.dependencies.stream().map(d -> d.name).toList());
+        assertNull(app.dependencies.getFirst().kind, "Normal dependencies have no kind, as in cargo metadata");
+
+        Map<String, String> manifests = metadata.packages.stream()
+                .filter(p -> !p.id.equals(app.id))
+                .collect(Collectors.toMap(p -> p.name + " " + p.version, p -> String.valueOf(p.manifest_path)));
+        assertEquals(4, manifests.size(), "Every locked external package is listed: " + manifests);
+        assertEquals(vendor.resolve("log/Cargo.toml").toString(), manifests.get("log 0.4.21"));
+        assertEquals("null", manifests.get("serde 1.0.200"), "Unavailable crates have no manifest path");
+    }
+
+    private static Path writeManifest(Path directory, String name, String version) throws IOException {
+        Files.createDirectories(directory.resolve("src"));
+        Files.writeString(directory.resolve("src/lib.rs"), "pub fn f() {}\n");
+        Path manifest = directory.resolve("Cargo.toml");
+        Files.writeString(
+                manifest,
+                """
+                [package]
+                name = "%s"
+                version = "%s"
+                edition = "2021"
+                """
+                        .formatted(name, version));
+        return manifest;
+    }
+}
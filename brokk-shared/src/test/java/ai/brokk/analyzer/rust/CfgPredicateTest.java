@@ -0,0 +1,95 @@
+package ai.brokk.analyzer.rust;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import ai.brokk.analyzer.rust.CfgPredicate.Environment;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+import org.junit.jupiter.api.Test;
+
+class CfgPredicateTest {
+
+    @Test
+    void parsesAndRendersNestedPredicates() {
+        var predicate = CfgPredicate.parse(
+                        "all(unix, not(feature = \"tokio\"), any(target_os = \"linux\", target_os = \"macos\"),)")
+                .orElseThrow();
+
+        assertEquals(
+                new CfgPredicate.All(List.of(
+                        new CfgPredicate.Option("unix", null),
+                        new CfgPredicate.Not(new CfgPredicate.Option("feature", "tokio")),
+                        new CfgPredicate.Any(List.of(
+                                new CfgPredicate.Option("target_os", "linux"),
+                                new CfgPredicate.Option("target_os", "macos"))))),
+                predicate);
+        assertEquals(
+                "all(unix, not(feature = \"tokio\"), any(target_os = \"linux\", target_os = \"macos\"))",
+                predicate.toString());
+
+        assertTrue(CfgPredicate.parse("all(unix").isEmpty());
+        assertTrue(CfgPredicate.parse("not(a, b)").isEmpty());
+        assertTrue(CfgPredicate.parse("feature = serde").isEmpty());
+        assertTrue(CfgPredicate.parse("unix windows").isEmpty());
+    }
+
+    @Test
+    void evaluatesAgainstEnvironment() {
+        var linux = new Environment(
+                Set.of("unix", "debug_assertions"),
+                Map.of("target_os", Set.of("linux"), "feature", Set.of("std", "fast")));
+
+        assertTrue(CfgPredicate.parse("unix").orElseThrow().evaluate(linux));
+        assertFalse(CfgPredicate.parse("windows").orElseThrow().evaluate(linux));
+        assertTrue(CfgPredicate.parse("all(feature = \"std\", not(test))").orElseThrow().evaluate(linux));
+        assertFalse(CfgPredicate.parse("any(feature = \"serde\", target_os = \"windows\")")
+                .orElseThrow()
+                .evaluate(linux));
+        // Empty all() holds and empty any() does not, as in rustc.
+        assertTrue(CfgPredicate.parse("all()").orElseThrow().evaluate(linux));
+        assertFalse(CfgPredicate.parse("any()").orElseThrow().evaluate(linux));
+    }
+
+    @Test
+    void expandsCargoDefaultFeaturesAndSettings() {
+        String manifest =
+                """
+                [package]
+                name = "plat"
+                version = "0.1.0"
+
+                [features]
+                default = ["std"]  # the usual build
+                std = [
+                    "fast",
+                    "log/std",
+                    "serde?/std",
+                ]
+                fast = []
+                json = ["dep:serde_json"]
+                "extra-io" = []
+
+                [dependencies]
+                log = { version = "0.4", optional = true }
+                """;
+        var features = CargoFeatures.parse(manifest).orElseThrow();
+        assertEquals("plat", features.packageName());
+
+        assertEquals(Set.of("default", "std", "fast", "log"), features.enabled(RustCfgSettings.DEFAULT));
+        var custom = new RustCfgSettings(false, List.of("json", "plat/extra-io", "other/std"), "", false, List.of());
+        assertEquals(Set.of("json", "extra-io"), features.enabled(custom));
+
+        assertTrue(CargoFeatures.parse("[workspace]\nmembers = [\"a\"]\n").isEmpty());
+
+        var windows = new RustCfgSettings(true, List.of(), "windows", true, List.of("tokio_unstable", "mode=\"fast\""))
+                .environment(Set.of("std"));
+        assertTrue(windows.isSet("windows", null));
+        assertFalse(windows.isSet("unix", null));
+        assertTrue(windows.isSet("test", null));
+        assertTrue(windows.isSet("tokio_unstable", null));
+        assertTrue(windows.isSet("mode", "fast"));
+        assertTrue(windows.isSet("target_family", "windows"));
+        assertTrue(windows.isSet("feature", "std"));
+    }
+}
@@ -0,0 +1,48 @@
+package ai.brokk.analyzer.rust;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.attribute.FileTime;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+class RustCfgConfigurationTest {
+
+    @Test
+    void rereadsFeaturesWhenTheManifestChanges(@TempDir Path root) throws Exception {
+        Path manifest = root.resolve("Cargo.toml");
+        Path lib = root.resolve("src/lib.rs");
+        Files.createDirectories(lib.getParent());
+        Files.writeString(lib, "");
+        Files.writeString(
+                manifest,
+                """
+                [package]
+                name = "plat"
+
+                [features]
+                default = ["fast"]
+                fast = []
+                """);
+        var configuration = new RustCfgConfiguration(RustCfgSettings.DEFAULT, root);
+
+        assertTrue(configuration.environmentFor(lib).isSet("feature", "fast"));
+
+        Files.writeString(
+                manifest,
+                """
+                [package]
+                name = "plat"
+
+                [features]
+                default = []
+                fast = []
+                """);
+        var modified = Files.getLastModifiedTime(manifest).toMillis() + 2_000;
+        Files.setLastModifiedTime(manifest, FileTime.fromMillis(modified));
+
+        assertFalse(configuration.environmentFor(lib).isSet("feature", "fast"));
+    }
+}
@@ -1,13 +1,18 @@
 package ai.brokk.analyzer.tests;
 
+import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertFalse;
 import static org.junit.jupiter.api.Assertions.assertTrue;
 
+import ai.brokk.analyzer.CodeUnit;
 import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.analyzer.RustAnalyzer;
 import ai.brokk.analyzer.TestFileHeuristics;
 import ai.brokk.testutil.ITestProject;
 import ai.brokk.testutil.InlineTestProjectCreator;
+import java.util.List;
+import java.util.Set;
+import java.util.stream.Collectors;
 import org.junit.jupiter.api.Test;
 
 public class RustTestDetectionTest {
@@ -134,4 +139,115 @@ public class RustTestDetectionTest {
                     "Function name containing 'test' should not trigger test detection without markers");
         }
     }
+
+    @Test
+    void testAsyncParameterizedPropertyAndBenchTestsDetection() throws Exception {
+        String asyncContent =
+                """
+            #[tokio::test]
+            async fn fetches() {}
+
+            #[async_std::test]
+            async fn reads() {}
+            """;
+        String parameterizedContent =
+                """
+            #[rstest]
+            #[case(1)]
+            fn parses(#[case] n: u32) {}
+
+            #[test_case(2, 4 ; "doubles")]
+            fn doubles(n: u32, expected: u32) {}
+            """;
+        String proptestContent =
+                """
+            mod props {
+                proptest! {
+                    #[test]
+                    fn round_trips(s in ".*") {
+                        prop_assert_eq!(s.clone(), s);
+                    }
+                }
+            }
+            """;
+        String benchContent =
+                """
+            #[bench]
+            fn bench_parse(b: &mut Bencher) {}
+            """;
+
+        try (ITestProject project = InlineTestProjectCreator.code(asyncContent, "fetch.rs")
+                .addFileContents(parameterizedContent, "parse.rs")
+                .addFileContents(proptestContent, "props.rs")
+                .addFileContents(benchContent, "perf.rs")
+                .build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+
+            for (String fileName : List.of("fetch.rs", "parse.rs", "props.rs", "perf.rs")) {
+                ProjectFile file = new ProjectFile(project.getRoot(), fileName);
+                assertTrue(analyzer.containsTests(file), fileName + " should be detected as containing tests");
+            }
+
+            var units = analyzer.testFilesToCodeUnits(List.of(
+                    new ProjectFile(project.getRoot(), "fetch.rs"),
+                    new ProjectFile(project.getRoot(), "props.rs")));
+            assertEquals(
+                    Set.of("fetches", "reads", "props"),
+                    units.stream().map(CodeUnit::identifier).collect(Collectors.toSet()));
+        }
+    }
+
+    @Test
+    void testDocTestsAttachToDocumentedItems() throws Exception {
+        String content =
+                """
+            /// Adds one.
+            ///
+            /// ```
+            /// assert_eq!(mylib::add_one(1), 2);
+            /// ```
+            pub fn add_one(n: u32) -> u32 {
+                n + 1
+            }
+
+            /**
+             * ```rust,no_run
+             * let _ = mylib::Parser::new();
+             * ```
+             */
+            #[derive(Default)]
+            pub struct Parser;
+
+            /// Prints usage.
+            ///
+            /// ```text
+            /// mylib --help
+            /// ```
+            pub fn usage() {}
+            """;
+        String plainContent =
+                """
+            //// Not a doc comment:
+            //// ```
+            //// let x = 1;
+            //// ```
+            pub fn plain() {}
+            """;
+
+        try (ITestProject project = InlineTestProjectCreator.code(content, "docs.rs")
+                .addFileContents(plainContent, "plain.rs")
+                .build()) {
+            RustAnalyzer analyzer = new RustAnalyzer(project);
+            ProjectFile docs = new ProjectFile(project.getRoot(), "docs.rs");
+            ProjectFile plain = new ProjectFile(project.getRoot(), "plain.rs");
+
+            assertTrue(analyzer.containsTests(docs), "Doc tests should mark the file as containing tests");
+            assertFalse(analyzer.containsTests(plain), "Ordinary comments with fences are not doc tests");
+            assertEquals(
+                    Set.of("add_one", "Parser"),
+                    analyzer.testFilesToCodeUnits(List.of(docs)).stream()
+                            .map(CodeUnit::identifier)
+                            .collect(Collectors.toSet()));
+        }
+    }
 }
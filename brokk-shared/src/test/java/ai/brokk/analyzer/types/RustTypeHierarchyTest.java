@@ -0,0 +1,226 @@
+package ai.brokk.analyzer.types;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import ai.brokk.analyzer.CodeUnit;
+import ai.brokk.analyzer.Languages;
+import ai.brokk.analyzer.ProjectFile;
+import ai.brokk.analyzer.RustAnalyzer;
+import ai.brokk.analyzer.TypeHierarchyProvider;
+import ai.brokk.testutil.CoreTestProject;
+import ai.brokk.testutil.InlineTestProjectCreator;
+import ai.brokk.testutil.TestCodeProject;
+import java.util.Collection;
+import java.util.List;
+import java.util.Set;
+import java.util.stream.Collectors;
+import org.jetbrains.annotations.Nullable;
+import org.junit.jupiter.api.AfterAll;
+import org.junit.jupiter.api.BeforeAll;
+import org.junit.jupiter.api.Test;
+
+public final class RustTypeHierarchyTest {
+
+    @Nullable
+    private static CoreTestProject project;
+
+    @Nullable
+    private static RustAnalyzer analyzer;
+
+    @BeforeAll
+    public static void setup() {
+        project = TestCodeProject.fromResourceDir("testcode-rs", Languages.RUST);
+        analyzer = new RustAnalyzer(project);
+    }
+
+    @AfterAll
+    public static void teardown() {
+        if (project != null) {
+            project.close();
+        }
+    }
+
+    @Test
+    void testTraitImplsAreAncestorsAndDescendants() {
+        var testAnalyzer = analyzer;
+        assertNotNull(testAnalyzer);
+        assertNotNull(project);
+        assertTrue(testAnalyzer.as(TypeHierarchyProvider.class).isPresent());
+
+        ProjectFile pointRs = new ProjectFile(project.getRoot(), "Point.rs");
+        CodeUnit point = classNamed(testAnalyzer, pointRs, "Point");
+        CodeUnit circle = classNamed(testAnalyzer, pointRs, "Circle");
+        CodeUnit drawable = classNamed(testAnalyzer, pointRs, "Drawable");
+        CodeUnit shape = classNamed(testAnalyzer, pointRs, "Shape");
+
+        assertEquals(
+                Set.of("DefaultPosition", "Drawable", "Shape"), identifiers(testAnalyzer.getDirectAncestors(point)));
+        assertEquals(Set.of("Shape"), identifiers(testAnalyzer.getDirectAncestors(circle)));
+
+        assertEquals(Set.of("Point"), identifiers(testAnalyzer.getDirectDescendants(drawable)));
+        assertEquals(Set.of("Point", "Circle"), identifiers(testAnalyzer.getDirectDescendants(shape)));
+        assertTrue(testAnalyzer.getDirectAncestors(drawable).isEmpty(), "Drawable has no supertraits");
+    }
+
+    @Test
+    void testSupertraitsAndCrossFileImpls() throws Exception {
+        String traits =
+                """
+                pub trait Named {
+                    fn name(&self) -> String;
+                }
+
+                pub trait Greeter: Named + std::fmt::Debug {
+                    fn greet(&self);
+                }
+                """;
+        String impls =
+                """
+                use crate::traits::{Greeter, Named};
+
+                #[derive(Debug)]
+                pub struct English;
+
+                impl Named for English {
+                    fn name(&self) -> String { "en".into() }
+                }
+
+                impl Greeter for English {
+                    fn greet(&self) {}
+                }
+
+                impl From<English> for String {
+                    fn from(_: English) -> String { String::new() }
+                }
+                """;
+
+        try (var testProject = InlineTestProjectCreator.code(traits, "src/traits.rs")
+                .addFileContents(impls, "src/english.rs")
+                .build()) {
+            var testAnalyzer = new RustAnalyzer(testProject);
+            ProjectFile traitsFile = new ProjectFile(testProject.getRoot(), "src/traits.rs");
+            ProjectFile englishFile = new ProjectFile(testProject.getRoot(), "src/english.rs");
+            CodeUnit named = classNamed(testAnalyzer, traitsFile, "Named");
+            CodeUnit greeter = classNamed(testAnalyzer, traitsFile, "Greeter");
+            CodeUnit english = classNamed(testAnalyzer, englishFile, "English");
+
+            assertEquals(List.of(named), testAnalyzer.getDirectAncestors(greeter));
+            assertEquals(Set.of(greeter, english), testAnalyzer.getDirectDescendants(named));
+
+            // `#[derive(Debug)]` adds the prelude trait as a synthetic ancestor.
+            assertEquals(
+                    Set.of("Named", "Greeter", "Debug"), identifiers(testAnalyzer.getDirectAncestors(english)));
+            assertEquals(Set.of(english), testAnalyzer.getDirectDescendants(greeter));
+            assertEquals(Set.of(english, greeter), Set.copyOf(testAnalyzer.getDescendants(named)));
+            // Generic arguments of an implemented trait are not supertypes: `impl From<English> for String`.
+            assertTrue(testAnalyzer.getDirectDescendants(english).isEmpty());
+        }
+    }
+
+    @Test
+    void testDerivedTraitsAreSyntheticImpls() throws Exception {
+        String describe =
+                """
+                pub trait Describe {
+                    fn describe(&self) -> String;
+                }
+                """;
+        String types =
+                """
+                use crate::describe::Describe;
+
+                /// A widget.
+                #[derive(Debug, Clone, Describe)]
+                pub struct Widget;
+
+                #[cfg_attr(feature = "serde", derive(serde::Serialize, Describe))]
+                pub enum Mode {
+                    On,
+                    Off,
+                }
+
+                #[derive(Debug)]
+                pub struct Plain;
+                """;
+
+        try (var testProject = InlineTestProjectCreator.code(describe, "src/describe.rs")
+                .addFileContents(types, "src/types.rs")
+                .build()) {
+            var testAnalyzer = new RustAnalyzer(testProject);
+            ProjectFile describeFile = new ProjectFile(testProject.getRoot(), "src/describe.rs");
+            ProjectFile typesFile = new ProjectFile(testProject.getRoot(), "src/types.rs");
+            CodeUnit describeTrait = classNamed(testAnalyzer, describeFile, "Describe");
+            CodeUnit widget = classNamed(testAnalyzer, typesFile, "Widget");
+            CodeUnit mode = classNamed(testAnalyzer, typesFile, "Mode");
+            CodeUnit plain = classNamed(testAnalyzer, typesFile, "Plain");
+
+            // Derived traits outside the project (Debug, Clone, serde::Serialize) follow the in-project ones as
+            // synthetic code units named by their paths.
+            assertEquals(
+                    List.of(describeTrait.fqName(), "Clone", "Debug"),
+                    fqNames(testAnalyzer.getDirectAncestors(widget)));
+            assertEquals(
+                    List.of(describeTrait.fqName(), "serde.Serialize"),
+                    fqNames(testAnalyzer.getDirectAncestors(mode)));
+            assertEquals(List.of("Debug"), fqNames(testAnalyzer.getDirectAncestors(plain)));
+            assertEquals(Set.of(widget, mode), testAnalyzer.getDirectDescendants(describeTrait));
+
+            CodeUnit debug = testAnalyzer.getDirectAncestors(plain).getFirst();
+            assertTrue(debug.isSynthetic());
+            assertEquals(Set.of(widget, plain), testAnalyzer.getDirectDescendants(debug));
+        }
+    }
+
+    @Test
+    void testDerivedExternalTraitsAreKeyedByTheirImportedPath() throws Exception {
+        String models =
+                """
+                use serde::Serialize;
+
+                #[derive(Serialize)]
+                pub struct Imported;
+
+                #[derive(serde::Serialize)]
+                pub struct Qualified;
+
+                #[derive(
+                    Clone, // not `Copy`
+                    /* serde::Deserialize, */
+                )]
+                #[cfg_attr(all(feature = "serde", not(test)), derive(serde::Deserialize))]
+                pub struct Commented;
+                """;
+
+        try (var testProject = InlineTestProjectCreator.code(models, "src/models.rs").build()) {
+            var testAnalyzer = new RustAnalyzer(testProject);
+            ProjectFile modelsFile = new ProjectFile(testProject.getRoot(), "src/models.rs");
+            CodeUnit imported = classNamed(testAnalyzer, modelsFile, "Imported");
+            CodeUnit qualified = classNamed(testAnalyzer, modelsFile, "Qualified");
+            CodeUnit commented = classNamed(testAnalyzer, modelsFile, "Commented");
+
+            assertEquals(List.of("serde.Serialize"), fqNames(testAnalyzer.getDirectAncestors(imported)));
+            assertEquals(List.of("serde.Serialize"), fqNames(testAnalyzer.getDirectAncestors(qualified)));
+            assertEquals(
+                    List.of("Clone", "serde.Deserialize"), fqNames(testAnalyzer.getDirectAncestors(commented)));
+
+            CodeUnit serialize = testAnalyzer.getDirectAncestors(imported).getFirst();
+            assertEquals(Set.of(imported, qualified), testAnalyzer.getDirectDescendants(serialize));
+        }
+    }
+
+    private static CodeUnit classNamed(RustAnalyzer analyzer, ProjectFile file, String identifier) {
+        return analyzer.getDeclarations(file).stream()
+                .filter(CodeUnit::isClass)
+                .filter(cu -> cu.identifier().equals(identifier))
+                .findFirst()
+                .orElseThrow(() -> new AssertionError(identifier + " should be declared in " + file));
+    }
+
+    private static List<String> fqNames(List<CodeUnit> units) {
+        return units.stream().map(CodeUnit::fqName).toList();
+    }
+
+    private static Set<String> identifiers(Collection<CodeUnit> units) {
+        return units.stream().map(CodeUnit::identifier).collect(Collectors.toSet());
+    }
+}
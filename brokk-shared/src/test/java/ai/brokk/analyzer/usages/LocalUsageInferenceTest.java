@@ -121,6 +121,58 @@ public class LocalUsageInferenceTest {
         assertEquals(confidences.get(1), confidences.get(0));
     }
 
+    @Test
+    public void callResult_followsReturnTypesAndDegradesPerCall() {
+        LocalUsageInference.ReturnTypeResolver resolver = (receiver, method) -> switch (method) {
+            case "get" -> Set.of(target("a", "Conn", true, 0.9));
+            case "transaction" -> Set.of(target("a", "Tx", true, 0.9));
+            default -> Set.of();
+        };
+        var result = LocalUsageInference.infer(
+                List.of(
+                        new LocalUsageEvent.SeedSymbol("pool", Set.of(target("a", "Pool", true, 0.95))),
+                        new LocalUsageEvent.CallResultSymbol("conn", "pool", List.of("get")),
+                        new LocalUsageEvent.CallResultSymbol("tx", "pool", List.of("get", "transaction")),
+                        new LocalUsageEvent.CallResultSymbol("unknown", "pool", List.of("close")),
+                        new LocalUsageEvent.ReceiverAccess(
+                                "conn", "query", ReferenceKind.METHOD_CALL, RANGE, ENCLOSING),
+                        new LocalUsageEvent.ReceiverAccess("tx", "commit", ReferenceKind.METHOD_CALL, RANGE, ENCLOSING),
+                        new LocalUsageEvent.ReceiverAccess(
+                                "unknown", "query", ReferenceKind.METHOD_CALL, RANGE, ENCLOSING)),
+                resolver);
+
+        assertEquals(2, result.size());
+        var conn = result.stream()
+                .filter(r -> r.identifier().equals("query"))
+                .findFirst()
+                .orElseThrow();
+        var tx = result.stream()
+                .filter(r -> r.identifier().equals("commit"))
+                .findFirst()
+                .orElseThrow();
+        assertEquals("Conn", conn.receiverTarget().exportedName());
+        assertEquals("Tx", tx.receiverTarget().exportedName());
+        assertTrue(conn.confidence() > tx.confidence());
+    }
+
+    @Test
+    public void callResult_fanOutOverCapIsDropped() {
+        LocalUsageInference.ReturnTypeResolver resolver = (receiver, method) -> Set.of(
+                target("a", "A", true, 0.9),
+                target("a", "B", true, 0.9),
+                target("a", "C", true, 0.9),
+                target("a", "D", true, 0.9),
+                target("a", "E", true, 0.9));
+        var result = LocalUsageInference.infer(
+                List.of(
+                        new LocalUsageEvent.SeedSymbol("x", Set.of(target("a", "Foo", true, 0.95))),
+                        new LocalUsageEvent.CallResultSymbol("y", "x", List.of("build")),
+                        new LocalUsageEvent.ReceiverAccess("y", "bar", ReferenceKind.METHOD_CALL, RANGE, ENCLOSING)),
+                resolver);
+
+        assertTrue(result.isEmpty());
+    }
+
     private static ReceiverTargetRef target(
             String moduleSpecifier, String exportedName, boolean instanceReceiver, double confidence) {
         return new ReceiverTargetRef(moduleSpecifier, exportedName, instanceReceiver, confidence, null);
@@ -1,5 +1,6 @@
 package ai.brokk.analyzer.usages;
 
+import static java.util.Objects.requireNonNull;
 import static org.junit.jupiter.api.Assertions.assertEquals;
 import static org.junit.jupiter.api.Assertions.assertFalse;
 import static org.junit.jupiter.api.Assertions.assertInstanceOf;
@@ -10,7 +11,10 @@ import ai.brokk.analyzer.ProjectFile;
 import ai.brokk.analyzer.RustAnalyzer;
 import ai.brokk.testutil.InlineTestProjectCreator;
 import java.util.List;
+import java.util.Map;
+import java.util.Optional;
 import java.util.Set;
+import java.util.stream.Collectors;
 import org.junit.jupiter.api.Test;
 
 class RustExportUsageGraphStrategyTest extends AbstractUsageReferenceGraphTest {
@@ -238,6 +242,58 @@ class RustExportUsageGraphStrategyTest extends AbstractUsageReferenceGraphTest {
         }
     }
 
+    @Test
+    void strategyFindsCallersOfTraitImplMethodDeclaredApartFromItsType() throws Exception {
+        String shapes =
+                """
+                pub trait Drawable {
+                    fn draw(&self);
+                }
+                """;
+        String point = "pub struct Point;\n";
+        String render =
+                """
+                use crate::point::Point;
+                use crate::shapes::Drawable;
+
+                impl Drawable for Point {
+                    fn draw(&self) {}
+                }
+                """;
+        String consumer =
+                """
+                use crate::point::Point;
+                use crate::shapes::Drawable as _;
+
+                fn main() {
+                    let p: Point = Point {};
+                    p.draw();
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(shapes, "src/shapes.rs")
+                .addFileContents(point, "src/point.rs")
+                .addFileContents(render, "src/render.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile shapesFile = projectFile(project.getAllFiles(), "src/shapes.rs");
+            ProjectFile renderFile = projectFile(project.getAllFiles(), "src/render.rs");
+            var strategy = new RustExportUsageGraphStrategy(analyzer);
+
+            List<CodeUnit> targets = List.of(
+                    member(analyzer, renderFile, "Point", "draw"), member(analyzer, shapesFile, "Drawable", "draw"));
+            for (CodeUnit target : targets) {
+                assertTrue(strategy.canHandle(target), target.toString());
+                FuzzyResult result = strategy.findUsages(List.of(target), Set.of(), 1000);
+                assertEquals(
+                        1,
+                        ((FuzzyResult.Success) result).hitsByOverload().get(target).size(),
+                        target.toString());
+            }
+        }
+    }
+
     @Test
     void exactMemberCacheReturnsConcreteMemberAcrossRepeatedLookups() throws Exception {
         String service =
@@ -339,6 +395,35 @@ class RustExportUsageGraphStrategyTest extends AbstractUsageReferenceGraphTest {
         }
     }
 
+    @Test
+    void externCrateDeclarationsBindNamespaceImports() throws Exception {
+        String main =
+                """
+                extern crate serde;
+                extern crate log as logging;
+                extern crate alloc as _;
+
+                fn main() {
+                    logging::info!("ready");
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(main, "src/main.rs").build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile mainFile = projectFile(project.getAllFiles(), "src/main.rs");
+            ImportBinder binder = analyzer.importBinderOf(mainFile);
+
+            assertEquals(
+                    new ImportBinder.ImportBinding("serde", ImportBinder.ImportKind.NAMESPACE, null),
+                    binder.bindings().get("serde"));
+            assertEquals(
+                    new ImportBinder.ImportBinding("log", ImportBinder.ImportKind.NAMESPACE, null),
+                    binder.bindings().get("logging"));
+            assertFalse(binder.bindings().containsKey("log"));
+            assertFalse(binder.bindings().containsKey("_"));
+        }
+    }
+
     @Test
     void strategyFindsSameFileStructReferencesInReturnTypesAndLiterals() throws Exception {
         String summary =
@@ -409,6 +494,241 @@ class RustExportUsageGraphStrategyTest extends AbstractUsageReferenceGraphTest {
         }
     }
 
+    @Test
+    void strategyClassifiesUsagesAsReadWriteBorrowMoveOrType() throws Exception {
+        String geometry =
+                """
+                pub struct Point {
+                    pub x: i32,
+                    pub label: String,
+                    pub tags: Vec<String>,
+                    pub log: Journal,
+                }
+
+                pub struct Journal;
+
+                impl Journal {
+                    pub fn record(&mut self) {}
+
+                    pub fn close(self) {}
+                }
+
+                impl Point {
+                    pub fn nudge(&mut self) {}
+
+                    pub fn area(&self) -> i32 {
+                        0
+                    }
+
+                    pub fn into_label(self) -> String {
+                        String::new()
+                    }
+                }
+                """;
+        String consumer =
+                """
+                use crate::geometry::{Journal, Point};
+
+                fn run() -> String {
+                    let mut p: Point = Point { x: 1, label: String::new(), tags: Vec::new(), log: Journal };
+                    p.x += 1;
+                    p.x = 2;
+                    let r = &mut p.x;
+                    *r += 1;
+                    let shown = &p.x;
+                    let doubled = p.x + 1;
+                    p.nudge();
+                    p.area();
+                    let name = p.label;
+                    let copied = p.x;
+                    p.tags.push(String::new());
+                    let count = p.tags.len();
+                    p.tags.shrink_to_fit();
+                    p.log.record();
+                    p.log.close();
+                    name
+                }
+
+                fn finish(p: Point) -> String {
+                    p.into_label()
+                }
+
+                fn bytes(p: Point) -> Vec<u8> {
+                    p.label.into_bytes()
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(geometry, "src/geometry.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile geometryFile = projectFile(project.getAllFiles(), "src/geometry.rs");
+            ProjectFile mainFile = projectFile(project.getAllFiles(), "src/main.rs");
+            var strategy = new RustExportUsageGraphStrategy(analyzer);
+
+            CodeUnit x = member(analyzer, geometryFile, "Point", "x");
+            FuzzyResult xResult = strategy.findUsages(List.of(x), project.getAllFiles(), 1000);
+            assertEquals(
+                    Map.of(
+                            4, UsageKind.WRITE,
+                            5, UsageKind.WRITE,
+                            6, UsageKind.WRITE,
+                            7, UsageKind.MUTABLE_BORROW,
+                            9, UsageKind.READ,
+                            10, UsageKind.READ,
+                            14, UsageKind.READ),
+                    kindsByLine(xResult, x, mainFile));
+            assertEquals(
+                    Set.of(4, 5, 6, 7),
+                    kindsByLine(xResult.withKinds(Set.of(UsageKind.WRITE, UsageKind.MUTABLE_BORROW)), x, mainFile)
+                            .keySet());
+
+            CodeUnit label = member(analyzer, geometryFile, "Point", "label");
+            assertEquals(
+                    Map.of(4, UsageKind.WRITE, 13, UsageKind.MOVE, 28, UsageKind.MOVE),
+                    kindsByLine(strategy.findUsages(List.of(label), project.getAllFiles(), 1000), label, mainFile));
+
+            // A method receiver is used the way the method takes self; an unknown method leaves it unclassified.
+            CodeUnit tags = member(analyzer, geometryFile, "Point", "tags");
+            FuzzyResult tagsResult = strategy.findUsages(List.of(tags), project.getAllFiles(), 1000);
+            Map<Integer, Optional<UsageKind>> tagKinds = ((FuzzyResult.Success) tagsResult)
+                    .hitsByOverload().get(tags).stream()
+                            .filter(hit -> hit.file().equals(mainFile))
+                            .collect(Collectors.toMap(UsageHit::line, hit -> Optional.ofNullable(hit.kind())));
+            assertEquals(
+                    Map.of(
+                            4, Optional.of(UsageKind.WRITE),
+                            15, Optional.of(UsageKind.MUTABLE_BORROW),
+                            16, Optional.of(UsageKind.READ),
+                            17, Optional.empty()),
+                    tagKinds);
+
+            CodeUnit log = member(analyzer, geometryFile, "Point", "log");
+            assertEquals(
+                    Map.of(4, UsageKind.WRITE, 18, UsageKind.MUTABLE_BORROW, 19, UsageKind.MOVE),
+                    kindsByLine(strategy.findUsages(List.of(log), project.getAllFiles(), 1000), log, mainFile));
+
+            var methodKinds =
+                    Map.of("nudge", UsageKind.MUTABLE_BORROW, "area", UsageKind.READ, "into_label", UsageKind.MOVE);
+            for (var expected : methodKinds.entrySet()) {
+                CodeUnit method = member(analyzer, geometryFile, "Point", expected.getKey());
+                FuzzyResult result = strategy.findUsages(List.of(method), project.getAllFiles(), 1000);
+                assertEquals(
+                        Set.of(expected.getValue()),
+                        Set.copyOf(kindsByLine(result, method, mainFile).values()),
+                        expected.getKey());
+            }
+
+            CodeUnit point = analyzer.resolveRustTypeName(geometryFile, "Point").orElseThrow();
+            FuzzyResult pointResult = strategy.findUsages(List.of(point), project.getAllFiles(), 1000);
+            Set<UsageHit> pointHits = ((FuzzyResult.Success) pointResult).hitsByOverload().get(point);
+            assertFalse(pointHits.isEmpty());
+            assertEquals(
+                    Set.of(UsageKind.TYPE), pointHits.stream().map(UsageHit::kind).collect(Collectors.toSet()));
+        }
+    }
+
+    @Test
+    void strategyTreatsCopyWrappersAsCopyAndPatternBindingsAsMoves() throws Exception {
+        String geometry =
+                """
+                pub struct Point {
+                    pub opt: Option<i32>,
+                    pub pair: (u8, Option<&'static str>),
+                    pub names: Option<String>,
+                    pub label: String,
+                }
+
+                pub struct Pair(pub String, pub i32);
+                """;
+        String consumer =
+                """
+                use crate::geometry::{Pair, Point};
+
+                fn run(p: Point, pair: Pair) -> Option<String> {
+                    let a = p.opt;
+                    let b = p.pair;
+                    let Point { label: ref shown, .. } = p;
+                    let Point { label, .. } = &p;
+                    let Point { label, .. } = p;
+                    let Pair(first, count) = pair;
+                    p.names
+                }
+
+                fn show(Point { label, .. }: &Point) {}
+
+                fn take(Point { label, .. }: Point) {}
+                """;
+
+        try (var project = InlineTestProjectCreator.code(geometry, "src/geometry.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile geometryFile = projectFile(project.getAllFiles(), "src/geometry.rs");
+            ProjectFile mainFile = projectFile(project.getAllFiles(), "src/main.rs");
+            var strategy = new RustExportUsageGraphStrategy(analyzer);
+
+            // A `ref` binding or a destructured reference reads the field; binding it by value moves it.
+            Map<Integer, UsageKind> labelKinds = Map.of(
+                    6, UsageKind.READ, 7, UsageKind.READ, 8, UsageKind.MOVE, 13, UsageKind.READ, 15, UsageKind.MOVE);
+            var expected = Map.of(
+                    member(analyzer, geometryFile, "Point", "opt"), Map.of(4, UsageKind.READ),
+                    member(analyzer, geometryFile, "Point", "pair"), Map.of(5, UsageKind.READ),
+                    member(analyzer, geometryFile, "Point", "names"), Map.of(10, UsageKind.MOVE),
+                    member(analyzer, geometryFile, "Point", "label"), labelKinds,
+                    member(analyzer, geometryFile, "Pair", "0"), Map.of(9, UsageKind.MOVE),
+                    member(analyzer, geometryFile, "Pair", "1"), Map.of(9, UsageKind.READ));
+            for (var entry : expected.entrySet()) {
+                CodeUnit field = entry.getKey();
+                FuzzyResult result = strategy.findUsages(List.of(field), project.getAllFiles(), 1000);
+                assertEquals(entry.getValue(), kindsByLine(result, field, mainFile), field.identifier());
+            }
+        }
+    }
+
+    @Test
+    void strategyReadsFieldsAStructUpdateTakesFromItsBase() throws Exception {
+        String geometry =
+                """
+                pub struct Point {
+                    pub x: i32,
+                    pub y: i32,
+                    pub label: String,
+                }
+                """;
+        String consumer =
+                """
+                use crate::geometry::Point;
+
+                fn rebuild(base: Point) -> Point {
+                    Point { x: 1, ..base }
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(geometry, "src/geometry.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile geometryFile = projectFile(project.getAllFiles(), "src/geometry.rs");
+            ProjectFile mainFile = projectFile(project.getAllFiles(), "src/main.rs");
+            var strategy = new RustExportUsageGraphStrategy(analyzer);
+
+            var expected = Map.of("x", UsageKind.WRITE, "y", UsageKind.READ, "label", UsageKind.MOVE);
+            for (var entry : expected.entrySet()) {
+                CodeUnit field = member(analyzer, geometryFile, "Point", entry.getKey());
+                FuzzyResult result = strategy.findUsages(List.of(field), project.getAllFiles(), 1000);
+                assertEquals(Map.of(4, entry.getValue()), kindsByLine(result, field, mainFile), entry.getKey());
+            }
+        }
+    }
+
+    private static Map<Integer, UsageKind> kindsByLine(FuzzyResult result, CodeUnit target, ProjectFile file) {
+        return ((FuzzyResult.Success) result)
+                .hitsByOverload().get(target).stream()
+                        .filter(hit -> hit.file().equals(file))
+                        .collect(Collectors.toMap(UsageHit::line, hit -> requireNonNull(hit.kind())));
+    }
+
     private static CodeUnit target(RustAnalyzer analyzer, ProjectFile file, String identifier) {
         return analyzer.getAllDeclarations().stream()
                 .filter(cu -> cu.source().equals(file))
@@ -730,6 +730,116 @@ class RustExportUsageReferenceGraphTest extends AbstractUsageReferenceGraphTest
         }
     }
 
+    @Test
+    void importedAliasChainThroughWrapperSeedsReceiver() throws Exception {
+        String db =
+                """
+                use std::sync::Arc;
+
+                pub struct Connection;
+                impl Connection {
+                    pub fn query(&self) {}
+                }
+
+                type Shared = Arc<Connection>;
+                pub type ConnRef = Shared;
+                """;
+        String consumer =
+                """
+                use crate::db::ConnRef;
+
+                fn run(conn: ConnRef) {
+                    conn.query();
+                    let again: ConnRef = conn;
+                    again.query();
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(db, "src/db.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile dbFile = projectFile(project.getAllFiles(), "src/db.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+            CodeUnit target = member(analyzer, dbFile, "Connection", "query");
+
+            assertEquals(2, find(analyzer, dbFile, "Connection", target, consumerFile).hits().size());
+        }
+    }
+
+    @Test
+    void traitObjectAliasDoesNotSeedReceiver() throws Exception {
+        String service =
+                """
+                pub struct Foo;
+                impl Foo {
+                    pub fn bar(&self) {}
+                }
+                """;
+        String consumer =
+                """
+                use crate::service::Foo;
+
+                type Handle = Box<dyn std::fmt::Debug>;
+
+                fn run(value: Handle) {
+                    value.bar();
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(service, "src/service.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            assertTrue(find(analyzer, serviceFile, "Foo", member(analyzer, serviceFile, "Foo", "bar"), consumerFile)
+                    .hits()
+                    .isEmpty());
+        }
+    }
+
+    @Test
+    void selfTypeInImplSeedsReceiver() throws Exception {
+        String service =
+                """
+                pub struct Foo;
+                impl Foo {
+                    pub fn new() -> Foo { Foo }
+                    pub fn bar(&self) {}
+                }
+                """;
+        String consumer =
+                """
+                use crate::service::Foo;
+
+                impl Foo {
+                    pub fn twin(&self) -> Foo {
+                        let typed: Self = Foo {};
+                        typed.bar();
+                        let fresh = Self::new();
+                        fresh.bar();
+                        self.bar();
+                        fresh
+                    }
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(service, "src/service.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+            CodeUnit target = member(analyzer, serviceFile, "Foo", "bar");
+
+            var result = find(analyzer, serviceFile, "Foo", target, consumerFile);
+
+            assertEquals(3, result.hits().size(), result.hits().toString());
+        }
+    }
+
     @Test
     void selfLikeAssociatedConstructorChainSeedsReceiver() throws Exception {
         String service =
@@ -783,6 +893,189 @@ class RustExportUsageReferenceGraphTest extends AbstractUsageReferenceGraphTest
         }
     }
 
+    @Test
+    void declaredReturnTypesSeedReceiversThroughTryUnwrapAndAwait() throws Exception {
+        String service =
+                """
+                pub struct Error;
+                pub struct Conn;
+                impl Conn {
+                    pub fn query(&self) -> u32 { 1 }
+                }
+                pub struct Stats;
+                impl Stats {
+                    pub fn query(&self) -> u32 { 0 }
+                }
+                pub struct Pool;
+                impl Pool {
+                    pub fn connect(url: &str) -> Result<Pool, Error> { todo!() }
+                    pub fn get(&self) -> Result<Conn, Error> { todo!() }
+                    pub async fn get_async(&self) -> Option<Conn> { todo!() }
+                    pub fn stats(&self) -> Stats { Stats }
+                }
+                pub fn open_pool() -> Pool { Pool }
+                """;
+        String consumer =
+                """
+                use crate::service::{open_pool, Error, Pool};
+
+                fn run(pool: &Pool) -> Result<u32, Error> {
+                    let conn = pool.get()?;
+                    conn.query();
+                    pool.get().unwrap().query();
+                    pool.stats().query();
+                    let fresh = Pool::connect("db")?.get()?;
+                    Ok(fresh.query())
+                }
+
+                async fn run_async() {
+                    let pool = open_pool();
+                    let conn = pool.get_async().await.unwrap();
+                    conn.query();
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(service, "src/service.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            var connQuery =
+                    find(analyzer, serviceFile, "Conn", member(analyzer, serviceFile, "Conn", "query"), consumerFile);
+            assertEquals(4, connQuery.hits().size(), connQuery.hits().toString());
+
+            var statsQuery =
+                    find(analyzer, serviceFile, "Stats", member(analyzer, serviceFile, "Stats", "query"), consumerFile);
+            assertEquals(1, statsQuery.hits().size(), statsQuery.hits().toString());
+        }
+    }
+
+    @Test
+    void builderChainFollowsReturnTypesAcrossCalls() throws Exception {
+        String service =
+                """
+                pub struct Server;
+                impl Server {
+                    pub fn serve(&self) {}
+                }
+                pub struct Builder {
+                    listen: u16,
+                }
+                impl Builder {
+                    pub fn new() -> Self { Builder { listen: 0 } }
+                    pub fn port(mut self, port: u16) -> Self { self.listen = port; self }
+                    pub fn build(self) -> Server { Server }
+                }
+                """;
+        String consumer =
+                """
+                use crate::service::Builder;
+
+                fn run() {
+                    let server = Builder::new().port(80).build();
+                    server.serve();
+                    Builder::new().port(81).build().serve();
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(service, "src/service.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            CodeUnit serve = member(analyzer, serviceFile, "Server", "serve");
+            var serveCalls = find(analyzer, serviceFile, "Server", serve, consumerFile);
+            assertEquals(2, serveCalls.hits().size(), serveCalls.hits().toString());
+
+            CodeUnit port = member(analyzer, serviceFile, "Builder", "port");
+            var portCalls = find(analyzer, serviceFile, "Builder", port, consumerFile);
+            assertEquals(2, portCalls.hits().size(), portCalls.hits().toString());
+        }
+    }
+
+    @Test
+    void structFieldsResolveThroughReceiversLiteralsAndPatterns() throws Exception {
+        String service =
+                """
+                pub struct Point {
+                    pub x: i32,
+                    pub y: i32,
+                }
+                pub struct Line {
+                    pub start: Point,
+                    pub end: Point,
+                }
+                pub struct Meters(pub f64, pub f64);
+                """;
+        String consumer =
+                """
+                use crate::service::{Line, Meters, Point};
+
+                fn read(p: &Point, line: &Line) -> i32 {
+                    let Point { x: px, .. } = p;
+                    if let Point { x, y: 0 } = line.end {
+                        return x;
+                    }
+                    match *p {
+                        Point { x: 0, .. } => px + line.start.x,
+                        Point { y, .. } => y + p.x,
+                    }
+                }
+
+                fn build(base: Point) -> Point {
+                    let x = 3;
+                    let moved = Point { x, ..base };
+                    Point { x: moved.x, y: 1 }
+                }
+
+                fn measure(m: Meters) -> f64 {
+                    let Meters(first, _) = m;
+                    let Meters(_, second) = m;
+                    m.0 + first + second
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(service, "src/service.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            var x = find(analyzer, serviceFile, "Point", member(analyzer, serviceFile, "Point", "x"), consumerFile);
+            assertEquals(8, x.hits().size(), x.hits().toString());
+            assertEquals(
+                    2,
+                    x.hits().stream()
+                            .filter(hit -> hit.kind() == ReferenceKind.FIELD_WRITE)
+                            .count());
+
+            // `Point { x, ..base }` reads the `y` it leaves out from `base`.
+            var y = find(analyzer, serviceFile, "Point", member(analyzer, serviceFile, "Point", "y"), consumerFile);
+            assertEquals(4, y.hits().size(), y.hits().toString());
+            assertEquals(
+                    1,
+                    y.hits().stream()
+                            .filter(hit -> hit.kind() == ReferenceKind.FIELD_WRITE)
+                            .count());
+
+            var start =
+                    find(analyzer, serviceFile, "Line", member(analyzer, serviceFile, "Line", "start"), consumerFile);
+            assertEquals(1, start.hits().size(), start.hits().toString());
+
+            var first =
+                    find(analyzer, serviceFile, "Meters", member(analyzer, serviceFile, "Meters", "0"), consumerFile);
+            assertEquals(2, first.hits().size(), first.hits().toString());
+            var second =
+                    find(analyzer, serviceFile, "Meters", member(analyzer, serviceFile, "Meters", "1"), consumerFile);
+            assertEquals(1, second.hits().size(), second.hits().toString());
+        }
+    }
+
     @Test
     void selfFieldAsRefLetElseSeedsReceiverFromStructFieldType() throws Exception {
         String service =
@@ -1012,7 +1305,7 @@ class RustExportUsageReferenceGraphTest extends AbstractUsageReferenceGraphTest
     }
 
     @Test
-    void associatedTypeResolvesAsStaticAssociatedField() throws Exception {
+    void associatedTypeResolvesToItsImplMember() throws Exception {
         String service =
                 """
                 pub struct Foo;
@@ -1033,8 +1326,7 @@ class RustExportUsageReferenceGraphTest extends AbstractUsageReferenceGraphTest
             var analyzer = new RustAnalyzer(project);
             ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
             ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
-            CodeUnit assocType =
-                    CodeUnit.field(serviceFile, "", "Foo.AssocType").withSynthetic(true);
+            CodeUnit assocType = CodeUnit.cls(serviceFile, "", "Foo.AssocType");
 
             assertEquals(
                     1,
@@ -1100,6 +1392,105 @@ class RustExportUsageReferenceGraphTest extends AbstractUsageReferenceGraphTest
         }
     }
 
+    @Test
+    void globImportFollowsPubUseGlobChains() throws Exception {
+        String shapes = "pub struct Circle;\n";
+        String geometry = "pub use crate::shapes::*;\n";
+        String prelude = "pub use crate::geometry::*;\n";
+        String consumer =
+                """
+                use crate::prelude::*;
+
+                fn run() {
+                    let _ = Circle {};
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(shapes, "src/shapes.rs")
+                .addFileContents(geometry, "src/geometry.rs")
+                .addFileContents(prelude, "src/prelude.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile shapesFile = projectFile(project.getAllFiles(), "src/shapes.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            assertEquals(
+                    1, find(analyzer, shapesFile, "Circle", consumerFile).hits().size());
+        }
+    }
+
+    @Test
+    void globImportsAreShadowedByLocalAndExplicitNamesAndAmbiguousNamesBindNothing() throws Exception {
+        String first = """
+                pub struct Circle;
+                pub struct Square;
+                pub struct Line;
+                """;
+        String second = """
+                pub struct Circle;
+                pub struct Square;
+                """;
+        String consumer =
+                """
+                use crate::first::*;
+                use crate::second::*;
+                use crate::second::Square;
+
+                struct Line;
+
+                fn run() {
+                    let _ = Circle {};
+                    let _ = Square {};
+                    let _ = Line {};
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(first, "src/first.rs")
+                .addFileContents(second, "src/second.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile firstFile = projectFile(project.getAllFiles(), "src/first.rs");
+            ProjectFile secondFile = projectFile(project.getAllFiles(), "src/second.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            assertTrue(find(analyzer, firstFile, "Circle", consumerFile).hits().isEmpty());
+            assertTrue(find(analyzer, secondFile, "Circle", consumerFile).hits().isEmpty());
+            assertTrue(find(analyzer, firstFile, "Square", consumerFile).hits().isEmpty());
+            assertEquals(
+                    1, find(analyzer, secondFile, "Square", consumerFile).hits().size());
+            assertTrue(find(analyzer, firstFile, "Line", consumerFile).hits().isEmpty());
+        }
+    }
+
+    @Test
+    void globImportsOfTheSameReexportedItemAreNotAmbiguous() throws Exception {
+        String shapes = "pub struct Circle;\n";
+        String prelude = "pub use crate::shapes::Circle;\n";
+        String consumer =
+                """
+                use crate::shapes::*;
+                use crate::prelude::*;
+
+                fn run() {
+                    let _ = Circle {};
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(shapes, "src/shapes.rs")
+                .addFileContents(prelude, "src/prelude.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile shapesFile = projectFile(project.getAllFiles(), "src/shapes.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            assertEquals(
+                    1, find(analyzer, shapesFile, "Circle", consumerFile).hits().size());
+        }
+    }
+
     @Test
     void barrelReexportFromPrivateModuleResolvesSelectedPublicItem() throws Exception {
         String service = "pub struct Foo;\n";
@@ -1578,6 +1969,157 @@ class RustExportUsageReferenceGraphTest extends AbstractUsageReferenceGraphTest
         }
     }
 
+    @Test
+    void traitMethodCallsResolveThroughImplsInOtherModules() throws Exception {
+        String shapes =
+                """
+                pub trait Drawable {
+                    fn draw(&self);
+                }
+                """;
+        String point = "pub struct Point;\npub struct Circle;\n";
+        String render =
+                """
+                use crate::point::{Circle, Point};
+                use crate::shapes::Drawable;
+
+                impl Drawable for Point {
+                    fn draw(&self) {}
+                }
+
+                impl Drawable for Circle {
+                    fn draw(&self) {}
+                }
+                """;
+        String consumer =
+                """
+                use crate::point::{Circle, Point};
+                use crate::shapes::Drawable;
+
+                fn run(p: Point) {
+                    p.draw();
+                    Drawable::draw(&p);
+                    <Point as Drawable>::draw(&p);
+                }
+
+                fn other(c: Circle) {
+                    c.draw();
+                    Drawable::draw(&c);
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(shapes, "src/shapes.rs")
+                .addFileContents(point, "src/point.rs")
+                .addFileContents(render, "src/render.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile shapesFile = projectFile(project.getAllFiles(), "src/shapes.rs");
+            ProjectFile pointFile = projectFile(project.getAllFiles(), "src/point.rs");
+            ProjectFile renderFile = projectFile(project.getAllFiles(), "src/render.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+            CodeUnit traitMethod = member(analyzer, shapesFile, "Drawable", "draw");
+            CodeUnit pointDraw = member(analyzer, renderFile, "Point", "draw");
+
+            assertEquals(pointDraw, analyzer.exactMember(pointFile, "Point", "draw", true));
+            assertEquals(
+                    5,
+                    find(analyzer, shapesFile, "Drawable", traitMethod, consumerFile)
+                            .hits()
+                            .size());
+            assertEquals(
+                    3,
+                    find(analyzer, pointFile, "Point", pointDraw, consumerFile)
+                            .hits()
+                            .size());
+        }
+    }
+
+    @Test
+    void derivedTraitsResolveReceiverCallsOnDerivedTypes() throws Exception {
+        String describe =
+                """
+                pub trait Describe {
+                    fn describe(&self) -> String {
+                        String::new()
+                    }
+                }
+                """;
+        String service =
+                """
+                use crate::describe::Describe;
+
+                #[derive(Clone, Describe)]
+                pub struct Config;
+                """;
+        String consumer =
+                """
+                use crate::service::Config;
+
+                fn run(config: Config) {
+                    let _copy = config.clone();
+                    config.describe();
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(describe, "src/describe.rs")
+                .addFileContents(service, "src/service.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile describeFile = projectFile(project.getAllFiles(), "src/describe.rs");
+            ProjectFile serviceFile = projectFile(project.getAllFiles(), "src/service.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            var typeHits = find(analyzer, serviceFile, "Config", target(analyzer, serviceFile, "Config"), consumerFile)
+                    .hits();
+            assertTrue(
+                    typeHits.stream()
+                            .anyMatch(hit -> consumer.substring(hit.range().startByte(), hit.range().endByte())
+                                    .equals("config.clone")),
+                    typeHits.toString());
+            assertEquals(
+                    1,
+                    find(
+                                    analyzer,
+                                    describeFile,
+                                    "Describe",
+                                    member(analyzer, describeFile, "Describe", "describe"),
+                                    consumerFile)
+                            .hits()
+                            .size());
+        }
+    }
+
+    @Test
+    void exportedMacroInvocationResolvesAcrossFiles() throws Exception {
+        String macros =
+                """
+                #[macro_export]
+                macro_rules! square {
+                    ($x:expr) => { $x * $x };
+                }
+                """;
+        String consumer =
+                """
+                fn run() -> i32 {
+                    square!(3)
+                }
+                """;
+
+        try (var project = InlineTestProjectCreator.code(macros, "src/macros.rs")
+                .addFileContents(consumer, "src/main.rs")
+                .build()) {
+            var analyzer = new RustAnalyzer(project);
+            ProjectFile macrosFile = projectFile(project.getAllFiles(), "src/macros.rs");
+            ProjectFile consumerFile = projectFile(project.getAllFiles(), "src/main.rs");
+
+            var result = find(analyzer, macrosFile, "square", consumerFile);
+
+            assertEquals(1, result.hits().size());
+        }
+    }
+
     private static ReferenceGraphResult find(
             RustAnalyzer analyzer, ProjectFile definingFile, String exportName, ProjectFile candidate)
             throws InterruptedException {
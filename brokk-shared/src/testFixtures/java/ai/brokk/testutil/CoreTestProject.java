@@ -31,6 +31,7 @@ public final class CoreTestProject implements ICoreProject {
 
     private Set<Language> analyzerLanguages;
     private final Map<Language, List<String>> sourceRootsByLanguage = new ConcurrentHashMap<>();
+    private final Map<Language, String> settingsByLanguage = new ConcurrentHashMap<>();
 
     public CoreTestProject(Path root, Set<Language> analyzerLanguages) {
         this.root = root.toAbsolutePath().normalize();
@@ -133,6 +134,16 @@ public final class CoreTestProject implements ICoreProject {
         sourceRootsByLanguage.put(language, List.copyOf(roots));
     }
 
+    @Override
+    public String getLanguageSettings(Language language) {
+        return settingsByLanguage.getOrDefault(language, "");
+    }
+
+    @Override
+    public void setLanguageSettings(Language language, String json) {
+        settingsByLanguage.put(language, json);
+    }
+
     @Override
     public boolean isGitignored(Path relPath) {
         return false;
@@ -172,6 +172,16 @@ public final class InlineCoreProject {
             project.setSourceRoots(language, roots);
         }
 
+        @Override
+        public String getLanguageSettings(Language language) {
+            return project.getLanguageSettings(language);
+        }
+
+        @Override
+        public void setLanguageSettings(Language language, String json) {
+            project.setLanguageSettings(language, json);
+        }
+
         @Override
         public boolean isGitignored(Path relPath) {
             return project.isGitignored(relPath);
@@ -127,6 +127,16 @@ public final class InlineTestProjectCreator {
             core.setSourceRoots(language, roots);
         }
 
+        @Override
+        public String getLanguageSettings(Language language) {
+            return core.getLanguageSettings(language);
+        }
+
+        @Override
+        public void setLanguageSettings(Language language, String json) {
+            core.setLanguageSettings(language, json);
+        }
+
         @Override
         public boolean isGitignored(Path relPath) {
             return core.isGitignored(relPath);